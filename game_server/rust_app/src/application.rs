@@ -1,3 +1,6 @@
+pub mod bot;
 pub mod game;
 pub mod matchmaking;
+pub mod player;
+pub mod simulator;
 pub mod websocket;
@@ -0,0 +1,3 @@
+pub mod bot_player;
+pub mod bot_turn_submitter;
+pub mod play_vs_bot_usecase;
@@ -0,0 +1,244 @@
+use uuid::Uuid;
+
+use crate::{
+    application::matchmaking::matchmaking_dto::CreateUnitDto,
+    domain::{
+        player_management::models::player::player_id::player_id::PlayerId,
+        triggergame_simulator::{
+            configs::{game_config::GameConfig, trigger_status::TriggerStatus},
+            models::{
+                action::{
+                    action_type::action_type::{ActionType, ActionTypeValue},
+                    trigger_azimuth::trigger_azimuth::TriggerAzimuth,
+                    Action,
+                },
+                step::{step::Step, step_id::step_id::StepId},
+            },
+        },
+        unit_management::models::{
+            unit::{position::position::Position, Unit},
+            unit_type::unit_type_spec::UnitTypeSpec,
+        },
+    },
+};
+
+/// ボットプレイヤー
+/// 対戦相手がいなくても遊べるよう、ユニットの行動を簡単な方針で決める
+///
+/// # 行動方針
+/// - 最も近い敵ユニットを目標とし、両トリガーを目標に向ける
+/// - メイントリガーの射程外であれば目標に近づき、射程内であればその場で待機する
+/// - トリガーHPが減ったユニットは目標から離れる
+pub struct BotPlayer;
+
+impl BotPlayer {
+    /// ボットのプレイヤーID
+    /// 登録済みのプレイヤーではないため、ボットとの対戦はレーティングに反映されない
+    const PLAYER_ID: &'static str = "00000000-0000-4000-8000-000000000b07";
+    /// 撤退を始めるトリガーHP
+    const RETREAT_TRIGGER_HP: i32 = 30;
+    /// 攻撃できるよう移動に使わずに残しておく行動ポイント
+    const RESERVED_ACTION_POINTS: i32 = 1;
+
+    /// ボットのプレイヤーIDを取得
+    pub fn player_id() -> PlayerId {
+        PlayerId::new(Self::PLAYER_ID.to_string())
+    }
+
+    /// 指定されたプレイヤーがボットかどうか
+    pub fn is_bot(player_id: &PlayerId) -> bool {
+        player_id.value() == Self::PLAYER_ID
+    }
+
+    /// ボットが出撃させるユニット
+    pub fn deploy_units() -> Vec<CreateUnitDto> {
+        [
+            ("MIKUMO_OSAMU", 4, "RAYGUST", "ASTEROID"),
+            ("KUGA_YUMA", 12, "SCORPION", "SHIELD"),
+            ("AMATORI_CHIKA", 20, "IBIS", "BAGWORM"),
+            ("HYUSE_KURONIN", 28, "KOGETSU", "SHIELD"),
+        ]
+        .into_iter()
+        .map(
+            |(unit_type_id, initial_x, main_trigger_id, sub_trigger_id)| CreateUnitDto {
+                unit_type_id: unit_type_id.to_string(),
+                initial_x,
+                initial_y: 34,
+                using_main_trigger_id: main_trigger_id.to_string(),
+                using_sub_trigger_id: sub_trigger_id.to_string(),
+                main_trigger_ids: vec![main_trigger_id.to_string()],
+                sub_trigger_ids: vec![sub_trigger_id.to_string()],
+            },
+        )
+        .collect()
+    }
+
+    /// ターン開始時点のユニット情報から、ボットのユニットの行動を決める
+    /// player_id: ボットとして行動するプレイヤーID
+    /// units: ゲームに参加している全ユニット
+    ///
+    /// 対戦相手の行動は分からないため、敵ユニットはターン開始時点の位置に留まるものとして計画する
+    pub fn plan_steps(player_id: &PlayerId, units: &[Unit]) -> Vec<Step> {
+        // 敵ユニットの位置は自分の座標系のピクセル座標に変換しておく
+        let enemy_positions: Vec<(i32, i32)> = units
+            .iter()
+            .filter(|u| u.owner_player_id() != player_id && !u.is_bailed_out())
+            .map(|u| u.position().get_enemy_pixel_position())
+            .collect();
+        if enemy_positions.is_empty() {
+            return Vec::new();
+        }
+
+        let mut plans: Vec<UnitPlan> = units
+            .iter()
+            .filter(|u| u.owner_player_id() == player_id && !u.is_bailed_out())
+            .map(UnitPlan::new)
+            .collect();
+        let step_count = plans
+            .iter()
+            .map(|plan| plan.action_points)
+            .max()
+            .unwrap_or(0);
+
+        let mut steps = Vec::new();
+        for _ in 0..step_count {
+            let actions = plans
+                .iter_mut()
+                .map(|plan| plan.next_action(&enemy_positions))
+                .collect();
+            steps.push(Step::create(
+                StepId::new(Uuid::new_v4().to_string()),
+                actions,
+                Vec::new(),
+            ));
+        }
+        steps
+    }
+
+    /// 2点間のピクセル距離
+    fn distance(from: (i32, i32), to: (i32, i32)) -> f64 {
+        (((from.0 - to.0).pow(2) + (from.1 - to.1).pow(2)) as f64).sqrt()
+    }
+
+    /// 指定した位置から目標に向けたトリガーの向き
+    /// 戦闘の方向判定と同じく、真上を0度とした時計回りの角度にする
+    fn azimuth_to(from: (i32, i32), to: (i32, i32)) -> TriggerAzimuth {
+        let angle = ((to.1 - from.1) as f64)
+            .atan2((to.0 - from.0) as f64)
+            .to_degrees()
+            + 90.0;
+        TriggerAzimuth::new((angle.round() as i32).rem_euclid(360))
+    }
+
+    /// 隣接するセル
+    /// 奇数列が半マス下にずれた六角形のグリッドとして扱う
+    fn neighbors(position: &Position) -> Vec<Position> {
+        let game_config = GameConfig::get_game_config();
+        let (col, row) = (position.col(), position.row());
+        let side_row_offsets = if col % 2 == 0 { [-1, 0] } else { [0, 1] };
+        let mut candidates = vec![(col, row - 1), (col, row + 1)];
+        for col_offset in [-1, 1] {
+            for row_offset in side_row_offsets {
+                candidates.push((col + col_offset, row + row_offset));
+            }
+        }
+        candidates
+            .into_iter()
+            .filter(|(col, row)| {
+                (0..game_config.gameboard_width()).contains(col)
+                    && (0..game_config.gameboard_height()).contains(row)
+            })
+            .map(|(col, row)| Position::new(col, row))
+            .collect()
+    }
+}
+
+/// ターン中のユニットの行動計画
+struct UnitPlan<'a> {
+    unit: &'a Unit,
+    /// 計画中のユニットの位置
+    position: Position,
+    /// ターン開始時の行動ポイント
+    action_points: i32,
+    /// 残りの移動回数
+    remaining_moves: i32,
+    /// トリガーHPが減り、撤退するかどうか
+    is_retreating: bool,
+    /// メイントリガーの射程（ピクセル長）
+    attack_range: f64,
+}
+
+impl<'a> UnitPlan<'a> {
+    fn new(unit: &'a Unit) -> Self {
+        let action_points = UnitTypeSpec::get_spec(unit.unit_type_id().value())
+            .map(|spec| spec.action_points())
+            .unwrap_or(0);
+        let hex_height = GameConfig::get_game_config().hex_height() as f64;
+        let main_trigger_range =
+            TriggerStatus::get_trigger_status(unit.using_main_trigger_id().value()).range();
+        Self {
+            unit,
+            position: unit.position().clone(),
+            action_points,
+            remaining_moves: (action_points - BotPlayer::RESERVED_ACTION_POINTS).max(0),
+            is_retreating: unit.main_trigger_hp().value() <= BotPlayer::RETREAT_TRIGGER_HP
+                || unit.sub_trigger_hp().value() <= BotPlayer::RETREAT_TRIGGER_HP,
+            // 0.5は、ユニット中心からセルの端までの距離の補正（戦闘の射程判定と同じ）
+            attack_range: hex_height * (main_trigger_range as f64 + 0.5),
+        }
+    }
+
+    /// 次のステップの行動を決めて、計画中の位置を更新する
+    fn next_action(&mut self, enemy_positions: &[(i32, i32)]) -> Action {
+        let current = self.position.get_pixel_position();
+        let target = enemy_positions
+            .iter()
+            .copied()
+            .min_by(|a, b| {
+                BotPlayer::distance(current, *a).total_cmp(&BotPlayer::distance(current, *b))
+            })
+            .unwrap_or(current);
+        let current_distance = BotPlayer::distance(current, target);
+
+        let destination = if self.remaining_moves <= 0 {
+            None
+        } else if self.is_retreating {
+            // 目標から最も離れられるセルへ移動
+            BotPlayer::neighbors(&self.position)
+                .into_iter()
+                .map(|p| (BotPlayer::distance(p.get_pixel_position(), target), p))
+                .filter(|(distance, _)| *distance > current_distance)
+                .max_by(|a, b| a.0.total_cmp(&b.0))
+        } else if current_distance >= self.attack_range {
+            // 目標に最も近づけるセルへ移動
+            BotPlayer::neighbors(&self.position)
+                .into_iter()
+                .map(|p| (BotPlayer::distance(p.get_pixel_position(), target), p))
+                .filter(|(distance, _)| *distance < current_distance)
+                .min_by(|a, b| a.0.total_cmp(&b.0))
+        } else {
+            None
+        };
+
+        let action_type = match destination {
+            Some((_, position)) => {
+                self.position = position;
+                self.remaining_moves -= 1;
+                ActionTypeValue::Move
+            }
+            None => ActionTypeValue::Wait,
+        };
+        // 移動後の位置から目標にトリガーを向ける
+        let azimuth = BotPlayer::azimuth_to(self.position.get_pixel_position(), target);
+        Action::create(
+            ActionType::new(action_type),
+            self.unit.unit_id().clone(),
+            self.unit.unit_type_id().clone(),
+            self.position.clone(),
+            self.unit.using_main_trigger_id().clone(),
+            self.unit.using_sub_trigger_id().clone(),
+            azimuth.clone(),
+            azimuth,
+        )
+    }
+}
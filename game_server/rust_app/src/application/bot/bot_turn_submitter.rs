@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use crate::{
+    application::{bot::bot_player::BotPlayer, game::process_turn_usecase::ProcessTurnUseCase},
+    domain::{
+        triggergame_simulator::{
+            models::{game::game_id::game_id::GameId, turn::turn_number::turn_number::TurnNumber},
+            repositories::{
+                game_repository::GameRepository,
+                turn_repository::{TurnRepository, TURN_ALREADY_EXISTS_ERROR},
+            },
+        },
+        unit_management::repositories::unit_repository::UnitRepository,
+    },
+};
+
+/// ボットのターン設定
+/// ボットが参加しているゲームで、プレイヤーと同じターン演算の処理を通してボットの行動を登録する
+pub struct BotTurnSubmitter {
+    game_repository: Arc<dyn GameRepository>,
+    turn_repository: Arc<dyn TurnRepository>,
+    unit_repository: Arc<dyn UnitRepository>,
+    process_turn_usecase: ProcessTurnUseCase,
+}
+
+impl BotTurnSubmitter {
+    pub fn new(
+        game_repository: Arc<dyn GameRepository>,
+        turn_repository: Arc<dyn TurnRepository>,
+        unit_repository: Arc<dyn UnitRepository>,
+        process_turn_usecase: ProcessTurnUseCase,
+    ) -> Self {
+        Self {
+            game_repository,
+            turn_repository,
+            unit_repository,
+            process_turn_usecase,
+        }
+    }
+
+    /// 現在のターンのボットの行動を登録
+    /// ボットが参加していないゲームや、既に行動を登録済みのターン（最終ターンの演算後を含む）では何もしない
+    pub async fn submit(&self, game_id: &GameId) -> Result<(), String> {
+        let game = self.game_repository.get_game_by_id(game_id).await?;
+        let bot_player_id = if BotPlayer::is_bot(game.player2_id()) {
+            game.player2_id().clone()
+        } else if BotPlayer::is_bot(game.player1_id()) {
+            game.player1_id().clone()
+        } else {
+            return Ok(());
+        };
+
+        let turn_data = self
+            .turn_repository
+            .get_turn_data(
+                game_id,
+                &bot_player_id,
+                &TurnNumber::new(game.current_turn_number().value()),
+            )
+            .await?;
+        if turn_data.is_some() {
+            return Ok(());
+        }
+
+        let units = self
+            .unit_repository
+            .get_game_units(game_id)
+            .await
+            .map_err(|e| format!("ユニット情報の取得に失敗しました: {}", e))?;
+        let steps = BotPlayer::plan_steps(&bot_player_id, &units);
+        match self
+            .process_turn_usecase
+            .execute(
+                game_id.value().to_string(),
+                bot_player_id.value().to_string(),
+                steps,
+            )
+            .await
+        {
+            Ok(()) => Ok(()),
+            // 同時に行動を登録した場合は、先に登録された方を正とする
+            Err(e) if e == TURN_ALREADY_EXISTS_ERROR => Ok(()),
+            Err(e) => Err(format!("ボットの行動の登録に失敗しました: {}", e)),
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::{
+    application::{
+        bot::{bot_player::BotPlayer, bot_turn_submitter::BotTurnSubmitter},
+        game::{
+            enemy_unit_dto::EnemyUnitDto, friend_unit_dto::FriendUnitDto,
+            game_event_recorder::GameEventRecorder,
+        },
+        matchmaking::matchmaking_dto::CreateUnitDto,
+        websocket::{websocket_response::WebSocketResponse, websocket_sender::WebSocketSender},
+    },
+    domain::{
+        matching_management::models::matching::MatchingStatusValue,
+        player_management::models::player::player_id::player_id::PlayerId,
+        triggergame_simulator::{
+            models::{
+                game::{game::Game, game_id::game_id::GameId},
+                game_event::game_event_payload::GameEventPayload,
+            },
+            repositories::{
+                game_event_repository::GameEventRepository, game_repository::GameRepository,
+            },
+        },
+        unit_management::repositories::unit_repository::UnitRepository,
+    },
+};
+
+/// ボット対戦の開始
+/// マッチメイキングを経ずにボットを対戦相手とするゲームを作成し、ボットの最初のターンの行動を登録する
+pub struct PlayVsBotUseCase {
+    game_repository: Arc<dyn GameRepository>,
+    unit_repository: Arc<dyn UnitRepository>,
+    game_event_recorder: GameEventRecorder,
+    bot_turn_submitter: BotTurnSubmitter,
+    websocket_sender: Arc<dyn WebSocketSender>,
+}
+
+impl PlayVsBotUseCase {
+    pub fn new(
+        game_repository: Arc<dyn GameRepository>,
+        unit_repository: Arc<dyn UnitRepository>,
+        game_event_repository: Arc<dyn GameEventRepository>,
+        bot_turn_submitter: BotTurnSubmitter,
+        websocket_sender: Arc<dyn WebSocketSender>,
+    ) -> Self {
+        Self {
+            game_repository,
+            unit_repository,
+            game_event_recorder: GameEventRecorder::new(game_event_repository),
+            bot_turn_submitter,
+            websocket_sender,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        player_id: &str,
+        connection_id: &str,
+        units: Vec<CreateUnitDto>,
+    ) -> Result<(), String> {
+        let player_id = PlayerId::new(player_id.to_string());
+        let bot_player_id = BotPlayer::player_id();
+
+        // ゲーム情報を登録
+        let game_id = GameId::new(Uuid::new_v4().to_string());
+        let game = Game::create(game_id.clone(), &player_id, &bot_player_id);
+        self.game_repository.save(&game).await?;
+
+        // ユニット情報を登録
+        let player_units = CreateUnitDto::to_units(&units, &game_id, &player_id);
+        let bot_units =
+            CreateUnitDto::to_units(&BotPlayer::deploy_units(), &game_id, &bot_player_id);
+        for unit in player_units.iter().chain(bot_units.iter()) {
+            self.unit_repository.save(unit).await?;
+        }
+
+        // 対戦開始時点の状態をイベントログに残す
+        let mut events = vec![GameEventPayload::GameStarted {
+            player1_id: game.player1_id().clone(),
+            player2_id: game.player2_id().clone(),
+            simulation_seed: Some(game.simulation_seed().clone()),
+        }];
+        events.extend(player_units.iter().map(GameEventPayload::unit_deployed));
+        events.extend(bot_units.iter().map(GameEventPayload::unit_deployed));
+        // リプレイの生成には対戦開始時のユニット配置が必要なため、記録に失敗した場合は開始しない
+        self.game_event_recorder
+            .record(&game_id, events)
+            .await
+            .map_err(|e| format!("ゲームイベントの記録に失敗しました: {}", e))?;
+
+        // ボットは対戦開始時点で最初のターンの行動を登録しておく
+        self.bot_turn_submitter.submit(&game_id).await?;
+
+        // 公開のマッチングと同じ形式でゲームの開始を通知
+        let response = WebSocketResponse::MatchmakingResult {
+            status: MatchingStatusValue::Completed,
+            game_id: Some(game_id.value().to_string()),
+            enemy_units: EnemyUnitDto::from_units(&bot_units),
+            friend_units: FriendUnitDto::from_units(&player_units),
+        };
+        self.websocket_sender
+            .send_message(connection_id, &response)
+            .await?;
+        Ok(())
+    }
+}
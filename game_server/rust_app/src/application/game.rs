@@ -1,4 +1,19 @@
 pub mod enemy_unit_dto;
 pub mod friend_unit_dto;
+pub mod game_access_guard;
+pub mod game_event_recorder;
 pub mod get_game_state_usecase;
+pub mod get_replay_usecase;
+pub mod get_turn_result_usecase;
+pub mod preview_combat_usecase;
 pub mod process_turn_usecase;
+pub mod process_turn_usecase_test;
+pub mod rating_updater;
+pub mod replay_exporter;
+pub mod resume_session_dto;
+pub mod resume_session_usecase;
+pub mod spectate_game_usecase;
+pub mod spectator_broadcaster;
+pub mod spectator_dto;
+pub mod step_request_dto;
+pub mod stop_spectating_usecase;
@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
 
 use crate::domain::unit_management::models::unit::{
-    position::position::Position, trigger_id::trigger_id::TriggerId, Unit,
+    position::position::Position, status_effect::status_effect::StatusEffect,
+    trigger_id::trigger_id::TriggerId, Unit,
 };
 
 /// マッチメイキングリクエストで受け取るユニット情報
@@ -16,16 +17,16 @@ pub struct EnemyUnitDto {
     pub position: Position,
     pub using_main_trigger_id: String,
     pub using_sub_trigger_id: String,
+    pub main_trigger_azimuth: i32,
+    pub sub_trigger_azimuth: i32,
     pub is_bailout: bool,
+    pub status_effects: Vec<StatusEffect>,
 }
 
 impl EnemyUnitDto {
     /// DTOをドメインエンティティに変換（ファクトリーメソッド）
     pub fn to_enemy_unit_data(unit: &Unit) -> Option<Self> {
-        if unit.using_main_trigger_id().is_bagworm()
-            || unit.using_sub_trigger_id().is_bagworm()
-            || unit.is_bailout_value().value()
-        {
+        if !unit.is_visible_to_enemy() {
             // バグワーム装備中か脱出済みの場合、敵から不可視にするためNoneを返す
             return None;
         }
@@ -35,7 +36,10 @@ impl EnemyUnitDto {
             position: unit.position().clone(),
             using_main_trigger_id: unit.using_main_trigger_id().value().to_string(),
             using_sub_trigger_id: unit.using_sub_trigger_id().value().to_string(),
+            main_trigger_azimuth: unit.main_trigger_azimuth().value(),
+            sub_trigger_azimuth: unit.sub_trigger_azimuth().value(),
             is_bailout: unit.is_bailout_value().value(),
+            status_effects: unit.status_effects().to_vec(),
         })
     }
 
@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
 
 use crate::domain::unit_management::models::unit::{
-    position::position::Position, trigger_id::trigger_id::TriggerId, Unit,
+    position::position::Position, status_effect::status_effect::StatusEffect,
+    trigger_id::trigger_id::TriggerId, Unit,
 };
 
 /// マッチメイキングリクエストで受け取るユニット情報
@@ -20,8 +21,12 @@ pub struct FriendUnitDto {
     pub having_sub_trigger_ids: Vec<TriggerId>,
     pub main_trigger_hp: i32,
     pub sub_trigger_hp: i32,
+    pub main_trigger_azimuth: i32,
+    pub sub_trigger_azimuth: i32,
+    /// 状態効果の補正を反映した視界範囲
     pub sight_range: i32,
     pub is_bailout: bool,
+    pub status_effects: Vec<StatusEffect>,
 }
 
 impl FriendUnitDto {
@@ -37,8 +42,11 @@ impl FriendUnitDto {
             having_sub_trigger_ids: unit.having_sub_trigger_ids().value().clone(),
             main_trigger_hp: unit.main_trigger_hp().value(),
             sub_trigger_hp: unit.sub_trigger_hp().value(),
-            sight_range: unit.sight_range().value(),
+            main_trigger_azimuth: unit.main_trigger_azimuth().value(),
+            sub_trigger_azimuth: unit.sub_trigger_azimuth().value(),
+            sight_range: unit.effective_sight_range(),
             is_bailout: unit.is_bailout_value().value(),
+            status_effects: unit.status_effects().to_vec(),
         }
     }
 
@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use crate::domain::{
+    player_management::models::player::player_id::player_id::PlayerId,
+    triggergame_simulator::{
+        models::game::{
+            game::Game, game_id::game_id::GameId, game_permission::game_permission::GamePermission,
+        },
+        repositories::game_repository::GameRepository,
+    },
+};
+
+/// ゲーム単位のリクエストの権限確認
+/// ゲームに関わらないプレイヤーからのリクエストを、ターンやユニットなどの取得・更新より前に拒否する
+pub struct GameAccessGuard {
+    game_repository: Arc<dyn GameRepository>,
+}
+
+impl GameAccessGuard {
+    pub fn new(game_repository: Arc<dyn GameRepository>) -> Self {
+        Self { game_repository }
+    }
+
+    /// プレイヤーがゲームに対する権限を持つか確認し、確認に使用したゲームを返す
+    pub async fn authorize(
+        &self,
+        game_id: &GameId,
+        player_id: &PlayerId,
+        permission: GamePermission,
+    ) -> Result<Game, String> {
+        let game = self
+            .game_repository
+            .get_game_by_id(game_id)
+            .await
+            .map_err(|e| format!("ゲーム情報の取得に失敗しました: {}", e))?;
+        game.authorize(player_id, permission)?;
+        Ok(game)
+    }
+}
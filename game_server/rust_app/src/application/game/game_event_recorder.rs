@@ -0,0 +1,92 @@
+use std::future::Future;
+use std::sync::Arc;
+
+use crate::domain::triggergame_simulator::{
+    models::{
+        game::game_id::game_id::GameId,
+        game_event::{
+            event_sequence::event_sequence::EventSequence, game_event::GameEvent,
+            game_event_payload::GameEventPayload,
+        },
+    },
+    repositories::game_event_repository::{
+        GameEventRepository, GAME_EVENT_SEQUENCE_CONFLICT_ERROR,
+    },
+};
+
+/// ゲームイベントを通し番号を採番してイベントログに追記する
+pub struct GameEventRecorder {
+    game_event_repository: Arc<dyn GameEventRepository>,
+}
+
+impl GameEventRecorder {
+    /// 他のリクエストと採番が競合した場合の再試行回数
+    const MAX_RETRY_COUNT: usize = 3;
+
+    pub fn new(game_event_repository: Arc<dyn GameEventRepository>) -> Self {
+        Self {
+            game_event_repository,
+        }
+    }
+
+    /// イベントを発生順に追記
+    pub async fn record(
+        &self,
+        game_id: &GameId,
+        payloads: Vec<GameEventPayload>,
+    ) -> Result<(), String> {
+        if payloads.is_empty() {
+            return Ok(());
+        }
+
+        let repository = &self.game_event_repository;
+        self.record_with(game_id, payloads, |events| async move {
+            repository.append(&events).await
+        })
+        .await
+    }
+
+    /// イベントを採番し、writeで他の更新と同じトランザクションに含めて書き込む
+    /// writeは通し番号が競合した場合に`GAME_EVENT_SEQUENCE_CONFLICT_ERROR`を返し、
+    /// その場合は最新の通し番号から採番し直して再試行する
+    pub async fn record_with<F, Fut>(
+        &self,
+        game_id: &GameId,
+        payloads: Vec<GameEventPayload>,
+        write: F,
+    ) -> Result<(), String>
+    where
+        F: Fn(Vec<GameEvent>) -> Fut,
+        Fut: Future<Output = Result<(), String>>,
+    {
+        for _ in 0..Self::MAX_RETRY_COUNT {
+            // 最新の通し番号の続きから採番する
+            let mut sequence = match self
+                .game_event_repository
+                .get_latest_sequence(game_id)
+                .await?
+            {
+                Some(latest) => latest.next(),
+                None => EventSequence::initial(),
+            };
+            let mut events = Vec::new();
+            for payload in &payloads {
+                events.push(GameEvent::create(
+                    game_id.clone(),
+                    sequence.clone(),
+                    payload.clone(),
+                ));
+                sequence = sequence.next();
+            }
+
+            match write(events).await {
+                Ok(()) => return Ok(()),
+                // 他のリクエストが先に追記した場合は採番し直す
+                Err(e) if e == GAME_EVENT_SEQUENCE_CONFLICT_ERROR => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        Err(GAME_EVENT_SEQUENCE_CONFLICT_ERROR.to_string())
+    }
+}
@@ -2,7 +2,10 @@ use std::sync::Arc;
 
 use crate::{
     application::{
-        game::{enemy_unit_dto::EnemyUnitDto, friend_unit_dto::FriendUnitDto},
+        game::{
+            enemy_unit_dto::EnemyUnitDto, friend_unit_dto::FriendUnitDto,
+            game_access_guard::GameAccessGuard,
+        },
         websocket::{websocket_response::WebSocketResponse, websocket_sender::WebSocketSender},
     },
     domain::{
@@ -11,7 +14,10 @@ use crate::{
             repositories::connection_repository::ConnectionRepository,
         },
         triggergame_simulator::{
-            models::game::game_id::game_id::GameId, repositories::game_repository::GameRepository,
+            models::game::{
+                game_id::game_id::GameId, game_permission::game_permission::GamePermission,
+            },
+            repositories::game_repository::GameRepository,
         },
         unit_management::repositories::unit_repository::UnitRepository,
     },
@@ -19,7 +25,7 @@ use crate::{
 
 pub struct GetGameStateUseCase {
     connection_repository: Arc<dyn ConnectionRepository>,
-    game_repository: Arc<dyn GameRepository>,
+    game_access_guard: GameAccessGuard,
     unit_repository: Arc<dyn UnitRepository>,
     websocket_sender: Arc<dyn WebSocketSender>,
 }
@@ -33,19 +39,18 @@ impl GetGameStateUseCase {
     ) -> Self {
         Self {
             connection_repository,
-            game_repository,
+            game_access_guard: GameAccessGuard::new(game_repository),
             unit_repository,
             websocket_sender,
         }
     }
 
     pub async fn execute(&self, game_id: GameId, player_id: PlayerId) -> Result<(), String> {
-        // ゲーム情報の取得
+        // ゲーム情報の取得と参加者の確認
         let game = self
-            .game_repository
-            .get_game_by_id(&game_id)
-            .await
-            .map_err(|e| format!("ゲーム情報の取得に失敗しました: {}", e))?;
+            .game_access_guard
+            .authorize(&game_id, &player_id, GamePermission::Play)
+            .await?;
 
         // ユニット情報の取得
         let units = self
@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use crate::{
+    application::{
+        game::game_access_guard::GameAccessGuard,
+        websocket::{websocket_response::WebSocketResponse, websocket_sender::WebSocketSender},
+    },
+    domain::{
+        player_management::{
+            models::player::player_id::player_id::PlayerId,
+            repositories::connection_repository::ConnectionRepository,
+        },
+        triggergame_simulator::{
+            models::game::{
+                game_id::game_id::GameId, game_permission::game_permission::GamePermission,
+            },
+            repositories::{game_repository::GameRepository, replay_repository::ReplayRepository},
+        },
+    },
+};
+
+pub struct GetReplayUseCase {
+    connection_repository: Arc<dyn ConnectionRepository>,
+    game_access_guard: GameAccessGuard,
+    replay_repository: Arc<dyn ReplayRepository>,
+    websocket_sender: Arc<dyn WebSocketSender>,
+}
+
+impl GetReplayUseCase {
+    pub fn new(
+        connection_repository: Arc<dyn ConnectionRepository>,
+        game_repository: Arc<dyn GameRepository>,
+        replay_repository: Arc<dyn ReplayRepository>,
+        websocket_sender: Arc<dyn WebSocketSender>,
+    ) -> Self {
+        Self {
+            connection_repository,
+            game_access_guard: GameAccessGuard::new(game_repository),
+            replay_repository,
+            websocket_sender,
+        }
+    }
+
+    pub async fn execute(&self, game_id: GameId, player_id: PlayerId) -> Result<(), String> {
+        // リプレイは陣営に依存しないため、観戦権限で閲覧できる
+        self.game_access_guard
+            .authorize(&game_id, &player_id, GamePermission::Spectate)
+            .await?;
+
+        // リプレイはゲーム終了時に生成されるため、終了前のゲームでは見つからない
+        let replay = self
+            .replay_repository
+            .find_by_game_id(&game_id)
+            .await
+            .map_err(|e| format!("リプレイの取得に失敗しました: {}", e))?
+            .ok_or("リプレイが見つかりませんでした。".to_string())?;
+
+        let connection_id = self
+            .connection_repository
+            .get_connection_id(player_id.value())
+            .await
+            .map_err(|e| format!("コネクションIDの取得に失敗しました: {}", e))?;
+
+        let response = WebSocketResponse::GetReplayResult { replay };
+
+        self.websocket_sender
+            .send_message(&connection_id, &response)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use crate::{
+    application::{
+        game::game_access_guard::GameAccessGuard,
+        websocket::{websocket_response::WebSocketResponse, websocket_sender::WebSocketSender},
+    },
+    domain::{
+        player_management::{
+            models::player::player_id::player_id::PlayerId,
+            repositories::connection_repository::ConnectionRepository,
+        },
+        triggergame_simulator::{
+            models::{
+                game::{
+                    game_id::game_id::GameId, game_permission::game_permission::GamePermission,
+                },
+                turn::turn_number::turn_number::TurnNumber,
+            },
+            repositories::{game_repository::GameRepository, turn_repository::TurnRepository},
+        },
+    },
+};
+
+pub struct GetTurnResultUseCase {
+    connection_repository: Arc<dyn ConnectionRepository>,
+    game_access_guard: GameAccessGuard,
+    turn_repository: Arc<dyn TurnRepository>,
+    websocket_sender: Arc<dyn WebSocketSender>,
+}
+
+impl GetTurnResultUseCase {
+    pub fn new(
+        connection_repository: Arc<dyn ConnectionRepository>,
+        game_repository: Arc<dyn GameRepository>,
+        turn_repository: Arc<dyn TurnRepository>,
+        websocket_sender: Arc<dyn WebSocketSender>,
+    ) -> Self {
+        Self {
+            connection_repository,
+            game_access_guard: GameAccessGuard::new(game_repository),
+            turn_repository,
+            websocket_sender,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        game_id: GameId,
+        player_id: PlayerId,
+        turn_number: TurnNumber,
+    ) -> Result<(), String> {
+        self.game_access_guard
+            .authorize(&game_id, &player_id, GamePermission::Play)
+            .await?;
+
+        // 演算済みのターン情報の取得
+        // 演算結果は両プレイヤーのターン情報に保存されているため、自分のターン情報を取得する
+        let mut turn = self
+            .turn_repository
+            .get_turn_data(&game_id, &player_id, &turn_number)
+            .await
+            .map_err(|e| format!("ターン情報の取得に失敗しました: {}", e))?
+            .ok_or("ターン情報が見つかりませんでした。".to_string())?;
+
+        if !turn.is_completed() {
+            return Err("ターンの演算がまだ完了していません".to_string());
+        }
+
+        let connection_id = self
+            .connection_repository
+            .get_connection_id(player_id.value())
+            .await
+            .map_err(|e| format!("コネクションIDの取得に失敗しました: {}", e))?;
+
+        let response = WebSocketResponse::GetTurnResultResult {
+            turn: turn.generate_player_turn(&player_id),
+        };
+
+        self.websocket_sender
+            .send_message(&connection_id, &response)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+use std::sync::Arc;
+
+use crate::{
+    application::{
+        game::game_access_guard::GameAccessGuard,
+        websocket::{websocket_response::WebSocketResponse, websocket_sender::WebSocketSender},
+    },
+    domain::{
+        player_management::{
+            models::player::player_id::player_id::PlayerId,
+            repositories::connection_repository::ConnectionRepository,
+        },
+        triggergame_simulator::{
+            models::{
+                action::action::Action,
+                game::{
+                    game_id::game_id::GameId, game_permission::game_permission::GamePermission,
+                },
+            },
+            repositories::game_repository::GameRepository,
+        },
+        unit_management::{
+            models::unit::unit_id::unit_id::UnitId, repositories::unit_repository::UnitRepository,
+        },
+    },
+};
+
+/// 戦闘予測の取得
+/// 行動設定中のアクションで敵ユニットを攻撃した場合の命中率・ダメージ・ベイルアウト率を返す
+pub struct PreviewCombatUseCase {
+    connection_repository: Arc<dyn ConnectionRepository>,
+    game_access_guard: GameAccessGuard,
+    unit_repository: Arc<dyn UnitRepository>,
+    websocket_sender: Arc<dyn WebSocketSender>,
+}
+
+impl PreviewCombatUseCase {
+    pub fn new(
+        connection_repository: Arc<dyn ConnectionRepository>,
+        game_repository: Arc<dyn GameRepository>,
+        unit_repository: Arc<dyn UnitRepository>,
+        websocket_sender: Arc<dyn WebSocketSender>,
+    ) -> Self {
+        Self {
+            connection_repository,
+            game_access_guard: GameAccessGuard::new(game_repository),
+            unit_repository,
+            websocket_sender,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        game_id: GameId,
+        player_id: PlayerId,
+        action: Action,
+        defending_unit_id: UnitId,
+    ) -> Result<(), String> {
+        // ゲーム情報の取得と参加者の確認
+        self.game_access_guard
+            .authorize(&game_id, &player_id, GamePermission::Play)
+            .await?;
+
+        // ユニット情報の取得
+        let units = self
+            .unit_repository
+            .get_game_units(&game_id)
+            .await
+            .map_err(|e| format!("ユニット情報の取得に失敗しました: {}", e))?;
+
+        // 攻撃側は自分のユニットで、アクションのユニットタイプが一致していること
+        let attacking_unit = units
+            .iter()
+            .find(|u| u.unit_id() == action.unit_id() && u.owner_player_id() == &player_id)
+            .ok_or("攻撃側のユニットが見つかりません".to_string())?;
+        if attacking_unit.unit_type_id() != action.unit_type_id() {
+            return Err("攻撃側のユニットタイプが一致しません".to_string());
+        }
+        // 防御側は相手のユニットで、敵から見えていること
+        // 不可視のユニットは存在しない場合と同じエラーにし、位置や状態を推測できないようにする
+        let defending_unit = units
+            .iter()
+            .find(|u| {
+                u.unit_id() == &defending_unit_id
+                    && u.owner_player_id() != &player_id
+                    && u.is_visible_to_enemy()
+            })
+            .ok_or("防御側のユニットが見つかりません".to_string())?;
+
+        let preview = if attacking_unit.is_bailed_out() {
+            None
+        } else {
+            action.preview_combat(attacking_unit, defending_unit)
+        };
+
+        let connection_id = self
+            .connection_repository
+            .get_connection_id(player_id.value())
+            .await
+            .map_err(|e| format!("コネクションIDの取得に失敗しました: {}", e))?;
+
+        let response = WebSocketResponse::PreviewCombatResult {
+            attacking_unit_id: action.unit_id().value().to_string(),
+            defending_unit_id: defending_unit_id.value().to_string(),
+            preview,
+        };
+        self.websocket_sender
+            .send_message(&connection_id, &response)
+            .await?;
+        Ok(())
+    }
+}
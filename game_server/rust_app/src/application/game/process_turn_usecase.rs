@@ -2,17 +2,34 @@ use std::sync::Arc;
 
 use crate::{
     application::{
-        game,
-        websocket::{websocket_response::WebSocketResponse, websocket_sender::WebSocketSender},
+        bot::bot_player::BotPlayer,
+        game::{
+            self, game_access_guard::GameAccessGuard, game_event_recorder::GameEventRecorder,
+            rating_updater::RatingUpdater, replay_exporter::ReplayExporter,
+            spectator_broadcaster::SpectatorBroadcaster,
+        },
+        websocket::{
+            player_notifier::PlayerNotifier, websocket_response::WebSocketResponse,
+            websocket_sender::WebSocketSender,
+        },
     },
     domain::{
         player_management::{
             models::player::player_id::player_id::PlayerId,
-            repositories::connection_repository::{self, ConnectionRepository},
+            repositories::{
+                connection_repository::{self, ConnectionRepository},
+                outbox_repository::OutboxRepository,
+                player_repository::PlayerRepository,
+            },
         },
         triggergame_simulator::{
             models::{
-                game::{game::Game, game_id::game_id::GameId},
+                game::{
+                    game::Game, game_id::game_id::GameId,
+                    game_permission::game_permission::GamePermission,
+                    spectator_delay::spectator_delay::SpectatorDelay,
+                },
+                game_event::game_event_payload::GameEventPayload,
                 step::step::Step,
                 turn::{
                     turn_id::turn_id::TurnId,
@@ -22,7 +39,13 @@ use crate::{
                     Turn,
                 },
             },
-            repositories::{game_repository::GameRepository, turn_repository::TurnRepository},
+            repositories::{
+                game_event_repository::GameEventRepository,
+                game_repository::{GameRepository, GAME_VERSION_CONFLICT_ERROR},
+                replay_repository::ReplayRepository,
+                turn_repository::{TurnRepository, TURN_ALREADY_EXISTS_ERROR},
+                turn_unit_of_work::TurnUnitOfWork,
+            },
         },
         unit_management::repositories::unit_repository::UnitRepository,
     },
@@ -31,24 +54,61 @@ use crate::{
 pub struct ProcessTurnUseCase {
     connection_repository: Arc<dyn ConnectionRepository>,
     game_repository: Arc<dyn GameRepository>,
+    game_access_guard: GameAccessGuard,
     turn_repository: Arc<dyn TurnRepository>,
     unit_repository: Arc<dyn UnitRepository>,
+    turn_unit_of_work: Arc<dyn TurnUnitOfWork>,
+    game_event_recorder: GameEventRecorder,
+    replay_exporter: ReplayExporter,
+    rating_updater: RatingUpdater,
+    player_notifier: PlayerNotifier,
+    spectator_broadcaster: SpectatorBroadcaster,
     websocket_sender: Arc<dyn WebSocketSender>,
 }
 
 impl ProcessTurnUseCase {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         connection_repository: Arc<dyn ConnectionRepository>,
         game_repository: Arc<dyn GameRepository>,
         turn_repository: Arc<dyn TurnRepository>,
         unit_repository: Arc<dyn UnitRepository>,
+        turn_unit_of_work: Arc<dyn TurnUnitOfWork>,
+        game_event_repository: Arc<dyn GameEventRepository>,
+        replay_repository: Arc<dyn ReplayRepository>,
+        player_repository: Arc<dyn PlayerRepository>,
+        outbox_repository: Arc<dyn OutboxRepository>,
         websocket_sender: Arc<dyn WebSocketSender>,
+        spectator_delay: SpectatorDelay,
     ) -> Self {
+        let replay_exporter = ReplayExporter::new(
+            game_event_repository.clone(),
+            turn_repository.clone(),
+            replay_repository,
+        );
+        let player_notifier = PlayerNotifier::new(
+            connection_repository.clone(),
+            outbox_repository,
+            websocket_sender.clone(),
+        );
+        let spectator_broadcaster = SpectatorBroadcaster::new(
+            connection_repository.clone(),
+            turn_repository.clone(),
+            websocket_sender.clone(),
+            spectator_delay,
+        );
         Self {
             connection_repository,
+            game_access_guard: GameAccessGuard::new(game_repository.clone()),
             game_repository,
             turn_repository,
             unit_repository,
+            turn_unit_of_work,
+            game_event_recorder: GameEventRecorder::new(game_event_repository.clone()),
+            replay_exporter,
+            rating_updater: RatingUpdater::new(player_repository),
+            player_notifier,
+            spectator_broadcaster,
             websocket_sender,
         }
     }
@@ -61,12 +121,11 @@ impl ProcessTurnUseCase {
     ) -> Result<(), String> {
         let game_id = GameId::new(game_id);
         let player_id = PlayerId::new(player_id);
-        // ゲーム情報の取得
+        // ゲーム情報の取得と参加者の確認
         let mut game = self
-            .game_repository
-            .get_game_by_id(&game_id)
-            .await
-            .map_err(|e| format!("ゲーム情報の取得に失敗しました: {}", e))?;
+            .game_access_guard
+            .authorize(&game_id, &player_id, GamePermission::Play)
+            .await?;
 
         // すでに登録されていないか確認
         let turn_data = self
@@ -78,7 +137,7 @@ impl ProcessTurnUseCase {
             )
             .await?;
         if turn_data.is_some() {
-            return Err("このターンの情報はすでに登録されています。".to_string());
+            return Err(TURN_ALREADY_EXISTS_ERROR.to_string());
         }
 
         // ターンエンティティの作成
@@ -98,11 +157,31 @@ impl ProcessTurnUseCase {
             steps,
         );
 
-        // リクエストされたターンの情報をDBに登録
-        self.turn_repository
-            .save(&turn)
+        // リクエストされたターンの情報をDBに登録し、登録したことをイベントログに残す
+        // 同時に同じターンが登録された場合は、先に登録された方を正とする
+        let turn_unit_of_work = &self.turn_unit_of_work;
+        let submitted_turn = &turn;
+        self.game_event_recorder
+            .record_with(
+                &game_id,
+                vec![GameEventPayload::TurnSubmitted {
+                    player_id: player_id.clone(),
+                    turn_number: turn.turn_number().clone(),
+                }],
+                |events| async move {
+                    turn_unit_of_work
+                        .submit_turn(submitted_turn, &events)
+                        .await
+                },
+            )
             .await
-            .map_err(|e| format!("ターン情報の登録に失敗しました: {}", e))?;
+            .map_err(|e| {
+                if e == TURN_ALREADY_EXISTS_ERROR {
+                    e
+                } else {
+                    format!("ターン情報の登録に失敗しました: {}", e)
+                }
+            })?;
 
         // すでに登録済みの対戦相手のターン情報を取得
         let opponent_turn_data = self
@@ -115,15 +194,26 @@ impl ProcessTurnUseCase {
             .await?;
 
         // 対戦相手のターン情報が登録済みでなければ何もしないで返す
-        if opponent_turn_data.is_none() {
-            return Ok(());
-        } else {
+        let opponent_turn = match opponent_turn_data {
+            Some(opponent_turn) => opponent_turn,
+            None => return Ok(()),
+        };
+        println!(
+            "対戦相手のターン情報が登録されていることを確認しました ゲームID: {}, プレイヤーID: {}, ターン番号: {:?}",
+            game_id.value(),
+            game.get_opponent_player_id(&player_id)?.value(),
+            TurnNumber::new(game.current_turn_number().value()).value()
+        );
+
+        // 対戦相手のリクエストが先にこのターンを演算・反映済みの場合は、そちらの演算結果を正とする
+        // 対戦相手のターン情報は演算結果の反映時に完了となり、ゲームのターン数も進む
+        if opponent_turn.is_completed() || self.is_turn_advanced(&game).await? {
             println!(
-                "対戦相手のターン情報が登録されていることを確認しました ゲームID: {}, プレイヤーID: {}, ターン番号: {:?}",
+                "ターンは既に他のリクエストで処理されています ゲームID: {}, プレイヤーID: {}",
                 game_id.value(),
-                game.get_opponent_player_id(&player_id)?.value(),
-                TurnNumber::new(game.current_turn_number().value()).value()
+                player_id.value()
             );
+            return Ok(());
         }
 
         // ユニット情報の取得
@@ -134,21 +224,73 @@ impl ProcessTurnUseCase {
             .map_err(|e| format!("ユニット情報の取得に失敗しました: {}", e))?;
 
         // **ターンエンティティの演算処理開始**
-        turn.turn_start(&mut units, &opponent_turn_data.unwrap())?;
-
-        // ユニット情報の更新
-        self.unit_repository
-            .update_units(&units)
-            .await
-            .map_err(|e| format!("ユニット情報の更新に失敗しました: {}", e))?;
+        // 回避判定の乱数はゲームに保存したシードとターン数から生成し、リプレイで再現できるようにする
+        let mut rng = game.simulation_seed().rng_for_turn(turn.turn_number());
+        let mut events = turn.turn_start(&mut units, &opponent_turn, &mut rng)?;
+        turn.complete(chrono::Utc::now())?;
+        // 再接続後にも結果を取得できるよう、対戦相手のターン情報にも演算結果を反映する
+        let opponent_resolved_turn = turn.resolved_turn_for(&opponent_turn)?;
 
         // ゲームのターン数を更新
-        game.advance_to_next_turn()
-            .map_err(|e| format!("ターン数の更新に失敗しました: {}", e))?;
-        self.game_repository
-            .update_current_turn(&game)
+        // 最終ターンの場合はターン数を進めずにゲーム終了とする
+        let is_final_turn = game.is_game_finished();
+        if is_final_turn {
+            game.finish()
+                .map_err(|e| format!("ゲームの終了に失敗しました: {}", e))?;
+            events.push(GameEventPayload::GameFinished {
+                final_turn_number: turn.turn_number().clone(),
+            });
+        } else {
+            game.advance_to_next_turn()
+                .map_err(|e| format!("ターン数の更新に失敗しました: {}", e))?;
+        }
+
+        // 演算済みのターン・ユニット・ゲーム情報と、演算中に発生したイベントをまとめて反映
+        // 途中で失敗しても一部だけが反映された状態にはならない
+        let resolved_turns = [turn.clone(), opponent_resolved_turn.clone()];
+        let (resolved_turns, resolved_units, resolved_game) = (&resolved_turns, &units, &game);
+        if let Err(e) = self
+            .game_event_recorder
+            .record_with(&game_id, events, |events| async move {
+                turn_unit_of_work
+                    .commit_turn(resolved_turns, resolved_units, resolved_game, &events)
+                    .await
+            })
             .await
-            .map_err(|e| format!("ゲーム情報の更新に失敗しました: {}", e))?;
+        {
+            if e == GAME_VERSION_CONFLICT_ERROR {
+                // 対戦相手のリクエストが同時にターンを進めたため、そちらの演算結果を正とする
+                println!(
+                    "ターンは既に他のリクエストで処理されています ゲームID: {}, プレイヤーID: {}",
+                    game_id.value(),
+                    player_id.value()
+                );
+                return Ok(());
+            }
+            return Err(format!("ターンの演算結果の反映に失敗しました: {}", e));
+        }
+        if is_final_turn {
+            // リプレイは観戦用のため、生成に失敗しても対戦結果の通知は継続する
+            if let Err(e) = self
+                .replay_exporter
+                .export(&game, &[turn.clone(), opponent_resolved_turn])
+                .await
+            {
+                eprintln!(
+                    "リプレイの生成に失敗しました ゲームID: {}, エラー: {}",
+                    game_id.value(),
+                    e
+                );
+            }
+            // 対戦結果は反映済みのため、レーティングの更新に失敗しても対戦結果の通知は継続する
+            if let Err(e) = self.rating_updater.update(&game, &units).await {
+                eprintln!(
+                    "レーティングの更新に失敗しました ゲームID: {}, エラー: {}",
+                    game_id.value(),
+                    e
+                );
+            }
+        }
 
         // ターンの情報をプレイヤーごとに向けた形に修正
         let player_a_id = turn.player_id().clone();
@@ -158,29 +300,52 @@ impl ProcessTurnUseCase {
         let response_a = WebSocketResponse::TurnExecutionResult { turn: turn_a };
         let response_b = WebSocketResponse::TurnExecutionResult { turn: turn_b };
 
-        // コネクションの取得
-        let player1_connection_id = self
-            .connection_repository
-            .get_connection_id(player_a_id.value())
-            .await
-            .map_err(|e| format!("コネクションIDの取得に失敗しました: {}", e))?;
-
         // WebSocket で通知を送信
-        self.websocket_sender
-            .send_message(&player1_connection_id, &response_a)
-            .await?;
+        // 演算結果は反映済みのため、オフラインのプレイヤーへの通知はOutboxから再接続時に再送する
+        // 一方への通知に失敗しても、もう一方には必ず通知する
+        let result_a = self.notify_player(&player_a_id, &response_a).await;
+        let result_b = self.notify_player(&player_b_id, &response_b).await;
 
-        let player2_connection_id = self
-            .connection_repository
-            .get_connection_id(player_b_id.value())
+        // 観戦者への配信は対戦の進行に影響させないため、失敗してもログに残すのみとする
+        if let Err(e) = self
+            .spectator_broadcaster
+            .broadcast(&game, &turn, is_final_turn)
             .await
-            .map_err(|e| format!("コネクションIDの取得に失敗しました: {}", e))?;
-
-        self.websocket_sender
-            .send_message(&player2_connection_id, &response_b)
-            .await?;
+        {
+            eprintln!(
+                "観戦者への配信に失敗しました ゲームID: {}, エラー: {}",
+                game_id.value(),
+                e
+            );
+        }
+        result_a?;
+        result_b?;
 
         // println!("Processing turn for game_id: {}", game_id);
         Ok(())
     }
+
+    /// リクエスト開始時に取得したゲーム情報から、ターン数が既に進められているか確認
+    async fn is_turn_advanced(&self, game: &Game) -> Result<bool, String> {
+        let latest_game = self
+            .game_repository
+            .get_game_by_id(game.game_id())
+            .await
+            .map_err(|e| format!("ゲーム情報の取得に失敗しました: {}", e))?;
+        Ok(latest_game.current_turn_number().value() != game.current_turn_number().value())
+    }
+
+    /// プレイヤーへのターン結果の通知
+    /// ボットには接続がないため通知しない
+    async fn notify_player(
+        &self,
+        player_id: &PlayerId,
+        response: &WebSocketResponse,
+    ) -> Result<(), String> {
+        if BotPlayer::is_bot(player_id) {
+            return Ok(());
+        }
+        self.player_notifier.notify(player_id, response).await?;
+        Ok(())
+    }
 }
@@ -0,0 +1,488 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    use crate::application::game::process_turn_usecase::ProcessTurnUseCase;
+    use crate::application::websocket::{
+        websocket_response::WebSocketResponse,
+        websocket_sender::{DeliveryStatus, WebSocketSender},
+    };
+    use crate::domain::player_management::models::{
+        outbox_message::{
+            message_sequence::message_sequence::MessageSequence, outbox_message::OutboxMessage,
+        },
+        player::{player_id::player_id::PlayerId, Player},
+    };
+    use crate::domain::player_management::repositories::{
+        connection_repository::ConnectionRepository, outbox_repository::OutboxRepository,
+        player_repository::PlayerRepository,
+    };
+    use crate::domain::triggergame_simulator::models::{
+        action::{
+            action_type::action_type::{ActionType, ActionTypeValue},
+            trigger_azimuth::trigger_azimuth::TriggerAzimuth,
+            Action,
+        },
+        game::{
+            current_turn_number::current_turn_number::CurrentTurnNumber, game::Game,
+            game_id::game_id::GameId, game_status::game_status::GameStatus,
+            game_version::game_version::GameVersion,
+            simulation_seed::simulation_seed::SimulationSeed, spectator::spectator::Spectator,
+            spectator_delay::spectator_delay::SpectatorDelay,
+        },
+        game_event::{event_sequence::event_sequence::EventSequence, game_event::GameEvent},
+        replay::replay::Replay,
+        step::{step::Step, step_id::step_id::StepId},
+        turn::{
+            turn_id::turn_id::TurnId,
+            turn_number::turn_number::TurnNumber,
+            turn_start_datetime::turn_start_datetime::TurnStartDatetime,
+            turn_status::turn_status::{TurnStatus, TurnStatusValue},
+            Turn,
+        },
+    };
+    use crate::domain::triggergame_simulator::repositories::{
+        game_event_repository::GameEventRepository, game_repository::GameRepository,
+        replay_repository::ReplayRepository, turn_repository::TurnRepository,
+        turn_unit_of_work::TurnUnitOfWork,
+    };
+    use crate::domain::unit_management::{
+        models::unit::{
+            having_trigger_ids::having_trigger_ids::HavingTriggerIds, position::position::Position,
+            trigger_id::trigger_id::TriggerId, unit_type_id::unit_type_id::UnitTypeId, Unit,
+        },
+        repositories::unit_repository::UnitRepository,
+    };
+
+    /// 取得のたびに登録順にゲーム情報を返すGameリポジトリ
+    /// 最後のゲーム情報は以降の取得でも返し続ける
+    struct InMemoryGameRepository {
+        games: Mutex<Vec<Game>>,
+    }
+
+    #[async_trait]
+    impl GameRepository for InMemoryGameRepository {
+        async fn save(&self, _game: &Game) -> Result<(), String> {
+            Ok(())
+        }
+        async fn update_current_turn(&self, _game: &Game) -> Result<(), String> {
+            Ok(())
+        }
+        async fn get_game_by_id(&self, _game_id: &GameId) -> Result<Game, String> {
+            let mut games = self.games.lock().unwrap();
+            if games.len() > 1 {
+                Ok(games.remove(0))
+            } else {
+                Ok(games[0].clone())
+            }
+        }
+        async fn find_latest_by_player_id(
+            &self,
+            _player_id: &PlayerId,
+        ) -> Result<Option<Game>, String> {
+            Ok(None)
+        }
+        async fn add_spectator(
+            &self,
+            _game_id: &GameId,
+            _spectator: &Spectator,
+        ) -> Result<(), String> {
+            Ok(())
+        }
+        async fn remove_spectator(
+            &self,
+            _game_id: &GameId,
+            _player_id: &PlayerId,
+        ) -> Result<(), String> {
+            Ok(())
+        }
+    }
+
+    struct InMemoryTurnRepository {
+        turns: Mutex<Vec<Turn>>,
+    }
+
+    #[async_trait]
+    impl TurnRepository for InMemoryTurnRepository {
+        async fn save(&self, turn: &Turn) -> Result<(), String> {
+            self.turns.lock().unwrap().push(turn.clone());
+            Ok(())
+        }
+        async fn update(&self, _turn: &Turn) -> Result<(), String> {
+            Ok(())
+        }
+        async fn get_turn_data(
+            &self,
+            game_id: &GameId,
+            player_id: &PlayerId,
+            turn_number: &TurnNumber,
+        ) -> Result<Option<Turn>, String> {
+            Ok(self
+                .turns
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|turn| {
+                    turn.game_id() == game_id
+                        && turn.player_id() == player_id
+                        && turn.turn_number().value() == turn_number.value()
+                })
+                .cloned())
+        }
+    }
+
+    /// コミットされたターンの演算結果を記録するユニットオブワーク
+    struct RecordingTurnUnitOfWork {
+        committed_turns: Mutex<Vec<Turn>>,
+    }
+
+    #[async_trait]
+    impl TurnUnitOfWork for RecordingTurnUnitOfWork {
+        async fn submit_turn(&self, _turn: &Turn, _events: &[GameEvent]) -> Result<(), String> {
+            Ok(())
+        }
+        async fn commit_turn(
+            &self,
+            turns: &[Turn],
+            _units: &[Unit],
+            _game: &Game,
+            _events: &[GameEvent],
+        ) -> Result<(), String> {
+            self.committed_turns
+                .lock()
+                .unwrap()
+                .extend(turns.iter().cloned());
+            Ok(())
+        }
+    }
+
+    struct InMemoryGameEventRepository;
+
+    #[async_trait]
+    impl GameEventRepository for InMemoryGameEventRepository {
+        async fn append(&self, _events: &[GameEvent]) -> Result<(), String> {
+            Ok(())
+        }
+        async fn get_latest_sequence(
+            &self,
+            _game_id: &GameId,
+        ) -> Result<Option<EventSequence>, String> {
+            Ok(None)
+        }
+        async fn get_events(&self, _game_id: &GameId) -> Result<Vec<GameEvent>, String> {
+            Ok(Vec::new())
+        }
+    }
+
+    /// 登録したユニットを返すUnitリポジトリ
+    struct InMemoryUnitRepository {
+        units: Vec<Unit>,
+    }
+
+    #[async_trait]
+    impl UnitRepository for InMemoryUnitRepository {
+        async fn save(&self, _unit: &Unit) -> Result<(), String> {
+            Ok(())
+        }
+        async fn update(&self, _unit: &Unit) -> Result<(), String> {
+            Ok(())
+        }
+        async fn update_units(&self, _units: &Vec<Unit>) -> Result<(), String> {
+            Ok(())
+        }
+        async fn get_game_units(&self, _game_id: &GameId) -> Result<Vec<Unit>, String> {
+            Ok(self.units.clone())
+        }
+        async fn delete_game_units(&self, _game_id: &GameId) -> Result<(), String> {
+            Ok(())
+        }
+    }
+
+    /// 何も保存せず、検索しても見つからないリポジトリ
+    /// 接続中のプレイヤーがいないため、通知はすべてOutboxに保存される扱いになる
+    struct NoopRepository;
+
+    #[async_trait]
+    impl ConnectionRepository for NoopRepository {
+        async fn save(&self, _player_id: &str, _connection_id: &str) -> Result<(), String> {
+            Ok(())
+        }
+        async fn get_connection_id(&self, _player_id: &str) -> Result<String, String> {
+            Err("コネクションが見つかりません".to_string())
+        }
+        async fn find_connection_id(&self, _player_id: &str) -> Result<Option<String>, String> {
+            Ok(None)
+        }
+        async fn find_player_id(&self, _connection_id: &str) -> Result<Option<String>, String> {
+            Ok(None)
+        }
+        async fn delete(&self, _player_id: &str, _connection_id: &str) -> Result<(), String> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl ReplayRepository for NoopRepository {
+        async fn save(&self, _replay: &Replay) -> Result<(), String> {
+            Ok(())
+        }
+        async fn find_by_game_id(&self, _game_id: &GameId) -> Result<Option<Replay>, String> {
+            Ok(None)
+        }
+    }
+
+    #[async_trait]
+    impl PlayerRepository for NoopRepository {
+        async fn save(&self, _player: &Player) -> Result<(), String> {
+            Ok(())
+        }
+        async fn update_rating(&self, _player: &Player) -> Result<(), String> {
+            Ok(())
+        }
+        async fn find_by_id(&self, _player_id: &PlayerId) -> Result<Option<Player>, String> {
+            Ok(None)
+        }
+    }
+
+    #[async_trait]
+    impl OutboxRepository for NoopRepository {
+        async fn next_sequence(&self, _player_id: &PlayerId) -> Result<MessageSequence, String> {
+            Ok(MessageSequence::initial())
+        }
+        async fn save(&self, _message: &OutboxMessage) -> Result<(), String> {
+            Ok(())
+        }
+        async fn get_messages(&self, _player_id: &PlayerId) -> Result<Vec<OutboxMessage>, String> {
+            Ok(Vec::new())
+        }
+        async fn delete(&self, _message: &OutboxMessage) -> Result<(), String> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl WebSocketSender for NoopRepository {
+        async fn send_message(
+            &self,
+            _connection_id: &str,
+            _response: &WebSocketResponse,
+        ) -> Result<DeliveryStatus, String> {
+            Ok(DeliveryStatus::Offline)
+        }
+        async fn send_json(
+            &self,
+            _connection_id: &str,
+            _payload: &str,
+        ) -> Result<DeliveryStatus, String> {
+            Ok(DeliveryStatus::Offline)
+        }
+    }
+
+    fn create_game(current_turn_number: i32) -> Game {
+        Game::new(
+            GameId::new(Uuid::new_v4().to_string()),
+            CurrentTurnNumber::new(current_turn_number),
+            PlayerId::new(Uuid::new_v4().to_string()),
+            PlayerId::new(Uuid::new_v4().to_string()),
+            GameVersion::initial(),
+            Utc::now(),
+            Vec::new(),
+            GameStatus::initial(),
+            SimulationSeed::generate(),
+        )
+    }
+
+    fn create_turn(
+        game: &Game,
+        player_id: &PlayerId,
+        status: TurnStatusValue,
+        steps: Vec<Step>,
+    ) -> Turn {
+        Turn::new(
+            TurnId::new(Uuid::new_v4().to_string()),
+            game.game_id().clone(),
+            player_id.clone(),
+            TurnNumber::new(game.current_turn_number().value()),
+            TurnStartDatetime::new(Utc::now()),
+            TurnStatus::new(status),
+            steps,
+        )
+    }
+
+    fn create_usecase(
+        games: Vec<Game>,
+        turns: Vec<Turn>,
+        units: Vec<Unit>,
+        turn_unit_of_work: Arc<RecordingTurnUnitOfWork>,
+    ) -> ProcessTurnUseCase {
+        ProcessTurnUseCase::new(
+            Arc::new(NoopRepository),
+            Arc::new(InMemoryGameRepository {
+                games: Mutex::new(games),
+            }),
+            Arc::new(InMemoryTurnRepository {
+                turns: Mutex::new(turns),
+            }),
+            Arc::new(InMemoryUnitRepository { units }),
+            turn_unit_of_work,
+            Arc::new(InMemoryGameEventRepository),
+            Arc::new(NoopRepository),
+            Arc::new(NoopRepository),
+            Arc::new(NoopRepository),
+            Arc::new(NoopRepository),
+            SpectatorDelay::new(0),
+        )
+    }
+
+    fn create_turn_unit_of_work() -> Arc<RecordingTurnUnitOfWork> {
+        Arc::new(RecordingTurnUnitOfWork {
+            committed_turns: Mutex::new(Vec::new()),
+        })
+    }
+
+    /// 互いの座標系で同じセルに位置し、正面を向けば戦闘が発生するユニット
+    fn create_unit(game: &Game, owner_player_id: &PlayerId, position: Position) -> Unit {
+        Unit::create(
+            UnitTypeId::new("MIKUMO_OSAMU".to_string()),
+            game.game_id().clone(),
+            owner_player_id.clone(),
+            position,
+            TriggerId::new("KOGETSU".to_string()),
+            TriggerId::new("RAYGUST".to_string()),
+            HavingTriggerIds::new(vec![TriggerId::new("KOGETSU".to_string())]),
+            HavingTriggerIds::new(vec![TriggerId::new("RAYGUST".to_string())]),
+            100,
+            100,
+            8,
+            13,
+        )
+    }
+
+    /// その場でトリガーを正面に向けるだけのステップ
+    fn create_attack_step(unit: &Unit) -> Step {
+        let action = Action::create(
+            ActionType::new(ActionTypeValue::Wait),
+            unit.unit_id().clone(),
+            unit.unit_type_id().clone(),
+            unit.position().clone(),
+            unit.using_main_trigger_id().clone(),
+            unit.using_sub_trigger_id().clone(),
+            TriggerAzimuth::new(90),
+            TriggerAzimuth::new(90),
+        );
+        Step::create(
+            StepId::new(Uuid::new_v4().to_string()),
+            vec![action],
+            vec![],
+        )
+    }
+
+    #[tokio::test]
+    async fn test_execute_with_step_setting_opponent_turn() {
+        // 対戦相手のターン情報が行動設定中で、ゲームのターン数も進んでいない場合
+        let game = create_game(1);
+        let unit1 = create_unit(&game, game.player1_id(), Position::new(17, 17));
+        let unit2 = create_unit(&game, game.player2_id(), Position::new(18, 18));
+        let opponent_turn = create_turn(
+            &game,
+            game.player2_id(),
+            TurnStatusValue::StepSetting,
+            vec![create_attack_step(&unit2)],
+        );
+        let turn_unit_of_work = create_turn_unit_of_work();
+        let usecase = create_usecase(
+            vec![game.clone()],
+            vec![opponent_turn],
+            vec![unit1.clone(), unit2],
+            turn_unit_of_work.clone(),
+        );
+
+        let result = usecase
+            .execute(
+                game.game_id().value().to_string(),
+                game.player1_id().value().to_string(),
+                vec![create_attack_step(&unit1)],
+            )
+            .await;
+
+        // 両プレイヤーのターン情報が、同じ演算結果で完了としてコミットされる
+        assert!(result.is_ok());
+        let committed_turns = turn_unit_of_work.committed_turns.lock().unwrap();
+        assert_eq!(committed_turns.len(), 2);
+        assert!(committed_turns
+            .iter()
+            .any(|turn| turn.player_id() == game.player1_id()));
+        assert!(committed_turns
+            .iter()
+            .any(|turn| turn.player_id() == game.player2_id()));
+        for turn in committed_turns.iter() {
+            assert!(turn.is_completed());
+            assert_eq!(turn.steps().len(), 1);
+            // 互いに正面を向けたユニット同士の戦闘が生成されている
+            assert_eq!(turn.steps()[0].combats().len(), 2);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_with_completed_opponent_turn() {
+        // 対戦相手のリクエストが先にターンを演算し、ゲーム情報を取得した後に反映した場合
+        let game = create_game(1);
+        let opponent_turn =
+            create_turn(&game, game.player2_id(), TurnStatusValue::Completed, vec![]);
+        let turn_unit_of_work = create_turn_unit_of_work();
+        let usecase = create_usecase(
+            vec![game.clone()],
+            vec![opponent_turn],
+            Vec::new(),
+            turn_unit_of_work.clone(),
+        );
+
+        let result = usecase
+            .execute(
+                game.game_id().value().to_string(),
+                game.player1_id().value().to_string(),
+                vec![],
+            )
+            .await;
+
+        // 対戦相手の演算結果を正とし、エラーにせず再演算もしない
+        assert!(result.is_ok());
+        assert!(turn_unit_of_work.committed_turns.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_execute_after_turn_advanced() {
+        // 対戦相手のターン情報を取得した時点では演算前だったが、ゲームのターン数は既に進んでいる場合
+        let game = create_game(1);
+        let mut advanced_game = game.clone();
+        advanced_game.advance_to_next_turn().unwrap();
+        let opponent_turn = create_turn(
+            &game,
+            game.player2_id(),
+            TurnStatusValue::StepSetting,
+            vec![],
+        );
+        let turn_unit_of_work = create_turn_unit_of_work();
+        let usecase = create_usecase(
+            vec![game.clone(), advanced_game],
+            vec![opponent_turn],
+            Vec::new(),
+            turn_unit_of_work.clone(),
+        );
+
+        let result = usecase
+            .execute(
+                game.game_id().value().to_string(),
+                game.player1_id().value().to_string(),
+                vec![],
+            )
+            .await;
+
+        assert!(result.is_ok());
+        assert!(turn_unit_of_work.committed_turns.lock().unwrap().is_empty());
+    }
+}
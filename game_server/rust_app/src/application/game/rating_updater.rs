@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use crate::domain::{
+    player_management::repositories::player_repository::PlayerRepository,
+    triggergame_simulator::models::game::{game::Game, game_result::game_result::GameResult},
+    unit_management::models::unit::Unit,
+};
+
+/// 終了したゲームの対戦結果をプレイヤーのレーティングに反映する
+pub struct RatingUpdater {
+    player_repository: Arc<dyn PlayerRepository>,
+}
+
+impl RatingUpdater {
+    pub fn new(player_repository: Arc<dyn PlayerRepository>) -> Self {
+        Self { player_repository }
+    }
+
+    /// 対戦結果を判定してレーティングを更新
+    /// units: 最終ターンの演算後のユニット情報
+    /// 登録されていないプレイヤーが参加している場合はレーティングを更新しない
+    pub async fn update(&self, game: &Game, units: &[Unit]) -> Result<GameResult, String> {
+        let result = GameResult::judge(game, units);
+
+        let player1 = self.player_repository.find_by_id(game.player1_id()).await?;
+        let player2 = self.player_repository.find_by_id(game.player2_id()).await?;
+        let (mut player1, mut player2) = match (player1, player2) {
+            (Some(player1), Some(player2)) => (player1, player2),
+            _ => {
+                println!(
+                    "登録されていないプレイヤーが参加しているためレーティングを更新しません ゲームID: {}",
+                    game.game_id().value()
+                );
+                return Ok(result);
+            }
+        };
+
+        // 対戦時点のレーティングをもとに両者の変動量を計算する
+        let player1_rating = player1.rating().clone();
+        let player2_rating = player2.rating().clone();
+        player1.apply_game_result(&player2_rating, result.score_for(game.player1_id()));
+        player2.apply_game_result(&player1_rating, result.score_for(game.player2_id()));
+
+        // 対戦中に行われた名前の変更やMFAの設定変更を上書きしないよう、レーティングのみを更新する
+        self.player_repository.update_rating(&player1).await?;
+        self.player_repository.update_rating(&player2).await?;
+        Ok(result)
+    }
+}
@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use crate::domain::{
+    player_management::models::player::player_id::player_id::PlayerId,
+    triggergame_simulator::{
+        models::{
+            game::game::Game,
+            game_event::{
+                game_event::GameEvent, game_event_payload::GameEventPayload,
+                game_event_projector::GameEventProjector,
+            },
+            replay::replay::Replay,
+            turn::{turn_number::turn_number::TurnNumber, Turn},
+        },
+        repositories::{
+            game_event_repository::GameEventRepository, replay_repository::ReplayRepository,
+            turn_repository::TurnRepository,
+        },
+    },
+};
+
+/// 終了したゲームのリプレイを生成して保存する
+pub struct ReplayExporter {
+    game_event_repository: Arc<dyn GameEventRepository>,
+    turn_repository: Arc<dyn TurnRepository>,
+    replay_repository: Arc<dyn ReplayRepository>,
+}
+
+impl ReplayExporter {
+    pub fn new(
+        game_event_repository: Arc<dyn GameEventRepository>,
+        turn_repository: Arc<dyn TurnRepository>,
+        replay_repository: Arc<dyn ReplayRepository>,
+    ) -> Self {
+        Self {
+            game_event_repository,
+            turn_repository,
+            replay_repository,
+        }
+    }
+
+    /// リプレイを生成して保存
+    /// final_turns: 最終ターンの演算済みのターン情報
+    /// 反映直後は読み取りに反映されていない可能性があるため、最終ターンのみ呼び出し元から受け取る
+    pub async fn export(&self, game: &Game, final_turns: &[Turn]) -> Result<Replay, String> {
+        // 対戦開始時のユニット配置はイベントログから復元する
+        let deployed_events: Vec<GameEvent> = self
+            .game_event_repository
+            .get_events(game.game_id())
+            .await?
+            .into_iter()
+            .filter(|event| matches!(event.payload(), GameEventPayload::UnitDeployed { .. }))
+            .collect();
+        if deployed_events.is_empty() {
+            return Err("対戦開始時のユニット配置が記録されていません".to_string());
+        }
+        let initial_units = GameEventProjector::project_units(&deployed_events)?;
+
+        // 最終ターンより前の演算済みのターン情報を取得
+        let mut turns = Vec::new();
+        for turn_number in 1..game.current_turn_number().value() {
+            for player_id in [game.player1_id(), game.player2_id()] {
+                let turn = self
+                    .turn_repository
+                    .get_turn_data(game.game_id(), player_id, &TurnNumber::new(turn_number))
+                    .await?
+                    .ok_or(format!(
+                        "ターン{}の情報が見つかりませんでした。",
+                        turn_number
+                    ))?;
+                turns.push(turn);
+            }
+        }
+        turns.extend(final_turns.iter().cloned());
+
+        let replay = Replay::create(game, &initial_units, turns)?;
+        self.replay_repository.save(&replay).await?;
+        Ok(replay)
+    }
+}
@@ -0,0 +1,26 @@
+use serde::Serialize;
+
+use crate::{
+    application::game::{enemy_unit_dto::EnemyUnitDto, friend_unit_dto::FriendUnitDto},
+    domain::triggergame_simulator::models::turn::Turn,
+};
+
+/// 再接続時に復元する進行中のゲーム情報
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResumeSessionDto {
+    /// ゲームID
+    pub game_id: String,
+    /// 現在のターン番号
+    pub current_turn_number: u32,
+    /// 現在のターンの行動を登録済みかどうか
+    pub is_turn_submitted: bool,
+    /// 現在のターンの残り時間（秒）
+    pub remaining_seconds: i64,
+    /// 敵ユニット情報
+    pub enemy_units: Vec<EnemyUnitDto>,
+    /// 味方ユニット情報
+    pub friend_units: Vec<FriendUnitDto>,
+    /// 直前に演算されたターンの結果（1ターン目の場合は存在しない）
+    pub last_turn_result: Option<Turn>,
+}
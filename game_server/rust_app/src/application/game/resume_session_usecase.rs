@@ -0,0 +1,159 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::{
+    application::{
+        game::{
+            enemy_unit_dto::EnemyUnitDto, friend_unit_dto::FriendUnitDto,
+            resume_session_dto::ResumeSessionDto,
+        },
+        websocket::{
+            player_notifier::PlayerNotifier, websocket_response::WebSocketResponse,
+            websocket_sender::WebSocketSender,
+        },
+    },
+    domain::{
+        player_management::{
+            models::player::player_id::player_id::PlayerId,
+            repositories::{
+                connection_repository::ConnectionRepository, outbox_repository::OutboxRepository,
+            },
+        },
+        triggergame_simulator::{
+            models::turn::turn_number::turn_number::TurnNumber,
+            repositories::{game_repository::GameRepository, turn_repository::TurnRepository},
+        },
+        unit_management::repositories::unit_repository::UnitRepository,
+    },
+};
+
+/// 再接続したプレイヤーに進行中のゲームの状態を返すユースケース
+pub struct ResumeSessionUseCase {
+    connection_repository: Arc<dyn ConnectionRepository>,
+    game_repository: Arc<dyn GameRepository>,
+    turn_repository: Arc<dyn TurnRepository>,
+    unit_repository: Arc<dyn UnitRepository>,
+    player_notifier: PlayerNotifier,
+    websocket_sender: Arc<dyn WebSocketSender>,
+}
+
+impl ResumeSessionUseCase {
+    pub fn new(
+        connection_repository: Arc<dyn ConnectionRepository>,
+        game_repository: Arc<dyn GameRepository>,
+        turn_repository: Arc<dyn TurnRepository>,
+        unit_repository: Arc<dyn UnitRepository>,
+        outbox_repository: Arc<dyn OutboxRepository>,
+        websocket_sender: Arc<dyn WebSocketSender>,
+    ) -> Self {
+        let player_notifier = PlayerNotifier::new(
+            connection_repository.clone(),
+            outbox_repository,
+            websocket_sender.clone(),
+        );
+        Self {
+            connection_repository,
+            game_repository,
+            turn_repository,
+            unit_repository,
+            player_notifier,
+            websocket_sender,
+        }
+    }
+
+    pub async fn execute(&self, player_id: PlayerId) -> Result<(), String> {
+        let session = self.find_session(&player_id).await?;
+
+        let connection_id = self
+            .connection_repository
+            .get_connection_id(player_id.value())
+            .await
+            .map_err(|e| format!("コネクションIDの取得に失敗しました: {}", e))?;
+
+        let response = WebSocketResponse::ResumeSessionResult { session };
+
+        self.websocket_sender
+            .send_message(&connection_id, &response)
+            .await?;
+
+        // 切断中に届けられなかった通知を再送
+        let delivered_count = self.player_notifier.flush(&player_id).await?;
+        if delivered_count > 0 {
+            println!(
+                "未送信の通知を再送しました プレイヤーID: {}, 件数: {}",
+                player_id.value(),
+                delivered_count
+            );
+        }
+
+        Ok(())
+    }
+
+    /// 進行中のゲーム情報を取得
+    /// 参加中のゲームが無い、または最終ターンまで演算済みの場合は`None`を返す
+    async fn find_session(&self, player_id: &PlayerId) -> Result<Option<ResumeSessionDto>, String> {
+        let game = match self
+            .game_repository
+            .find_latest_by_player_id(player_id)
+            .await
+            .map_err(|e| format!("ゲーム情報の取得に失敗しました: {}", e))?
+        {
+            Some(game) => game,
+            None => return Ok(None),
+        };
+        let game_id = game.game_id().clone();
+        let current_turn_number = TurnNumber::new(game.current_turn_number().value());
+
+        // 現在のターンの登録状況
+        let current_turn = self
+            .turn_repository
+            .get_turn_data(&game_id, player_id, &current_turn_number)
+            .await
+            .map_err(|e| format!("ターン情報の取得に失敗しました: {}", e))?;
+        if let Some(turn) = &current_turn {
+            // 最終ターンの演算が完了していればゲームは終了している
+            if turn.is_completed() {
+                return Ok(None);
+            }
+        }
+
+        // 直前のターンの演算結果
+        let last_turn_result = if current_turn_number.value() > 1 {
+            self.turn_repository
+                .get_turn_data(
+                    &game_id,
+                    player_id,
+                    &TurnNumber::new(current_turn_number.value() - 1),
+                )
+                .await
+                .map_err(|e| format!("ターン情報の取得に失敗しました: {}", e))?
+                .map(|mut turn| turn.generate_player_turn(player_id))
+        } else {
+            None
+        };
+
+        // ユニット情報の取得
+        let units = self
+            .unit_repository
+            .get_game_units(&game_id)
+            .await
+            .map_err(|e| format!("ユニット情報の取得に失敗しました: {}", e))?;
+
+        // 敵味方ユニットを分割
+        let (enemy_units, friend_units): (Vec<_>, Vec<_>) = units
+            .iter()
+            .cloned()
+            .partition(|u| u.owner_player_id() != player_id);
+
+        Ok(Some(ResumeSessionDto {
+            game_id: game_id.value().to_string(),
+            current_turn_number: game.current_turn_number().value() as u32,
+            is_turn_submitted: current_turn.is_some(),
+            remaining_seconds: game.remaining_turn_time(Utc::now()).num_seconds(),
+            enemy_units: EnemyUnitDto::from_units(&enemy_units),
+            friend_units: FriendUnitDto::from_units(&friend_units),
+            last_turn_result,
+        }))
+    }
+}
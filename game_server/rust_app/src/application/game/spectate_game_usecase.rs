@@ -0,0 +1,118 @@
+use std::sync::Arc;
+
+use crate::{
+    application::{
+        game::{spectator_broadcaster::SpectatorBroadcaster, spectator_dto::SpectatorDto},
+        websocket::{websocket_response::WebSocketResponse, websocket_sender::WebSocketSender},
+    },
+    domain::{
+        player_management::models::player::player_id::player_id::PlayerId,
+        triggergame_simulator::{
+            models::{
+                game::{
+                    game::Game,
+                    game_id::game_id::GameId,
+                    spectator::spectator::{Spectator, SpectatorView},
+                    spectator_delay::spectator_delay::SpectatorDelay,
+                },
+                turn::{turn_number::turn_number::TurnNumber, Turn},
+            },
+            repositories::{game_repository::GameRepository, turn_repository::TurnRepository},
+        },
+    },
+};
+
+/// ゲームを観戦するユースケース
+/// 観戦者として登録し、遅延を考慮して閲覧可能な演算済みのターンを返す
+/// 以降のターンは`SpectatorBroadcaster`から配信される
+pub struct SpectateGameUseCase {
+    game_repository: Arc<dyn GameRepository>,
+    turn_repository: Arc<dyn TurnRepository>,
+    websocket_sender: Arc<dyn WebSocketSender>,
+    spectator_delay: SpectatorDelay,
+}
+
+impl SpectateGameUseCase {
+    pub fn new(
+        game_repository: Arc<dyn GameRepository>,
+        turn_repository: Arc<dyn TurnRepository>,
+        websocket_sender: Arc<dyn WebSocketSender>,
+        spectator_delay: SpectatorDelay,
+    ) -> Self {
+        Self {
+            game_repository,
+            turn_repository,
+            websocket_sender,
+            spectator_delay,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        game_id: GameId,
+        player_id: PlayerId,
+        view: SpectatorView,
+        connection_id: &str,
+    ) -> Result<(), String> {
+        let mut game = self
+            .game_repository
+            .get_game_by_id(&game_id)
+            .await
+            .map_err(|e| format!("ゲーム情報の取得に失敗しました: {}", e))?;
+
+        let spectator = Spectator::create(player_id, view.clone());
+        game.add_spectator(spectator.clone())?;
+        self.game_repository
+            .add_spectator(&game_id, &spectator)
+            .await?;
+
+        let resolved_turns = self.get_resolved_turns(&game).await?;
+        let is_game_over = game.is_game_finished()
+            && resolved_turns.len() as i32 == game.current_turn_number().value();
+        let latest_visible_turn = self
+            .spectator_delay
+            .latest_visible_turn(resolved_turns.len() as i32, is_game_over);
+        let turns = resolved_turns
+            .iter()
+            .take(latest_visible_turn as usize)
+            .map(|turn| SpectatorBroadcaster::turn_for_view(&game, turn, &view))
+            .collect();
+
+        let response = WebSocketResponse::SpectateGameResult {
+            game_id: game_id.value().to_string(),
+            player1_id: game.player1_id().value().to_string(),
+            player2_id: game.player2_id().value().to_string(),
+            current_turn_number: game.current_turn_number().value() as u32,
+            delay_turns: self.spectator_delay.turns() as u32,
+            view,
+            spectators: SpectatorDto::from_spectators(game.spectators()),
+            turns,
+            is_game_over,
+        };
+        self.websocket_sender
+            .send_message(connection_id, &response)
+            .await?;
+        Ok(())
+    }
+
+    // ヘルパーメソッド：演算が完了しているターンを古い順に取得
+    async fn get_resolved_turns(&self, game: &Game) -> Result<Vec<Turn>, String> {
+        let mut turns = Vec::new();
+        for turn_number in 1..=game.current_turn_number().value() {
+            let turn = self
+                .turn_repository
+                .get_turn_data(
+                    game.game_id(),
+                    game.player1_id(),
+                    &TurnNumber::new(turn_number),
+                )
+                .await
+                .map_err(|e| format!("ターン情報の取得に失敗しました: {}", e))?;
+            match turn {
+                Some(turn) if turn.is_completed() => turns.push(turn),
+                _ => break,
+            }
+        }
+        Ok(turns)
+    }
+}
@@ -0,0 +1,138 @@
+use std::sync::Arc;
+
+use crate::{
+    application::websocket::{
+        websocket_response::WebSocketResponse, websocket_sender::WebSocketSender,
+    },
+    domain::{
+        player_management::repositories::connection_repository::ConnectionRepository,
+        triggergame_simulator::{
+            models::{
+                game::{
+                    game::Game,
+                    spectator::spectator::{Spectator, SpectatorView},
+                    spectator_delay::spectator_delay::SpectatorDelay,
+                },
+                turn::{turn_number::turn_number::TurnNumber, Turn},
+            },
+            repositories::turn_repository::TurnRepository,
+        },
+    },
+};
+
+/// 観戦者への演算済みターンの配信
+/// 観戦者から対戦者へ情報が漏れないよう、`SpectatorDelay`のターン数だけ遅らせて配信する
+///
+/// 観戦者はOutboxの対象外とし、オフラインの観戦者には配信しない
+/// 再接続した観戦者は`SpectateGame`で閲覧可能なターンを取得し直す
+pub struct SpectatorBroadcaster {
+    connection_repository: Arc<dyn ConnectionRepository>,
+    turn_repository: Arc<dyn TurnRepository>,
+    websocket_sender: Arc<dyn WebSocketSender>,
+    spectator_delay: SpectatorDelay,
+}
+
+impl SpectatorBroadcaster {
+    pub fn new(
+        connection_repository: Arc<dyn ConnectionRepository>,
+        turn_repository: Arc<dyn TurnRepository>,
+        websocket_sender: Arc<dyn WebSocketSender>,
+        spectator_delay: SpectatorDelay,
+    ) -> Self {
+        Self {
+            connection_repository,
+            turn_repository,
+            websocket_sender,
+            spectator_delay,
+        }
+    }
+
+    /// ターンの演算完了時に、遅延を考慮して配信対象となったターンを観戦者に配信
+    ///
+    /// # Arguments
+    /// * `game` - 観戦者一覧を含むゲーム
+    /// * `resolved_turn` - 演算が完了したターン
+    /// * `is_game_over` - ゲームが終了したかどうか
+    pub async fn broadcast(
+        &self,
+        game: &Game,
+        resolved_turn: &Turn,
+        is_game_over: bool,
+    ) -> Result<(), String> {
+        if game.spectators().is_empty() {
+            return Ok(());
+        }
+
+        let turn_numbers = self
+            .spectator_delay
+            .turns_to_broadcast(resolved_turn.turn_number().value(), is_game_over);
+        for turn_number in turn_numbers {
+            let turn = if turn_number == resolved_turn.turn_number().value() {
+                resolved_turn.clone()
+            } else {
+                self.turn_repository
+                    .get_turn_data(
+                        game.game_id(),
+                        game.player1_id(),
+                        &TurnNumber::new(turn_number),
+                    )
+                    .await?
+                    .ok_or(format!(
+                        "配信するターン情報が見つかりませんでした。ターン番号: {}",
+                        turn_number
+                    ))?
+            };
+
+            for spectator in game.spectators() {
+                // 一人への配信に失敗しても、他の観戦者には配信する
+                if let Err(e) = self.send(game, spectator, &turn, is_game_over).await {
+                    eprintln!(
+                        "観戦者への配信に失敗しました ゲームID: {}, プレイヤーID: {}, エラー: {}",
+                        game.game_id().value(),
+                        spectator.player_id().value(),
+                        e
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// 観戦者の視点に合わせたターン情報を作成
+    /// 両陣営を表示する場合は、プレイヤー1を基準としたターン情報とする
+    pub fn turn_for_view(game: &Game, turn: &Turn, view: &SpectatorView) -> Turn {
+        let mut turn = turn.clone();
+        match view {
+            SpectatorView::Omniscient => turn.generate_player_turn(game.player1_id()),
+            SpectatorView::Side { player_id } => turn.generate_player_turn(player_id),
+        }
+    }
+
+    // ヘルパーメソッド：接続中の観戦者に送信
+    async fn send(
+        &self,
+        game: &Game,
+        spectator: &Spectator,
+        turn: &Turn,
+        is_game_over: bool,
+    ) -> Result<(), String> {
+        let connection_id = match self
+            .connection_repository
+            .find_connection_id(spectator.player_id().value())
+            .await?
+        {
+            Some(connection_id) => connection_id,
+            None => return Ok(()),
+        };
+        let response = WebSocketResponse::SpectatorTurnResult {
+            game_id: game.game_id().value().to_string(),
+            view: spectator.view().clone(),
+            turn: Self::turn_for_view(game, turn, spectator.view()),
+            is_game_over,
+        };
+        self.websocket_sender
+            .send_message(&connection_id, &response)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::domain::triggergame_simulator::models::game::spectator::spectator::{
+    Spectator, SpectatorView,
+};
+
+/// クライアントに返す観戦者情報
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpectatorDto {
+    /// 観戦者のプレイヤーID
+    pub player_id: String,
+    /// 観戦時の視点
+    pub view: SpectatorView,
+    /// 観戦開始日時
+    pub joined_at: DateTime<Utc>,
+}
+
+impl SpectatorDto {
+    pub fn from_spectators(spectators: &[Spectator]) -> Vec<Self> {
+        spectators
+            .iter()
+            .map(|spectator| Self {
+                player_id: spectator.player_id().value().to_string(),
+                view: spectator.view().clone(),
+                joined_at: *spectator.joined_at(),
+            })
+            .collect()
+    }
+}
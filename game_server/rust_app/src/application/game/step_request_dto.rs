@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::triggergame_simulator::models::{
+    action::action::Action,
+    step::{step::Step, step_id::step_id::StepId},
+};
+
+/// ターン設定リクエストで受け取るステップ情報
+/// 戦闘や状態効果の変化などの演算結果はサーバーで求めるため、クライアントからは受け取らない
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StepRequestDto {
+    pub step_id: StepId,
+    pub actions: Vec<Action>,
+}
+
+impl StepRequestDto {
+    /// DTOをドメインエンティティに変換（ファクトリーメソッド）
+    pub fn to_step(&self) -> Step {
+        Step::create(self.step_id.clone(), self.actions.clone(), Vec::new())
+    }
+
+    /// 複数ステップを DTO 配列から変換
+    pub fn to_steps(steps: &[StepRequestDto]) -> Vec<Step> {
+        steps.iter().map(|dto| dto.to_step()).collect()
+    }
+}
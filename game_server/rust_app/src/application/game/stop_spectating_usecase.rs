@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use crate::{
+    application::websocket::{
+        websocket_response::WebSocketResponse, websocket_sender::WebSocketSender,
+    },
+    domain::{
+        player_management::models::player::player_id::player_id::PlayerId,
+        triggergame_simulator::{
+            models::game::game_id::game_id::GameId, repositories::game_repository::GameRepository,
+        },
+    },
+};
+
+/// ゲームの観戦を終了するユースケース
+pub struct StopSpectatingUseCase {
+    game_repository: Arc<dyn GameRepository>,
+    websocket_sender: Arc<dyn WebSocketSender>,
+}
+
+impl StopSpectatingUseCase {
+    pub fn new(
+        game_repository: Arc<dyn GameRepository>,
+        websocket_sender: Arc<dyn WebSocketSender>,
+    ) -> Self {
+        Self {
+            game_repository,
+            websocket_sender,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        game_id: GameId,
+        player_id: PlayerId,
+        connection_id: &str,
+    ) -> Result<(), String> {
+        let mut game = self
+            .game_repository
+            .get_game_by_id(&game_id)
+            .await
+            .map_err(|e| format!("ゲーム情報の取得に失敗しました: {}", e))?;
+
+        game.remove_spectator(&player_id)?;
+        self.game_repository
+            .remove_spectator(&game_id, &player_id)
+            .await?;
+
+        let response = WebSocketResponse::StopSpectatingResult {
+            game_id: game_id.value().to_string(),
+        };
+        self.websocket_sender
+            .send_message(connection_id, &response)
+            .await?;
+        Ok(())
+    }
+}
@@ -1,2 +1,4 @@
+pub mod cancel_matchmaking_usecase;
+pub mod matching_interrupter;
 pub mod matchmaking_application_service;
 pub mod matchmaking_dto;
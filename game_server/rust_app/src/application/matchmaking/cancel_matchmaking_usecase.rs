@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use crate::{
+    application::{
+        matchmaking::matching_interrupter::MatchingInterrupter,
+        websocket::{websocket_response::WebSocketResponse, websocket_sender::WebSocketSender},
+    },
+    domain::{
+        matching_management::repositories::matching_repository::MatchingRepository,
+        player_management::models::player::player_id::player_id::PlayerId,
+        unit_management::repositories::unit_repository::UnitRepository,
+    },
+};
+
+/// マッチング待機のキャンセル
+pub struct CancelMatchmakingUseCase {
+    matching_interrupter: MatchingInterrupter,
+    websocket_sender: Arc<dyn WebSocketSender>,
+}
+
+impl CancelMatchmakingUseCase {
+    pub fn new(
+        matching_repository: Arc<dyn MatchingRepository>,
+        unit_repository: Arc<dyn UnitRepository>,
+        websocket_sender: Arc<dyn WebSocketSender>,
+    ) -> Self {
+        Self {
+            matching_interrupter: MatchingInterrupter::new(matching_repository, unit_repository),
+            websocket_sender,
+        }
+    }
+
+    pub async fn execute(&self, player_id: PlayerId, connection_id: &str) -> Result<(), String> {
+        let matching = self
+            .matching_interrupter
+            .interrupt_by_player_id(&player_id)
+            .await?
+            .ok_or("キャンセルできる待機中のマッチングがありません".to_string())?;
+
+        let response = WebSocketResponse::CancelMatchmakingResult {
+            status: matching.matching_status().value().clone(),
+        };
+        self.websocket_sender
+            .send_message(connection_id, &response)
+            .await?;
+        Ok(())
+    }
+}
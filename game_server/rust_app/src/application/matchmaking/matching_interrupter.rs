@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use crate::domain::{
+    matching_management::{
+        models::matching::Matching, repositories::matching_repository::MatchingRepository,
+    },
+    player_management::models::player::player_id::player_id::PlayerId,
+    triggergame_simulator::models::game::game_id::game_id::GameId,
+    unit_management::repositories::unit_repository::UnitRepository,
+};
+
+/// 待機中のマッチングを中断し、マッチング時に登録したユニットを片付ける
+/// キャンセル・切断・待機時間の超過で共通して使用する
+pub struct MatchingInterrupter {
+    matching_repository: Arc<dyn MatchingRepository>,
+    unit_repository: Arc<dyn UnitRepository>,
+}
+
+impl MatchingInterrupter {
+    pub fn new(
+        matching_repository: Arc<dyn MatchingRepository>,
+        unit_repository: Arc<dyn UnitRepository>,
+    ) -> Self {
+        Self {
+            matching_repository,
+            unit_repository,
+        }
+    }
+
+    /// マッチングを中断
+    pub async fn interrupt(&self, matching: &mut Matching) -> Result<(), String> {
+        matching.interrupt()?;
+        self.matching_repository.update(matching).await?;
+
+        // ユニットはマッチングIDをゲームIDとして登録されている
+        let game_id = GameId::new(matching.matching_id().value().to_string());
+        self.unit_repository.delete_game_units(&game_id).await?;
+
+        println!(
+            "マッチングを中断しました マッチングID: {}, プレイヤーID: {}",
+            matching.matching_id().value(),
+            matching.player1_id().value()
+        );
+        Ok(())
+    }
+
+    /// 指定したプレイヤーが待機中のマッチングを中断
+    /// 待機中のマッチングが無ければ何もせず`None`を返す
+    pub async fn interrupt_by_player_id(
+        &self,
+        player_id: &PlayerId,
+    ) -> Result<Option<Matching>, String> {
+        match self
+            .matching_repository
+            .find_waiting_matching_by_player_id(player_id)
+            .await?
+        {
+            Some(mut matching) => {
+                self.interrupt(&mut matching).await?;
+                Ok(Some(matching))
+            }
+            None => Ok(None),
+        }
+    }
+}
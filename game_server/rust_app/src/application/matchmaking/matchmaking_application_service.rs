@@ -1,24 +1,41 @@
 use crate::{
     application::{
-        game::{enemy_unit_dto::EnemyUnitDto, friend_unit_dto::FriendUnitDto},
-        matchmaking::matchmaking_dto::CreateUnitDto,
-        websocket::{websocket_response::WebSocketResponse, websocket_sender::WebSocketSender},
+        game::{
+            enemy_unit_dto::EnemyUnitDto, friend_unit_dto::FriendUnitDto,
+            game_event_recorder::GameEventRecorder,
+        },
+        matchmaking::{matching_interrupter::MatchingInterrupter, matchmaking_dto::CreateUnitDto},
+        websocket::{
+            player_notifier::PlayerNotifier, websocket_response::WebSocketResponse,
+            websocket_sender::WebSocketSender,
+        },
     },
     domain::{
         matching_management::{
-            models::matching::{Matching, MatchingStatusValue},
-            repositories::matching_repository::MatchingRepository,
+            models::matching::{Matching, MatchingId, MatchingStatusValue, RoomCode},
+            repositories::matching_repository::{MatchingRepository, MATCHING_CONFLICT_ERROR},
+            services::matchmaking_strategy::MatchmakingStrategy,
         },
         player_management::{
-            models::player::player_id::player_id::PlayerId,
-            repositories::connection_repository::ConnectionRepository,
+            models::player::{player_id::player_id::PlayerId, rating::rating::Rating},
+            repositories::{
+                connection_repository::ConnectionRepository, outbox_repository::OutboxRepository,
+                player_repository::PlayerRepository,
+            },
         },
         triggergame_simulator::{
-            models::game::{
-                current_turn_number::current_turn_number::CurrentTurnNumber, game::Game,
-                game_id::game_id::GameId,
+            models::{
+                game::{
+                    current_turn_number::current_turn_number::CurrentTurnNumber, game::Game,
+                    game_id::game_id::GameId, game_status::game_status::GameStatus,
+                    game_version::game_version::GameVersion,
+                    simulation_seed::simulation_seed::SimulationSeed,
+                },
+                game_event::game_event_payload::GameEventPayload,
+            },
+            repositories::{
+                game_event_repository::GameEventRepository, game_repository::GameRepository,
             },
-            repositories::game_repository::GameRepository,
         },
         unit_management::{
             models::unit::{self, Unit},
@@ -27,6 +44,7 @@ use crate::{
     },
     infrastructure::dynamodb::connection_dynamodb_repository,
 };
+use chrono::Utc;
 use std::sync::Arc;
 
 pub struct MatchmakingApplicationService {
@@ -34,24 +52,51 @@ pub struct MatchmakingApplicationService {
     connection_repository: Arc<dyn ConnectionRepository>,
     unit_repository: Arc<dyn UnitRepository>,
     game_repository: Arc<dyn GameRepository>,
+    player_repository: Arc<dyn PlayerRepository>,
+    matchmaking_strategy: Arc<dyn MatchmakingStrategy>,
+    game_event_recorder: GameEventRecorder,
+    matching_interrupter: MatchingInterrupter,
+    player_notifier: PlayerNotifier,
     websocket_sender: Arc<dyn WebSocketSender>,
 }
 
 /// マッチメイキングアプリケーションサービスの実装
 impl MatchmakingApplicationService {
+    /// 一度に取得する待機中のマッチングの件数
+    const WAITING_CANDIDATES_LIMIT: i32 = 10;
+    /// 招待コードが待機中のルームと重複した場合に生成し直す回数
+    const ROOM_CODE_GENERATION_ATTEMPTS: usize = 5;
+
     /// コンストラクタで Repository を注入
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         matching_repository: Arc<dyn MatchingRepository>,
         connection_repository: Arc<dyn ConnectionRepository>,
         unit_repository: Arc<dyn UnitRepository>,
         game_repository: Arc<dyn GameRepository>,
+        player_repository: Arc<dyn PlayerRepository>,
+        matchmaking_strategy: Arc<dyn MatchmakingStrategy>,
+        game_event_repository: Arc<dyn GameEventRepository>,
+        outbox_repository: Arc<dyn OutboxRepository>,
         websocket_sender: Arc<dyn WebSocketSender>,
     ) -> Self {
+        let player_notifier = PlayerNotifier::new(
+            connection_repository.clone(),
+            outbox_repository,
+            websocket_sender.clone(),
+        );
+        let matching_interrupter =
+            MatchingInterrupter::new(matching_repository.clone(), unit_repository.clone());
         Self {
             matching_repository,
             connection_repository,
             unit_repository,
             game_repository,
+            player_repository,
+            matchmaking_strategy,
+            game_event_recorder: GameEventRecorder::new(game_event_repository),
+            matching_interrupter,
+            player_notifier,
             websocket_sender,
         }
     }
@@ -64,16 +109,18 @@ impl MatchmakingApplicationService {
         units: Vec<CreateUnitDto>,
     ) -> Result<(), String> {
         println!("Executing matchmaking for player_id: {}", player_id);
-        // 待機中のマッチングを取得
+        let player_rating = self
+            .find_player_rating(&PlayerId::new(player_id.to_string()))
+            .await?;
+        // 待機中のマッチングに対戦相手として参加
         let waiting_matching = self
-            .matching_repository
-            .get_latest_waiting_matching()
+            .join_waiting_matching(&PlayerId::new(player_id.to_string()), &player_rating)
             .await?;
 
         println!("Waiting matching: {:?}", waiting_matching);
 
         match waiting_matching {
-            Some(mut matching) => {
+            Some(matching) => {
                 println!(
                     "プレイヤー１のID: {}、プレイヤー２のID: {}",
                     matching.player1_id().value(),
@@ -94,96 +141,15 @@ impl MatchmakingApplicationService {
                     return Ok(());
                 }
 
-                // 既存のマッチングに参加
-                let result = matching
-                    .matchmaking(PlayerId::new(player_id.to_string()))
-                    .map_err(|e| {
-                        format!(
-                            "マッチング参加に失敗しました。player_id: {}, error: {}",
-                            player_id, e
-                        )
-                    });
-                if result.is_err() {
-                    return Err(result.err().unwrap());
-                }
-                let result = self.matching_repository.update(&matching).await;
-                if result.is_err() {
-                    // 更新失敗時のエラーハンドリング
-                    return Err(result.err().unwrap());
-                }
-                // ゲーム情報を登録
-                let game_id = GameId::new(matching.matching_id().value().to_string());
-                let game = Game::new(
-                    game_id.clone(),
-                    CurrentTurnNumber::new(1),
-                    matching.player1_id().clone(),
-                    PlayerId::new(player_id.to_string()),
-                );
-                let result = self.game_repository.save(&game).await;
-                if result.is_err() {
-                    return Err(result.err().unwrap());
-                }
-
-                println!("Matching updated successfully for player_id: {}", player_id);
-                // ユニット情報をエンティティに変換
-                let unit_entities: Vec<Unit> = CreateUnitDto::to_units(
-                    &units,
-                    &GameId::new(matching.matching_id().value().to_string()), // GameId をMatchingから生成する
-                    &PlayerId::new(player_id.to_string()),
-                );
-                // すでに登録済みの敵ユニット情報を取得
-                let enemy_units = self
-                    .unit_repository
-                    .get_game_units(&GameId::new(matching.matching_id().value().to_string()))
-                    .await?;
-                // ユニット情報を保存
-                self.insert_units(&unit_entities).await?;
-                // マッチング完了を通知
-                let response = WebSocketResponse::MatchmakingResult {
-                    status: MatchingStatusValue::Completed,
-                    game_id: Some(game_id.value().to_string()),
-                    enemy_units: EnemyUnitDto::from_units(&enemy_units),
-                    friend_units: FriendUnitDto::from_units(&unit_entities),
-                };
-                // WebSocket で通知を送信
-                self.websocket_sender
-                    .send_message(connection_id, &response)
-                    .await?;
-
-                // 対戦相手のコネクションIDを取得
-                let opponent_player_id = matching.player1_id().value();
-                let opponent_connection_id = self
-                    .connection_repository
-                    .get_connection_id(opponent_player_id)
-                    .await?;
-                // 対戦相手にマッチング完了を通知
-                let opponent_response = WebSocketResponse::MatchmakingResult {
-                    status: MatchingStatusValue::Completed,
-                    game_id: Some(game_id.value().to_string()),
-                    enemy_units: EnemyUnitDto::from_units(&unit_entities),
-                    friend_units: FriendUnitDto::from_units(&enemy_units),
-                };
-                self.websocket_sender
-                    .send_message(&opponent_connection_id, &opponent_response)
+                self.start_game(&matching, player_id, connection_id, &units)
                     .await?;
             }
             None => {
                 // 新規マッチングを作成
-                let new_matching = Matching::create(PlayerId::new(player_id.to_string()));
-                // マッチング情報を保存
-                let result = self.matching_repository.save(&new_matching).await;
-                if result.is_err() {
-                    // 保存失敗時のエラーハンドリング
-                    return Err(result.err().unwrap());
-                }
-                // ユニット情報をエンティティに変換
-                let unit_entities: Vec<Unit> = CreateUnitDto::to_units(
-                    &units,
-                    &GameId::new(new_matching.matching_id().value().to_string()), // GameId をMatchingから生成する
-                    &PlayerId::new(player_id.to_string()),
-                );
-                // ユニット情報を保存
-                self.insert_units(&unit_entities).await?;
+                let new_matching =
+                    Matching::create(PlayerId::new(player_id.to_string()), player_rating);
+                self.register_waiting_matching(&new_matching, player_id, &units)
+                    .await?;
                 // マッチング待機中を通知
                 let response = WebSocketResponse::MatchmakingResult {
                     status: MatchingStatusValue::InProgress,
@@ -200,6 +166,320 @@ impl MatchmakingApplicationService {
         Ok(())
     }
 
+    /// プライベートルームを作成するメソッド
+    /// 作成したプレイヤーのユニットを登録し、招待コードを通知する
+    pub async fn create_room(
+        &self,
+        player_id: &str,
+        connection_id: &str,
+        units: Vec<CreateUnitDto>,
+    ) -> Result<(), String> {
+        let player_rating = self
+            .find_player_rating(&PlayerId::new(player_id.to_string()))
+            .await?;
+        let room_code = self.generate_room_code().await?;
+        let matching = Matching::create_private(
+            PlayerId::new(player_id.to_string()),
+            player_rating,
+            room_code.clone(),
+        );
+        self.register_waiting_matching(&matching, player_id, &units)
+            .await?;
+
+        // 招待コードを通知
+        let response = WebSocketResponse::CreateRoomResult {
+            status: matching.matching_status().value().clone(),
+            room_code: room_code.value().to_string(),
+            expires_at: matching.expires_at(),
+        };
+        self.websocket_sender
+            .send_message(connection_id, &response)
+            .await?;
+        Ok(())
+    }
+
+    /// 招待コードを指定してプライベートルームに参加するメソッド
+    /// 参加できた場合は公開のマッチングと同様にゲームを開始する
+    pub async fn join_room(
+        &self,
+        player_id: &str,
+        connection_id: &str,
+        code: &str,
+        units: Vec<CreateUnitDto>,
+    ) -> Result<(), String> {
+        let room_code = RoomCode::parse(code)?;
+        // ルームに参加できるのは登録済みのプレイヤーのみ
+        self.find_player_rating(&PlayerId::new(player_id.to_string()))
+            .await?;
+        let mut matching = self
+            .matching_repository
+            .find_waiting_matching_by_room_code(&room_code)
+            .await?
+            .ok_or("招待コードに対応するルームが見つかりません".to_string())?;
+        if matching.player1_id().value() == player_id {
+            return Err("自分が作成したルームには参加できません".to_string());
+        }
+
+        if matching.is_expired(Utc::now()) {
+            match self.matching_interrupter.interrupt(&mut matching).await {
+                Ok(()) => {
+                    // ルームを作成したプレイヤーに中断を通知（オフラインの場合は再接続時に再送）
+                    let response = WebSocketResponse::MatchmakingResult {
+                        status: MatchingStatusValue::Interrupted,
+                        game_id: None,
+                        enemy_units: vec![],
+                        friend_units: vec![],
+                    };
+                    self.player_notifier
+                        .notify(matching.player1_id(), &response)
+                        .await?;
+                }
+                // 他のリクエストが先に中断した場合は通知不要
+                Err(e) if e == MATCHING_CONFLICT_ERROR => {}
+                Err(e) => return Err(e),
+            }
+            return Err("ルームの有効期限が切れています".to_string());
+        }
+
+        matching.matchmaking(PlayerId::new(player_id.to_string()))?;
+        match self.matching_repository.update(&matching).await {
+            Ok(()) => {}
+            Err(e) if e == MATCHING_CONFLICT_ERROR => {
+                return Err("ルームには既に他のプレイヤーが参加しています".to_string());
+            }
+            Err(e) => return Err(e),
+        }
+
+        self.start_game(&matching, player_id, connection_id, &units)
+            .await
+    }
+
+    /// 対戦相手として参加したマッチングのゲームを開始するメソッド
+    /// ゲーム・ユニット情報を登録し、両プレイヤーにマッチング完了を通知する
+    async fn start_game(
+        &self,
+        matching: &Matching,
+        player_id: &str,
+        connection_id: &str,
+        units: &[CreateUnitDto],
+    ) -> Result<(), String> {
+        // ゲーム情報を登録
+        let game_id = GameId::new(matching.matching_id().value().to_string());
+        let game = Game::new(
+            game_id.clone(),
+            CurrentTurnNumber::new(1),
+            matching.player1_id().clone(),
+            PlayerId::new(player_id.to_string()),
+            GameVersion::initial(),
+            Utc::now(),
+            Vec::new(),
+            GameStatus::initial(),
+            SimulationSeed::generate(),
+        );
+        let result = self.game_repository.save(&game).await;
+        if result.is_err() {
+            return Err(result.err().unwrap());
+        }
+
+        println!("Matching updated successfully for player_id: {}", player_id);
+        // ユニット情報をエンティティに変換
+        let unit_entities: Vec<Unit> = CreateUnitDto::to_units(
+            units,
+            &GameId::new(matching.matching_id().value().to_string()), // GameId をMatchingから生成する
+            &PlayerId::new(player_id.to_string()),
+        );
+        // すでに登録済みの敵ユニット情報を取得
+        let enemy_units = self
+            .unit_repository
+            .get_game_units(&GameId::new(matching.matching_id().value().to_string()))
+            .await?;
+        // ユニット情報を保存
+        self.insert_units(&unit_entities).await?;
+        // 対戦開始時点の状態をイベントログに残す
+        let mut events = vec![GameEventPayload::GameStarted {
+            player1_id: game.player1_id().clone(),
+            player2_id: game.player2_id().clone(),
+            simulation_seed: Some(game.simulation_seed().clone()),
+        }];
+        events.extend(enemy_units.iter().map(GameEventPayload::unit_deployed));
+        events.extend(unit_entities.iter().map(GameEventPayload::unit_deployed));
+        // リプレイの生成には対戦開始時のユニット配置が必要なため、記録に失敗した場合は開始しない
+        self.game_event_recorder
+            .record(&game_id, events)
+            .await
+            .map_err(|e| format!("ゲームイベントの記録に失敗しました: {}", e))?;
+        // マッチング完了を通知
+        let response = WebSocketResponse::MatchmakingResult {
+            status: MatchingStatusValue::Completed,
+            game_id: Some(game_id.value().to_string()),
+            enemy_units: EnemyUnitDto::from_units(&enemy_units),
+            friend_units: FriendUnitDto::from_units(&unit_entities),
+        };
+        // WebSocket で通知を送信
+        self.websocket_sender
+            .send_message(connection_id, &response)
+            .await?;
+
+        // 対戦相手にマッチング完了を通知
+        let opponent_response = WebSocketResponse::MatchmakingResult {
+            status: MatchingStatusValue::Completed,
+            game_id: Some(game_id.value().to_string()),
+            enemy_units: EnemyUnitDto::from_units(&unit_entities),
+            friend_units: FriendUnitDto::from_units(&enemy_units),
+        };
+        self.player_notifier
+            .notify(matching.player1_id(), &opponent_response)
+            .await?;
+        Ok(())
+    }
+
+    /// 対戦相手を待機するマッチングと、作成したプレイヤーのユニット情報を登録するメソッド
+    async fn register_waiting_matching(
+        &self,
+        matching: &Matching,
+        player_id: &str,
+        units: &[CreateUnitDto],
+    ) -> Result<(), String> {
+        // マッチング情報を保存
+        self.matching_repository.save(matching).await?;
+        // ユニット情報をエンティティに変換
+        let unit_entities: Vec<Unit> = CreateUnitDto::to_units(
+            units,
+            &GameId::new(matching.matching_id().value().to_string()), // GameId をMatchingから生成する
+            &PlayerId::new(player_id.to_string()),
+        );
+        // ユニット情報を保存
+        self.insert_units(&unit_entities).await
+    }
+
+    /// 待機中のルームと重複しない招待コードを生成するメソッド
+    async fn generate_room_code(&self) -> Result<RoomCode, String> {
+        for _ in 0..Self::ROOM_CODE_GENERATION_ATTEMPTS {
+            let room_code = RoomCode::generate(&mut rand::rng());
+            let existing = self
+                .matching_repository
+                .find_waiting_matching_by_room_code(&room_code)
+                .await?;
+            if existing.is_none() {
+                return Ok(room_code);
+            }
+        }
+        Err("招待コードの生成に失敗しました".to_string())
+    }
+
+    /// 登録済みのプレイヤーのレーティングを取得するメソッド
+    /// 登録されていないプレイヤーはマッチメイキングできない
+    async fn find_player_rating(&self, player_id: &PlayerId) -> Result<Rating, String> {
+        let player = self
+            .player_repository
+            .find_by_id(player_id)
+            .await
+            .map_err(|e| format!("プレイヤー情報の取得に失敗しました: {}", e))?
+            .ok_or(format!(
+                "登録されていないプレイヤーはマッチメイキングできません。player_id: {}",
+                player_id.value()
+            ))?;
+        Ok(player.rating().clone())
+    }
+
+    /// 待機中のマッチングに対戦相手として参加するメソッド
+    /// 参加できたマッチング、または自分自身が待機中のマッチングを返す
+    ///
+    /// 他のプレイヤーが同時に同じマッチングへ参加した場合は条件付き更新が競合するため、
+    /// 残りの待機中のマッチングから選び直して再試行する
+    async fn join_waiting_matching(
+        &self,
+        player_id: &PlayerId,
+        player_rating: &Rating,
+    ) -> Result<Option<Matching>, String> {
+        // 競合や期限切れで参加できなかったマッチング
+        let mut skipped_matching_ids: Vec<MatchingId> = Vec::new();
+        loop {
+            let mut matching = match self
+                .find_waiting_matching(player_id, player_rating, &mut skipped_matching_ids)
+                .await?
+            {
+                Some(matching) => matching,
+                None => return Ok(None),
+            };
+            if matching.player1_id() == player_id {
+                return Ok(Some(matching));
+            }
+
+            matching.matchmaking(player_id.clone()).map_err(|e| {
+                format!(
+                    "マッチング参加に失敗しました。player_id: {}, error: {}",
+                    player_id.value(),
+                    e
+                )
+            })?;
+            match self.matching_repository.update(&matching).await {
+                Ok(()) => return Ok(Some(matching)),
+                Err(e) if e == MATCHING_CONFLICT_ERROR => {
+                    println!(
+                        "他のプレイヤーが先に参加したため次のマッチングを探します マッチングID: {}",
+                        matching.matching_id().value()
+                    );
+                    skipped_matching_ids.push(matching.matching_id().clone());
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// 待機時間を超過していない待機中のマッチングから、マッチメイキングの戦略に従って参加先を選ぶメソッド
+    /// 自分自身が待機中のマッチングがある場合はそれを返す
+    /// 放置されたマッチングと対戦しないよう、超過したマッチングは中断して候補から除く
+    async fn find_waiting_matching(
+        &self,
+        player_id: &PlayerId,
+        player_rating: &Rating,
+        skipped_matching_ids: &mut Vec<MatchingId>,
+    ) -> Result<Option<Matching>, String> {
+        // インデックスへの反映には遅延があるため、処理済みのマッチングは取得後に除外する
+        let candidates = self
+            .matching_repository
+            .get_waiting_matchings(Self::WAITING_CANDIDATES_LIMIT)
+            .await?;
+
+        let now = Utc::now();
+        let mut waiting_matchings: Vec<Matching> = Vec::new();
+        for mut matching in candidates {
+            if skipped_matching_ids.contains(matching.matching_id()) {
+                continue;
+            }
+            if !matching.is_expired(now) {
+                if matching.player1_id() == player_id {
+                    return Ok(Some(matching));
+                }
+                waiting_matchings.push(matching);
+                continue;
+            }
+
+            skipped_matching_ids.push(matching.matching_id().clone());
+            match self.matching_interrupter.interrupt(&mut matching).await {
+                Ok(()) => {}
+                // 他のリクエストが先に参加・中断した場合は通知不要
+                Err(e) if e == MATCHING_CONFLICT_ERROR => continue,
+                Err(e) => return Err(e),
+            }
+            // 待機していたプレイヤーに中断を通知（オフラインの場合は再接続時に再送）
+            let response = WebSocketResponse::MatchmakingResult {
+                status: MatchingStatusValue::Interrupted,
+                game_id: None,
+                enemy_units: vec![],
+                friend_units: vec![],
+            };
+            self.player_notifier
+                .notify(matching.player1_id(), &response)
+                .await?;
+        }
+        Ok(self
+            .matchmaking_strategy
+            .select(player_rating, &waiting_matchings, now)
+            .cloned())
+    }
+
     /// ユニット情報を保存するメソッド
     async fn insert_units(&self, units: &Vec<Unit>) -> Result<(), String> {
         for unit in units {
@@ -0,0 +1,8 @@
+pub mod disable_mfa_usecase;
+pub mod enable_mfa_usecase;
+pub mod get_profile_usecase;
+pub mod login_usecase;
+pub mod player_profile_dto;
+pub mod register_player_usecase;
+pub mod rename_player_usecase;
+pub mod setup_mfa_usecase;
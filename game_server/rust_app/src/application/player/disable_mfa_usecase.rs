@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::{
+    application::{
+        player::player_profile_dto::PlayerProfileDto,
+        websocket::{websocket_response::WebSocketResponse, websocket_sender::WebSocketSender},
+    },
+    domain::player_management::{
+        models::player::player_id::player_id::PlayerId,
+        repositories::player_repository::PlayerRepository,
+    },
+};
+
+/// MFA認証を無効化するユースケース
+/// 本人確認のため、ワンタイムパスワードまたはリカバリーコードを必要とする
+pub struct DisableMfaUseCase {
+    player_repository: Arc<dyn PlayerRepository>,
+    websocket_sender: Arc<dyn WebSocketSender>,
+}
+
+impl DisableMfaUseCase {
+    pub fn new(
+        player_repository: Arc<dyn PlayerRepository>,
+        websocket_sender: Arc<dyn WebSocketSender>,
+    ) -> Self {
+        Self {
+            player_repository,
+            websocket_sender,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        player_id: PlayerId,
+        code: &str,
+        connection_id: &str,
+    ) -> Result<(), String> {
+        let mut player = self
+            .player_repository
+            .find_by_id(&player_id)
+            .await
+            .map_err(|e| format!("プレイヤー情報の取得に失敗しました: {}", e))?
+            .ok_or("プレイヤーが見つかりませんでした。".to_string())?;
+
+        let result = player.disable_mfa(code, Utc::now());
+        // 失敗回数を記録するため、無効化に失敗した場合も保存する
+        self.player_repository
+            .save(&player)
+            .await
+            .map_err(|e| format!("プレイヤー情報の更新に失敗しました: {}", e))?;
+        result?;
+
+        let response = WebSocketResponse::DisableMfaResult {
+            profile: PlayerProfileDto::from_player(&player),
+        };
+        self.websocket_sender
+            .send_message(connection_id, &response)
+            .await?;
+        Ok(())
+    }
+}
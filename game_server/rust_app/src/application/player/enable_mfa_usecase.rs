@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::{
+    application::{
+        player::player_profile_dto::PlayerProfileDto,
+        websocket::{websocket_response::WebSocketResponse, websocket_sender::WebSocketSender},
+    },
+    domain::player_management::{
+        models::player::{
+            player_id::player_id::PlayerId, recovery_code::recovery_code::RecoveryCode,
+        },
+        repositories::player_repository::PlayerRepository,
+    },
+};
+
+/// 有効化と同時に発行するリカバリーコードの数
+const RECOVERY_CODE_COUNT: usize = 10;
+
+/// ワンタイムパスワードで登録中のシークレットを確認し、MFA認証を有効化するユースケース
+/// リカバリーコードはこのレスポンスでのみ平文で返し、サーバーにはハッシュ値のみ保存する
+pub struct EnableMfaUseCase {
+    player_repository: Arc<dyn PlayerRepository>,
+    websocket_sender: Arc<dyn WebSocketSender>,
+}
+
+impl EnableMfaUseCase {
+    pub fn new(
+        player_repository: Arc<dyn PlayerRepository>,
+        websocket_sender: Arc<dyn WebSocketSender>,
+    ) -> Self {
+        Self {
+            player_repository,
+            websocket_sender,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        player_id: PlayerId,
+        code: &str,
+        connection_id: &str,
+    ) -> Result<(), String> {
+        let mut player = self
+            .player_repository
+            .find_by_id(&player_id)
+            .await
+            .map_err(|e| format!("プレイヤー情報の取得に失敗しました: {}", e))?
+            .ok_or("プレイヤーが見つかりませんでした。".to_string())?;
+
+        let (codes, recovery_codes): (Vec<String>, Vec<RecoveryCode>) = {
+            let mut rng = rand::rng();
+            (0..RECOVERY_CODE_COUNT)
+                .map(|_| RecoveryCode::generate(&mut rng))
+                .unzip()
+        };
+        let result = player.enable_mfa(code, Utc::now(), recovery_codes);
+        // 失敗回数を記録するため、有効化に失敗した場合も保存する
+        self.player_repository
+            .save(&player)
+            .await
+            .map_err(|e| format!("プレイヤー情報の更新に失敗しました: {}", e))?;
+        result?;
+
+        let response = WebSocketResponse::EnableMfaResult {
+            profile: PlayerProfileDto::from_player(&player),
+            recovery_codes: codes,
+        };
+        self.websocket_sender
+            .send_message(connection_id, &response)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use crate::{
+    application::{
+        player::player_profile_dto::PlayerProfileDto,
+        websocket::{websocket_response::WebSocketResponse, websocket_sender::WebSocketSender},
+    },
+    domain::player_management::{
+        models::player::player_id::player_id::PlayerId,
+        repositories::player_repository::PlayerRepository,
+    },
+};
+
+/// プレイヤー情報を取得するユースケース
+pub struct GetProfileUseCase {
+    player_repository: Arc<dyn PlayerRepository>,
+    websocket_sender: Arc<dyn WebSocketSender>,
+}
+
+impl GetProfileUseCase {
+    pub fn new(
+        player_repository: Arc<dyn PlayerRepository>,
+        websocket_sender: Arc<dyn WebSocketSender>,
+    ) -> Self {
+        Self {
+            player_repository,
+            websocket_sender,
+        }
+    }
+
+    pub async fn execute(&self, player_id: PlayerId, connection_id: &str) -> Result<(), String> {
+        let player = self
+            .player_repository
+            .find_by_id(&player_id)
+            .await
+            .map_err(|e| format!("プレイヤー情報の取得に失敗しました: {}", e))?
+            .ok_or("プレイヤーが見つかりませんでした。".to_string())?;
+
+        let response = WebSocketResponse::GetProfileResult {
+            profile: PlayerProfileDto::from_player(&player),
+        };
+        self.websocket_sender
+            .send_message(connection_id, &response)
+            .await?;
+        Ok(())
+    }
+}
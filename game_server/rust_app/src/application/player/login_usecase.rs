@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::{
+    application::{
+        player::player_profile_dto::PlayerProfileDto,
+        websocket::{
+            request_authenticator::RequestAuthenticator, websocket_response::WebSocketResponse,
+            websocket_sender::WebSocketSender,
+        },
+    },
+    domain::player_management::{
+        repositories::{
+            connection_repository::ConnectionRepository, player_repository::PlayerRepository,
+        },
+        services::session_token_service::SessionTokenService,
+    },
+};
+
+/// 発行済みのセッショントークンでログインするユースケース
+/// 認証したプレイヤーをコネクションに紐付け、有効期限を延長したトークンを再発行する
+/// MFA認証が有効なプレイヤーは、ワンタイムパスワードまたはリカバリーコードも必要
+pub struct LoginUseCase {
+    session_token_service: Arc<dyn SessionTokenService>,
+    request_authenticator: RequestAuthenticator,
+    websocket_sender: Arc<dyn WebSocketSender>,
+}
+
+impl LoginUseCase {
+    pub fn new(
+        connection_repository: Arc<dyn ConnectionRepository>,
+        player_repository: Arc<dyn PlayerRepository>,
+        session_token_service: Arc<dyn SessionTokenService>,
+        websocket_sender: Arc<dyn WebSocketSender>,
+    ) -> Self {
+        Self {
+            session_token_service: session_token_service.clone(),
+            request_authenticator: RequestAuthenticator::new(
+                connection_repository,
+                player_repository,
+                session_token_service,
+            ),
+            websocket_sender,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        session_token: &str,
+        mfa_code: Option<&str>,
+        connection_id: &str,
+    ) -> Result<(), String> {
+        let player = self
+            .request_authenticator
+            .authenticate(session_token, mfa_code, connection_id)
+            .await?;
+
+        let refreshed_token = self
+            .session_token_service
+            .issue(player.player_id(), Utc::now())?;
+        let response = WebSocketResponse::LoginResult {
+            profile: PlayerProfileDto::from_player(&player),
+            session_token: refreshed_token.value().to_string(),
+            expires_at: *refreshed_token.expires_at(),
+        };
+        self.websocket_sender
+            .send_message(connection_id, &response)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::domain::player_management::models::player::Player;
+
+/// クライアントに返すプレイヤー情報
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerProfileDto {
+    /// プレイヤーID
+    pub player_id: String,
+    /// プレイヤー名
+    pub player_name: String,
+    /// 登録日時
+    pub registered_datetime: DateTime<Utc>,
+    /// レーティング
+    pub rating: i32,
+    /// MFA認証が有効かどうか
+    pub is_mfa_enabled: bool,
+}
+
+impl PlayerProfileDto {
+    pub fn from_player(player: &Player) -> Self {
+        Self {
+            player_id: player.player_id().value().to_string(),
+            player_name: player.player_name().value().to_string(),
+            registered_datetime: *player.registered_datetime().value(),
+            rating: player.rating().value(),
+            is_mfa_enabled: player.is_mfa_enabled(),
+        }
+    }
+}
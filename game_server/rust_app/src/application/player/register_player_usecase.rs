@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::{
+    application::{
+        player::player_profile_dto::PlayerProfileDto,
+        websocket::{websocket_response::WebSocketResponse, websocket_sender::WebSocketSender},
+    },
+    domain::player_management::{
+        models::player::{player_name::player_name::PlayerName, Player},
+        repositories::{
+            connection_repository::ConnectionRepository, player_repository::PlayerRepository,
+        },
+        services::session_token_service::SessionTokenService,
+    },
+};
+
+/// プレイヤーを新規登録するユースケース
+/// 登録したプレイヤーのセッショントークンを発行し、コネクションを認証済みとする
+pub struct RegisterPlayerUseCase {
+    connection_repository: Arc<dyn ConnectionRepository>,
+    player_repository: Arc<dyn PlayerRepository>,
+    session_token_service: Arc<dyn SessionTokenService>,
+    websocket_sender: Arc<dyn WebSocketSender>,
+}
+
+impl RegisterPlayerUseCase {
+    pub fn new(
+        connection_repository: Arc<dyn ConnectionRepository>,
+        player_repository: Arc<dyn PlayerRepository>,
+        session_token_service: Arc<dyn SessionTokenService>,
+        websocket_sender: Arc<dyn WebSocketSender>,
+    ) -> Self {
+        Self {
+            connection_repository,
+            player_repository,
+            session_token_service,
+            websocket_sender,
+        }
+    }
+
+    pub async fn execute(&self, player_name: &str, connection_id: &str) -> Result<(), String> {
+        let player_name = PlayerName::parse(player_name)?;
+        let player = Player::create(player_name);
+        self.player_repository
+            .save(&player)
+            .await
+            .map_err(|e| format!("プレイヤー情報の登録に失敗しました: {}", e))?;
+
+        let session_token = self
+            .session_token_service
+            .issue(player.player_id(), Utc::now())?;
+
+        // 採番されたPlayerIDとコネクションIDを紐付け、以降のリクエストを認証済みとする
+        self.connection_repository
+            .save(player.player_id().value(), connection_id)
+            .await?;
+
+        let response = WebSocketResponse::RegisterPlayerResult {
+            profile: PlayerProfileDto::from_player(&player),
+            session_token: session_token.value().to_string(),
+            expires_at: *session_token.expires_at(),
+        };
+        self.websocket_sender
+            .send_message(connection_id, &response)
+            .await?;
+        Ok(())
+    }
+}
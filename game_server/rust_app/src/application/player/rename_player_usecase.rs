@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use crate::{
+    application::{
+        player::player_profile_dto::PlayerProfileDto,
+        websocket::{websocket_response::WebSocketResponse, websocket_sender::WebSocketSender},
+    },
+    domain::player_management::{
+        models::player::{player_id::player_id::PlayerId, player_name::player_name::PlayerName},
+        repositories::player_repository::PlayerRepository,
+    },
+};
+
+/// プレイヤー名を変更するユースケース
+pub struct RenamePlayerUseCase {
+    player_repository: Arc<dyn PlayerRepository>,
+    websocket_sender: Arc<dyn WebSocketSender>,
+}
+
+impl RenamePlayerUseCase {
+    pub fn new(
+        player_repository: Arc<dyn PlayerRepository>,
+        websocket_sender: Arc<dyn WebSocketSender>,
+    ) -> Self {
+        Self {
+            player_repository,
+            websocket_sender,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        player_id: PlayerId,
+        player_name: &str,
+        connection_id: &str,
+    ) -> Result<(), String> {
+        let player_name = PlayerName::parse(player_name)?;
+        let mut player = self
+            .player_repository
+            .find_by_id(&player_id)
+            .await
+            .map_err(|e| format!("プレイヤー情報の取得に失敗しました: {}", e))?
+            .ok_or("プレイヤーが見つかりませんでした。".to_string())?;
+
+        player.change_name(player_name);
+        self.player_repository
+            .save(&player)
+            .await
+            .map_err(|e| format!("プレイヤー情報の更新に失敗しました: {}", e))?;
+
+        let response = WebSocketResponse::RenamePlayerResult {
+            profile: PlayerProfileDto::from_player(&player),
+        };
+        self.websocket_sender
+            .send_message(connection_id, &response)
+            .await?;
+        Ok(())
+    }
+}
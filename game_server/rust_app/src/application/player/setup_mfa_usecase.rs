@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use crate::{
+    application::websocket::{
+        websocket_response::WebSocketResponse, websocket_sender::WebSocketSender,
+    },
+    domain::player_management::{
+        models::player::{player_id::player_id::PlayerId, totp_secret::totp_secret::TotpSecret},
+        repositories::player_repository::PlayerRepository,
+    },
+};
+
+/// 認証アプリに表示する発行者名
+const MFA_ISSUER: &str = "TriggerGame";
+
+/// MFA認証の登録を開始するユースケース
+/// TOTPシークレットを発行し、認証アプリに登録するためのプロビジョニングURIを返す
+/// 発行したシークレットは、EnableMfaでワンタイムパスワードを確認するまで有効にならない
+pub struct SetupMfaUseCase {
+    player_repository: Arc<dyn PlayerRepository>,
+    websocket_sender: Arc<dyn WebSocketSender>,
+}
+
+impl SetupMfaUseCase {
+    pub fn new(
+        player_repository: Arc<dyn PlayerRepository>,
+        websocket_sender: Arc<dyn WebSocketSender>,
+    ) -> Self {
+        Self {
+            player_repository,
+            websocket_sender,
+        }
+    }
+
+    pub async fn execute(&self, player_id: PlayerId, connection_id: &str) -> Result<(), String> {
+        let mut player = self
+            .player_repository
+            .find_by_id(&player_id)
+            .await
+            .map_err(|e| format!("プレイヤー情報の取得に失敗しました: {}", e))?
+            .ok_or("プレイヤーが見つかりませんでした。".to_string())?;
+
+        let totp_secret = TotpSecret::generate(&mut rand::rng());
+        player.start_mfa_enrollment(totp_secret.clone())?;
+        self.player_repository
+            .save(&player)
+            .await
+            .map_err(|e| format!("プレイヤー情報の更新に失敗しました: {}", e))?;
+
+        let response = WebSocketResponse::SetupMfaResult {
+            secret: totp_secret.value().to_string(),
+            provisioning_uri: totp_secret
+                .provisioning_uri(MFA_ISSUER, player.player_name().value()),
+        };
+        self.websocket_sender
+            .send_message(connection_id, &response)
+            .await?;
+        Ok(())
+    }
+}
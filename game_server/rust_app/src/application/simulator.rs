@@ -0,0 +1,3 @@
+pub mod match_simulator;
+pub mod simulation_report;
+pub mod simulation_report_test;
@@ -0,0 +1,200 @@
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::{
+    application::{
+        bot::bot_player::BotPlayer,
+        matchmaking::matchmaking_dto::CreateUnitDto,
+        simulator::simulation_report::{GameRecord, SimulationReport},
+    },
+    domain::{
+        player_management::models::player::player_id::player_id::PlayerId,
+        triggergame_simulator::{
+            configs::game_config::GameConfig,
+            models::{
+                game::{
+                    game::Game, game_id::game_id::GameId, game_result::game_result::GameResult,
+                    simulation_seed::simulation_seed::SimulationSeed,
+                },
+                step::step::Step,
+                turn::{
+                    turn_id::turn_id::TurnId,
+                    turn_number::turn_number::TurnNumber,
+                    turn_start_datetime::turn_start_datetime::TurnStartDatetime,
+                    turn_status::turn_status::{TurnStatus, TurnStatusValue},
+                    Turn,
+                },
+            },
+        },
+        unit_management::models::unit::Unit,
+    },
+};
+
+/// シミュレーションの設定
+/// 未指定の項目は既定値を使用する
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SimulationSettings {
+    /// 対戦数
+    pub games: u32,
+    /// 最初の対戦のシード値（以降の対戦は1ずつ加算する）
+    pub seed: u32,
+    /// プレイヤー1の出撃ユニット
+    pub player1_units: Vec<CreateUnitDto>,
+    /// プレイヤー2の出撃ユニット
+    pub player2_units: Vec<CreateUnitDto>,
+    /// 戦闘計算の設定値
+    pub game_config: GameConfigSettings,
+}
+
+impl Default for SimulationSettings {
+    fn default() -> Self {
+        Self {
+            games: 100,
+            seed: 0,
+            player1_units: BotPlayer::deploy_units(),
+            player2_units: BotPlayer::deploy_units(),
+            game_config: GameConfigSettings::default(),
+        }
+    }
+}
+
+/// 戦闘計算の設定値
+/// 未指定の項目はゲームの設定値を使用する
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameConfigSettings {
+    pub avoid_weight: Option<i32>,
+    pub damage_weight: Option<f64>,
+    pub defend_weight: Option<f64>,
+    pub min_damage: Option<i32>,
+}
+
+impl GameConfigSettings {
+    /// ゲームの設定値に反映
+    pub fn apply(&self, config: GameConfig) -> GameConfig {
+        let avoid_weight = self.avoid_weight.unwrap_or(config.avoid_weight());
+        let damage_weight = self.damage_weight.unwrap_or(config.damage_weight());
+        let defend_weight = self.defend_weight.unwrap_or(config.defend_weight());
+        let min_damage = self.min_damage.unwrap_or(config.min_damage());
+        config.with_combat_weights(avoid_weight, damage_weight, defend_weight, min_damage)
+    }
+}
+
+/// 対戦シミュレーター
+/// ボット同士の対戦をリポジトリを使わずにメモリ上で繰り返し、バランス調整用の集計結果を返す
+///
+/// ターンの演算はProcessTurnUseCaseと同じくTurn::turn_startで行い、回避判定の乱数はシード値から生成する
+pub struct MatchSimulator {
+    settings: SimulationSettings,
+}
+
+impl MatchSimulator {
+    pub fn new(settings: SimulationSettings) -> Self {
+        Self { settings }
+    }
+
+    /// 設定された対戦数のシミュレーションを実行
+    pub fn run(&self) -> Result<SimulationReport, String> {
+        if self.settings.player1_units.is_empty() || self.settings.player2_units.is_empty() {
+            return Err("両プレイヤーの出撃ユニットを指定してください".to_string());
+        }
+        GameConfig::set_thread_override(Some(
+            self.settings
+                .game_config
+                .apply(GameConfig::get_game_config()),
+        ));
+        let records = (0..self.settings.games)
+            .map(|i| self.simulate_game(SimulationSeed::new(self.settings.seed.wrapping_add(i))))
+            .collect::<Result<Vec<GameRecord>, String>>();
+        GameConfig::set_thread_override(None);
+        Ok(SimulationReport::aggregate(&records?))
+    }
+
+    /// 1対戦分のシミュレーション
+    fn simulate_game(&self, seed: SimulationSeed) -> Result<GameRecord, String> {
+        let game_id = GameId::new(Uuid::new_v4().to_string());
+        let player1_id = PlayerId::new(Uuid::new_v4().to_string());
+        let player2_id = PlayerId::new(Uuid::new_v4().to_string());
+        let mut game = Game::create(game_id.clone(), &player1_id, &player2_id);
+        let mut units =
+            CreateUnitDto::to_units(&self.settings.player1_units, &game_id, &player1_id);
+        units.extend(CreateUnitDto::to_units(
+            &self.settings.player2_units,
+            &game_id,
+            &player2_id,
+        ));
+
+        let mut combats = Vec::new();
+        loop {
+            let turn_number = TurnNumber::new(game.current_turn_number().value());
+            let mut turn = Self::create_turn(
+                &game_id,
+                &player1_id,
+                &turn_number,
+                BotPlayer::plan_steps(&player1_id, &units),
+            );
+            let opponent_turn = Self::create_turn(
+                &game_id,
+                &player2_id,
+                &turn_number,
+                BotPlayer::plan_steps(&player2_id, &units),
+            );
+            let mut rng = seed.rng_for_turn(&turn_number);
+            turn.turn_start(&mut units, &opponent_turn, &mut rng)?;
+            combats.extend(turn.steps().iter().flat_map(|s| s.combats().clone()));
+
+            // どちらかのユニットが全滅した場合は勝敗が変わらないため、以降のターンは演算しない
+            if game.is_game_finished()
+                || Self::is_annihilated(&units, &player1_id)
+                || Self::is_annihilated(&units, &player2_id)
+            {
+                break;
+            }
+            game.advance_to_next_turn()?;
+        }
+
+        let winner = match GameResult::judge(&game, &units) {
+            GameResult::Winner(winner_id) if winner_id == player1_id => Some(1),
+            GameResult::Winner(_) => Some(2),
+            GameResult::Draw => None,
+        };
+        Ok(GameRecord {
+            winner,
+            turns: game.current_turn_number().value(),
+            combats,
+            units,
+        })
+    }
+
+    /// 行動設定済みのターンを生成
+    fn create_turn(
+        game_id: &GameId,
+        player_id: &PlayerId,
+        turn_number: &TurnNumber,
+        steps: Vec<Step>,
+    ) -> Turn {
+        Turn::new(
+            TurnId::new(format!(
+                "{}_{}_{}",
+                game_id.value(),
+                player_id.value(),
+                turn_number.value()
+            )),
+            game_id.clone(),
+            player_id.clone(),
+            turn_number.clone(),
+            TurnStartDatetime::new(chrono::Utc::now()),
+            TurnStatus::new(TurnStatusValue::StepSetting),
+            steps,
+        )
+    }
+
+    /// 指定されたプレイヤーのユニットが全滅しているかどうか
+    fn is_annihilated(units: &[Unit], player_id: &PlayerId) -> bool {
+        units
+            .iter()
+            .filter(|u| u.owner_player_id() == player_id)
+            .all(|u| u.is_bailed_out())
+    }
+}
@@ -0,0 +1,174 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::domain::{
+    triggergame_simulator::models::combat::Combat, unit_management::models::unit::Unit,
+};
+
+/// 1対戦分のシミュレーション結果
+pub struct GameRecord {
+    /// 勝者（1: プレイヤー1、2: プレイヤー2、None: 引き分け）
+    pub winner: Option<u8>,
+    /// 演算したターン数
+    pub turns: i32,
+    /// 発生した戦闘
+    pub combats: Vec<Combat>,
+    /// 最終ターンの演算後のユニット
+    pub units: Vec<Unit>,
+}
+
+/// シミュレーションの集計結果
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulationReport {
+    games: u32,
+    player1_wins: u32,
+    player2_wins: u32,
+    draws: u32,
+    player1_win_rate: f64,
+    player2_win_rate: f64,
+    draw_rate: f64,
+    /// 1対戦あたりの平均ターン数
+    average_turns: f64,
+    /// 攻撃に使用したトリガーごとの戦闘結果
+    triggers: Vec<TriggerStats>,
+    /// ユニットタイプごとのベイルアウト数
+    unit_types: Vec<UnitTypeStats>,
+}
+
+/// トリガーごとの戦闘結果
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TriggerStats {
+    trigger_id: String,
+    /// トリガーを攻撃に使用した戦闘の数
+    combats: u32,
+    total_damage: i64,
+    /// 1戦闘あたりの平均ダメージ（メイン・サブトリガーへのダメージの合計）
+    average_damage: f64,
+}
+
+/// ユニットタイプごとのベイルアウト数
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnitTypeStats {
+    unit_type_id: String,
+    deployed: u32,
+    bailouts: u32,
+    bailout_rate: f64,
+}
+
+impl SimulationReport {
+    /// 対戦ごとの結果を集計
+    pub fn aggregate(records: &[GameRecord]) -> Self {
+        let games = records.len() as u32;
+        let count_winner =
+            |winner: Option<u8>| records.iter().filter(|r| r.winner == winner).count() as u32;
+        let player1_wins = count_winner(Some(1));
+        let player2_wins = count_winner(Some(2));
+        let draws = count_winner(None);
+
+        let mut triggers: BTreeMap<String, TriggerStats> = BTreeMap::new();
+        for combat in records.iter().flat_map(|r| &r.combats) {
+            // ダメージは攻撃に使用したトリガーごとに計上する（回避された攻撃のダメージは0）
+            let mut used_trigger_ids: Vec<&str> = Vec::new();
+            for hit in combat.hits() {
+                let trigger_id = hit.trigger_id().value();
+                let stats = triggers.entry(trigger_id.to_string()).or_default();
+                stats.trigger_id = trigger_id.to_string();
+                if !used_trigger_ids.contains(&trigger_id) {
+                    // 同じトリガーで複数回攻撃した場合も、戦闘数は1回と数える
+                    used_trigger_ids.push(trigger_id);
+                    stats.combats += 1;
+                }
+                stats.total_damage += (hit.main_trigger_damage() + hit.sub_trigger_damage()) as i64;
+            }
+        }
+        for stats in triggers.values_mut() {
+            stats.average_damage = Self::rate(stats.total_damage as f64, stats.combats);
+        }
+
+        let mut unit_types: BTreeMap<String, UnitTypeStats> = BTreeMap::new();
+        for unit in records.iter().flat_map(|r| &r.units) {
+            let unit_type_id = unit.unit_type_id().value().to_string();
+            let stats = unit_types.entry(unit_type_id.clone()).or_default();
+            stats.unit_type_id = unit_type_id;
+            stats.deployed += 1;
+            if unit.is_bailed_out() {
+                stats.bailouts += 1;
+            }
+        }
+        for stats in unit_types.values_mut() {
+            stats.bailout_rate = Self::rate(stats.bailouts as f64, stats.deployed);
+        }
+
+        Self {
+            games,
+            player1_wins,
+            player2_wins,
+            draws,
+            player1_win_rate: Self::rate(player1_wins as f64, games),
+            player2_win_rate: Self::rate(player2_wins as f64, games),
+            draw_rate: Self::rate(draws as f64, games),
+            average_turns: Self::rate(records.iter().map(|r| r.turns as f64).sum(), games),
+            triggers: triggers.into_values().collect(),
+            unit_types: unit_types.into_values().collect(),
+        }
+    }
+
+    /// JSON形式で出力
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| format!("集計結果の変換に失敗しました: {}", e))
+    }
+
+    /// CSV形式で出力
+    /// 集計項目ごとに「項目,キー,値」の1行で出力する
+    pub fn to_csv(&self) -> String {
+        let mut rows = vec!["metric,key,value".to_string()];
+        rows.push(format!("games,,{}", self.games));
+        rows.push(format!("wins,player1,{}", self.player1_wins));
+        rows.push(format!("wins,player2,{}", self.player2_wins));
+        rows.push(format!("wins,draw,{}", self.draws));
+        rows.push(format!("win_rate,player1,{}", self.player1_win_rate));
+        rows.push(format!("win_rate,player2,{}", self.player2_win_rate));
+        rows.push(format!("win_rate,draw,{}", self.draw_rate));
+        rows.push(format!("average_turns,,{}", self.average_turns));
+        for stats in &self.triggers {
+            rows.push(format!("combats,{},{}", stats.trigger_id, stats.combats));
+            rows.push(format!(
+                "total_damage,{},{}",
+                stats.trigger_id, stats.total_damage
+            ));
+            rows.push(format!(
+                "average_damage,{},{}",
+                stats.trigger_id, stats.average_damage
+            ));
+        }
+        for stats in &self.unit_types {
+            rows.push(format!(
+                "deployed,{},{}",
+                stats.unit_type_id, stats.deployed
+            ));
+            rows.push(format!(
+                "bailouts,{},{}",
+                stats.unit_type_id, stats.bailouts
+            ));
+            rows.push(format!(
+                "bailout_rate,{},{}",
+                stats.unit_type_id, stats.bailout_rate
+            ));
+        }
+        rows.join("\n") + "\n"
+    }
+
+    /// 0件の場合は0とする割合
+    fn rate(value: f64, count: u32) -> f64 {
+        if count == 0 {
+            0.0
+        } else {
+            value / count as f64
+        }
+    }
+}
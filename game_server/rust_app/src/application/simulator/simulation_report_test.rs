@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::application::simulator::simulation_report::{GameRecord, SimulationReport};
+    use crate::domain::triggergame_simulator::models::{
+        action::trigger_azimuth::trigger_azimuth::TriggerAzimuth,
+        combat::{
+            combat_hit::combat_hit::CombatHit, combat_id::combat_id::CombatId,
+            is_avoided::is_avoided::IsAvoided, Combat,
+        },
+    };
+    use crate::domain::unit_management::models::unit::{
+        position::position::Position, trigger_id::trigger_id::TriggerId, unit_id::unit_id::UnitId,
+    };
+
+    /// メイントリガーにKOGETSU、サブトリガーにASTEROIDを使用した攻撃側との戦闘
+    fn create_combat(hits: Vec<CombatHit>) -> Combat {
+        let main_trigger_damage = hits.iter().map(|hit| hit.main_trigger_damage()).sum();
+        let sub_trigger_damage = hits.iter().map(|hit| hit.sub_trigger_damage()).sum();
+        Combat::reconstruct(
+            CombatId::new(Uuid::new_v4().to_string()),
+            UnitId::new(Uuid::new_v4().to_string()),
+            Position::new(17, 17),
+            TriggerId::new("KOGETSU".to_string()),
+            TriggerId::new("ASTEROID".to_string()),
+            TriggerAzimuth::new(90),
+            TriggerAzimuth::new(90),
+            10,
+            UnitId::new(Uuid::new_v4().to_string()),
+            Position::new(18, 18),
+            TriggerId::new("KOGETSU".to_string()),
+            TriggerId::new("SHIELD".to_string()),
+            TriggerAzimuth::new(90),
+            TriggerAzimuth::new(90),
+            5,
+            2,
+            100 - main_trigger_damage,
+            100 - sub_trigger_damage,
+            main_trigger_damage,
+            sub_trigger_damage,
+            IsAvoided::new(false),
+            false,
+            hits,
+        )
+    }
+
+    #[test]
+    fn test_aggregate_damage_by_hit_trigger() {
+        // メイン・サブトリガーの両方で攻撃し、サブトリガーの2回目の攻撃は回避された戦闘
+        let combat = create_combat(vec![
+            CombatHit::new(
+                TriggerId::new("KOGETSU".to_string()),
+                IsAvoided::new(false),
+                20,
+                10,
+            ),
+            CombatHit::new(
+                TriggerId::new("ASTEROID".to_string()),
+                IsAvoided::new(false),
+                0,
+                8,
+            ),
+            CombatHit::new(
+                TriggerId::new("ASTEROID".to_string()),
+                IsAvoided::new(true),
+                0,
+                0,
+            ),
+        ]);
+        let report = SimulationReport::aggregate(&[GameRecord {
+            winner: Some(1),
+            turns: 1,
+            combats: vec![combat],
+            units: Vec::new(),
+        }]);
+
+        // ダメージは攻撃に使用したトリガーごとに計上され、メイントリガーにまとめられない
+        let csv = report.to_csv();
+        assert!(csv.contains("combats,ASTEROID,1\n"));
+        assert!(csv.contains("total_damage,ASTEROID,8\n"));
+        assert!(csv.contains("combats,KOGETSU,1\n"));
+        assert!(csv.contains("total_damage,KOGETSU,30\n"));
+    }
+}
@@ -1,3 +1,7 @@
+pub mod disconnect_usecase;
+pub mod player_notifier;
+pub mod request_authenticator;
 pub mod websocket_request;
+pub mod websocket_request_test;
 pub mod websocket_response;
 pub mod websocket_sender;
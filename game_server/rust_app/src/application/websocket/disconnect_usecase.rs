@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use crate::{
+    application::matchmaking::matching_interrupter::MatchingInterrupter,
+    domain::{
+        matching_management::repositories::matching_repository::MatchingRepository,
+        player_management::{
+            models::player::player_id::player_id::PlayerId,
+            repositories::connection_repository::ConnectionRepository,
+        },
+        unit_management::repositories::unit_repository::UnitRepository,
+    },
+};
+
+/// WebSocket切断時の処理
+pub struct DisconnectUseCase {
+    connection_repository: Arc<dyn ConnectionRepository>,
+    matching_interrupter: MatchingInterrupter,
+}
+
+impl DisconnectUseCase {
+    pub fn new(
+        connection_repository: Arc<dyn ConnectionRepository>,
+        matching_repository: Arc<dyn MatchingRepository>,
+        unit_repository: Arc<dyn UnitRepository>,
+    ) -> Self {
+        Self {
+            connection_repository,
+            matching_interrupter: MatchingInterrupter::new(matching_repository, unit_repository),
+        }
+    }
+
+    pub async fn execute(&self, connection_id: &str) -> Result<(), String> {
+        // 切断イベントにはコネクションIDしか含まれないため、プレイヤーIDを逆引きする
+        let player_id = match self
+            .connection_repository
+            .find_player_id(connection_id)
+            .await?
+        {
+            Some(player_id) => player_id,
+            None => {
+                // リクエストを送信する前に切断された場合など、紐付けが無ければ何もしない
+                println!(
+                    "紐付くプレイヤーが存在しないコネクションです: {}",
+                    connection_id
+                );
+                return Ok(());
+            }
+        };
+
+        // 切断されたコネクション情報を削除し、以降の通知ではオフラインとして扱う
+        self.connection_repository
+            .delete(&player_id, connection_id)
+            .await?;
+        println!(
+            "コネクション情報を削除しました プレイヤーID: {}, コネクションID: {}",
+            player_id, connection_id
+        );
+
+        // 既に別のコネクションで再接続している場合は、待機中のマッチングを継続する
+        if self
+            .connection_repository
+            .find_connection_id(&player_id)
+            .await?
+            .is_some()
+        {
+            return Ok(());
+        }
+        // 対戦相手を待機中に切断された場合は、相手が決まらないようマッチングを中断する
+        self.matching_interrupter
+            .interrupt_by_player_id(&PlayerId::new(player_id))
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use crate::{
+    application::websocket::{
+        websocket_response::WebSocketResponse,
+        websocket_sender::{DeliveryStatus, WebSocketSender},
+    },
+    domain::player_management::{
+        models::{
+            outbox_message::{
+                message_sequence::message_sequence::MessageSequence, outbox_message::OutboxMessage,
+            },
+            player::player_id::player_id::PlayerId,
+        },
+        repositories::{
+            connection_repository::ConnectionRepository, outbox_repository::OutboxRepository,
+        },
+    },
+};
+
+/// プレイヤーへの通知
+/// プレイヤーIDから接続中のコネクションを引き当てて送信する
+///
+/// 通知にはプレイヤーごとの通し番号（`sequence`）を付与し、クライアントが番号の欠けから
+/// 未受信の通知を検知できるようにする。届けられなかった通知はOutboxに保存し、再接続時に再送する
+pub struct PlayerNotifier {
+    connection_repository: Arc<dyn ConnectionRepository>,
+    outbox_repository: Arc<dyn OutboxRepository>,
+    websocket_sender: Arc<dyn WebSocketSender>,
+}
+
+impl PlayerNotifier {
+    pub fn new(
+        connection_repository: Arc<dyn ConnectionRepository>,
+        outbox_repository: Arc<dyn OutboxRepository>,
+        websocket_sender: Arc<dyn WebSocketSender>,
+    ) -> Self {
+        Self {
+            connection_repository,
+            outbox_repository,
+            websocket_sender,
+        }
+    }
+
+    /// プレイヤーに通知を送信
+    /// 送信できなかった場合はエラーにせずOutboxに保存し、`DeliveryStatus::Offline`を返す
+    pub async fn notify(
+        &self,
+        player_id: &PlayerId,
+        response: &WebSocketResponse,
+    ) -> Result<DeliveryStatus, String> {
+        let sequence = self.outbox_repository.next_sequence(player_id).await?;
+        let payload = Self::to_sequenced_payload(response, &sequence)?;
+
+        let status = match self.send(player_id, &payload).await {
+            Ok(status) => status,
+            Err(e) => {
+                eprintln!(
+                    "通知の送信に失敗したため再送用に保存します プレイヤーID: {}, エラー: {}",
+                    player_id.value(),
+                    e
+                );
+                DeliveryStatus::Offline
+            }
+        };
+
+        if status == DeliveryStatus::Offline {
+            self.outbox_repository
+                .save(&OutboxMessage::create(player_id.clone(), sequence, payload))
+                .await?;
+        }
+        Ok(status)
+    }
+
+    /// Outboxに保存されている未送信の通知を通し番号順に再送
+    /// 送信できた通知はOutboxから削除し、再送できた件数を返す
+    pub async fn flush(&self, player_id: &PlayerId) -> Result<usize, String> {
+        let messages = self.outbox_repository.get_messages(player_id).await?;
+
+        let mut delivered_count = 0;
+        for message in messages {
+            // 再びオフラインになった場合は、順序を保つため残りの通知も次回の再接続時に再送する
+            if self.send(player_id, message.payload()).await? == DeliveryStatus::Offline {
+                break;
+            }
+            self.outbox_repository.delete(&message).await?;
+            delivered_count += 1;
+        }
+        Ok(delivered_count)
+    }
+
+    /// 接続中のコネクションに送信
+    async fn send(&self, player_id: &PlayerId, payload: &str) -> Result<DeliveryStatus, String> {
+        let connection_id = match self
+            .connection_repository
+            .find_connection_id(player_id.value())
+            .await
+            .map_err(|e| format!("コネクションIDの取得に失敗しました: {}", e))?
+        {
+            Some(connection_id) => connection_id,
+            None => {
+                println!("プレイヤーはオフラインです: {}", player_id.value());
+                return Ok(DeliveryStatus::Offline);
+            }
+        };
+
+        let status = self
+            .websocket_sender
+            .send_json(&connection_id, payload)
+            .await?;
+        if status == DeliveryStatus::Offline {
+            // 切断済みのコネクション情報が残っていたため削除する
+            self.connection_repository
+                .delete(player_id.value(), &connection_id)
+                .await?;
+        }
+        Ok(status)
+    }
+
+    /// レスポンスに通し番号を付与したJSONを作成
+    /// 既存のレスポンスの形は変えず、`sequence`フィールドを追加する
+    fn to_sequenced_payload(
+        response: &WebSocketResponse,
+        sequence: &MessageSequence,
+    ) -> Result<String, String> {
+        let mut value = serde_json::to_value(response)
+            .map_err(|e| format!("通知のシリアライズに失敗しました: {}", e))?;
+        let object = value
+            .as_object_mut()
+            .ok_or("通知のJSONがオブジェクトではありません".to_string())?;
+        object.insert("sequence".to_string(), serde_json::json!(sequence.value()));
+        serde_json::to_string(&value)
+            .map_err(|e| format!("通知のシリアライズに失敗しました: {}", e))
+    }
+}
@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::domain::player_management::{
+    models::player::Player,
+    repositories::{
+        connection_repository::ConnectionRepository, player_repository::PlayerRepository,
+    },
+    services::session_token_service::SessionTokenService,
+};
+
+/// WebSocketリクエストの認証
+/// セッショントークンで認証したプレイヤーをコネクションに紐付け、
+/// 以降のリクエストのプレイヤーIDが紐付いたプレイヤー本人であることを確認する
+pub struct RequestAuthenticator {
+    connection_repository: Arc<dyn ConnectionRepository>,
+    player_repository: Arc<dyn PlayerRepository>,
+    session_token_service: Arc<dyn SessionTokenService>,
+}
+
+impl RequestAuthenticator {
+    pub fn new(
+        connection_repository: Arc<dyn ConnectionRepository>,
+        player_repository: Arc<dyn PlayerRepository>,
+        session_token_service: Arc<dyn SessionTokenService>,
+    ) -> Self {
+        Self {
+            connection_repository,
+            player_repository,
+            session_token_service,
+        }
+    }
+
+    /// セッショントークンを検証し、認証したプレイヤーをコネクションに紐付ける
+    /// MFA認証が有効なプレイヤーは、ワンタイムパスワードまたはリカバリーコードも必要
+    ///
+    /// # Arguments
+    /// * `session_token` - セッショントークン
+    /// * `mfa_code` - ワンタイムパスワードまたはリカバリーコード（MFA認証が有効な場合のみ必須）
+    /// * `connection_id` - 紐付けるコネクションID
+    pub async fn authenticate(
+        &self,
+        session_token: &str,
+        mfa_code: Option<&str>,
+        connection_id: &str,
+    ) -> Result<Player, String> {
+        let now = Utc::now();
+        let player_id = self.session_token_service.verify(session_token, now)?;
+        let mut player = self
+            .player_repository
+            .find_by_id(&player_id)
+            .await
+            .map_err(|e| format!("プレイヤー情報の取得に失敗しました: {}", e))?
+            .ok_or("プレイヤーが見つかりませんでした。".to_string())?;
+
+        if player.is_mfa_enabled() {
+            let mfa_code =
+                mfa_code.ok_or("MFA認証が有効なため、認証コードが必要です".to_string())?;
+            let result = player.verify_mfa(mfa_code, now);
+            // 使用したワンタイムパスワード・リカバリーコードと失敗回数を記録するため、成否にかかわらず保存
+            self.player_repository
+                .save(&player)
+                .await
+                .map_err(|e| format!("プレイヤー情報の更新に失敗しました: {}", e))?;
+            result?;
+        }
+
+        self.connection_repository
+            .save(player.player_id().value(), connection_id)
+            .await?;
+        Ok(player)
+    }
+
+    /// リクエストのプレイヤーIDがコネクションに紐付いたプレイヤーと一致するか確認する
+    /// 他のコネクションで再認証した場合、古いコネクションからのリクエストは拒否される
+    pub async fn authorize(&self, connection_id: &str, player_id: &str) -> Result<(), String> {
+        let authenticated_player_id = self
+            .connection_repository
+            .find_player_id(connection_id)
+            .await?
+            .ok_or("認証されていないコネクションです。ログインしてください".to_string())?;
+        if authenticated_player_id != player_id {
+            return Err("認証されたプレイヤー以外として操作することはできません".to_string());
+        }
+        Ok(())
+    }
+}
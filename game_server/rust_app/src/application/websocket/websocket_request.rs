@@ -1,13 +1,17 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    application::matchmaking::matchmaking_dto::CreateUnitDto,
+    application::{
+        game::step_request_dto::StepRequestDto, matchmaking::matchmaking_dto::CreateUnitDto,
+    },
     domain::{
         player_management::models::player::player_id::player_id::PlayerId,
         triggergame_simulator::models::{
-            game::{game::Game, game_id::game_id::GameId},
-            step::step::Step,
+            action::action::Action,
+            game::{game::Game, game_id::game_id::GameId, spectator::spectator::SpectatorView},
+            turn::turn_number::turn_number::TurnNumber,
         },
+        unit_management::models::unit::unit_id::unit_id::UnitId,
     },
 };
 
@@ -30,12 +34,71 @@ use crate::{
     rename_all_fields = "camelCase" // player_id -> playerId
 )]
 pub enum WebSocketRequest {
+    /// プレイヤー登録リクエスト
+    /// 初回起動時にクライアントから送信され、採番されたプレイヤーIDを返す
+    RegisterPlayer { player_name: String },
+
+    /// ログインリクエスト
+    /// 接続後に発行済みのセッショントークンを送信し、コネクションを認証する
+    /// （接続時のクエリパラメータ`token`で認証した場合は不要）
+    Login {
+        session_token: String,
+        /// ワンタイムパスワードまたはリカバリーコード（MFA認証が有効な場合のみ必須）
+        mfa_code: Option<String>,
+    },
+
+    /// プレイヤー情報取得リクエスト
+    GetProfile { player_id: PlayerId },
+
+    /// プレイヤー名変更リクエスト
+    RenamePlayer {
+        player_id: PlayerId,
+        player_name: String,
+    },
+
+    /// MFA認証の登録開始リクエスト
+    SetupMfa { player_id: PlayerId },
+
+    /// MFA認証の有効化リクエスト
+    /// SetupMfaで登録したシークレットのワンタイムパスワードを送信する
+    EnableMfa { player_id: PlayerId, code: String },
+
+    /// MFA認証の無効化リクエスト
+    /// ワンタイムパスワードまたはリカバリーコードを送信する
+    DisableMfa { player_id: PlayerId, code: String },
+
     /// マッチメイキングリクエスト
     Matchmaking {
         player_id: String,
         units: Vec<CreateUnitDto>,
     },
 
+    /// ボット対戦リクエスト
+    /// 対戦相手を待たずに、ボットを対戦相手としてゲームを開始する
+    PlayVsBot {
+        player_id: String,
+        units: Vec<CreateUnitDto>,
+    },
+
+    /// プライベートルーム作成リクエスト
+    /// 特定の相手と対戦するために、招待コードで参加できるルームを作成する
+    CreateRoom {
+        player_id: String,
+        units: Vec<CreateUnitDto>,
+    },
+
+    /// プライベートルーム参加リクエスト
+    /// 対戦相手から伝えられた招待コードを指定してルームに参加する
+    JoinRoom {
+        player_id: String,
+        code: String,
+        units: Vec<CreateUnitDto>,
+    },
+
+    /// マッチングキャンセルリクエスト
+    /// 対戦相手を待機中にロビーから離れるときにクライアントから送信される
+    CancelMatchmaking { player_id: PlayerId },
+
     /// ゲーム状態取得リクエスト
     /// ゲーム画面に遷移したときにクライアントから送信される
     GetGameState {
@@ -47,9 +110,84 @@ pub enum WebSocketRequest {
     TurnExecution {
         game_id: String,
         player_id: String,
-        steps: Vec<Step>,
+        steps: Vec<StepRequestDto>,
     },
 
+    /// ターン結果取得リクエスト
+    /// 再接続時など、演算済みのターン結果を再取得するときにクライアントから送信される
+    GetTurnResult {
+        player_id: PlayerId,
+        game_id: GameId,
+        turn_number: TurnNumber,
+    },
+
+    /// 戦闘予測リクエスト
+    /// 行動設定中に、アクションで敵ユニットを攻撃した場合の見込みを表示するときに送信される
+    PreviewCombat {
+        player_id: PlayerId,
+        game_id: GameId,
+        /// 攻撃側ユニットの設定中のアクション
+        attacker_action: Action,
+        defending_unit_id: UnitId,
+    },
+
+    /// リプレイ取得リクエスト
+    /// 終了したゲームを見返すときにクライアントから送信される
+    GetReplay {
+        player_id: PlayerId,
+        game_id: GameId,
+    },
+
+    /// 観戦開始リクエスト
+    /// 観戦者として登録し、以降の演算済みのターンを配信する
+    SpectateGame {
+        player_id: PlayerId,
+        game_id: GameId,
+        /// 観戦時の視点（省略時は両陣営を表示）
+        #[serde(default)]
+        view: SpectatorView,
+    },
+
+    /// 観戦終了リクエスト
+    StopSpectating {
+        player_id: PlayerId,
+        game_id: GameId,
+    },
+
+    /// セッション再開リクエスト
+    /// 再接続したクライアントが進行中のゲームへ復帰するときに送信される
+    ResumeSession { player_id: PlayerId },
+
     /// Ping/Pong
     Ping,
 }
+
+impl WebSocketRequest {
+    /// リクエストを送信したプレイヤーとして指定されたプレイヤーID
+    /// 認証前に送信できるリクエストの場合はNoneを返す
+    pub fn player_id(&self) -> Option<&str> {
+        match self {
+            WebSocketRequest::RegisterPlayer { .. }
+            | WebSocketRequest::Login { .. }
+            | WebSocketRequest::Ping => None,
+            WebSocketRequest::Matchmaking { player_id, .. }
+            | WebSocketRequest::CreateRoom { player_id, .. }
+            | WebSocketRequest::PlayVsBot { player_id, .. }
+            | WebSocketRequest::JoinRoom { player_id, .. }
+            | WebSocketRequest::TurnExecution { player_id, .. } => Some(player_id),
+            WebSocketRequest::GetProfile { player_id }
+            | WebSocketRequest::RenamePlayer { player_id, .. }
+            | WebSocketRequest::SetupMfa { player_id }
+            | WebSocketRequest::EnableMfa { player_id, .. }
+            | WebSocketRequest::DisableMfa { player_id, .. }
+            | WebSocketRequest::CancelMatchmaking { player_id }
+            | WebSocketRequest::GetGameState { player_id, .. }
+            | WebSocketRequest::GetTurnResult { player_id, .. }
+            | WebSocketRequest::PreviewCombat { player_id, .. }
+            | WebSocketRequest::GetReplay { player_id, .. }
+            | WebSocketRequest::SpectateGame { player_id, .. }
+            | WebSocketRequest::StopSpectating { player_id, .. }
+            | WebSocketRequest::ResumeSession { player_id } => Some(player_id.value()),
+        }
+    }
+}
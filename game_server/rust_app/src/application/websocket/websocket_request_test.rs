@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod tests {
+    use super::super::websocket_request::WebSocketRequest;
+    use crate::application::game::step_request_dto::StepRequestDto;
+
+    /// クライアントのStepモデル（stepId, actions, combats）と同じ形式のターン設定リクエスト
+    const TURN_EXECUTION_JSON: &str = r#"{
+        "action": "turnExecution",
+        "gameId": "game_1",
+        "playerId": "player_1",
+        "steps": [
+            {
+                "stepId": "step_1",
+                "actions": [
+                    {
+                        "actionId": "action_1",
+                        "actionType": "Move",
+                        "unitId": "unit_1",
+                        "unitTypeId": "MIKUMO_OSAMU",
+                        "position": { "col": 3, "row": 4 },
+                        "usingMainTriggerId": "KOGETSU",
+                        "usingSubTriggerId": "RAYGUST",
+                        "mainTriggerAzimuth": 90,
+                        "subTriggerAzimuth": 180
+                    }
+                ],
+                "combats": []
+            }
+        ]
+    }"#;
+
+    fn parse_steps(json: &str) -> Vec<StepRequestDto> {
+        match serde_json::from_str::<WebSocketRequest>(json).unwrap() {
+            WebSocketRequest::TurnExecution { steps, .. } => steps,
+            other => panic!("unexpected request: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_client_turn_execution() {
+        let steps = StepRequestDto::to_steps(&parse_steps(TURN_EXECUTION_JSON));
+
+        assert_eq!(steps.len(), 1);
+        assert_eq!(steps[0].step_id().value(), "step_1");
+        assert_eq!(steps[0].actions().len(), 1);
+        assert_eq!(steps[0].actions()[0].unit_id().value(), "unit_1");
+        assert!(steps[0].combats().is_empty());
+        assert!(steps[0].status_effect_changes().is_empty());
+    }
+
+    #[test]
+    fn test_parse_turn_execution_ignores_result_fields() {
+        // 演算結果はクライアントから指定されても受け取らない
+        let mut json: serde_json::Value = serde_json::from_str(TURN_EXECUTION_JSON).unwrap();
+        json["steps"][0]["statusEffectChanges"] = serde_json::json!([
+            { "unitId": "unit_1", "statusEffects": [] }
+        ]);
+        json["steps"][0]["combats"] = serde_json::json!([{ "combatId": "combat_1" }]);
+
+        let steps = StepRequestDto::to_steps(&parse_steps(&json.to_string()));
+
+        assert!(steps[0].combats().is_empty());
+        assert!(steps[0].status_effect_changes().is_empty());
+    }
+}
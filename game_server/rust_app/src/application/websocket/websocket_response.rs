@@ -1,10 +1,20 @@
+use chrono::{DateTime, Utc};
 use serde::Serialize;
 
 use crate::{
-    application::game::{enemy_unit_dto::EnemyUnitDto, friend_unit_dto::FriendUnitDto},
+    application::{
+        game::{
+            enemy_unit_dto::EnemyUnitDto, friend_unit_dto::FriendUnitDto,
+            resume_session_dto::ResumeSessionDto, spectator_dto::SpectatorDto,
+        },
+        player::player_profile_dto::PlayerProfileDto,
+    },
     domain::{
         matching_management::models::matching::MatchingStatusValue,
-        triggergame_simulator::models::turn::Turn,
+        triggergame_simulator::models::{
+            combat::CombatPreview, game::spectator::spectator::SpectatorView,
+            replay::replay::Replay, turn::Turn,
+        },
     },
 };
 
@@ -16,6 +26,60 @@ use crate::{
     rename_all_fields = "camelCase" // player_id -> playerId
 )]
 pub enum WebSocketResponse {
+    /// プレイヤー登録結果
+    RegisterPlayerResult {
+        /// 登録したプレイヤー情報（採番されたプレイヤーIDを含む）
+        profile: PlayerProfileDto,
+        /// 以降のログインに使用するセッショントークン
+        session_token: String,
+        /// セッショントークンの有効期限
+        expires_at: DateTime<Utc>,
+    },
+
+    /// ログイン結果
+    LoginResult {
+        /// ログインしたプレイヤー情報
+        profile: PlayerProfileDto,
+        /// 有効期限を延長したセッショントークン
+        session_token: String,
+        /// セッショントークンの有効期限
+        expires_at: DateTime<Utc>,
+    },
+
+    /// プレイヤー情報取得結果
+    GetProfileResult {
+        /// プレイヤー情報
+        profile: PlayerProfileDto,
+    },
+
+    /// プレイヤー名変更結果
+    RenamePlayerResult {
+        /// 変更後のプレイヤー情報
+        profile: PlayerProfileDto,
+    },
+
+    /// MFA認証の登録開始結果
+    SetupMfaResult {
+        /// TOTPシークレット（Base32、手入力用）
+        secret: String,
+        /// 認証アプリに登録するためのプロビジョニングURI（QRコード用）
+        provisioning_uri: String,
+    },
+
+    /// MFA認証の有効化結果
+    EnableMfaResult {
+        /// 有効化後のプレイヤー情報
+        profile: PlayerProfileDto,
+        /// リカバリーコード（再表示できないため、プレイヤーに控えてもらう）
+        recovery_codes: Vec<String>,
+    },
+
+    /// MFA認証の無効化結果
+    DisableMfaResult {
+        /// 無効化後のプレイヤー情報
+        profile: PlayerProfileDto,
+    },
+
     /// マッチメイキング結果
     MatchmakingResult {
         /// マッチングステータス
@@ -28,6 +92,22 @@ pub enum WebSocketResponse {
         friend_units: Vec<FriendUnitDto>,
     },
 
+    /// プライベートルーム作成結果
+    CreateRoomResult {
+        /// マッチングステータス（InProgress）
+        status: MatchingStatusValue,
+        /// 対戦相手に伝える招待コード
+        room_code: String,
+        /// 対戦相手を待機できる期限
+        expires_at: DateTime<Utc>,
+    },
+
+    /// マッチングキャンセル結果
+    CancelMatchmakingResult {
+        /// キャンセル後のマッチングステータス（Interrupted）
+        status: MatchingStatusValue,
+    },
+
     /// ゲーム状態取得結果
     GetGameStateResult {
         // ゲームのターン番号
@@ -44,6 +124,65 @@ pub enum WebSocketResponse {
         turn: Turn,
     },
 
+    /// ターン結果取得結果
+    GetTurnResultResult {
+        /// 演算済みのターン情報
+        turn: Turn,
+    },
+
+    /// 戦闘予測結果
+    PreviewCombatResult {
+        attacking_unit_id: String,
+        defending_unit_id: String,
+        /// 戦闘予測（射程外・角度の範囲外で戦闘が発生しない場合はnull）
+        preview: Option<CombatPreview>,
+    },
+
+    /// リプレイ取得結果
+    GetReplayResult {
+        /// 再演算に必要な情報をまとめたリプレイ
+        replay: Replay,
+    },
+
+    /// 観戦開始結果
+    SpectateGameResult {
+        game_id: String,
+        player1_id: String,
+        player2_id: String,
+        /// ゲームのターン番号
+        current_turn_number: u32,
+        /// 観戦者への配信の遅延ターン数
+        delay_turns: u32,
+        /// 観戦時の視点
+        view: SpectatorView,
+        /// 観戦者一覧
+        spectators: Vec<SpectatorDto>,
+        /// 閲覧可能な演算済みのターン（古い順）
+        turns: Vec<Turn>,
+        /// ゲームが終了したかどうか
+        is_game_over: bool,
+    },
+
+    /// 観戦者へのターン配信
+    SpectatorTurnResult {
+        game_id: String,
+        /// 観戦時の視点
+        view: SpectatorView,
+        /// 演算済みのターン情報
+        turn: Turn,
+        /// ゲームが終了したかどうか（終了時は遅延させていたターンもまとめて配信される）
+        is_game_over: bool,
+    },
+
+    /// 観戦終了結果
+    StopSpectatingResult { game_id: String },
+
+    /// セッション再開結果
+    ResumeSessionResult {
+        /// 進行中のゲーム情報（進行中のゲームが無い場合は`null`）
+        session: Option<ResumeSessionDto>,
+    },
+
     /// エラーレスポンス
     Error { message: String },
 
@@ -2,13 +2,29 @@ use async_trait::async_trait;
 
 use crate::application::websocket::websocket_response::WebSocketResponse;
 
+/// 送信結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeliveryStatus {
+    /// 送信できた
+    Delivered,
+    /// 送信先のコネクションが既に切断されていた（プレイヤーがオフライン）
+    /// `PlayerNotifier`経由の通知の場合はOutboxに保存され、再接続時に再送される
+    Offline,
+}
+
 /// WebSocket送信のトレイト
 /// 実際の送信処理はインフラ層で実装
 #[async_trait]
 pub trait WebSocketSender: Send + Sync {
+    /// 切断済みのコネクションへの送信はエラーではなく`DeliveryStatus::Offline`を返す
     async fn send_message(
         &self,
         connection_id: &str,
         response: &WebSocketResponse,
-    ) -> Result<(), String>;
+    ) -> Result<DeliveryStatus, String>;
+
+    /// シリアライズ済みのJSONをそのまま送信
+    /// 通し番号を付与した通知やOutboxに保存していた通知の再送に使用する
+    async fn send_json(&self, connection_id: &str, payload: &str)
+        -> Result<DeliveryStatus, String>;
 }
@@ -0,0 +1,118 @@
+//! バランス調整用の対戦シミュレーター
+//! ボット同士の対戦をメモリ上で繰り返し、勝率・トリガーごとの平均ダメージ・
+//! ユニットタイプごとのベイルアウト数・ターン数を集計する
+//!
+//! 使い方:
+//! cargo run --bin match_simulator -- [--settings <設定ファイル>] [--games <対戦数>]
+//!     [--seed <シード値>] [--format json|csv] [--output <出力先>]
+//!
+//! 設定ファイル（JSON）では出撃ユニット（player1Units / player2Units）と
+//! 戦闘計算の設定値（gameConfig）を指定できる
+//! 演算中のログが標準出力に出るため、集計結果はファイルに出力する
+
+use std::fs;
+use std::process::ExitCode;
+
+use game_server::application::simulator::match_simulator::{MatchSimulator, SimulationSettings};
+
+/// 出力形式
+enum OutputFormat {
+    Json,
+    Csv,
+}
+
+/// コマンドライン引数
+struct Args {
+    settings_path: Option<String>,
+    games: Option<u32>,
+    seed: Option<u32>,
+    format: OutputFormat,
+    output_path: Option<String>,
+}
+
+impl Args {
+    fn parse(args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut parsed = Args {
+            settings_path: None,
+            games: None,
+            seed: None,
+            format: OutputFormat::Json,
+            output_path: None,
+        };
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("{} の値を指定してください", arg));
+            match arg.as_str() {
+                "--settings" => parsed.settings_path = Some(value()?),
+                "--games" => {
+                    parsed.games = Some(
+                        value()?
+                            .parse()
+                            .map_err(|e| format!("対戦数が不正です: {}", e))?,
+                    )
+                }
+                "--seed" => {
+                    parsed.seed = Some(
+                        value()?
+                            .parse()
+                            .map_err(|e| format!("シード値が不正です: {}", e))?,
+                    )
+                }
+                "--format" => {
+                    parsed.format = match value()?.as_str() {
+                        "json" => OutputFormat::Json,
+                        "csv" => OutputFormat::Csv,
+                        other => return Err(format!("出力形式が不正です: {}", other)),
+                    }
+                }
+                "--output" => parsed.output_path = Some(value()?),
+                other => return Err(format!("不明な引数です: {}", other)),
+            }
+        }
+        Ok(parsed)
+    }
+}
+
+fn run() -> Result<String, String> {
+    let args = Args::parse(std::env::args().skip(1))?;
+
+    let mut settings = match &args.settings_path {
+        Some(path) => {
+            let json = fs::read_to_string(path)
+                .map_err(|e| format!("設定ファイルの読み込みに失敗しました: {}", e))?;
+            serde_json::from_str::<SimulationSettings>(&json)
+                .map_err(|e| format!("設定ファイルの形式が不正です: {}", e))?
+        }
+        None => SimulationSettings::default(),
+    };
+    if let Some(games) = args.games {
+        settings.games = games;
+    }
+    if let Some(seed) = args.seed {
+        settings.seed = seed;
+    }
+
+    let report = MatchSimulator::new(settings).run()?;
+    let (content, extension) = match args.format {
+        OutputFormat::Json => (report.to_json()?, "json"),
+        OutputFormat::Csv => (report.to_csv(), "csv"),
+    };
+    let output_path = args
+        .output_path
+        .unwrap_or(format!("simulation_report.{}", extension));
+    fs::write(&output_path, content).map_err(|e| format!("集計結果の出力に失敗しました: {}", e))?;
+    Ok(output_path)
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(output_path) => {
+            eprintln!("集計結果を出力しました: {}", output_path);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
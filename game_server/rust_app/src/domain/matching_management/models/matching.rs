@@ -2,15 +2,19 @@ pub mod matching_end_datetime;
 pub mod matching_id;
 pub mod matching_start_datetime;
 pub mod matching_status;
+pub mod room_code;
 
-use chrono::Utc;
+use chrono::{DateTime, Duration, Utc};
 use uuid::Uuid;
 
-use crate::domain::player_management::models::player::player_id::player_id::PlayerId;
+use crate::domain::player_management::models::player::{
+    player_id::player_id::PlayerId, rating::rating::Rating,
+};
 pub use matching_end_datetime::matching_end_datetime::MatchingEndDatetime;
 pub use matching_id::matching_id::MatchingId;
 pub use matching_start_datetime::matching_start_datetime::MatchingStartDatetime;
 pub use matching_status::matching_status::{MatchingStatus, MatchingStatusValue};
+pub use room_code::room_code::RoomCode;
 /// Matching 集約ルート
 ///
 /// マッチングは2人のプレイヤーをマッチングさせるプロセスを管理する集約です。
@@ -19,29 +23,44 @@ pub use matching_status::matching_status::{MatchingStatus, MatchingStatusValue};
 pub struct Matching {
     matching_id: MatchingId,
     player1_id: PlayerId,
+    /// マッチング開始時点のプレイヤー1のレーティング
+    player1_rating: Rating,
     player2_id: Option<PlayerId>,
     matching_start_datetime: MatchingStartDatetime,
     matching_end_datetime: MatchingEndDatetime,
     matching_status: MatchingStatus,
+    /// プライベートルームの招待コード（公開のマッチングの場合はNone）
+    room_code: Option<RoomCode>,
 }
 
 impl Matching {
+    /// 対戦相手を待機できる時間（秒）
+    /// 超過したマッチングは放置されたものとみなし、中断する
+    const WAITING_TIMEOUT_SECONDS: i64 = 300;
+    /// プライベートルームで対戦相手を待機できる時間（秒）
+    /// 招待した相手が参加するまでの時間を考慮し、公開のマッチングより長くする
+    const ROOM_WAITING_TIMEOUT_SECONDS: i64 = 1800;
+
     /// コンストラクタ
     pub fn new(
         matching_id: MatchingId,
         player1_id: PlayerId,
+        player1_rating: Rating,
         player2_id: Option<PlayerId>,
         matching_start_datetime: MatchingStartDatetime,
         matching_end_datetime: MatchingEndDatetime,
         matching_status: MatchingStatus,
+        room_code: Option<RoomCode>,
     ) -> Self {
         Self {
             matching_id,
             player1_id,
+            player1_rating,
             player2_id,
             matching_start_datetime,
             matching_end_datetime,
             matching_status,
+            room_code,
         }
     }
 
@@ -49,13 +68,14 @@ impl Matching {
     ///
     /// # Arguments
     /// * `player1_id` - プレイヤー1のID
+    /// * `player1_rating` - プレイヤー1のレーティング
     ///
     /// # Returns
     /// 新しいMatchingインスタンス
     ///
     /// # Panics
     /// 同じプレイヤーIDが指定された場合にパニックします
-    pub fn create(player1_id: PlayerId) -> Self {
+    pub fn create(player1_id: PlayerId, player1_rating: Rating) -> Self {
         let matching_id = MatchingId::new(Uuid::new_v4().to_string());
         let player2_id = None;
         let matching_start_datetime = MatchingStartDatetime::new(Utc::now());
@@ -65,13 +85,32 @@ impl Matching {
         Self::new(
             matching_id,
             player1_id,
+            player1_rating,
             player2_id,
             matching_start_datetime,
             matching_end_datetime,
             matching_status,
+            None,
         )
     }
 
+    /// 新規プライベートルームの生成
+    /// 招待コードを知っているプレイヤーのみが参加でき、公開のマッチングには表示されない
+    ///
+    /// # Arguments
+    /// * `player1_id` - ルームを作成したプレイヤーのID
+    /// * `player1_rating` - ルームを作成したプレイヤーのレーティング
+    /// * `room_code` - 招待コード
+    pub fn create_private(
+        player1_id: PlayerId,
+        player1_rating: Rating,
+        room_code: RoomCode,
+    ) -> Self {
+        let mut matching = Self::create(player1_id, player1_rating);
+        matching.room_code = Some(room_code);
+        matching
+    }
+
     /// # マッチングの実行
     ///
     /// ## Arguments
@@ -98,6 +137,48 @@ impl Matching {
         Ok(())
     }
 
+    /// # マッチングの中断
+    ///
+    /// キャンセル・切断・待機時間の超過により、対戦相手が決まる前にマッチングを終了する
+    ///
+    /// ## Returns
+    /// 成功時はOk、進行中でないマッチングの場合はErrを返す
+    pub fn interrupt(&mut self) -> Result<(), String> {
+        // ビジネスルール: 進行中のマッチングのみ中断できる
+        if !self.is_in_progress() {
+            return Err("進行中ではないマッチングは中断できません".to_string());
+        }
+
+        self.matching_status = MatchingStatus::new(MatchingStatusValue::Interrupted);
+        self.matching_end_datetime = MatchingEndDatetime::new(Some(Utc::now()));
+        Ok(())
+    }
+
+    /// 待機時間を超過しているかどうかを確認する
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.is_in_progress() && self.expires_at() <= now
+    }
+
+    /// 対戦相手を待機できる期限を取得する
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        let timeout_seconds = if self.is_private() {
+            Self::ROOM_WAITING_TIMEOUT_SECONDS
+        } else {
+            Self::WAITING_TIMEOUT_SECONDS
+        };
+        *self.matching_start_datetime.value() + Duration::seconds(timeout_seconds)
+    }
+
+    /// プライベートルームかどうかを確認する
+    pub fn is_private(&self) -> bool {
+        self.room_code.is_some()
+    }
+
+    /// マッチング開始からの待機時間を取得する
+    pub fn waiting_duration(&self, now: DateTime<Utc>) -> Duration {
+        now - *self.matching_start_datetime.value()
+    }
+
     /// マッチングが進行中かどうかを確認する
     pub fn is_in_progress(&self) -> bool {
         self.matching_status.is_in_progress()
@@ -117,6 +198,10 @@ impl Matching {
         &self.player1_id
     }
 
+    pub fn player1_rating(&self) -> &Rating {
+        &self.player1_rating
+    }
+
     pub fn player2_id(&self) -> &Option<PlayerId> {
         &self.player2_id
     }
@@ -132,4 +217,8 @@ impl Matching {
     pub fn matching_status(&self) -> &MatchingStatus {
         &self.matching_status
     }
+
+    pub fn room_code(&self) -> &Option<RoomCode> {
+        &self.room_code
+    }
 }
@@ -0,0 +1,2 @@
+pub mod room_code;
+pub mod room_code_test;
\ No newline at end of file
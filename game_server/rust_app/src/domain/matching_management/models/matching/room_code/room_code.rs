@@ -0,0 +1,63 @@
+use rand::Rng;
+
+/// プライベートルームの招待コード
+/// 口頭やチャットで伝えやすいよう、読み間違えやすい文字（0/O、1/I/L）を除いた英大文字と数字で構成する
+#[derive(Debug, Clone)]
+pub struct RoomCode {
+    value: String,
+}
+
+impl RoomCode {
+    const LENGTH: usize = 6;
+    const CHARACTERS: &'static [u8] = b"ABCDEFGHJKMNPQRSTUVWXYZ23456789";
+
+    pub fn new(value: String) -> Self {
+        if let Err(e) = Self::validate(&value) {
+            panic!("{}", e);
+        }
+        Self { value }
+    }
+
+    /// ランダムな招待コードを生成
+    pub fn generate(rng: &mut impl Rng) -> Self {
+        let value = (0..Self::LENGTH)
+            .map(|_| Self::CHARACTERS[rng.random_range(0..Self::CHARACTERS.len())] as char)
+            .collect();
+        Self { value }
+    }
+
+    /// プレイヤーが入力した招待コードを解析
+    /// 前後の空白と大文字・小文字の違いは無視する
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let value = input.trim().to_uppercase();
+        Self::validate(&value)?;
+        Ok(Self { value })
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    // バリデーションの実装
+    fn validate(value: &str) -> Result<(), String> {
+        if value.chars().count() != Self::LENGTH {
+            return Err(format!(
+                "RoomCodeは{}文字である必要があります",
+                Self::LENGTH
+            ));
+        }
+        if !value.bytes().all(|c| Self::CHARACTERS.contains(&c)) {
+            return Err("RoomCodeに使用できない文字が含まれています".to_string());
+        }
+        Ok(())
+    }
+}
+
+// 等価性の比較を実装
+impl PartialEq for RoomCode {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for RoomCode {}
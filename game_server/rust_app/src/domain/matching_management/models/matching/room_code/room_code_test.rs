@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests {
+    use super::super::room_code::RoomCode;
+
+    #[test]
+    fn test_generate() {
+        let mut rng = rand::rng();
+        for _ in 0..100 {
+            let room_code = RoomCode::generate(&mut rng);
+            // 生成したコードはそのまま入力しても解析できる
+            assert_eq!(RoomCode::parse(room_code.value()).unwrap(), room_code);
+        }
+    }
+
+    #[test]
+    fn test_parse_normalizes_input() {
+        let room_code = RoomCode::parse(" abc234 ").unwrap();
+        assert_eq!(room_code.value(), "ABC234");
+    }
+
+    #[test]
+    fn test_parse_invalid_length() {
+        assert_eq!(
+            RoomCode::parse("ABC23").unwrap_err(),
+            "RoomCodeは6文字である必要があります"
+        );
+    }
+
+    #[test]
+    fn test_parse_ambiguous_characters() {
+        // 読み間違えやすい文字は使用できない
+        assert_eq!(
+            RoomCode::parse("ABCD0O").unwrap_err(),
+            "RoomCodeに使用できない文字が含まれています"
+        );
+        assert!(RoomCode::parse("ABCD1I").is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "RoomCodeは6文字である必要があります")]
+    fn test_new_invalid_panic() {
+        RoomCode::new("".to_string());
+    }
+
+    #[test]
+    fn test_equality() {
+        assert_eq!(
+            RoomCode::new("XYZ789".to_string()),
+            RoomCode::parse("xyz789").unwrap()
+        );
+    }
+}
@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod tests {
-    use crate::domain::player_management::models::player::player_id::player_id::PlayerId;
+    use crate::domain::player_management::models::player::{
+        player_id::player_id::PlayerId, rating::rating::Rating,
+    };
 
     use super::super::matching::*;
     use uuid::Uuid;
@@ -18,14 +20,14 @@ mod tests {
     fn test_create_matching_with_same_player() {
         let uuid3 = "550e8400-e29b-41d4-a716-446655440003";
         let player_id = PlayerId::new(uuid3.to_string());
-        let mut matching = Matching::create(player_id.clone());
+        let mut matching = Matching::create(player_id.clone(), Rating::initial());
         matching.matchmaking(player_id).unwrap();
     }
 
     #[test]
     fn test_complete_matching() {
         let (player1_id, player2_id) = create_test_player_ids();
-        let mut matching = Matching::create(player1_id);
+        let mut matching = Matching::create(player1_id, Rating::initial());
 
         // 少し待機（テストでは即座に完了するため、時間差を作る）
         std::thread::sleep(std::time::Duration::from_millis(10));
@@ -44,7 +46,7 @@ mod tests {
     #[should_panic(expected = "既にマッチング相手が存在します")]
     fn test_complete_already_finished_matching() {
         let (player1_id, player2_id) = create_test_player_ids();
-        let mut matching = Matching::create(player1_id);
+        let mut matching = Matching::create(player1_id, Rating::initial());
 
         // matchmaking関数は所有権ごと渡すので、クローンを作成
         let copy_player2_id = player2_id.clone();
@@ -55,4 +57,90 @@ mod tests {
         // 既に完了しているマッチングを再度完了しようとする
         matching.matchmaking(copy_player2_id).unwrap();
     }
+
+    #[test]
+    fn test_interrupt_matching() {
+        let (player1_id, _) = create_test_player_ids();
+        let mut matching = Matching::create(player1_id, Rating::initial());
+
+        let result = matching.interrupt();
+        assert!(result.is_ok());
+        assert!(matching.matching_status().is_interrupted());
+        assert!(matching.matching_end_datetime().value().is_some());
+        assert!(matching.is_finished());
+    }
+
+    #[test]
+    fn test_interrupt_completed_matching() {
+        let (player1_id, player2_id) = create_test_player_ids();
+        let mut matching = Matching::create(player1_id, Rating::initial());
+        matching.matchmaking(player2_id).unwrap();
+
+        let result = matching.interrupt();
+        assert_eq!(
+            result.unwrap_err(),
+            "進行中ではないマッチングは中断できません"
+        );
+        assert!(matching.matching_status().is_completed());
+    }
+
+    #[test]
+    fn test_is_expired() {
+        let (player1_id, player2_id) = create_test_player_ids();
+        let matching = Matching::create(player1_id.clone(), Rating::initial());
+        let started_at = *matching.matching_start_datetime().value();
+
+        assert!(!matching.is_expired(started_at + chrono::Duration::seconds(299)));
+        assert!(matching.is_expired(started_at + chrono::Duration::seconds(300)));
+
+        // 完了済みのマッチングは期限切れにならない
+        let mut completed = Matching::create(player1_id, Rating::initial());
+        completed.matchmaking(player2_id).unwrap();
+        assert!(!completed.is_expired(started_at + chrono::Duration::seconds(600)));
+    }
+
+    #[test]
+    fn test_waiting_duration() {
+        let (player1_id, _) = create_test_player_ids();
+        let matching = Matching::create(player1_id, Rating::new(1620));
+        let started_at = *matching.matching_start_datetime().value();
+
+        assert_eq!(matching.player1_rating().value(), 1620);
+        assert_eq!(
+            matching.waiting_duration(started_at + chrono::Duration::seconds(45)),
+            chrono::Duration::seconds(45)
+        );
+    }
+
+    #[test]
+    fn test_create_private() {
+        let (player1_id, _) = create_test_player_ids();
+        let room_code = RoomCode::new("ABC234".to_string());
+        let matching = Matching::create_private(player1_id, Rating::initial(), room_code.clone());
+
+        assert!(matching.is_private());
+        assert_eq!(matching.room_code(), &Some(room_code));
+        assert!(matching.is_in_progress());
+        assert!(!Matching::create(create_test_player_ids().0, Rating::initial()).is_private());
+    }
+
+    #[test]
+    fn test_private_matching_expires_later() {
+        let (player1_id, _) = create_test_player_ids();
+        let matching = Matching::create_private(
+            player1_id,
+            Rating::initial(),
+            RoomCode::new("ABC234".to_string()),
+        );
+        let started_at = *matching.matching_start_datetime().value();
+
+        // 公開のマッチングの待機時間を超えても期限切れにならない
+        assert!(!matching.is_expired(started_at + chrono::Duration::seconds(300)));
+        assert!(!matching.is_expired(started_at + chrono::Duration::seconds(1799)));
+        assert!(matching.is_expired(started_at + chrono::Duration::seconds(1800)));
+        assert_eq!(
+            matching.expires_at(),
+            started_at + chrono::Duration::seconds(1800)
+        );
+    }
 }
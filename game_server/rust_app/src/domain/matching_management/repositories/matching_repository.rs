@@ -1,6 +1,11 @@
-use crate::domain::matching_management::models::matching::Matching;
+use crate::domain::matching_management::models::matching::{Matching, RoomCode};
+use crate::domain::player_management::models::player::player_id::player_id::PlayerId;
 use async_trait::async_trait;
 
+/// 条件付き更新で待機中のマッチングでなくなっていた場合のエラーメッセージ
+/// 他のリクエストが先に対戦相手として参加した、または中断したことを表す
+pub const MATCHING_CONFLICT_ERROR: &str = "マッチング情報が他のリクエストによって更新されています";
+
 /// Matchingリポジトリのトレイト
 #[async_trait]
 pub trait MatchingRepository: Send + Sync {
@@ -8,8 +13,26 @@ pub trait MatchingRepository: Send + Sync {
     async fn save(&self, matching: &Matching) -> Result<(), String>;
 
     /// マッチング情報を更新
+    /// 待機中（InProgressかつ対戦相手が未決定）のマッチングのみ更新でき、
+    /// 既に他のリクエストによって更新されていた場合は`MATCHING_CONFLICT_ERROR`を返す
     async fn update(&self, matching: &Matching) -> Result<(), String>;
 
     /// 最新の待機中マッチングを取得
     async fn get_latest_waiting_matching(&self) -> Result<Option<Matching>, String>;
+
+    /// 公開の待機中のマッチングを古い順に最大`limit`件取得
+    /// プライベートルームは含まない
+    async fn get_waiting_matchings(&self, limit: i32) -> Result<Vec<Matching>, String>;
+
+    /// 指定したプレイヤーが作成した待機中のマッチングを取得
+    async fn find_waiting_matching_by_player_id(
+        &self,
+        player_id: &PlayerId,
+    ) -> Result<Option<Matching>, String>;
+
+    /// 招待コードに対応する待機中のプライベートルームを取得
+    async fn find_waiting_matching_by_room_code(
+        &self,
+        room_code: &RoomCode,
+    ) -> Result<Option<Matching>, String>;
 }
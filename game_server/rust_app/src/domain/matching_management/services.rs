@@ -0,0 +1,2 @@
+pub mod matchmaking_strategy;
+pub mod matchmaking_strategy_test;
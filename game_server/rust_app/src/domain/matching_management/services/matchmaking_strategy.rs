@@ -0,0 +1,94 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::{
+    matching_management::models::matching::Matching,
+    player_management::models::player::rating::rating::Rating,
+};
+
+/// マッチメイキングの戦略
+/// 待機中のマッチングの中から、参加するプレイヤーの対戦相手を選ぶ
+pub trait MatchmakingStrategy: Send + Sync {
+    /// 参加するマッチングを選ぶ
+    ///
+    /// # Arguments
+    /// * `player_rating` - 参加するプレイヤーのレーティング
+    /// * `candidates` - 待機中のマッチング（待機開始が古い順）
+    /// * `now` - 現在日時
+    ///
+    /// # Returns
+    /// 参加するマッチング。対戦相手として適切なマッチングがない場合はNone
+    fn select<'a>(
+        &self,
+        player_rating: &Rating,
+        candidates: &'a [Matching],
+        now: DateTime<Utc>,
+    ) -> Option<&'a Matching>;
+}
+
+/// 先着順のマッチメイキング
+/// レーティングに関係なく、最も長く待機しているプレイヤーと対戦する
+pub struct FifoMatchmakingStrategy;
+
+impl MatchmakingStrategy for FifoMatchmakingStrategy {
+    fn select<'a>(
+        &self,
+        _player_rating: &Rating,
+        candidates: &'a [Matching],
+        _now: DateTime<Utc>,
+    ) -> Option<&'a Matching> {
+        candidates.first()
+    }
+}
+
+/// レーティング帯によるマッチメイキング
+/// 待機中のプレイヤーとのレーティング差が許容範囲内の場合のみ対戦する
+/// 許容範囲は待機時間に応じて広がり、長く待機しているプレイヤーほど対戦相手が見つかりやすくなる
+///
+/// 許容範囲はマッチメイキングのリクエスト時に判定するため、
+/// 待機中のプレイヤー同士は後から参加したプレイヤーのリクエストによって組み合わされる
+pub struct RatingWindowMatchmakingStrategy;
+
+impl RatingWindowMatchmakingStrategy {
+    /// 待機開始直後に許容するレーティング差
+    const INITIAL_WINDOW: i32 = 100;
+    /// 一定時間待機するごとに広げるレーティング差
+    const WINDOW_STEP: i32 = 50;
+    /// 許容範囲を広げる間隔（秒）
+    const WINDOW_STEP_INTERVAL_SECONDS: i64 = 30;
+    /// 許容するレーティング差の上限
+    const MAX_WINDOW: i32 = 400;
+
+    /// 待機しているマッチングに対して許容するレーティング差を取得
+    pub fn allowed_difference(matching: &Matching, now: DateTime<Utc>) -> i32 {
+        let steps = matching.waiting_duration(now).num_seconds().max(0)
+            / Self::WINDOW_STEP_INTERVAL_SECONDS;
+        let widened = Self::INITIAL_WINDOW as i64 + Self::WINDOW_STEP as i64 * steps;
+        widened.min(Self::MAX_WINDOW as i64) as i32
+    }
+}
+
+impl MatchmakingStrategy for RatingWindowMatchmakingStrategy {
+    fn select<'a>(
+        &self,
+        player_rating: &Rating,
+        candidates: &'a [Matching],
+        now: DateTime<Utc>,
+    ) -> Option<&'a Matching> {
+        // 許容範囲内で最もレーティングが近い相手を選ぶ（同じ差の場合は待機が長い方を優先）
+        let mut selected: Option<&Matching> = None;
+        for matching in candidates {
+            let difference = player_rating.difference(matching.player1_rating());
+            if difference > Self::allowed_difference(matching, now) {
+                continue;
+            }
+            let is_closer = match selected {
+                Some(current) => difference < player_rating.difference(current.player1_rating()),
+                None => true,
+            };
+            if is_closer {
+                selected = Some(matching);
+            }
+        }
+        selected
+    }
+}
@@ -0,0 +1,111 @@
+#[cfg(test)]
+mod tests {
+    use super::super::matchmaking_strategy::{
+        FifoMatchmakingStrategy, MatchmakingStrategy, RatingWindowMatchmakingStrategy,
+    };
+    use crate::domain::{
+        matching_management::models::matching::{
+            Matching, MatchingEndDatetime, MatchingId, MatchingStartDatetime, MatchingStatus,
+            MatchingStatusValue,
+        },
+        player_management::models::player::{
+            player_id::player_id::PlayerId, rating::rating::Rating,
+        },
+    };
+    use chrono::{DateTime, Duration, Utc};
+    use uuid::Uuid;
+
+    /// 指定したレーティング・待機開始日時の待機中マッチングを作成
+    fn create_waiting_matching(rating: i32, started_at: DateTime<Utc>) -> Matching {
+        Matching::new(
+            MatchingId::new(Uuid::new_v4().to_string()),
+            PlayerId::new(Uuid::new_v4().to_string()),
+            Rating::new(rating),
+            None,
+            MatchingStartDatetime::new(started_at),
+            MatchingEndDatetime::new(None),
+            MatchingStatus::new(MatchingStatusValue::InProgress),
+            None,
+        )
+    }
+
+    #[test]
+    fn test_fifo_selects_oldest_matching() {
+        let now = Utc::now();
+        let candidates = vec![
+            create_waiting_matching(2000, now - Duration::seconds(10)),
+            create_waiting_matching(1500, now - Duration::seconds(5)),
+        ];
+
+        let selected = FifoMatchmakingStrategy.select(&Rating::new(1500), &candidates, now);
+
+        assert_eq!(selected.unwrap().matching_id(), candidates[0].matching_id());
+    }
+
+    #[test]
+    fn test_fifo_returns_none_without_candidates() {
+        let selected = FifoMatchmakingStrategy.select(&Rating::initial(), &[], Utc::now());
+
+        assert!(selected.is_none());
+    }
+
+    #[test]
+    fn test_rating_window_selects_closest_rating() {
+        let now = Utc::now();
+        let candidates = vec![
+            create_waiting_matching(1580, now),
+            create_waiting_matching(1530, now),
+            create_waiting_matching(1470, now),
+        ];
+
+        let selected = RatingWindowMatchmakingStrategy.select(&Rating::new(1500), &candidates, now);
+
+        // 1530と1470は同じ差のため、先に待機していた1530を選ぶ
+        assert_eq!(selected.unwrap().matching_id(), candidates[1].matching_id());
+    }
+
+    #[test]
+    fn test_rating_window_skips_out_of_window() {
+        let now = Utc::now();
+        let candidates = vec![create_waiting_matching(1800, now)];
+
+        let selected = RatingWindowMatchmakingStrategy.select(&Rating::new(1500), &candidates, now);
+
+        assert!(selected.is_none());
+    }
+
+    #[test]
+    fn test_rating_window_widens_with_waiting_time() {
+        let now = Utc::now();
+        // 待機開始から60秒経過で許容範囲は200まで広がる
+        let candidates = vec![create_waiting_matching(1700, now - Duration::seconds(60))];
+
+        let selected = RatingWindowMatchmakingStrategy.select(&Rating::new(1500), &candidates, now);
+
+        assert!(selected.is_some());
+    }
+
+    #[test]
+    fn test_allowed_difference() {
+        let now = Utc::now();
+
+        let just_started = create_waiting_matching(1500, now);
+        assert_eq!(
+            RatingWindowMatchmakingStrategy::allowed_difference(&just_started, now),
+            100
+        );
+
+        let waited = create_waiting_matching(1500, now - Duration::seconds(59));
+        assert_eq!(
+            RatingWindowMatchmakingStrategy::allowed_difference(&waited, now),
+            150
+        );
+
+        // 上限を超えて広がらない
+        let long_waited = create_waiting_matching(1500, now - Duration::seconds(3600));
+        assert_eq!(
+            RatingWindowMatchmakingStrategy::allowed_difference(&long_waited, now),
+            400
+        );
+    }
+}
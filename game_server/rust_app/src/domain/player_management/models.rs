@@ -1,2 +1,4 @@
+pub mod outbox_message;
 pub mod player;
 pub mod player_test;
+pub mod session_token;
@@ -0,0 +1,3 @@
+pub mod message_sequence;
+pub mod outbox_message;
+pub mod outbox_message_test;
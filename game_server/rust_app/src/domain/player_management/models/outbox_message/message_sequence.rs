@@ -0,0 +1,2 @@
+pub mod message_sequence;
+pub mod message_sequence_test;
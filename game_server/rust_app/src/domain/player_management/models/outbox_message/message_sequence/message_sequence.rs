@@ -0,0 +1,45 @@
+use serde::Serialize;
+
+/// プレイヤーへの通知の通し番号
+/// プレイヤーごとに1から順に採番され、クライアントは番号の欠けから未受信の通知を検知できる
+#[derive(Debug, Clone, Serialize)]
+#[serde(transparent)]
+pub struct MessageSequence {
+    value: i64,
+}
+
+impl MessageSequence {
+    pub fn new(value: i64) -> Self {
+        Self::validate(value);
+        Self { value }
+    }
+
+    pub fn value(&self) -> i64 {
+        self.value
+    }
+
+    pub fn initial() -> Self {
+        Self { value: 1 }
+    }
+
+    /// 次の通し番号を取得
+    pub fn next(&self) -> Self {
+        Self {
+            value: self.value + 1,
+        }
+    }
+
+    fn validate(value: i64) {
+        if value < 1 {
+            panic!("MessageSequenceは1以上である必要があります");
+        }
+    }
+}
+
+impl PartialEq for MessageSequence {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for MessageSequence {}
@@ -0,0 +1,22 @@
+#[cfg(test)]
+mod tests {
+    use super::super::message_sequence::MessageSequence;
+
+    #[test]
+    fn test_initial() {
+        let sequence = MessageSequence::initial();
+        assert_eq!(sequence.value(), 1);
+    }
+
+    #[test]
+    fn test_next() {
+        let sequence = MessageSequence::new(5);
+        assert_eq!(sequence.next().value(), 6);
+    }
+
+    #[test]
+    #[should_panic(expected = "MessageSequenceは1以上である必要があります")]
+    fn test_invalid_sequence() {
+        MessageSequence::new(0);
+    }
+}
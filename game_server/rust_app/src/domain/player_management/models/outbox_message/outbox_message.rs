@@ -0,0 +1,73 @@
+use crate::domain::player_management::models::player::player_id::player_id::PlayerId;
+use chrono::{DateTime, Utc};
+
+use super::message_sequence::message_sequence::MessageSequence;
+
+/// OutboxMessageエンティティ
+/// オフラインなどで届けられなかったプレイヤーへの通知
+/// 再接続時に通し番号順に再送され、送信できたものから削除される
+#[derive(Debug, Clone)]
+pub struct OutboxMessage {
+    player_id: PlayerId,
+    sequence: MessageSequence,
+    /// 通知内容（シリアライズ済みのJSON）
+    payload: String,
+    created_at: DateTime<Utc>,
+}
+
+impl OutboxMessage {
+    // privateなコンストラクタ
+    fn new(
+        player_id: PlayerId,
+        sequence: MessageSequence,
+        payload: String,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            player_id,
+            sequence,
+            payload,
+            created_at,
+        }
+    }
+
+    /// 新規メッセージの生成
+    pub fn create(player_id: PlayerId, sequence: MessageSequence, payload: String) -> Self {
+        Self::new(player_id, sequence, payload, Utc::now())
+    }
+
+    /// メッセージの再構築（リポジトリから取得時に使用）
+    pub fn reconstruct(
+        player_id: PlayerId,
+        sequence: MessageSequence,
+        payload: String,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self::new(player_id, sequence, payload, created_at)
+    }
+
+    // ゲッター
+    pub fn player_id(&self) -> &PlayerId {
+        &self.player_id
+    }
+
+    pub fn sequence(&self) -> &MessageSequence {
+        &self.sequence
+    }
+
+    pub fn payload(&self) -> &str {
+        &self.payload
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+}
+
+impl PartialEq for OutboxMessage {
+    fn eq(&self, other: &Self) -> bool {
+        self.player_id == other.player_id && self.sequence == other.sequence
+    }
+}
+
+impl Eq for OutboxMessage {}
@@ -0,0 +1,50 @@
+#[cfg(test)]
+mod tests {
+    use super::super::message_sequence::message_sequence::MessageSequence;
+    use super::super::outbox_message::OutboxMessage;
+    use crate::domain::player_management::models::player::player_id::player_id::PlayerId;
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_create_outbox_message() {
+        let player_id = PlayerId::new(Uuid::new_v4().to_string());
+        let message = OutboxMessage::create(
+            player_id.clone(),
+            MessageSequence::new(3),
+            r#"{"action":"pong"}"#.to_string(),
+        );
+
+        assert_eq!(message.player_id(), &player_id);
+        assert_eq!(message.sequence().value(), 3);
+        assert_eq!(message.payload(), r#"{"action":"pong"}"#);
+    }
+
+    #[test]
+    fn test_outbox_message_equality() {
+        let player_id = PlayerId::new(Uuid::new_v4().to_string());
+        let created_at = Utc::now();
+        let message1 = OutboxMessage::reconstruct(
+            player_id.clone(),
+            MessageSequence::new(1),
+            "{}".to_string(),
+            created_at,
+        );
+        let message2 = OutboxMessage::reconstruct(
+            player_id.clone(),
+            MessageSequence::new(1),
+            r#"{"action":"pong"}"#.to_string(),
+            created_at,
+        );
+        let message3 = OutboxMessage::reconstruct(
+            player_id,
+            MessageSequence::new(2),
+            "{}".to_string(),
+            created_at,
+        );
+
+        // 同一性はプレイヤーIDと通し番号で判定する
+        assert_eq!(message1, message2);
+        assert_ne!(message1, message3);
+    }
+}
@@ -1,13 +1,21 @@
+pub mod mfa_attempts;
 pub mod mfa_authentication;
 pub mod player_id;
 pub mod player_name;
+pub mod rating;
+pub mod recovery_code;
 pub mod registered_datetime;
+pub mod totp_secret;
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use mfa_attempts::mfa_attempts::MfaAttempts;
 use mfa_authentication::mfa_authentication::MFAAuthentication;
 use player_id::player_id::PlayerId;
 use player_name::player_name::PlayerName;
+use rating::rating::Rating;
+use recovery_code::recovery_code::RecoveryCode;
 use registered_datetime::registered_datetime::RegisteredDatetime;
+use totp_secret::totp_secret::TotpSecret;
 use uuid::Uuid;
 
 /// Player集約ルートエンティティ
@@ -16,14 +24,20 @@ use uuid::Uuid;
 /// ビジネスルール:
 /// - プレイヤーIDは一意でなければならない
 /// - プレイヤー名は変更可能
-/// - MFA認証の有効化/無効化が可能
+/// - MFA認証はワンタイムパスワードで確認してから有効化する
+/// - MFA認証の無効化にはワンタイムパスワードまたはリカバリーコードが必要
+/// - 一度受け付けたワンタイムパスワードは再利用できない
+/// - MFA認証に連続で失敗した場合は一定時間ロックする
 /// - 登録日時は変更不可
+/// - レーティングは対戦結果によってのみ変動する
 #[derive(Debug, Clone)]
 pub struct Player {
     player_id: PlayerId,
     player_name: PlayerName,
     registered_datetime: RegisteredDatetime,
     mfa_authentication: MFAAuthentication,
+    mfa_attempts: MfaAttempts,
+    rating: Rating,
 }
 
 impl Player {
@@ -34,12 +48,16 @@ impl Player {
         player_name: PlayerName,
         registered_datetime: RegisteredDatetime,
         mfa_authentication: MFAAuthentication,
+        mfa_attempts: MfaAttempts,
+        rating: Rating,
     ) -> Self {
         Self {
             player_id,
             player_name,
             registered_datetime,
             mfa_authentication,
+            mfa_attempts,
+            rating,
         }
     }
 
@@ -55,16 +73,21 @@ impl Player {
     /// - PlayerIdは自動採番（UUID）
     /// - 登録日時は現在時刻
     /// - MFA認証はデフォルトで無効
+    /// - レーティングは初期値
     pub fn create(player_name: PlayerName) -> Self {
         let player_id = PlayerId::new(Uuid::new_v4().to_string());
         let registered_datetime = RegisteredDatetime::new(Utc::now());
-        let mfa_authentication = MFAAuthentication::new(false);
+        let mfa_authentication = MFAAuthentication::disabled();
+        let mfa_attempts = MfaAttempts::initial();
+        let rating = Rating::initial();
 
         Self::new(
             player_id,
             player_name,
             registered_datetime,
             mfa_authentication,
+            mfa_attempts,
+            rating,
         )
     }
 
@@ -77,17 +100,23 @@ impl Player {
     /// * `player_name` - プレイヤー名
     /// * `registered_datetime` - 登録日時
     /// * `mfa_authentication` - MFA認証設定
+    /// * `mfa_attempts` - MFA認証の連続失敗回数とロック状態
+    /// * `rating` - レーティング
     pub fn reconstruct(
         player_id: PlayerId,
         player_name: PlayerName,
         registered_datetime: RegisteredDatetime,
         mfa_authentication: MFAAuthentication,
+        mfa_attempts: MfaAttempts,
+        rating: Rating,
     ) -> Self {
         Self::new(
             player_id,
             player_name,
             registered_datetime,
             mfa_authentication,
+            mfa_attempts,
+            rating,
         )
     }
 
@@ -99,14 +128,98 @@ impl Player {
         self.player_name = new_name;
     }
 
-    /// MFA認証を有効化
-    pub fn enable_mfa(&mut self) {
-        self.mfa_authentication = MFAAuthentication::new(true);
+    /// MFA認証の登録を開始
+    /// 発行したシークレットは、ワンタイムパスワードで確認するまで有効にならない
+    ///
+    /// # Arguments
+    /// * `totp_secret` - 認証アプリに登録するシークレット
+    pub fn start_mfa_enrollment(&mut self, totp_secret: TotpSecret) -> Result<(), String> {
+        if self.mfa_authentication.is_enabled() {
+            return Err("MFA認証は既に有効です".to_string());
+        }
+        self.mfa_authentication = MFAAuthentication::pending(totp_secret);
+        Ok(())
+    }
+
+    /// ワンタイムパスワードで登録中のシークレットを確認し、MFA認証を有効化
+    /// 失敗回数を記録するため、失敗した場合もプレイヤー情報を保存する必要がある
+    ///
+    /// # Arguments
+    /// * `code` - 認証アプリに表示されたワンタイムパスワード
+    /// * `now` - 現在日時
+    /// * `recovery_codes` - 有効化と同時に発行するリカバリーコード
+    pub fn enable_mfa(
+        &mut self,
+        code: &str,
+        now: DateTime<Utc>,
+        recovery_codes: Vec<RecoveryCode>,
+    ) -> Result<(), String> {
+        if !self.mfa_authentication.is_pending() {
+            return Err("MFA認証の登録が開始されていません".to_string());
+        }
+        self.ensure_mfa_unlocked(now)?;
+        if !self.mfa_authentication.verify_totp(code, now) {
+            self.mfa_attempts = self.mfa_attempts.record_failure(now);
+            return Err("ワンタイムパスワードが正しくありません".to_string());
+        }
+        self.mfa_attempts = MfaAttempts::initial();
+        let totp_secret = self
+            .mfa_authentication
+            .totp_secret()
+            .cloned()
+            .expect("登録中のMFA認証はシークレットを保持している");
+        // 確認に使用したワンタイムパスワードを有効化後に再利用できないよう、受け付けたステップを引き継ぐ
+        self.mfa_authentication = MFAAuthentication::new(
+            true,
+            Some(totp_secret),
+            recovery_codes,
+            self.mfa_authentication.last_used_time_step(),
+        );
+        Ok(())
     }
 
     /// MFA認証を無効化
-    pub fn disable_mfa(&mut self) {
-        self.mfa_authentication = MFAAuthentication::new(false);
+    ///
+    /// # Arguments
+    /// * `code` - ワンタイムパスワードまたはリカバリーコード
+    /// * `now` - 現在日時
+    pub fn disable_mfa(&mut self, code: &str, now: DateTime<Utc>) -> Result<(), String> {
+        self.verify_mfa(code, now)?;
+        self.mfa_authentication = MFAAuthentication::disabled();
+        Ok(())
+    }
+
+    /// MFA認証を行う
+    /// ワンタイムパスワードに一致しない場合はリカバリーコードとして検証し、使用したリカバリーコードは無効になる
+    /// 受け付けたワンタイムパスワードと失敗回数を記録するため、成否にかかわらずプレイヤー情報を保存する必要がある
+    ///
+    /// # Arguments
+    /// * `code` - ワンタイムパスワードまたはリカバリーコード
+    /// * `now` - 現在日時
+    pub fn verify_mfa(&mut self, code: &str, now: DateTime<Utc>) -> Result<(), String> {
+        if !self.mfa_authentication.is_enabled() {
+            return Err("MFA認証が有効ではありません".to_string());
+        }
+        self.ensure_mfa_unlocked(now)?;
+        if self.mfa_authentication.verify_totp(code, now)
+            || self.mfa_authentication.use_recovery_code(code)
+        {
+            self.mfa_attempts = MfaAttempts::initial();
+            return Ok(());
+        }
+        self.mfa_attempts = self.mfa_attempts.record_failure(now);
+        Err("ワンタイムパスワードまたはリカバリーコードが正しくありません".to_string())
+    }
+
+    // ヘルパーメソッド：連続失敗によりロック中の場合は、コードを検証せずに拒否する
+    fn ensure_mfa_unlocked(&self, now: DateTime<Utc>) -> Result<(), String> {
+        if self.mfa_attempts.is_locked(now) {
+            return Err(
+                "MFA認証の失敗回数が上限に達したため、しばらく時間をおいてから再度お試しください"
+                    .to_string(),
+            );
+        }
+        Ok(())
     }
 
     /// MFA認証が有効かどうかを確認
@@ -114,6 +227,15 @@ impl Player {
         self.mfa_authentication.is_enabled()
     }
 
+    /// 対戦結果をレーティングに反映
+    ///
+    /// # Arguments
+    /// * `opponent_rating` - 対戦時点の対戦相手のレーティング
+    /// * `score` - 対戦結果（勝利: 1.0、引き分け: 0.5、敗北: 0.0）
+    pub fn apply_game_result(&mut self, opponent_rating: &Rating, score: f64) {
+        self.rating = self.rating.updated(opponent_rating, score);
+    }
+
     // ゲッター
 
     pub fn player_id(&self) -> &PlayerId {
@@ -131,6 +253,14 @@ impl Player {
     pub fn mfa_authentication(&self) -> &MFAAuthentication {
         &self.mfa_authentication
     }
+
+    pub fn mfa_attempts(&self) -> &MfaAttempts {
+        &self.mfa_attempts
+    }
+
+    pub fn rating(&self) -> &Rating {
+        &self.rating
+    }
 }
 
 // 等価性の比較を実装（同一性はPlayerIdで判定）
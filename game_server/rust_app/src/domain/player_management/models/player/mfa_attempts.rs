@@ -0,0 +1,2 @@
+pub mod mfa_attempts;
+pub mod mfa_attempts_test;
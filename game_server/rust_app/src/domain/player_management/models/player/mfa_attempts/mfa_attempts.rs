@@ -0,0 +1,65 @@
+use chrono::{DateTime, Duration, Utc};
+
+/// MFA認証の連続失敗回数とロック状態
+/// 6桁のワンタイムパスワードを総当たりで推測されないよう、
+/// 一定回数連続で失敗した場合は一定時間すべての認証を拒否する
+#[derive(Debug, Clone)]
+pub struct MfaAttempts {
+    failed_count: i32,
+    locked_until: Option<DateTime<Utc>>,
+}
+
+impl MfaAttempts {
+    /// ロックするまでに許容する連続失敗回数
+    const MAX_FAILED_COUNT: i32 = 5;
+    /// ロックする時間（分）
+    const LOCKOUT_MINUTES: i64 = 15;
+
+    pub fn new(failed_count: i32, locked_until: Option<DateTime<Utc>>) -> Self {
+        if failed_count < 0 {
+            panic!("MfaAttemptsの失敗回数は0以上である必要があります");
+        }
+        Self {
+            failed_count,
+            locked_until,
+        }
+    }
+
+    /// 失敗のない状態
+    pub fn initial() -> Self {
+        Self::new(0, None)
+    }
+
+    pub fn failed_count(&self) -> i32 {
+        self.failed_count
+    }
+
+    pub fn locked_until(&self) -> Option<&DateTime<Utc>> {
+        self.locked_until.as_ref()
+    }
+
+    /// 指定日時にロック中かどうか
+    pub fn is_locked(&self, now: DateTime<Utc>) -> bool {
+        self.locked_until
+            .is_some_and(|locked_until| now < locked_until)
+    }
+
+    /// 認証の失敗を記録
+    /// 連続失敗回数が上限に達した場合はロックし、ロック解除後は再び上限まで試行できる
+    pub fn record_failure(&self, now: DateTime<Utc>) -> Self {
+        let failed_count = self.failed_count + 1;
+        if failed_count >= Self::MAX_FAILED_COUNT {
+            return Self::new(0, Some(now + Duration::minutes(Self::LOCKOUT_MINUTES)));
+        }
+        Self::new(failed_count, self.locked_until)
+    }
+}
+
+// 等価性の比較を実装
+impl PartialEq for MfaAttempts {
+    fn eq(&self, other: &Self) -> bool {
+        self.failed_count == other.failed_count && self.locked_until == other.locked_until
+    }
+}
+
+impl Eq for MfaAttempts {}
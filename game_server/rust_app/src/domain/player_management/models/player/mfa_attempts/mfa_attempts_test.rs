@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod tests {
+    use super::super::mfa_attempts::MfaAttempts;
+    use chrono::{Duration, Utc};
+
+    #[test]
+    fn test_initial() {
+        let attempts = MfaAttempts::initial();
+        assert_eq!(attempts.failed_count(), 0);
+        assert!(attempts.locked_until().is_none());
+        assert!(!attempts.is_locked(Utc::now()));
+    }
+
+    #[test]
+    #[should_panic(expected = "MfaAttemptsの失敗回数は0以上である必要があります")]
+    fn test_negative_failed_count() {
+        MfaAttempts::new(-1, None);
+    }
+
+    #[test]
+    fn test_record_failure_locks_at_limit() {
+        let now = Utc::now();
+        let mut attempts = MfaAttempts::initial();
+        for count in 1..5 {
+            attempts = attempts.record_failure(now);
+            assert_eq!(attempts.failed_count(), count);
+            assert!(!attempts.is_locked(now));
+        }
+
+        // 5回連続で失敗するとロックされる
+        attempts = attempts.record_failure(now);
+        assert!(attempts.is_locked(now));
+        assert!(attempts.is_locked(now + Duration::minutes(14)));
+        // ロック時間が経過すると解除される
+        assert!(!attempts.is_locked(now + Duration::minutes(15)));
+        assert_eq!(attempts.failed_count(), 0);
+    }
+
+    #[test]
+    fn test_equality() {
+        let now = Utc::now();
+        assert_eq!(MfaAttempts::initial(), MfaAttempts::new(0, None));
+        assert_ne!(MfaAttempts::initial(), MfaAttempts::new(1, None));
+        assert_ne!(MfaAttempts::initial(), MfaAttempts::new(0, Some(now)));
+    }
+}
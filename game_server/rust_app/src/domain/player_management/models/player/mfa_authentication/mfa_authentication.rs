@@ -1,11 +1,56 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::player_management::models::player::{
+    recovery_code::recovery_code::RecoveryCode, totp_secret::totp_secret::TotpSecret,
+};
+
+/// MFA（TOTP）認証の設定
+///
+/// 状態:
+/// - 無効: シークレットなし
+/// - 登録中: シークレットを発行済みだが、ワンタイムパスワードでの確認が済んでいない
+/// - 有効: シークレットとリカバリーコードを保持し、ログイン時に認証を要求する
+///
+/// 同じワンタイムパスワードを再利用できないよう、最後に受け付けたタイムステップを保持する
 #[derive(Debug, Clone)]
 pub struct MFAAuthentication {
     value: bool,
+    totp_secret: Option<TotpSecret>,
+    recovery_codes: Vec<RecoveryCode>,
+    last_used_time_step: Option<i64>,
 }
 
 impl MFAAuthentication {
-    pub fn new(value: bool) -> Self {
-        Self { value }
+    pub fn new(
+        value: bool,
+        totp_secret: Option<TotpSecret>,
+        recovery_codes: Vec<RecoveryCode>,
+        last_used_time_step: Option<i64>,
+    ) -> Self {
+        if value && totp_secret.is_none() {
+            panic!("MFAを有効にするにはTOTPシークレットが必要です");
+        }
+        Self {
+            value,
+            totp_secret,
+            recovery_codes,
+            last_used_time_step,
+        }
+    }
+
+    /// 無効なMFA設定
+    pub fn disabled() -> Self {
+        Self::new(false, None, Vec::new(), None)
+    }
+
+    /// 登録中のMFA設定
+    pub fn pending(totp_secret: TotpSecret) -> Self {
+        Self::new(false, Some(totp_secret), Vec::new(), None)
+    }
+
+    /// 有効なMFA設定
+    pub fn enabled(totp_secret: TotpSecret, recovery_codes: Vec<RecoveryCode>) -> Self {
+        Self::new(true, Some(totp_secret), recovery_codes, None)
     }
 
     pub fn value(&self) -> bool {
@@ -19,12 +64,71 @@ impl MFAAuthentication {
     pub fn is_disabled(&self) -> bool {
         !self.value
     }
+
+    /// 登録中（シークレット発行済み・未確認）かどうか
+    pub fn is_pending(&self) -> bool {
+        !self.value && self.totp_secret.is_some()
+    }
+
+    pub fn totp_secret(&self) -> Option<&TotpSecret> {
+        self.totp_secret.as_ref()
+    }
+
+    pub fn recovery_codes(&self) -> &[RecoveryCode] {
+        &self.recovery_codes
+    }
+
+    /// 最後に受け付けたワンタイムパスワードのタイムステップ
+    pub fn last_used_time_step(&self) -> Option<i64> {
+        self.last_used_time_step
+    }
+
+    /// ワンタイムパスワードを検証
+    /// 最後に受け付けたステップ以前のパスワードは、使用済みとして拒否する
+    /// シークレットが発行されていない場合は常に失敗する
+    pub fn verify_totp(&mut self, code: &str, now: DateTime<Utc>) -> bool {
+        let step = match self
+            .totp_secret
+            .as_ref()
+            .and_then(|secret| secret.matching_time_step(code, now))
+        {
+            Some(step) => step,
+            None => return false,
+        };
+        if self.last_used_time_step.is_some_and(|last| step <= last) {
+            return false;
+        }
+        self.last_used_time_step = Some(step);
+        true
+    }
+
+    /// リカバリーコードを使用
+    /// 一致したリカバリーコードは再利用できないよう削除する
+    ///
+    /// # Returns
+    /// 一致するリカバリーコードが存在した場合はtrue
+    pub fn use_recovery_code(&mut self, code: &str) -> bool {
+        match self
+            .recovery_codes
+            .iter()
+            .position(|recovery_code| recovery_code.matches(code))
+        {
+            Some(index) => {
+                self.recovery_codes.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
 }
 
 // 等価性の比較を実装
 impl PartialEq for MFAAuthentication {
     fn eq(&self, other: &Self) -> bool {
         self.value == other.value
+            && self.totp_secret == other.totp_secret
+            && self.recovery_codes == other.recovery_codes
+            && self.last_used_time_step == other.last_used_time_step
     }
 }
 
@@ -1,36 +1,105 @@
 #[cfg(test)]
 mod tests {
+    use super::super::super::{
+        recovery_code::recovery_code::RecoveryCode, totp_secret::totp_secret::TotpSecret,
+    };
     use super::super::mfa_authentication::MFAAuthentication;
+    use chrono::{Duration, Utc};
+
+    fn test_secret() -> TotpSecret {
+        TotpSecret::new("GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ".to_string())
+    }
 
     #[test]
     fn test_new_enabled() {
-        let mfa = MFAAuthentication::new(true);
-        assert_eq!(mfa.value(), true);
+        let mfa = MFAAuthentication::enabled(test_secret(), Vec::new());
+        assert!(mfa.value());
         assert!(mfa.is_enabled());
         assert!(!mfa.is_disabled());
+        assert!(!mfa.is_pending());
     }
 
     #[test]
     fn test_new_disabled() {
-        let mfa = MFAAuthentication::new(false);
-        assert_eq!(mfa.value(), false);
+        let mfa = MFAAuthentication::disabled();
+        assert!(!mfa.value());
         assert!(!mfa.is_enabled());
         assert!(mfa.is_disabled());
+        assert!(!mfa.is_pending());
+        assert!(mfa.totp_secret().is_none());
+    }
+
+    #[test]
+    fn test_new_pending() {
+        let mfa = MFAAuthentication::pending(test_secret());
+        assert!(mfa.is_disabled());
+        assert!(mfa.is_pending());
+        assert_eq!(mfa.totp_secret(), Some(&test_secret()));
+    }
+
+    #[test]
+    #[should_panic(expected = "MFAを有効にするにはTOTPシークレットが必要です")]
+    fn test_enabled_without_secret() {
+        MFAAuthentication::new(true, None, Vec::new(), None);
+    }
+
+    #[test]
+    fn test_verify_totp() {
+        let now = Utc::now();
+        let mut mfa = MFAAuthentication::pending(test_secret());
+        assert!(mfa.verify_totp(&test_secret().code_at(now), now));
+        assert!(!MFAAuthentication::disabled().verify_totp(&test_secret().code_at(now), now));
+    }
+
+    #[test]
+    fn test_verify_totp_rejects_replayed_code() {
+        let now = Utc::now();
+        let mut mfa = MFAAuthentication::enabled(test_secret(), Vec::new());
+        let code = test_secret().code_at(now);
+
+        assert!(mfa.verify_totp(&code, now));
+        // 受け付けたパスワードは、許容するずれの範囲内でも再利用できない
+        assert!(!mfa.verify_totp(&code, now));
+        assert!(!mfa.verify_totp(&code, now + Duration::seconds(30)));
+        // 受け付けたステップ以前のパスワードも使用できない
+        assert!(!mfa.verify_totp(&test_secret().code_at(now - Duration::seconds(30)), now));
+        // 次のステップのパスワードは使用できる
+        let next = now + Duration::seconds(30);
+        assert!(mfa.verify_totp(&test_secret().code_at(next), next));
+    }
+
+    #[test]
+    fn test_use_recovery_code_only_once() {
+        let mut mfa = MFAAuthentication::enabled(
+            test_secret(),
+            vec![
+                RecoveryCode::from_code("ABCDE-23456"),
+                RecoveryCode::from_code("FGHJK-78923"),
+            ],
+        );
+
+        assert!(mfa.use_recovery_code("ABCDE-23456"));
+        assert_eq!(mfa.recovery_codes().len(), 1);
+        // 使用済みのコードは再利用できない
+        assert!(!mfa.use_recovery_code("ABCDE-23456"));
+        assert!(!mfa.use_recovery_code("ZZZZZ-22222"));
+        assert!(mfa.use_recovery_code("FGHJK-78923"));
+        assert!(mfa.recovery_codes().is_empty());
     }
 
     #[test]
     fn test_equality() {
-        let mfa1 = MFAAuthentication::new(true);
-        let mfa2 = MFAAuthentication::new(true);
-        let mfa3 = MFAAuthentication::new(false);
-        
+        let mfa1 = MFAAuthentication::enabled(test_secret(), Vec::new());
+        let mfa2 = MFAAuthentication::enabled(test_secret(), Vec::new());
+        let mfa3 = MFAAuthentication::disabled();
+
         assert_eq!(mfa1, mfa2);
         assert_ne!(mfa1, mfa3);
     }
 
     #[test]
     fn test_clone() {
-        let mfa1 = MFAAuthentication::new(true);
+        let mfa1 = MFAAuthentication::enabled(test_secret(), Vec::new());
         let mfa2 = mfa1.clone();
         assert_eq!(mfa1, mfa2);
     }
@@ -8,25 +8,42 @@ impl PlayerName {
     const MIN_LENGTH: usize = 1;
 
     pub fn new(value: String) -> Self {
-        Self::validate(&value);
+        if let Err(e) = Self::validate(&value) {
+            panic!("{}", e);
+        }
         Self { value }
     }
 
+    /// プレイヤーが入力したプレイヤー名を解析
+    /// 前後の空白は取り除く
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let value = input.trim().to_string();
+        Self::validate(&value)?;
+        Ok(Self { value })
+    }
+
     pub fn value(&self) -> &str {
         &self.value
     }
 
     // バリデーションの実装
-    fn validate(value: &str) {
+    fn validate(value: &str) -> Result<(), String> {
         let length = value.chars().count();
-        
+
         if length < Self::MIN_LENGTH {
-            panic!("PlayerNameは{}文字以上である必要があります", Self::MIN_LENGTH);
+            return Err(format!(
+                "PlayerNameは{}文字以上である必要があります",
+                Self::MIN_LENGTH
+            ));
         }
-        
+
         if length > Self::MAX_LENGTH {
-            panic!("PlayerNameは{}文字以下である必要があります", Self::MAX_LENGTH);
+            return Err(format!(
+                "PlayerNameは{}文字以下である必要があります",
+                Self::MAX_LENGTH
+            ));
         }
+        Ok(())
     }
 }
 
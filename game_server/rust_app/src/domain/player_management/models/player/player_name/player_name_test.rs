@@ -57,4 +57,22 @@ mod tests {
     fn test_japanese_characters_too_long() {
         PlayerName::new("あいうえおかきくけこさしすせそたちつてとな".to_string());
     }
+
+    #[test]
+    fn test_parse_trims_input() {
+        let name = PlayerName::parse("  TestPlayer ").unwrap();
+        assert_eq!(name.value(), "TestPlayer");
+    }
+
+    #[test]
+    fn test_parse_invalid_name() {
+        assert_eq!(
+            PlayerName::parse("   ").unwrap_err(),
+            "PlayerNameは1文字以上である必要があります"
+        );
+        assert_eq!(
+            PlayerName::parse("123456789012345678901").unwrap_err(),
+            "PlayerNameは20文字以下である必要があります"
+        );
+    }
 }
@@ -0,0 +1,2 @@
+pub mod rating;
+pub mod rating_test;
@@ -0,0 +1,68 @@
+/// プレイヤーのレーティング（イロレーティング）
+/// 対戦結果に応じて増減し、実力の近いプレイヤー同士のマッチングに使用する
+#[derive(Debug, Clone)]
+pub struct Rating {
+    value: i32,
+}
+
+impl Rating {
+    /// 新規プレイヤーのレーティング
+    const INITIAL_VALUE: i32 = 1500;
+    const MIN_VALUE: i32 = 0;
+    /// 1試合あたりの最大変動量（K係数）
+    const K_FACTOR: f64 = 32.0;
+
+    pub fn new(value: i32) -> Self {
+        Self::validate(value);
+        Self { value }
+    }
+
+    pub fn value(&self) -> i32 {
+        self.value
+    }
+
+    pub fn initial() -> Self {
+        Self {
+            value: Self::INITIAL_VALUE,
+        }
+    }
+
+    /// 対戦相手に勝利する期待値（0.0〜1.0）を計算
+    pub fn expected_score(&self, opponent: &Rating) -> f64 {
+        1.0 / (1.0 + 10f64.powf((opponent.value - self.value) as f64 / 400.0))
+    }
+
+    /// 対戦結果を反映したレーティングを計算
+    ///
+    /// # Arguments
+    /// * `opponent` - 対戦時点の対戦相手のレーティング
+    /// * `score` - 対戦結果（勝利: 1.0、引き分け: 0.5、敗北: 0.0）
+    pub fn updated(&self, opponent: &Rating, score: f64) -> Rating {
+        if !(0.0..=1.0).contains(&score) {
+            panic!("対戦結果は0.0以上1.0以下である必要があります");
+        }
+        let delta = Self::K_FACTOR * (score - self.expected_score(opponent));
+        Self {
+            value: (self.value + delta.round() as i32).max(Self::MIN_VALUE),
+        }
+    }
+
+    /// レーティングの差の絶対値
+    pub fn difference(&self, other: &Rating) -> i32 {
+        (self.value - other.value).abs()
+    }
+
+    fn validate(value: i32) {
+        if value < Self::MIN_VALUE {
+            panic!("Ratingは{}以上である必要があります", Self::MIN_VALUE);
+        }
+    }
+}
+
+impl PartialEq for Rating {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for Rating {}
@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod tests {
+    use super::super::rating::Rating;
+
+    #[test]
+    fn test_initial_rating() {
+        assert_eq!(Rating::initial().value(), 1500);
+    }
+
+    #[test]
+    #[should_panic(expected = "Ratingは0以上である必要があります")]
+    fn test_negative_rating_panic() {
+        Rating::new(-1);
+    }
+
+    #[test]
+    fn test_expected_score_between_equal_ratings() {
+        let rating = Rating::new(1500);
+        assert!((rating.expected_score(&Rating::new(1500)) - 0.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_updated_on_win_and_lose() {
+        let rating = Rating::new(1500);
+        let opponent = Rating::new(1500);
+
+        assert_eq!(rating.updated(&opponent, 1.0).value(), 1516);
+        assert_eq!(rating.updated(&opponent, 0.0).value(), 1484);
+        assert_eq!(rating.updated(&opponent, 0.5).value(), 1500);
+    }
+
+    #[test]
+    fn test_updated_against_stronger_opponent() {
+        // 格上に勝利した場合は格下に勝利した場合より大きく上昇する
+        let rating = Rating::new(1500);
+        let stronger_win = rating.updated(&Rating::new(1700), 1.0);
+        let weaker_win = rating.updated(&Rating::new(1300), 1.0);
+
+        assert!(stronger_win.value() > weaker_win.value());
+        assert_eq!(stronger_win.value(), 1524);
+        assert_eq!(weaker_win.value(), 1508);
+    }
+
+    #[test]
+    fn test_updated_does_not_go_below_min() {
+        let rating = Rating::new(0);
+        assert_eq!(rating.updated(&Rating::new(0), 0.0).value(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "対戦結果は0.0以上1.0以下である必要があります")]
+    fn test_updated_with_invalid_score_panic() {
+        Rating::initial().updated(&Rating::initial(), 1.5);
+    }
+
+    #[test]
+    fn test_difference() {
+        assert_eq!(Rating::new(1400).difference(&Rating::new(1550)), 150);
+        assert_eq!(Rating::new(1550).difference(&Rating::new(1400)), 150);
+    }
+}
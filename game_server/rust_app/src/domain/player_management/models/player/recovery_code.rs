@@ -0,0 +1,2 @@
+pub mod recovery_code;
+pub mod recovery_code_test;
@@ -0,0 +1,75 @@
+use data_encoding::HEXLOWER;
+use rand::Rng;
+use sha2::{Digest, Sha256};
+
+/// MFAのリカバリーコード
+/// 認証アプリを利用できなくなった場合に、ワンタイムパスワードの代わりに一度だけ使用できる
+/// 漏洩に備えてコード自体は保持せず、SHA-256のハッシュ値のみを保持する
+#[derive(Debug, Clone)]
+pub struct RecoveryCode {
+    hash: String,
+}
+
+impl RecoveryCode {
+    /// コードの文字数（区切り文字を除く）
+    const LENGTH: usize = 10;
+    /// 読み間違えやすい文字（0/O、1/I/L）を除いた英大文字と数字
+    const CHARACTERS: &'static [u8] = b"ABCDEFGHJKMNPQRSTUVWXYZ23456789";
+    /// SHA-256の16進表記の文字数
+    const HASH_LENGTH: usize = 64;
+
+    pub fn new(hash: String) -> Self {
+        if hash.len() != Self::HASH_LENGTH || !hash.bytes().all(|c| c.is_ascii_hexdigit()) {
+            panic!("RecoveryCodeのハッシュ値はSHA-256の16進表記である必要があります");
+        }
+        Self { hash }
+    }
+
+    /// ランダムなリカバリーコードを生成
+    ///
+    /// # Returns
+    /// プレイヤーに提示するコード（"XXXXX-XXXXX"形式）と、保持用のRecoveryCode
+    pub fn generate(rng: &mut impl Rng) -> (String, Self) {
+        let characters: String = (0..Self::LENGTH)
+            .map(|_| Self::CHARACTERS[rng.random_range(0..Self::CHARACTERS.len())] as char)
+            .collect();
+        let code = format!(
+            "{}-{}",
+            &characters[..Self::LENGTH / 2],
+            &characters[Self::LENGTH / 2..]
+        );
+        let recovery_code = Self::from_code(&code);
+        (code, recovery_code)
+    }
+
+    /// プレイヤーが入力したコードから生成
+    /// 区切り文字・空白と大文字・小文字の違いは無視する
+    pub fn from_code(code: &str) -> Self {
+        let normalized: String = code
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .collect::<String>()
+            .to_uppercase();
+        Self {
+            hash: HEXLOWER.encode(&Sha256::digest(normalized.as_bytes())),
+        }
+    }
+
+    pub fn hash(&self) -> &str {
+        &self.hash
+    }
+
+    /// 入力されたコードがこのリカバリーコードと一致するか確認
+    pub fn matches(&self, code: &str) -> bool {
+        *self == Self::from_code(code)
+    }
+}
+
+// 等価性の比較を実装
+impl PartialEq for RecoveryCode {
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash
+    }
+}
+
+impl Eq for RecoveryCode {}
@@ -0,0 +1,38 @@
+#[cfg(test)]
+mod tests {
+    use super::super::recovery_code::RecoveryCode;
+
+    #[test]
+    fn test_generate() {
+        let mut rng = rand::rng();
+        let (code, recovery_code) = RecoveryCode::generate(&mut rng);
+        assert_eq!(code.len(), 11);
+        assert_eq!(&code[5..6], "-");
+        assert!(recovery_code.matches(&code));
+        // コード自体は保持しない
+        assert_ne!(recovery_code.hash(), code);
+        assert_eq!(recovery_code.hash().len(), 64);
+    }
+
+    #[test]
+    fn test_matches_normalizes_input() {
+        let recovery_code = RecoveryCode::from_code("ABCDE-23456");
+        assert!(recovery_code.matches("abcde-23456"));
+        assert!(recovery_code.matches(" ABCDE23456 "));
+        assert!(!recovery_code.matches("ABCDE-23457"));
+    }
+
+    #[test]
+    fn test_reconstruct_from_hash() {
+        let recovery_code = RecoveryCode::from_code("ABCDE-23456");
+        let reconstructed = RecoveryCode::new(recovery_code.hash().to_string());
+        assert_eq!(reconstructed, recovery_code);
+        assert!(reconstructed.matches("ABCDE-23456"));
+    }
+
+    #[test]
+    #[should_panic(expected = "RecoveryCodeのハッシュ値はSHA-256の16進表記である必要があります")]
+    fn test_invalid_hash() {
+        RecoveryCode::new("ABCDE-23456".to_string());
+    }
+}
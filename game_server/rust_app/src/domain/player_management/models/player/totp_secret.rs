@@ -0,0 +1,2 @@
+pub mod totp_secret;
+pub mod totp_secret_test;
@@ -0,0 +1,146 @@
+use chrono::{DateTime, Utc};
+use data_encoding::BASE32_NOPAD;
+use hmac::{Hmac, KeyInit, Mac};
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use rand::Rng;
+use sha1::Sha1;
+
+type HmacSha1 = Hmac<Sha1>;
+
+/// TOTP（RFC 6238）の共有シークレット
+/// 認証アプリへの登録に使用するため、Base32（パディングなし）でエンコードした値を保持する
+#[derive(Debug, Clone)]
+pub struct TotpSecret {
+    value: String,
+}
+
+impl TotpSecret {
+    /// 生成するシークレットのバイト数（RFC 4226 推奨の160ビット）
+    const SECRET_BYTES: usize = 20;
+    /// シークレットの最小バイト数（RFC 4226 の下限である128ビット）
+    const MIN_SECRET_BYTES: usize = 16;
+    /// ワンタイムパスワードの桁数
+    const DIGITS: u32 = 6;
+    /// ワンタイムパスワードの更新間隔（秒）
+    const PERIOD_SECONDS: i64 = 30;
+    /// 端末との時刻ずれとして許容する前後のステップ数
+    const ALLOWED_SKEW_STEPS: i64 = 1;
+
+    pub fn new(value: String) -> Self {
+        if let Err(e) = Self::validate(&value) {
+            panic!("{}", e);
+        }
+        Self { value }
+    }
+
+    /// ランダムなシークレットを生成
+    pub fn generate(rng: &mut impl Rng) -> Self {
+        let mut bytes = [0u8; Self::SECRET_BYTES];
+        rng.fill(&mut bytes);
+        Self {
+            value: BASE32_NOPAD.encode(&bytes),
+        }
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// 認証アプリに登録するためのプロビジョニングURI（otpauth://）を生成
+    ///
+    /// # Arguments
+    /// * `issuer` - 発行者名（認証アプリ上の表示名）
+    /// * `account_name` - アカウント名
+    pub fn provisioning_uri(&self, issuer: &str, account_name: &str) -> String {
+        let issuer = utf8_percent_encode(issuer, NON_ALPHANUMERIC).to_string();
+        let account_name = utf8_percent_encode(account_name, NON_ALPHANUMERIC).to_string();
+        format!(
+            "otpauth://totp/{}:{}?secret={}&issuer={}&algorithm=SHA1&digits={}&period={}",
+            issuer,
+            account_name,
+            self.value,
+            issuer,
+            Self::DIGITS,
+            Self::PERIOD_SECONDS
+        )
+    }
+
+    /// 指定日時のワンタイムパスワードを計算
+    pub fn code_at(&self, datetime: DateTime<Utc>) -> String {
+        self.code_for_step(Self::time_step(datetime))
+    }
+
+    /// ワンタイムパスワードを検証
+    /// 時刻のずれを考慮し、前後のステップのパスワードも有効とする
+    pub fn verify(&self, code: &str, now: DateTime<Utc>) -> bool {
+        self.matching_time_step(code, now).is_some()
+    }
+
+    /// ワンタイムパスワードに一致するタイムステップを取得
+    /// 同じパスワードの再利用（RFC 6238 5.2節）を防ぐため、受け付けたステップの記録に使用する
+    ///
+    /// # Returns
+    /// 許容するずれの範囲内で一致するステップ。一致しない場合はNone
+    pub fn matching_time_step(&self, code: &str, now: DateTime<Utc>) -> Option<i64> {
+        let code = code.trim();
+        if code.len() != Self::DIGITS as usize || !code.bytes().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        let current_step = Self::time_step(now);
+        (-Self::ALLOWED_SKEW_STEPS..=Self::ALLOWED_SKEW_STEPS)
+            .map(|skew| current_step + skew)
+            .find(|step| self.code_for_step(*step) == code)
+    }
+
+    // ヘルパーメソッド：日時をTOTPのタイムステップに変換
+    fn time_step(datetime: DateTime<Utc>) -> i64 {
+        datetime.timestamp().div_euclid(Self::PERIOD_SECONDS)
+    }
+
+    // ヘルパーメソッド：HOTP（RFC 4226）でタイムステップに対応するパスワードを計算
+    fn code_for_step(&self, step: i64) -> String {
+        let key = BASE32_NOPAD
+            .decode(self.value.as_bytes())
+            .expect("シークレットは生成時に検証済み");
+        let mut mac = HmacSha1::new_from_slice(&key).expect("HMACは任意の長さの鍵を受け付ける");
+        mac.update(&(step as u64).to_be_bytes());
+        let hash = mac.finalize().into_bytes();
+
+        // 動的切り捨て
+        let offset = (hash[hash.len() - 1] & 0x0f) as usize;
+        let binary = u32::from_be_bytes([
+            hash[offset] & 0x7f,
+            hash[offset + 1],
+            hash[offset + 2],
+            hash[offset + 3],
+        ]);
+        format!(
+            "{:0width$}",
+            binary % 10u32.pow(Self::DIGITS),
+            width = Self::DIGITS as usize
+        )
+    }
+
+    // バリデーションの実装
+    fn validate(value: &str) -> Result<(), String> {
+        let bytes = BASE32_NOPAD
+            .decode(value.as_bytes())
+            .map_err(|_| "TotpSecretはBase32でエンコードされている必要があります".to_string())?;
+        if bytes.len() < Self::MIN_SECRET_BYTES {
+            return Err(format!(
+                "TotpSecretは{}バイト以上である必要があります",
+                Self::MIN_SECRET_BYTES
+            ));
+        }
+        Ok(())
+    }
+}
+
+// 等価性の比較を実装
+impl PartialEq for TotpSecret {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for TotpSecret {}
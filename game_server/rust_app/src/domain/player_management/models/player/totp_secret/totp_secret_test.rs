@@ -0,0 +1,111 @@
+#[cfg(test)]
+mod tests {
+    use super::super::totp_secret::TotpSecret;
+    use chrono::{DateTime, Duration, Utc};
+
+    // RFC 6238 のテストベクタで使用されているシークレット（"12345678901234567890"）
+    const RFC_SECRET: &str = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+
+    fn datetime(timestamp: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(timestamp, 0).unwrap()
+    }
+
+    #[test]
+    fn test_code_at_rfc_test_vectors() {
+        let secret = TotpSecret::new(RFC_SECRET.to_string());
+        // RFC 6238 Appendix B（SHA1、8桁）の下6桁
+        assert_eq!(secret.code_at(datetime(59)), "287082");
+        assert_eq!(secret.code_at(datetime(1111111109)), "081804");
+        assert_eq!(secret.code_at(datetime(1234567890)), "005924");
+        assert_eq!(secret.code_at(datetime(2000000000)), "279037");
+    }
+
+    #[test]
+    fn test_generate() {
+        let mut rng = rand::rng();
+        let secret1 = TotpSecret::generate(&mut rng);
+        let secret2 = TotpSecret::generate(&mut rng);
+        // 160ビットのシークレットはBase32で32文字になる
+        assert_eq!(secret1.value().len(), 32);
+        assert_ne!(secret1, secret2);
+        // 生成した値から再構築できる
+        assert_eq!(TotpSecret::new(secret1.value().to_string()), secret1);
+    }
+
+    #[test]
+    fn test_verify_current_code() {
+        let secret = TotpSecret::new(RFC_SECRET.to_string());
+        let now = Utc::now();
+        assert!(secret.verify(&secret.code_at(now), now));
+    }
+
+    #[test]
+    fn test_verify_allows_one_step_skew() {
+        let secret = TotpSecret::new(RFC_SECRET.to_string());
+        let now = datetime(1111111109);
+        assert!(secret.verify(&secret.code_at(now - Duration::seconds(30)), now));
+        assert!(secret.verify(&secret.code_at(now + Duration::seconds(30)), now));
+    }
+
+    #[test]
+    fn test_verify_rejects_code_outside_window() {
+        let secret = TotpSecret::new(RFC_SECRET.to_string());
+        let now = datetime(1111111109);
+        assert!(!secret.verify(&secret.code_at(now - Duration::seconds(90)), now));
+        assert!(!secret.verify(&secret.code_at(now + Duration::seconds(90)), now));
+    }
+
+    #[test]
+    fn test_matching_time_step() {
+        let secret = TotpSecret::new(RFC_SECRET.to_string());
+        let now = datetime(1111111109);
+        let current_step = 1111111109 / 30;
+        assert_eq!(
+            secret.matching_time_step(&secret.code_at(now), now),
+            Some(current_step)
+        );
+        assert_eq!(
+            secret.matching_time_step(&secret.code_at(now - Duration::seconds(30)), now),
+            Some(current_step - 1)
+        );
+        assert_eq!(
+            secret.matching_time_step(&secret.code_at(now + Duration::seconds(90)), now),
+            None
+        );
+    }
+
+    #[test]
+    fn test_verify_rejects_malformed_code() {
+        let secret = TotpSecret::new(RFC_SECRET.to_string());
+        let now = datetime(59);
+        assert!(!secret.verify("", now));
+        assert!(!secret.verify("28708", now));
+        assert!(!secret.verify("2870822", now));
+        assert!(!secret.verify("28708a", now));
+        // 前後の空白は無視する
+        assert!(secret.verify(" 287082 ", now));
+    }
+
+    #[test]
+    fn test_provisioning_uri() {
+        let secret = TotpSecret::new(RFC_SECRET.to_string());
+        assert_eq!(
+            secret.provisioning_uri("TriggerGame", "player 1"),
+            "otpauth://totp/TriggerGame:player%201?secret=GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ\
+             &issuer=TriggerGame&algorithm=SHA1&digits=6&period=30"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "TotpSecretはBase32でエンコードされている必要があります")]
+    fn test_invalid_base32() {
+        TotpSecret::new("not-base32!".to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "TotpSecretは16バイト以上である必要があります")]
+    fn test_too_short() {
+        // 10バイト
+        TotpSecret::new("GEZDGNBVGY3TQOJQ".to_string());
+    }
+}
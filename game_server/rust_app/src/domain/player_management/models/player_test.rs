@@ -5,9 +5,13 @@ mod player_tests {
         player_id::player_id::PlayerId,
         player_name::player_name::PlayerName,
         registered_datetime::registered_datetime::RegisteredDatetime,
+        mfa_attempts::mfa_attempts::MfaAttempts,
         mfa_authentication::mfa_authentication::MFAAuthentication,
+        rating::rating::Rating,
+        recovery_code::recovery_code::RecoveryCode,
+        totp_secret::totp_secret::TotpSecret,
     };
-    use chrono::Utc;
+    use chrono::{Duration, Utc};
     use uuid::Uuid;
 
     /// テスト用のPlayerインスタンスを生成
@@ -16,6 +20,25 @@ mod player_tests {
         Player::create(player_name)
     }
 
+    /// テスト用のTOTPシークレット
+    fn test_secret() -> TotpSecret {
+        TotpSecret::new("GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ".to_string())
+    }
+
+    /// テスト用のシークレットでMFA認証を有効化
+    /// 有効化に使用したワンタイムパスワードは再利用できないため、1つ前のステップのパスワードで確認する
+    fn enable_test_mfa(player: &mut Player) {
+        let now = Utc::now();
+        player.start_mfa_enrollment(test_secret()).unwrap();
+        player
+            .enable_mfa(
+                &test_secret().code_at(now - Duration::seconds(30)),
+                now,
+                vec![RecoveryCode::from_code("ABCDE-23456")],
+            )
+            .unwrap();
+    }
+
     #[test]
     fn test_create_player_with_default_values() {
         let player_name = PlayerName::new("新規プレイヤー".to_string());
@@ -47,7 +70,7 @@ mod player_tests {
         
         assert!(!player.is_mfa_enabled());
 
-        player.enable_mfa();
+        enable_test_mfa(&mut player);
 
         assert!(player.is_mfa_enabled());
         assert!(player.mfa_authentication().is_enabled());
@@ -59,11 +82,11 @@ mod player_tests {
         let mut player = create_test_player();
         
         // まずMFAを有効化
-        player.enable_mfa();
+        enable_test_mfa(&mut player);
         assert!(player.is_mfa_enabled());
 
         // その後無効化
-        player.disable_mfa();
+        player.disable_mfa(&test_secret().code_at(Utc::now()), Utc::now()).unwrap();
 
         assert!(!player.is_mfa_enabled());
         assert!(!player.mfa_authentication().is_enabled());
@@ -78,15 +101,191 @@ mod player_tests {
         assert!(!player.is_mfa_enabled());
 
         // 有効化
-        player.enable_mfa();
+        enable_test_mfa(&mut player);
         assert!(player.is_mfa_enabled());
 
         // 無効化
-        player.disable_mfa();
+        player.disable_mfa(&test_secret().code_at(Utc::now()), Utc::now()).unwrap();
         assert!(!player.is_mfa_enabled());
 
         // 再度有効化
-        player.enable_mfa();
+        enable_test_mfa(&mut player);
+        assert!(player.is_mfa_enabled());
+    }
+
+    #[test]
+    fn test_mfa_enrollment_is_pending_until_confirmed() {
+        let mut player = create_test_player();
+
+        player.start_mfa_enrollment(test_secret()).unwrap();
+
+        // ワンタイムパスワードで確認するまでは有効にならない
+        assert!(!player.is_mfa_enabled());
+        assert!(player.mfa_authentication().is_pending());
+    }
+
+    #[test]
+    fn test_enable_mfa_with_wrong_code() {
+        let mut player = create_test_player();
+        let now = Utc::now();
+        player.start_mfa_enrollment(test_secret()).unwrap();
+
+        let result = player.enable_mfa("000000", now - chrono::Duration::hours(1), Vec::new());
+
+        assert_eq!(result.unwrap_err(), "ワンタイムパスワードが正しくありません");
+        assert!(!player.is_mfa_enabled());
+    }
+
+    #[test]
+    fn test_enable_mfa_without_enrollment() {
+        let mut player = create_test_player();
+        let now = Utc::now();
+
+        let result = player.enable_mfa(&test_secret().code_at(now), now, Vec::new());
+
+        assert_eq!(result.unwrap_err(), "MFA認証の登録が開始されていません");
+    }
+
+    #[test]
+    fn test_start_mfa_enrollment_when_already_enabled() {
+        let mut player = create_test_player();
+        enable_test_mfa(&mut player);
+
+        let result = player.start_mfa_enrollment(test_secret());
+
+        assert_eq!(result.unwrap_err(), "MFA認証は既に有効です");
+        assert!(player.is_mfa_enabled());
+    }
+
+    #[test]
+    fn test_verify_mfa_with_totp() {
+        let mut player = create_test_player();
+        enable_test_mfa(&mut player);
+        let now = Utc::now();
+
+        assert!(player.verify_mfa(&test_secret().code_at(now), now).is_ok());
+    }
+
+    #[test]
+    fn test_verify_mfa_with_recovery_code_only_once() {
+        let mut player = create_test_player();
+        enable_test_mfa(&mut player);
+        let now = Utc::now();
+
+        assert!(player.verify_mfa("ABCDE-23456", now).is_ok());
+        // 使用済みのリカバリーコードは再利用できない
+        assert_eq!(
+            player.verify_mfa("ABCDE-23456", now).unwrap_err(),
+            "ワンタイムパスワードまたはリカバリーコードが正しくありません"
+        );
+    }
+
+    #[test]
+    fn test_verify_mfa_rejects_replayed_totp() {
+        let mut player = create_test_player();
+        enable_test_mfa(&mut player);
+        let now = Utc::now();
+        let code = test_secret().code_at(now);
+
+        assert!(player.verify_mfa(&code, now).is_ok());
+        // 受け付けたワンタイムパスワードは、有効期間内でも再利用できない
+        assert_eq!(
+            player.verify_mfa(&code, now).unwrap_err(),
+            "ワンタイムパスワードまたはリカバリーコードが正しくありません"
+        );
+    }
+
+    #[test]
+    fn test_enable_mfa_code_cannot_be_replayed() {
+        let mut player = create_test_player();
+        let now = Utc::now();
+        let code = test_secret().code_at(now);
+        player.start_mfa_enrollment(test_secret()).unwrap();
+        player.enable_mfa(&code, now, Vec::new()).unwrap();
+
+        // 有効化の確認に使用したワンタイムパスワードではログインできない
+        assert!(player.verify_mfa(&code, now).is_err());
+    }
+
+    #[test]
+    fn test_verify_mfa_locks_after_consecutive_failures() {
+        let mut player = create_test_player();
+        enable_test_mfa(&mut player);
+        let now = Utc::now();
+
+        for _ in 0..5 {
+            assert!(player.verify_mfa("000000", now).is_err());
+        }
+        assert!(player.mfa_attempts().is_locked(now));
+
+        // ロック中は正しいワンタイムパスワードでも拒否する
+        assert_eq!(
+            player
+                .verify_mfa(&test_secret().code_at(now), now)
+                .unwrap_err(),
+            "MFA認証の失敗回数が上限に達したため、しばらく時間をおいてから再度お試しください"
+        );
+        // リカバリーコードも拒否し、消費しない
+        assert!(player.verify_mfa("ABCDE-23456", now).is_err());
+        assert_eq!(player.mfa_authentication().recovery_codes().len(), 1);
+
+        // ロックが解除されると認証でき、失敗回数もリセットされる
+        let later = now + Duration::minutes(15);
+        assert!(player
+            .verify_mfa(&test_secret().code_at(later), later)
+            .is_ok());
+        assert_eq!(player.mfa_attempts(), &MfaAttempts::initial());
+    }
+
+    #[test]
+    fn test_verify_mfa_success_resets_failed_count() {
+        let mut player = create_test_player();
+        enable_test_mfa(&mut player);
+        let now = Utc::now();
+
+        for _ in 0..4 {
+            assert!(player.verify_mfa("000000", now).is_err());
+        }
+        assert_eq!(player.mfa_attempts().failed_count(), 4);
+        assert!(player.verify_mfa("ABCDE-23456", now).is_ok());
+        assert_eq!(player.mfa_attempts().failed_count(), 0);
+    }
+
+    #[test]
+    fn test_enable_mfa_locks_after_consecutive_failures() {
+        let mut player = create_test_player();
+        let now = Utc::now();
+        player.start_mfa_enrollment(test_secret()).unwrap();
+
+        for _ in 0..5 {
+            assert!(player.enable_mfa("000000", now, Vec::new()).is_err());
+        }
+
+        assert!(player
+            .enable_mfa(&test_secret().code_at(now), now, Vec::new())
+            .is_err());
+        assert!(!player.is_mfa_enabled());
+    }
+
+    #[test]
+    fn test_verify_mfa_when_disabled() {
+        let mut player = create_test_player();
+        let now = Utc::now();
+
+        assert_eq!(
+            player.verify_mfa(&test_secret().code_at(now), now).unwrap_err(),
+            "MFA認証が有効ではありません"
+        );
+    }
+
+    #[test]
+    fn test_disable_mfa_with_wrong_code() {
+        let mut player = create_test_player();
+        enable_test_mfa(&mut player);
+
+        let result = player.disable_mfa("ZZZZZ-22222", Utc::now());
+
+        assert!(result.is_err());
         assert!(player.is_mfa_enabled());
     }
 
@@ -95,18 +294,21 @@ mod player_tests {
         let player_id = PlayerId::new(Uuid::new_v4().to_string());
         let player_name = PlayerName::new("再構築プレイヤー".to_string());
         let registered_datetime = RegisteredDatetime::new(Utc::now());
-        let mfa_authentication = MFAAuthentication::new(true);
+        let mfa_authentication = MFAAuthentication::enabled(test_secret(), Vec::new());
 
         let player = Player::reconstruct(
             player_id.clone(),
             player_name,
             registered_datetime,
             mfa_authentication,
+            MfaAttempts::initial(),
+            Rating::new(1620),
         );
 
         assert_eq!(player.player_id().value(), player_id.value());
         assert_eq!(player.player_name().value(), "再構築プレイヤー");
         assert!(player.is_mfa_enabled());
+        assert_eq!(player.rating().value(), 1620);
     }
 
     #[test]
@@ -115,13 +317,15 @@ mod player_tests {
         let player_name1 = PlayerName::new("プレイヤー1".to_string());
         let player_name2 = PlayerName::new("プレイヤー2".to_string());
         let registered_datetime = RegisteredDatetime::new(Utc::now());
-        let mfa_authentication = MFAAuthentication::new(false);
+        let mfa_authentication = MFAAuthentication::disabled();
 
         let player1 = Player::reconstruct(
             player_id.clone(),
             player_name1,
             registered_datetime.clone(),
             mfa_authentication.clone(),
+            MfaAttempts::initial(),
+            Rating::initial(),
         );
 
         let player2 = Player::reconstruct(
@@ -129,6 +333,8 @@ mod player_tests {
             player_name2,
             registered_datetime,
             mfa_authentication,
+            MfaAttempts::initial(),
+            Rating::initial(),
         );
 
         // 同じPlayerIdを持つため、等価とみなされる
@@ -141,13 +347,15 @@ mod player_tests {
         let player_id2 = PlayerId::new(Uuid::new_v4().to_string());
         let player_name = PlayerName::new("プレイヤー".to_string());
         let registered_datetime = RegisteredDatetime::new(Utc::now());
-        let mfa_authentication = MFAAuthentication::new(false);
+        let mfa_authentication = MFAAuthentication::disabled();
 
         let player1 = Player::reconstruct(
             player_id1,
             player_name.clone(),
             registered_datetime.clone(),
             mfa_authentication.clone(),
+            MfaAttempts::initial(),
+            Rating::initial(),
         );
 
         let player2 = Player::reconstruct(
@@ -155,12 +363,36 @@ mod player_tests {
             player_name,
             registered_datetime,
             mfa_authentication,
+            MfaAttempts::initial(),
+            Rating::initial(),
         );
 
         // 異なるPlayerIdを持つため、等価ではない
         assert_ne!(player1, player2);
     }
 
+    #[test]
+    fn test_create_player_with_initial_rating() {
+        let player = create_test_player();
+
+        assert_eq!(player.rating(), &Rating::initial());
+    }
+
+    #[test]
+    fn test_apply_game_result() {
+        let mut winner = create_test_player();
+        let mut loser = create_test_player();
+        let winner_rating = winner.rating().clone();
+        let loser_rating = loser.rating().clone();
+
+        // 対戦時点のレーティングを使ってそれぞれ反映する
+        winner.apply_game_result(&loser_rating, 1.0);
+        loser.apply_game_result(&winner_rating, 0.0);
+
+        assert_eq!(winner.rating().value(), 1516);
+        assert_eq!(loser.rating().value(), 1484);
+    }
+
     #[test]
     fn test_registered_datetime_immutability() {
         let player = create_test_player();
@@ -170,7 +402,7 @@ mod player_tests {
         // 名前変更やMFA変更を行っても、登録日時は変わらない
         let mut mutable_player = player.clone();
         mutable_player.change_name(PlayerName::new("新しい名前".to_string()));
-        mutable_player.enable_mfa();
+        enable_test_mfa(&mut mutable_player);
 
         assert_eq!(
             mutable_player.registered_datetime().value(),
@@ -187,7 +419,7 @@ mod player_tests {
         // 名前変更やMFA変更を行っても、PlayerIdは変わらない
         let mut mutable_player = player.clone();
         mutable_player.change_name(PlayerName::new("新しい名前".to_string()));
-        mutable_player.enable_mfa();
+        enable_test_mfa(&mut mutable_player);
 
         assert_eq!(mutable_player.player_id().value(), initial_id);
     }
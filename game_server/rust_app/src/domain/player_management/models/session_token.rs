@@ -0,0 +1,2 @@
+pub mod session_token;
+pub mod session_token_test;
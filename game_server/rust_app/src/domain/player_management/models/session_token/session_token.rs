@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+
+/// プレイヤーの認証に使用するセッショントークン
+/// 登録・ログイン時に発行し、以降のリクエストでプレイヤー本人であることの確認に使用する
+#[derive(Debug, Clone)]
+pub struct SessionToken {
+    value: String,
+    expires_at: DateTime<Utc>,
+}
+
+impl SessionToken {
+    pub fn new(value: String, expires_at: DateTime<Utc>) -> Self {
+        Self::validate(&value);
+        Self { value, expires_at }
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn expires_at(&self) -> &DateTime<Utc> {
+        &self.expires_at
+    }
+
+    /// 有効期限が切れているかどうか
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+
+    // バリデーションの実装
+    fn validate(value: &str) {
+        if value.is_empty() {
+            panic!("SessionTokenが空文字です");
+        }
+    }
+}
+
+impl PartialEq for SessionToken {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for SessionToken {}
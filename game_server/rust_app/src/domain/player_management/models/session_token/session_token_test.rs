@@ -0,0 +1,20 @@
+#[cfg(test)]
+mod tests {
+    use super::super::session_token::SessionToken;
+    use chrono::{Duration, Utc};
+
+    #[test]
+    fn test_is_expired() {
+        let now = Utc::now();
+        let token = SessionToken::new("token".to_string(), now + Duration::hours(1));
+
+        assert!(!token.is_expired(now));
+        assert!(token.is_expired(now + Duration::hours(1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "SessionTokenが空文字です")]
+    fn test_empty_token_panic() {
+        SessionToken::new("".to_string(), Utc::now());
+    }
+}
@@ -1,2 +1,3 @@
 pub mod connection_repository;
+pub mod outbox_repository;
 pub mod player_repository;
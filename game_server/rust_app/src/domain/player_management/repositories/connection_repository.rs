@@ -8,4 +8,16 @@ pub trait ConnectionRepository: Send + Sync {
 
     /// コネクション情報を取得
     async fn get_connection_id(&self, player_id: &str) -> Result<String, String>;
+
+    /// コネクション情報を取得
+    /// 接続中のコネクションが存在しない（オフライン）場合はNoneを返す
+    async fn find_connection_id(&self, player_id: &str) -> Result<Option<String>, String>;
+
+    /// コネクションIDからプレイヤーIDを逆引き
+    /// 切断時など、コネクションIDしか分からない場合に使用
+    async fn find_player_id(&self, connection_id: &str) -> Result<Option<String>, String>;
+
+    /// コネクション情報を削除
+    /// 既に別のコネクションで再接続している場合は、新しいコネクション情報を残す
+    async fn delete(&self, player_id: &str, connection_id: &str) -> Result<(), String>;
 }
@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+
+use crate::domain::player_management::models::{
+    outbox_message::{
+        message_sequence::message_sequence::MessageSequence, outbox_message::OutboxMessage,
+    },
+    player::player_id::player_id::PlayerId,
+};
+
+/// Outboxリポジトリのトレイト
+/// プレイヤーへ届けられなかった通知を再接続時まで保持する
+#[async_trait]
+pub trait OutboxRepository: Send + Sync {
+    /// 指定したプレイヤーへの通知の通し番号を採番
+    /// 送信できたかどうかに関わらず、通知ごとに1つずつ進む
+    async fn next_sequence(&self, player_id: &PlayerId) -> Result<MessageSequence, String>;
+
+    /// 届けられなかった通知を保存
+    async fn save(&self, message: &OutboxMessage) -> Result<(), String>;
+
+    /// 指定したプレイヤーの未送信の通知を通し番号順に取得
+    async fn get_messages(&self, player_id: &PlayerId) -> Result<Vec<OutboxMessage>, String>;
+
+    /// 送信済みの通知を削除
+    async fn delete(&self, message: &OutboxMessage) -> Result<(), String>;
+}
@@ -1,4 +1,4 @@
-use crate::domain::player_management::models::player::Player;
+use crate::domain::player_management::models::player::{player_id::player_id::PlayerId, Player};
 use async_trait::async_trait;
 
 /// Playerリポジトリのトレイト
@@ -6,4 +6,10 @@ use async_trait::async_trait;
 pub trait PlayerRepository: Send + Sync {
     /// プレイヤー情報を保存
     async fn save(&self, player: &Player) -> Result<(), String>;
+    /// レーティングのみを更新
+    /// 同時に行われた名前の変更やMFAの設定変更を上書きしないよう、他の属性は更新しない
+    async fn update_rating(&self, player: &Player) -> Result<(), String>;
+
+    /// プレイヤーIDからプレイヤー情報を取得
+    async fn find_by_id(&self, player_id: &PlayerId) -> Result<Option<Player>, String>;
 }
@@ -0,0 +1 @@
+pub mod session_token_service;
@@ -0,0 +1,16 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::player_management::models::{
+    player::player_id::player_id::PlayerId, session_token::session_token::SessionToken,
+};
+
+/// セッショントークンの発行と検証を行うサービス
+/// 外部の認証基盤に依存せず、サーバー内で署名・検証できる方式で実装する
+pub trait SessionTokenService: Send + Sync {
+    /// プレイヤーのセッショントークンを発行
+    fn issue(&self, player_id: &PlayerId, now: DateTime<Utc>) -> Result<SessionToken, String>;
+
+    /// セッショントークンを検証し、トークンが示すプレイヤーIDを取得
+    /// 署名が不正な場合や有効期限が切れている場合はErrを返す
+    fn verify(&self, token: &str, now: DateTime<Utc>) -> Result<PlayerId, String>;
+}
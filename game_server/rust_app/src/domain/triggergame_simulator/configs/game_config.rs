@@ -1,6 +1,14 @@
+use std::cell::RefCell;
 use std::collections::HashMap;
 
+thread_local! {
+    /// 現在のスレッドで使用する設定値
+    /// バランス調整用のシミュレーターで設定値を差し替える場合のみ設定する
+    static GAME_CONFIG_OVERRIDE: RefCell<Option<GameConfig>> = const { RefCell::new(None) };
+}
+
 /// ゲーム内の設定値情報
+#[derive(Debug, Clone, PartialEq)]
 pub struct GameConfig {
     /// グリッドセルの半径
     hex_radius: i32,
@@ -20,11 +28,24 @@ pub struct GameConfig {
     defend_weight: f64,
     /// 最小ダメージ量
     min_damage: i32,
+    /// マスターデータ（ユニット・トリガーの性能値と設定値）のバージョン
+    /// 性能値を変更した場合は更新し、過去のリプレイと区別できるようにする
+    master_data_version: &'static str,
+    /// 1ターンあたりの制限時間（秒）
+    turn_time_limit_seconds: i64,
 }
 
 impl GameConfig {
     ///
     pub fn get_game_config() -> GameConfig {
+        match GAME_CONFIG_OVERRIDE.with(|config| config.borrow().clone()) {
+            Some(config) => config,
+            None => Self::default_config(),
+        }
+    }
+
+    /// 既定の設定値
+    fn default_config() -> GameConfig {
         GameConfig {
             hex_radius: 24,
             hex_width: 24 * 2,
@@ -35,6 +56,31 @@ impl GameConfig {
             damage_weight: 1.0,
             defend_weight: 1.0,
             min_damage: 20,
+            master_data_version: "2",
+            turn_time_limit_seconds: 120,
+        }
+    }
+
+    /// 現在のスレッドで使用する設定値を差し替える
+    /// Noneを指定すると既定の設定値に戻す（バランス調整用のシミュレーター専用）
+    pub fn set_thread_override(config: Option<GameConfig>) {
+        GAME_CONFIG_OVERRIDE.with(|current| *current.borrow_mut() = config);
+    }
+
+    /// 戦闘計算の重み付けを変更した設定値を生成
+    pub fn with_combat_weights(
+        self,
+        avoid_weight: i32,
+        damage_weight: f64,
+        defend_weight: f64,
+        min_damage: i32,
+    ) -> GameConfig {
+        GameConfig {
+            avoid_weight,
+            damage_weight,
+            defend_weight,
+            min_damage,
+            ..self
         }
     }
 
@@ -82,4 +128,14 @@ impl GameConfig {
     pub fn min_damage(&self) -> i32 {
         self.min_damage
     }
+
+    /// マスターデータのバージョンを取得
+    pub fn master_data_version(&self) -> &'static str {
+        self.master_data_version
+    }
+
+    /// 1ターンあたりの制限時間（秒）を取得
+    pub fn turn_time_limit_seconds(&self) -> i64 {
+        self.turn_time_limit_seconds
+    }
 }
@@ -14,6 +14,8 @@ pub struct TriggerStatus {
     avoid: i32,
     /// 防御力
     defense: i32,
+    /// 命中時に防御側へ付与する状態効果
+    status_effect_id: Option<String>,
 }
 
 impl TriggerStatus {
@@ -29,6 +31,7 @@ impl TriggerStatus {
                     attack: 8,
                     avoid: 5,
                     defense: 0,
+                    status_effect_id: None,
                 },
             ),
             (
@@ -40,6 +43,7 @@ impl TriggerStatus {
                     attack: 6,
                     avoid: 3,
                     defense: 10,
+                    status_effect_id: None,
                 },
             ),
             (
@@ -51,6 +55,7 @@ impl TriggerStatus {
                     attack: 8,
                     avoid: 10,
                     defense: 0,
+                    status_effect_id: None,
                 },
             ),
             (
@@ -62,6 +67,7 @@ impl TriggerStatus {
                     attack: 4,
                     avoid: 10,
                     defense: 0,
+                    status_effect_id: None,
                 },
             ),
             (
@@ -73,6 +79,19 @@ impl TriggerStatus {
                     attack: 10,
                     avoid: 3,
                     defense: 0,
+                    status_effect_id: None,
+                },
+            ),
+            (
+                "LEAD_BULLET",
+                TriggerStatus {
+                    trigger_id: "LEAD_BULLET".to_string(),
+                    angle: 60,
+                    range: 4,
+                    attack: 2,
+                    avoid: 3,
+                    defense: 0,
+                    status_effect_id: Some("LEAD_WEIGHT".to_string()),
                 },
             ),
             (
@@ -84,6 +103,7 @@ impl TriggerStatus {
                     attack: 0,
                     avoid: 10,
                     defense: 5,
+                    status_effect_id: None,
                 },
             ),
             (
@@ -95,6 +115,7 @@ impl TriggerStatus {
                     attack: 0,
                     avoid: 10,
                     defense: 0,
+                    status_effect_id: None,
                 },
             ),
         ]);
@@ -128,6 +149,10 @@ impl TriggerStatus {
     pub fn avoid(&self) -> i32 {
         self.avoid
     }
+
+    pub fn status_effect_id(&self) -> Option<&str> {
+        self.status_effect_id.as_deref()
+    }
 }
 
 impl Clone for TriggerStatus {
@@ -139,6 +164,7 @@ impl Clone for TriggerStatus {
             attack: self.attack,
             avoid: self.avoid,
             defense: self.defense,
+            status_effect_id: self.status_effect_id.clone(),
         }
     }
 }
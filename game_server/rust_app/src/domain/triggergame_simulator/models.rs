@@ -1,5 +1,7 @@
 pub mod action;
 pub mod combat;
 pub mod game;
+pub mod game_event;
+pub mod replay;
 pub mod step;
 pub mod turn;
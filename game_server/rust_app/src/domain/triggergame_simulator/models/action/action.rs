@@ -1,5 +1,7 @@
 use crate::domain::player_management::models::player::player_id::player_id::PlayerId;
-use crate::domain::triggergame_simulator::models::combat::Combat;
+use crate::domain::triggergame_simulator::models::combat::{
+    Combat, CombatAttacker, CombatDefender, CombatPreview,
+};
 use crate::domain::unit_management::models::unit;
 use crate::domain::unit_management::models::unit::unit_id::unit_id::UnitId;
 use crate::domain::unit_management::models::unit::unit_type_id::unit_type_id::UnitTypeId;
@@ -11,6 +13,7 @@ use crate::domain::unit_management::models::unit_type::unit_type_spec::UnitTypeS
 use super::action_id::action_id::ActionId;
 use super::action_type::action_type::{ActionType, ActionTypeValue};
 use super::trigger_azimuth::trigger_azimuth::TriggerAzimuth;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -108,48 +111,84 @@ impl Action {
 
     /// ユニット情報をもとに発生したcombatを返す
     /// ただし、combatが発生しなかった場合はNoneを返す
-    /// action_player_id: actionを実行したプレイヤーID(攻撃側)
-    /// unit: 防御側ユニット情報
-    pub fn generate_combats(&self, defence_unit: &mut Unit) -> Option<Combat> {
-        // ユニットのステータス取得
-        let unit_status = UnitTypeSpec::get_spec(&self.unit_type_id.value()).unwrap();
+    /// 撃墜されたユニットのベイルアウトは、ステップ内の全てのcombatを生成した後にStepで反映する
+    /// attack_unit: アクション主のユニット情報（状態効果の補正に使用）
+    /// defence_unit: 防御側ユニット情報
+    /// rng: 回避判定に使用する乱数生成器
+    pub fn generate_combats(
+        &self,
+        attack_unit: &Unit,
+        defence_unit: &Unit,
+        rng: &mut impl Rng,
+    ) -> Option<Combat> {
         // アクションタイプに応じてcombatを生成
         if self.is_attack() {
             // action主を攻撃者、引数の防御側ユニットを防御者とするcombatを生成
-            let combat = Combat::create(
-                self.unit_id.clone(),
-                self.position.clone(),
-                self.using_main_trigger_id.clone(),
-                self.using_sub_trigger_id.clone(),
-                self.main_trigger_azimuth.clone(),
-                self.sub_trigger_azimuth.clone(),
-                unit_status.base_attack(),
-                defence_unit.unit_id().clone(),
-                defence_unit.position().clone(),
-                defence_unit.using_main_trigger_id().clone(),
-                defence_unit.using_sub_trigger_id().clone(),
-                defence_unit.main_trigger_hp().value(),
-                defence_unit.sub_trigger_hp().value(),
-                defence_unit.main_trigger_azimuth().clone(),
-                defence_unit.sub_trigger_azimuth().clone(),
-                unit_status.base_defense(),
-                unit_status.base_avoid(),
-            );
-
-            if combat.is_some() {
-                // combatでis_defeatedがtrueのときはunitも更新する
-                let combat_unwrapped = combat.as_ref().unwrap();
-                if combat_unwrapped.is_defeated() {
-                    defence_unit.bailout();
-                }
-            }
-            combat
+            let (attacker, defender) = self.combatants(attack_unit, defence_unit)?;
+            Combat::create(&attacker, &defender, rng)
         } else {
             // 攻撃アクションでない場合、Noneを返す
             None
         }
     }
 
+    /// 防御側ユニットとの戦闘予測を返す
+    /// generate_combatsと同じステータスで計算し、combatが発生しない場合はNoneを返す
+    pub fn preview_combat(&self, attack_unit: &Unit, defence_unit: &Unit) -> Option<CombatPreview> {
+        if !self.is_attack() {
+            return None;
+        }
+        let (attacker, defender) = self.combatants(attack_unit, defence_unit)?;
+        CombatPreview::calculate(&attacker, &defender)
+    }
+
+    /// アクション主を攻撃側、引数の防御側ユニットを防御側とする戦闘の情報を作成
+    /// ユニットタイプのステータスが存在しない場合はNoneを返す
+    fn combatants(
+        &self,
+        attack_unit: &Unit,
+        defence_unit: &Unit,
+    ) -> Option<(CombatAttacker, CombatDefender)> {
+        // 攻撃側・防御側それぞれのユニットのステータス取得
+        let (attacker_status, defender_status) = Self::combat_statuses(attack_unit, defence_unit)?;
+        let attacker = CombatAttacker::new(
+            self.unit_id.clone(),
+            self.position.clone(),
+            self.using_main_trigger_id.clone(),
+            self.using_sub_trigger_id.clone(),
+            self.main_trigger_azimuth.clone(),
+            self.sub_trigger_azimuth.clone(),
+            attacker_status.base_attack(),
+        );
+        let defender = CombatDefender::new(
+            defence_unit.unit_id().clone(),
+            defence_unit.position().clone(),
+            defence_unit.using_main_trigger_id().clone(),
+            defence_unit.using_sub_trigger_id().clone(),
+            defence_unit.main_trigger_azimuth().clone(),
+            defence_unit.sub_trigger_azimuth().clone(),
+            defence_unit.main_trigger_hp().value(),
+            defence_unit.sub_trigger_hp().value(),
+            defender_status.base_defense(),
+            defender_status.base_avoid(),
+        );
+        Some((attacker, defender))
+    }
+
+    /// 攻撃側・防御側それぞれのユニットタイプのステータスに状態効果の補正を反映して取得
+    /// アクションのユニットタイプはクライアントから指定されるため使用せず、ユニット自身のユニットタイプを使用する
+    /// いずれかのユニットタイプのステータスが存在しない場合はNoneを返す
+    fn combat_statuses(
+        attack_unit: &Unit,
+        defence_unit: &Unit,
+    ) -> Option<(UnitTypeSpec, UnitTypeSpec)> {
+        let attacker_status = UnitTypeSpec::get_spec(attack_unit.unit_type_id().value())?
+            .with_modifier(&attack_unit.status_modifier());
+        let defender_status = UnitTypeSpec::get_spec(defence_unit.unit_type_id().value())?
+            .with_modifier(&defence_unit.status_modifier());
+        Some((attacker_status, defender_status))
+    }
+
     /// 攻撃を行うアクションかどうか
     fn is_attack(&self) -> bool {
         // 仮の実装、まだ特殊なアクションはないからね
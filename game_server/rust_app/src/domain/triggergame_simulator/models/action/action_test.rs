@@ -1,2 +1,228 @@
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::super::action::Action;
+    use super::super::action_type::action_type::{ActionType, ActionTypeValue};
+    use super::super::trigger_azimuth::trigger_azimuth::TriggerAzimuth;
+    use crate::domain::player_management::models::player::player_id::player_id::PlayerId;
+    use crate::domain::triggergame_simulator::configs::game_config::GameConfig;
+    use crate::domain::triggergame_simulator::models::game::game_id::game_id::GameId;
+    use crate::domain::unit_management::models::unit::{
+        having_trigger_ids::having_trigger_ids::HavingTriggerIds, position::position::Position,
+        trigger_id::trigger_id::TriggerId, unit_type_id::unit_type_id::UnitTypeId, Unit,
+    };
+    use crate::domain::unit_management::models::unit_type::unit_type_spec::UnitTypeSpec;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use uuid::Uuid;
+
+    /// 全ユニットタイプの組み合わせの戦闘結果（手計算）
+    /// (攻撃側, 防御側, 攻撃1回の命中率, 全て命中した場合のメイン・サブトリガーそれぞれへのダメージ)
+    ///
+    /// 攻撃側はKOGETSU（攻撃8）とRAYGUST（攻撃6）の2回攻撃、防御側はKOGETSU（防御0・回避5）と
+    /// RAYGUST（防御10・回避3）で両防御し、トリガーHPはともに100
+    /// - 命中率: 1 - 防御側の基本回避 × (5 + 3) / 100
+    /// - 1回目: max(攻撃側の基本攻撃 × 8 - 防御側の基本防御 × (0 + 10), 20) をHPの比率で等分
+    /// - 2回目: max(攻撃側の基本攻撃 × 6 - 防御側の基本防御 × (0 + 10), 20) をHPの比率で等分
+    ///
+    /// 基本攻撃/防御/回避: MIKUMO_OSAMU 3/4/4, KUGA_YUMA 9/8/10, AMATORI_CHIKA 2/4/3, HYUSE_KURONIN 8/9/7
+    const PAIRINGS: [(&str, &str, f64, i32); 16] = [
+        // 3×8-40, 3×6-40 はいずれも最低ダメージ20 → 10 + 10
+        ("MIKUMO_OSAMU", "MIKUMO_OSAMU", 0.68, 20),
+        ("MIKUMO_OSAMU", "KUGA_YUMA", 0.2, 20),
+        ("MIKUMO_OSAMU", "AMATORI_CHIKA", 0.76, 20),
+        ("MIKUMO_OSAMU", "HYUSE_KURONIN", 0.44, 20),
+        // 9×8-40=32 → 16、9×6-40=14 は最低ダメージ20 → 10
+        ("KUGA_YUMA", "MIKUMO_OSAMU", 0.68, 26),
+        // 9×8-80, 9×6-80 はいずれも最低ダメージ20 → 10 + 10
+        ("KUGA_YUMA", "KUGA_YUMA", 0.2, 20),
+        ("KUGA_YUMA", "AMATORI_CHIKA", 0.76, 26),
+        ("KUGA_YUMA", "HYUSE_KURONIN", 0.44, 20),
+        // 2×8-40, 2×6-40 はいずれも最低ダメージ20 → 10 + 10
+        ("AMATORI_CHIKA", "MIKUMO_OSAMU", 0.68, 20),
+        ("AMATORI_CHIKA", "KUGA_YUMA", 0.2, 20),
+        ("AMATORI_CHIKA", "AMATORI_CHIKA", 0.76, 20),
+        ("AMATORI_CHIKA", "HYUSE_KURONIN", 0.44, 20),
+        // 8×8-40=24 → 12、8×6-40=8 は最低ダメージ20 → 10
+        ("HYUSE_KURONIN", "MIKUMO_OSAMU", 0.68, 22),
+        // 8×8-80, 8×6-80 はいずれも最低ダメージ20 → 10 + 10
+        ("HYUSE_KURONIN", "KUGA_YUMA", 0.2, 20),
+        ("HYUSE_KURONIN", "AMATORI_CHIKA", 0.76, 22),
+        ("HYUSE_KURONIN", "HYUSE_KURONIN", 0.44, 20),
+    ];
+
+    fn create_unit(unit_type_id: &str, owner_player_id: &PlayerId, position: Position) -> Unit {
+        let mut unit = Unit::create(
+            UnitTypeId::new(unit_type_id.to_string()),
+            GameId::new(Uuid::new_v4().to_string()),
+            owner_player_id.clone(),
+            position,
+            TriggerId::new("KOGETSU".to_string()),
+            TriggerId::new("RAYGUST".to_string()),
+            HavingTriggerIds::new(vec![TriggerId::new("KOGETSU".to_string())]),
+            HavingTriggerIds::new(vec![TriggerId::new("RAYGUST".to_string())]),
+            100,
+            100,
+            8,
+            13,
+        );
+        unit.set_main_trigger_azimuth(TriggerAzimuth::new(90));
+        unit.set_sub_trigger_azimuth(TriggerAzimuth::new(90));
+        unit
+    }
+
+    /// 互いの座標系で同じセルに位置し、正面を向き合う攻撃側のアクションと攻撃側・防御側のユニット
+    fn create_pairing(attacker_type: &str, defender_type: &str) -> (Action, Unit, Unit) {
+        let attacker = create_unit(
+            attacker_type,
+            &PlayerId::new(Uuid::new_v4().to_string()),
+            Position::new(17, 17),
+        );
+        let defender = create_unit(
+            defender_type,
+            &PlayerId::new(Uuid::new_v4().to_string()),
+            Position::new(18, 18),
+        );
+        let action = Action::create(
+            ActionType::new(ActionTypeValue::Wait),
+            attacker.unit_id().clone(),
+            attacker.unit_type_id().clone(),
+            attacker.position().clone(),
+            attacker.using_main_trigger_id().clone(),
+            attacker.using_sub_trigger_id().clone(),
+            attacker.main_trigger_azimuth().clone(),
+            attacker.sub_trigger_azimuth().clone(),
+        );
+        (action, attacker, defender)
+    }
+
+    /// 回避率の重みを変更した設定でテストを実行
+    fn with_avoid_weight<T>(avoid_weight: i32, f: impl FnOnce() -> T) -> T {
+        let config = GameConfig::get_game_config();
+        let damage_weight = config.damage_weight();
+        let defend_weight = config.defend_weight();
+        let min_damage = config.min_damage();
+        GameConfig::set_thread_override(Some(config.with_combat_weights(
+            avoid_weight,
+            damage_weight,
+            defend_weight,
+            min_damage,
+        )));
+        let result = f();
+        GameConfig::set_thread_override(None);
+        result
+    }
+
+    #[test]
+    fn test_preview_combat_for_every_pairing() {
+        for (attacker_type, defender_type, hit_chance, damage) in PAIRINGS {
+            let (action, attacker, defender) = create_pairing(attacker_type, defender_type);
+            let preview =
+                with_avoid_weight(100, || action.preview_combat(&attacker, &defender)).unwrap();
+            assert!(
+                (preview.hit_chance() - hit_chance).abs() < 1e-9,
+                "{} -> {}: hit_chance={}",
+                attacker_type,
+                defender_type,
+                preview.hit_chance()
+            );
+            assert_eq!(
+                (preview.main_trigger_damage(), preview.sub_trigger_damage()),
+                (damage, damage),
+                "{} -> {}",
+                attacker_type,
+                defender_type
+            );
+        }
+    }
+
+    #[test]
+    fn test_generate_combats_uses_each_side_spec() {
+        for (attacker_type, defender_type, _, damage) in PAIRINGS {
+            let (action, attacker, defender) = create_pairing(attacker_type, defender_type);
+            // 回避率がほぼ0になる設定で、全ての攻撃を命中させる
+            let combat = with_avoid_weight(i32::MAX, || {
+                action.generate_combats(&attacker, &defender, &mut StdRng::seed_from_u64(0))
+            })
+            .unwrap();
+            let attacker_spec = UnitTypeSpec::get_spec(attacker_type).unwrap();
+            let defender_spec = UnitTypeSpec::get_spec(defender_type).unwrap();
+            assert_eq!(combat.attacker_base_attack(), attacker_spec.base_attack());
+            assert_eq!(combat.defender_base_defense(), defender_spec.base_defense());
+            assert_eq!(combat.defender_base_avoid(), defender_spec.base_avoid());
+            assert!(combat.hits().iter().all(|hit| hit.is_avoided().is_hit()));
+            assert_eq!(
+                (combat.main_trigger_damage(), combat.sub_trigger_damage()),
+                (damage, damage),
+                "{} -> {}",
+                attacker_type,
+                defender_type
+            );
+            assert!(!combat.is_defeated());
+        }
+    }
+
+    #[test]
+    fn test_combat_ignores_action_unit_type() {
+        let (_, attacker, defender) = create_pairing("MIKUMO_OSAMU", "KUGA_YUMA");
+        // アクションのユニットタイプを基本攻撃力の高いKUGA_YUMAと偽っても、ユニット自身のステータスを使用する
+        let action = Action::create(
+            ActionType::new(ActionTypeValue::Wait),
+            attacker.unit_id().clone(),
+            UnitTypeId::new("KUGA_YUMA".to_string()),
+            attacker.position().clone(),
+            attacker.using_main_trigger_id().clone(),
+            attacker.using_sub_trigger_id().clone(),
+            attacker.main_trigger_azimuth().clone(),
+            attacker.sub_trigger_azimuth().clone(),
+        );
+
+        let combat = with_avoid_weight(i32::MAX, || {
+            action.generate_combats(&attacker, &defender, &mut StdRng::seed_from_u64(0))
+        })
+        .unwrap();
+        assert_eq!(combat.attacker_base_attack(), 3);
+        let preview =
+            with_avoid_weight(100, || action.preview_combat(&attacker, &defender)).unwrap();
+        assert_eq!(
+            (preview.main_trigger_damage(), preview.sub_trigger_damage()),
+            (20, 20)
+        );
+    }
+
+    #[test]
+    fn test_combat_with_unknown_unit_type() {
+        let (action, attacker, _) = create_pairing("KUGA_YUMA", "KUGA_YUMA");
+        let defender = create_unit(
+            "UNKNOWN_UNIT",
+            &PlayerId::new(Uuid::new_v4().to_string()),
+            Position::new(18, 18),
+        );
+
+        // ステータスが存在しないユニットタイプの場合は戦闘を生成しない
+        assert!(action
+            .generate_combats(&attacker, &defender, &mut StdRng::seed_from_u64(0))
+            .is_none());
+        assert!(action.preview_combat(&attacker, &defender).is_none());
+    }
+
+    #[test]
+    fn test_combat_reflects_status_effects() {
+        let (action, mut attacker, mut defender) = create_pairing("KUGA_YUMA", "MIKUMO_OSAMU");
+        // 攻撃側は攻撃力+2、防御側は回避力-3
+        attacker.apply_status_effect("BOOST_SEAL").unwrap();
+        defender.apply_status_effect("LEAD_WEIGHT").unwrap();
+
+        let combat = with_avoid_weight(i32::MAX, || {
+            action.generate_combats(&attacker, &defender, &mut StdRng::seed_from_u64(0))
+        })
+        .unwrap();
+        assert_eq!(combat.attacker_base_attack(), 9 + 2);
+        assert_eq!(combat.defender_base_defense(), 4);
+        assert_eq!(combat.defender_base_avoid(), 4 - 3);
+
+        // 命中率: 1 - 1 × (5 + 3) / 100
+        let preview =
+            with_avoid_weight(100, || action.preview_combat(&attacker, &defender)).unwrap();
+        assert!((preview.hit_chance() - 0.92).abs() < 1e-9);
+    }
+}
@@ -1,10 +1,17 @@
 pub mod combat_id;
+pub mod combat_hit;
 pub mod attacking_unit_id;
 pub mod defending_unit_id;
 pub mod is_avoided;
 
 mod combat;
 pub use combat::Combat;
+mod combat_preview;
+pub use combat_preview::CombatPreview;
+mod combatant;
+pub use combatant::{CombatAttacker, CombatDefender};
 
 #[cfg(test)]
 mod combat_test;
+#[cfg(test)]
+mod combat_preview_test;
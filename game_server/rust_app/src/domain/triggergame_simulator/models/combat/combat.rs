@@ -6,8 +6,11 @@ use crate::domain::unit_management::models::unit::position::position::Position;
 use crate::domain::unit_management::models::unit::trigger_id::trigger_id::TriggerId;
 use crate::domain::unit_management::models::unit::unit_id::unit_id::UnitId;
 
+use super::combat_hit::combat_hit::CombatHit;
 use super::combat_id::combat_id::CombatId;
+use super::combatant::{CombatAttacker, CombatDefender};
 use super::is_avoided::is_avoided::IsAvoided;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -32,14 +35,19 @@ pub struct Combat {
     defender_sub_trigger_azimuth: TriggerAzimuth,
     main_trigger_hp: i32,
     sub_trigger_hp: i32,
+    main_trigger_damage: i32,
+    sub_trigger_damage: i32,
     defender_base_defense: i32,
     defender_base_avoid: i32,
     is_avoided: IsAvoided,
     is_defeated: bool,
+    /// 攻撃側のトリガーごとの攻撃結果（メイン・サブの順）
+    hits: Vec<CombatHit>,
 }
 
 impl Combat {
     // privateなコンストラクタ
+    #[allow(clippy::too_many_arguments)]
     fn new(
         combat_id: CombatId,
         attacking_unit_id: UnitId,
@@ -59,8 +67,11 @@ impl Combat {
         defender_base_avoid: i32,
         main_trigger_hp: i32,
         sub_trigger_hp: i32,
+        main_trigger_damage: i32,
+        sub_trigger_damage: i32,
         is_avoided: IsAvoided,
         is_defeated: bool,
+        hits: Vec<CombatHit>,
     ) -> Self {
         Self {
             combat_id,
@@ -79,71 +90,47 @@ impl Combat {
             defender_sub_trigger_azimuth,
             main_trigger_hp,
             sub_trigger_hp,
+            main_trigger_damage,
+            sub_trigger_damage,
             defender_base_defense,
             defender_base_avoid,
             is_avoided,
             is_defeated,
+            hits,
         }
     }
 
     /// 新規戦闘の生成
+    /// 回避判定の乱数はrngから取得する（リプレイで同じ結果を再現するため）
     pub fn create(
-        attacking_unit_id: UnitId,
-        attacker_position: Position,
-        attacker_main_trigger_id: TriggerId,
-        attacker_sub_trigger_id: TriggerId,
-        attacker_main_trigger_azimuth: TriggerAzimuth,
-        attacker_sub_trigger_azimuth: TriggerAzimuth,
-        attacker_base_attack: i32,
-        defending_unit_id: UnitId,
-        defender_position: Position,
-        defender_main_trigger_id: TriggerId,
-        defender_sub_trigger_id: TriggerId,
-        main_trigger_hp: i32,
-        sub_trigger_hp: i32,
-        defender_main_trigger_azimuth: TriggerAzimuth,
-        defender_sub_trigger_azimuth: TriggerAzimuth,
-        defender_base_defense: i32,
-        defender_base_avoid: i32,
+        attacker: &CombatAttacker,
+        defender: &CombatDefender,
+        rng: &mut impl Rng,
     ) -> Option<Self> {
-        // 攻撃側のメイントリガーが防御側に当たる可能性があるか確認
-        let is_main_trigger_hit = Self::check_trigger_in_range_and_angle(
-            &attacker_position,
-            &attacker_main_trigger_id,
-            &attacker_main_trigger_azimuth,
-            &defender_position,
-        );
-        // 攻撃側のサブトリガーが防御側に当たる可能性があるか確認
-        let is_sub_trigger_hit = Self::check_trigger_in_range_and_angle(
-            &attacker_position,
-            &attacker_sub_trigger_id,
-            &attacker_sub_trigger_azimuth,
-            &defender_position,
-        );
-        if !is_main_trigger_hit && !is_sub_trigger_hit {
+        Self::resolve(attacker, defender, |defender_base_avoid, trigger_avoid| {
+            Self::calculate_avoidance(defender_base_avoid, trigger_avoid, rng)
+        })
+    }
+
+    /// 戦闘の解決
+    /// 射程と方向内に防御側がいる攻撃側のトリガーごとに、メイン・サブの順で回避判定とダメージ計算を行う
+    /// avoid: 攻撃1回分の回避判定（引数はcalculate_avoidanceと同じ）
+    pub(super) fn resolve(
+        attacker: &CombatAttacker,
+        defender: &CombatDefender,
+        mut avoid: impl FnMut(i32, i32) -> IsAvoided,
+    ) -> Option<Self> {
+        // 攻撃側のトリガーのうち、防御側に当たる可能性があるものを確認
+        let attacking_trigger_ids = Self::attacking_trigger_ids(attacker, defender);
+        if attacking_trigger_ids.is_empty() {
             // 射程外、角度の範囲外の場合はNoneを返す
             return None;
         }
 
-        let defender_main_trigger_status =
-            TriggerStatus::get_trigger_status(defender_main_trigger_id.value());
-        let defender_sub_trigger_status =
-            TriggerStatus::get_trigger_status(defender_sub_trigger_id.value());
-
-        // 防御側のメイントリガーが攻撃者に向いているか確認
-        let is_defender_facing_attacker_main = Self::check_trigger_in_angle(
-            &defender_position,
-            &attacker_position,
-            defender_main_trigger_azimuth.value(),
-            defender_main_trigger_status.angle(),
-        );
-        // 防御側のサブトリガーが攻撃者に向いているか確認
-        let is_defender_facing_attacker_sub = Self::check_trigger_in_angle(
-            &defender_position,
-            &attacker_position,
-            defender_sub_trigger_azimuth.value(),
-            defender_sub_trigger_status.angle(),
-        );
+        // 防御側のトリガーが攻撃者に向いているか確認
+        let (is_defender_facing_attacker_main, is_defender_facing_attacker_sub) =
+            Self::check_defender_facing(defender, attacker.position());
+        let is_full_guard = is_defender_facing_attacker_main && is_defender_facing_attacker_sub;
 
         let mut is_defeated = false;
         if !is_defender_facing_attacker_main && !is_defender_facing_attacker_sub {
@@ -151,72 +138,117 @@ impl Combat {
             is_defeated = true;
         }
 
-        // 回避計算の実行
-        let trigger_avoid = if is_defender_facing_attacker_main {
-            defender_main_trigger_status.avoid()
-        } else {
-            0
-        } + if is_defender_facing_attacker_sub {
-            defender_sub_trigger_status.avoid()
-        } else {
-            0
-        };
-        let is_avoided = Self::calculate_avoidance(defender_base_avoid, trigger_avoid);
+        // 回避値は防御側のトリガーの向きで決まるため、攻撃ごとに共通
+        let trigger_avoid = Self::calculate_trigger_avoid(
+            defender.main_trigger_id(),
+            defender.sub_trigger_id(),
+            is_defender_facing_attacker_main,
+            is_defender_facing_attacker_sub,
+        );
 
         // メイントリガーの残HP
-        let mut main_trigger_hp = main_trigger_hp;
+        let mut main_trigger_hp = defender.main_trigger_hp();
         // サブトリガーの残HP
-        let mut sub_trigger_hp = sub_trigger_hp;
+        let mut sub_trigger_hp = defender.sub_trigger_hp();
 
-        if !is_avoided.value() {
-            // ダメージ量の計算
-            if is_defender_facing_attacker_main && is_defender_facing_attacker_sub {
-                // 両防御の場合
-                let (main_trigger_damage, sub_trigger_damage) = Self::calculate_full_guard_damage(
-                    attacker_base_attack,
-                    defender_base_defense,
-                    TriggerStatus::get_trigger_status(attacker_main_trigger_id.value()).attack()
-                        + TriggerStatus::get_trigger_status(attacker_sub_trigger_id.value())
-                            .attack(),
-                    defender_main_trigger_status.defense(),
-                    defender_sub_trigger_status.defense(),
+        let mut hits = Vec::new();
+        for trigger_id in attacking_trigger_ids {
+            if is_full_guard && is_defeated {
+                // 両防御で撃墜済みの場合、以降の攻撃は行わない
+                break;
+            }
+
+            // 回避計算の実行
+            let is_avoided = avoid(defender.base_avoid(), trigger_avoid);
+            let (main_trigger_damage, sub_trigger_damage) = if is_avoided.is_hit() {
+                // ダメージ量の計算（攻撃に使用したトリガーの攻撃力のみ）
+                Self::calculate_damage(
+                    is_defender_facing_attacker_main,
+                    is_defender_facing_attacker_sub,
+                    attacker.base_attack(),
+                    TriggerStatus::get_trigger_status(trigger_id.value()).attack(),
+                    defender,
                     main_trigger_hp,
                     sub_trigger_hp,
-                );
-
-                main_trigger_hp -= main_trigger_damage;
-                sub_trigger_hp -= sub_trigger_damage;
-
-                if main_trigger_hp <= 0 || sub_trigger_hp <= 0 {
-                    is_defeated = true;
-                }
-            } else if is_defender_facing_attacker_main && !is_defender_facing_attacker_sub {
-                // 片方防御の場合（メイントリガーのみ防御）
-                let damage = Self::calculate_partial_guard_damage(
-                    attacker_base_attack,
-                    defender_base_defense,
-                    TriggerStatus::get_trigger_status(attacker_main_trigger_id.value()).attack()
-                        + TriggerStatus::get_trigger_status(attacker_sub_trigger_id.value())
-                            .attack(),
-                    defender_main_trigger_status.defense(),
-                );
-                main_trigger_hp -= damage;
-            } else if !is_defender_facing_attacker_main && is_defender_facing_attacker_sub {
-                // 片方防御の場合（サブトリガーのみ防御）
-                let damage = Self::calculate_partial_guard_damage(
-                    attacker_base_attack,
-                    defender_base_defense,
-                    TriggerStatus::get_trigger_status(attacker_main_trigger_id.value()).attack()
-                        + TriggerStatus::get_trigger_status(attacker_sub_trigger_id.value())
-                            .attack(),
-                    defender_sub_trigger_status.defense(),
-                );
-                sub_trigger_hp -= damage;
+                )
+            } else {
+                (0, 0)
+            };
+            main_trigger_hp -= main_trigger_damage;
+            sub_trigger_hp -= sub_trigger_damage;
+
+            // 両防御の場合は、どちらかのトリガーHPが0以下になると撃墜
+            if is_full_guard && (main_trigger_hp <= 0 || sub_trigger_hp <= 0) {
+                is_defeated = true;
             }
+
+            hits.push(CombatHit::new(
+                trigger_id,
+                is_avoided,
+                main_trigger_damage,
+                sub_trigger_damage,
+            ));
         }
 
+        // 全ての攻撃を回避した場合のみ回避とする
+        let is_avoided = IsAvoided::new(hits.iter().all(|hit| hit.is_avoided().is_avoided()));
+
         Some(Self::new(
             CombatId::new(Uuid::new_v4().to_string()),
+            attacker.unit_id().clone(),
+            attacker.position().clone(),
+            attacker.main_trigger_id().clone(),
+            attacker.sub_trigger_id().clone(),
+            attacker.main_trigger_azimuth().clone(),
+            attacker.sub_trigger_azimuth().clone(),
+            attacker.base_attack(),
+            defender.unit_id().clone(),
+            defender.position().clone(),
+            defender.main_trigger_id().clone(),
+            defender.sub_trigger_id().clone(),
+            defender.main_trigger_azimuth().clone(),
+            defender.sub_trigger_azimuth().clone(),
+            defender.base_defense(),
+            defender.base_avoid(),
+            main_trigger_hp,
+            sub_trigger_hp,
+            defender.main_trigger_hp() - main_trigger_hp,
+            defender.sub_trigger_hp() - sub_trigger_hp,
+            is_avoided,
+            is_defeated,
+            hits,
+        ))
+    }
+
+    /// 戦闘の再構築（リポジトリから取得時に使用）
+    #[allow(clippy::too_many_arguments)]
+    pub fn reconstruct(
+        combat_id: CombatId,
+        attacking_unit_id: UnitId,
+        attacker_position: Position,
+        attacker_main_trigger_id: TriggerId,
+        attacker_sub_trigger_id: TriggerId,
+        attacker_main_trigger_azimuth: TriggerAzimuth,
+        attacker_sub_trigger_azimuth: TriggerAzimuth,
+        attacker_base_attack: i32,
+        defending_unit_id: UnitId,
+        defender_position: Position,
+        defender_main_trigger_id: TriggerId,
+        defender_sub_trigger_id: TriggerId,
+        defender_main_trigger_azimuth: TriggerAzimuth,
+        defender_sub_trigger_azimuth: TriggerAzimuth,
+        defender_base_defense: i32,
+        defender_base_avoid: i32,
+        main_trigger_hp: i32,
+        sub_trigger_hp: i32,
+        main_trigger_damage: i32,
+        sub_trigger_damage: i32,
+        is_avoided: IsAvoided,
+        is_defeated: bool,
+        hits: Vec<CombatHit>,
+    ) -> Self {
+        Self::new(
+            combat_id,
             attacking_unit_id,
             attacker_position,
             attacker_main_trigger_id,
@@ -234,13 +266,16 @@ impl Combat {
             defender_base_avoid,
             main_trigger_hp,
             sub_trigger_hp,
+            main_trigger_damage,
+            sub_trigger_damage,
             is_avoided,
             is_defeated,
-        ))
+            hits,
+        )
     }
 
     /// トリガーの射程と方向内に敵がいるか確認する
-    fn check_trigger_in_range_and_angle(
+    pub(super) fn check_trigger_in_range_and_angle(
         attacker_position: &Position,
         attacker_trigger_id: &TriggerId,
         attacker_trigger_azimuth: &TriggerAzimuth,
@@ -324,19 +359,142 @@ impl Combat {
         }
     }
 
+    /// 攻撃側のトリガーのうち、射程と方向内に防御側がいるもの（メイン・サブの順）
+    pub(super) fn attacking_trigger_ids(
+        attacker: &CombatAttacker,
+        defender: &CombatDefender,
+    ) -> Vec<TriggerId> {
+        [
+            (attacker.main_trigger_id(), attacker.main_trigger_azimuth()),
+            (attacker.sub_trigger_id(), attacker.sub_trigger_azimuth()),
+        ]
+        .into_iter()
+        .filter(|(trigger_id, azimuth)| {
+            Self::check_trigger_in_range_and_angle(
+                attacker.position(),
+                trigger_id,
+                azimuth,
+                defender.position(),
+            )
+        })
+        .map(|(trigger_id, _)| trigger_id.clone())
+        .collect()
+    }
+
+    /// 防御側のメイン・サブトリガーがそれぞれ攻撃者に向いているか確認する
+    pub(super) fn check_defender_facing(
+        defender: &CombatDefender,
+        attacker_position: &Position,
+    ) -> (bool, bool) {
+        let is_facing_main = Self::check_trigger_in_angle(
+            defender.position(),
+            attacker_position,
+            defender.main_trigger_azimuth().value(),
+            TriggerStatus::get_trigger_status(defender.main_trigger_id().value()).angle(),
+        );
+        let is_facing_sub = Self::check_trigger_in_angle(
+            defender.position(),
+            attacker_position,
+            defender.sub_trigger_azimuth().value(),
+            TriggerStatus::get_trigger_status(defender.sub_trigger_id().value()).angle(),
+        );
+        (is_facing_main, is_facing_sub)
+    }
+
+    /// 攻撃者に向いている防御側トリガーの回避値の合計
+    pub(super) fn calculate_trigger_avoid(
+        defender_main_trigger_id: &TriggerId,
+        defender_sub_trigger_id: &TriggerId,
+        is_facing_main: bool,
+        is_facing_sub: bool,
+    ) -> i32 {
+        let main_avoid = if is_facing_main {
+            TriggerStatus::get_trigger_status(defender_main_trigger_id.value()).avoid()
+        } else {
+            0
+        };
+        let sub_avoid = if is_facing_sub {
+            TriggerStatus::get_trigger_status(defender_sub_trigger_id.value()).avoid()
+        } else {
+            0
+        };
+        main_avoid + sub_avoid
+    }
+
+    /// 回避率（1以上の場合は必ず回避する）
+    pub(super) fn avoid_chance(defender_base_avoid: i32, trigger_avoid: i32) -> f64 {
+        (defender_base_avoid as f64) * (trigger_avoid as f64)
+            / (GameConfig::get_game_config().avoid_weight() as f64)
+    }
+
     /// 回避計算の実行
-    fn calculate_avoidance(defender_base_avoid: i32, trigger_avoid: i32) -> IsAvoided {
+    fn calculate_avoidance(
+        defender_base_avoid: i32,
+        trigger_avoid: i32,
+        rng: &mut impl Rng,
+    ) -> IsAvoided {
         // 仮の実装、ランダムで回避成功・失敗を決定
-        let random_value = rand::random::<f64>();
-        let avoid_chance = (defender_base_avoid as f64) * (trigger_avoid as f64)
-            / (GameConfig::get_game_config().avoid_weight() as f64);
-        if random_value < avoid_chance {
+        let random_value = rng.random::<f64>();
+        if random_value < Self::avoid_chance(defender_base_avoid, trigger_avoid) {
             IsAvoided::new(true)
         } else {
             IsAvoided::new(false)
         }
     }
 
+    /// 攻撃1回が命中した場合のメイン・サブトリガーへのダメージ
+    /// 防御側のトリガーの向きによって両防御・片方防御のダメージ計算を使い分ける
+    /// トリガーが向いていない場合は即撃墜となるため、ダメージは0とする
+    pub(super) fn calculate_damage(
+        is_facing_main: bool,
+        is_facing_sub: bool,
+        attacker_base_attack: i32,
+        trigger_attack: i32,
+        defender: &CombatDefender,
+        main_trigger_hp: i32,
+        sub_trigger_hp: i32,
+    ) -> (i32, i32) {
+        let defender_base_defense = defender.base_defense();
+        let main_trigger_defense =
+            TriggerStatus::get_trigger_status(defender.main_trigger_id().value()).defense();
+        let sub_trigger_defense =
+            TriggerStatus::get_trigger_status(defender.sub_trigger_id().value()).defense();
+
+        match (is_facing_main, is_facing_sub) {
+            // 両防御の場合
+            (true, true) => Self::calculate_full_guard_damage(
+                attacker_base_attack,
+                defender_base_defense,
+                trigger_attack,
+                main_trigger_defense,
+                sub_trigger_defense,
+                main_trigger_hp,
+                sub_trigger_hp,
+            ),
+            // 片方防御の場合（メイントリガーのみ防御）
+            (true, false) => (
+                Self::calculate_partial_guard_damage(
+                    attacker_base_attack,
+                    defender_base_defense,
+                    trigger_attack,
+                    main_trigger_defense,
+                ),
+                0,
+            ),
+            // 片方防御の場合（サブトリガーのみ防御）
+            (false, true) => (
+                0,
+                Self::calculate_partial_guard_damage(
+                    attacker_base_attack,
+                    defender_base_defense,
+                    trigger_attack,
+                    sub_trigger_defense,
+                ),
+            ),
+            (false, false) => (0, 0),
+        }
+    }
+
     /// 両防御の場合のダメージを計算
     /// 例：9 × 8 × 2 - 4 × (10 + 5) = 144 - 60
     fn calculate_full_guard_damage(
@@ -393,9 +551,99 @@ impl Combat {
     }
 
     // ゲッター
+    pub fn combat_id(&self) -> &CombatId {
+        &self.combat_id
+    }
+
+    pub fn attacking_unit_id(&self) -> &UnitId {
+        &self.attacking_unit_id
+    }
+
+    pub fn attacker_position(&self) -> &Position {
+        &self.attacker_position
+    }
+
+    pub fn attacker_main_trigger_id(&self) -> &TriggerId {
+        &self.attacker_main_trigger_id
+    }
+
+    pub fn attacker_sub_trigger_id(&self) -> &TriggerId {
+        &self.attacker_sub_trigger_id
+    }
+
+    pub fn attacker_main_trigger_azimuth(&self) -> &TriggerAzimuth {
+        &self.attacker_main_trigger_azimuth
+    }
+
+    pub fn attacker_sub_trigger_azimuth(&self) -> &TriggerAzimuth {
+        &self.attacker_sub_trigger_azimuth
+    }
+
+    pub fn attacker_base_attack(&self) -> i32 {
+        self.attacker_base_attack
+    }
+
+    pub fn defending_unit_id(&self) -> &UnitId {
+        &self.defending_unit_id
+    }
+
+    pub fn defender_position(&self) -> &Position {
+        &self.defender_position
+    }
+
+    pub fn defender_main_trigger_id(&self) -> &TriggerId {
+        &self.defender_main_trigger_id
+    }
+
+    pub fn defender_sub_trigger_id(&self) -> &TriggerId {
+        &self.defender_sub_trigger_id
+    }
+
+    pub fn defender_main_trigger_azimuth(&self) -> &TriggerAzimuth {
+        &self.defender_main_trigger_azimuth
+    }
+
+    pub fn defender_sub_trigger_azimuth(&self) -> &TriggerAzimuth {
+        &self.defender_sub_trigger_azimuth
+    }
+
+    pub fn main_trigger_hp(&self) -> i32 {
+        self.main_trigger_hp
+    }
+
+    pub fn sub_trigger_hp(&self) -> i32 {
+        self.sub_trigger_hp
+    }
+
+    /// メイントリガーが受けたダメージ量
+    pub fn main_trigger_damage(&self) -> i32 {
+        self.main_trigger_damage
+    }
+
+    /// サブトリガーが受けたダメージ量
+    pub fn sub_trigger_damage(&self) -> i32 {
+        self.sub_trigger_damage
+    }
+
+    pub fn defender_base_defense(&self) -> i32 {
+        self.defender_base_defense
+    }
+
+    pub fn defender_base_avoid(&self) -> i32 {
+        self.defender_base_avoid
+    }
+
+    pub fn is_avoided(&self) -> &IsAvoided {
+        &self.is_avoided
+    }
+
     pub fn is_defeated(&self) -> bool {
         self.is_defeated
     }
+
+    pub fn hits(&self) -> &[CombatHit] {
+        &self.hits
+    }
 }
 
 impl PartialEq for Combat {
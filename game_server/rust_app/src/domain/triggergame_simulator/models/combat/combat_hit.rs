@@ -0,0 +1,2 @@
+pub mod combat_hit;
+pub mod combat_hit_test;
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::triggergame_simulator::models::combat::is_avoided::is_avoided::IsAvoided;
+use crate::domain::unit_management::models::unit::trigger_id::trigger_id::TriggerId;
+
+/// 攻撃側のトリガー1つ分の攻撃結果
+/// 射程と方向内に防御側がいるトリガーごとに、回避判定とダメージ計算を行う
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CombatHit {
+    /// 攻撃に使用したトリガー
+    trigger_id: TriggerId,
+    is_avoided: IsAvoided,
+    /// この攻撃によるメイントリガーへのダメージ
+    main_trigger_damage: i32,
+    /// この攻撃によるサブトリガーへのダメージ
+    sub_trigger_damage: i32,
+}
+
+impl CombatHit {
+    pub fn new(
+        trigger_id: TriggerId,
+        is_avoided: IsAvoided,
+        main_trigger_damage: i32,
+        sub_trigger_damage: i32,
+    ) -> Self {
+        Self {
+            trigger_id,
+            is_avoided,
+            main_trigger_damage,
+            sub_trigger_damage,
+        }
+    }
+
+    pub fn trigger_id(&self) -> &TriggerId {
+        &self.trigger_id
+    }
+
+    pub fn is_avoided(&self) -> &IsAvoided {
+        &self.is_avoided
+    }
+
+    pub fn main_trigger_damage(&self) -> i32 {
+        self.main_trigger_damage
+    }
+
+    pub fn sub_trigger_damage(&self) -> i32 {
+        self.sub_trigger_damage
+    }
+}
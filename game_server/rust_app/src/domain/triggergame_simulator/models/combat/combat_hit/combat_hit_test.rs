@@ -0,0 +1,35 @@
+#[cfg(test)]
+mod tests {
+    use super::super::combat_hit::CombatHit;
+    use crate::domain::triggergame_simulator::models::combat::is_avoided::is_avoided::IsAvoided;
+    use crate::domain::unit_management::models::unit::trigger_id::trigger_id::TriggerId;
+
+    #[test]
+    fn test_new() {
+        let hit = CombatHit::new(
+            TriggerId::new("KOGETSU".to_string()),
+            IsAvoided::new(false),
+            30,
+            20,
+        );
+        assert_eq!(hit.trigger_id().value(), "KOGETSU");
+        assert!(hit.is_avoided().is_hit());
+        assert_eq!(hit.main_trigger_damage(), 30);
+        assert_eq!(hit.sub_trigger_damage(), 20);
+    }
+
+    #[test]
+    fn test_serialize() {
+        let hit = CombatHit::new(
+            TriggerId::new("ASTEROID".to_string()),
+            IsAvoided::new(true),
+            0,
+            0,
+        );
+        let json = serde_json::to_value(&hit).unwrap();
+        assert_eq!(json["triggerId"], "ASTEROID");
+        assert_eq!(json["isAvoided"], true);
+        assert_eq!(json["mainTriggerDamage"], 0);
+        assert_eq!(json["subTriggerDamage"], 0);
+    }
+}
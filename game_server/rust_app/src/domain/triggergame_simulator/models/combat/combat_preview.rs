@@ -0,0 +1,130 @@
+use crate::domain::unit_management::models::unit::trigger_id::trigger_id::TriggerId;
+
+use super::combat::Combat;
+use super::combatant::{CombatAttacker, CombatDefender};
+use super::is_avoided::is_avoided::IsAvoided;
+use serde::{Deserialize, Serialize};
+
+/// 戦闘予測
+/// 回避判定の乱数を使わずに、Combat::createと同じ計算式から戦闘結果の期待値を求める
+/// クライアントで行動設定中に戦闘の見込みを表示するために使用する
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CombatPreview {
+    /// 射程と方向内に防御側がいる攻撃側のトリガー（メイン・サブの順）
+    attacking_trigger_ids: Vec<TriggerId>,
+    /// 攻撃1回あたりの命中率（回避されない確率）
+    hit_chance: f64,
+    /// 全ての攻撃が命中した場合のメイントリガーへのダメージ
+    main_trigger_damage: i32,
+    /// 全ての攻撃が命中した場合のサブトリガーへのダメージ
+    sub_trigger_damage: i32,
+    /// メイントリガーへのダメージの期待値
+    expected_main_trigger_damage: f64,
+    /// サブトリガーへのダメージの期待値
+    expected_sub_trigger_damage: f64,
+    /// 防御側がベイルアウトする確率
+    bailout_chance: f64,
+}
+
+impl CombatPreview {
+    /// 攻撃側のトリガー数（メイン・サブ）
+    const MAX_HITS: u32 = 2;
+
+    /// 戦闘予測の計算
+    /// 攻撃ごとの回避成否の組み合わせを全て解決し、発生確率で重み付けして期待値を求める
+    /// 射程外、角度の範囲外で戦闘が発生しない場合はNoneを返す
+    pub fn calculate(attacker: &CombatAttacker, defender: &CombatDefender) -> Option<Self> {
+        // avoided_pattern: i番目の攻撃を回避する場合にiビット目を立てる
+        // 戻り値は戦闘結果と、その回避成否の組み合わせが発生する確率
+        let resolve = |avoided_pattern: u32| -> Option<(Combat, f64, u32)> {
+            let mut probability = 1.0;
+            let mut hit_index = 0;
+            let combat =
+                Combat::resolve(attacker, defender, |defender_base_avoid, trigger_avoid| {
+                    let avoid_chance =
+                        Combat::avoid_chance(defender_base_avoid, trigger_avoid).clamp(0.0, 1.0);
+                    let is_avoided = avoided_pattern & (1 << hit_index) != 0;
+                    probability *= if is_avoided {
+                        avoid_chance
+                    } else {
+                        1.0 - avoid_chance
+                    };
+                    hit_index += 1;
+                    IsAvoided::new(is_avoided)
+                })?;
+            Some((combat, probability, hit_index))
+        };
+
+        // 全ての攻撃が命中した場合の結果
+        let (all_hit_combat, _, _) = resolve(0)?;
+
+        let attacking_trigger_ids = Combat::attacking_trigger_ids(attacker, defender);
+
+        let (is_facing_main, is_facing_sub) =
+            Combat::check_defender_facing(defender, attacker.position());
+        let trigger_avoid = Combat::calculate_trigger_avoid(
+            defender.main_trigger_id(),
+            defender.sub_trigger_id(),
+            is_facing_main,
+            is_facing_sub,
+        );
+        let hit_chance =
+            1.0 - Combat::avoid_chance(defender.base_avoid(), trigger_avoid).clamp(0.0, 1.0);
+
+        let mut expected_main_trigger_damage = 0.0;
+        let mut expected_sub_trigger_damage = 0.0;
+        let mut bailout_chance = 0.0;
+        for avoided_pattern in 0..(1 << Self::MAX_HITS) {
+            let (combat, probability, resolved_hits) = resolve(avoided_pattern)?;
+            if avoided_pattern >> resolved_hits != 0 {
+                // 行われなかった攻撃の回避成否だけが異なる組み合わせは集計済み
+                continue;
+            }
+            expected_main_trigger_damage += probability * combat.main_trigger_damage() as f64;
+            expected_sub_trigger_damage += probability * combat.sub_trigger_damage() as f64;
+            if combat.is_defeated() {
+                bailout_chance += probability;
+            }
+        }
+
+        Some(Self {
+            attacking_trigger_ids,
+            hit_chance,
+            main_trigger_damage: all_hit_combat.main_trigger_damage(),
+            sub_trigger_damage: all_hit_combat.sub_trigger_damage(),
+            expected_main_trigger_damage,
+            expected_sub_trigger_damage,
+            bailout_chance,
+        })
+    }
+
+    // ゲッター
+    pub fn attacking_trigger_ids(&self) -> &[TriggerId] {
+        &self.attacking_trigger_ids
+    }
+
+    pub fn hit_chance(&self) -> f64 {
+        self.hit_chance
+    }
+
+    pub fn main_trigger_damage(&self) -> i32 {
+        self.main_trigger_damage
+    }
+
+    pub fn sub_trigger_damage(&self) -> i32 {
+        self.sub_trigger_damage
+    }
+
+    pub fn expected_main_trigger_damage(&self) -> f64 {
+        self.expected_main_trigger_damage
+    }
+
+    pub fn expected_sub_trigger_damage(&self) -> f64 {
+        self.expected_sub_trigger_damage
+    }
+
+    pub fn bailout_chance(&self) -> f64 {
+        self.bailout_chance
+    }
+}
@@ -0,0 +1,213 @@
+#[cfg(test)]
+mod tests {
+    use super::super::combat::Combat;
+    use super::super::combat_preview::CombatPreview;
+    use super::super::combatant::{CombatAttacker, CombatDefender};
+    use crate::domain::triggergame_simulator::configs::game_config::GameConfig;
+    use crate::domain::triggergame_simulator::models::action::trigger_azimuth::trigger_azimuth::TriggerAzimuth;
+    use crate::domain::unit_management::models::unit::position::position::Position;
+    use crate::domain::unit_management::models::unit::trigger_id::trigger_id::TriggerId;
+    use crate::domain::unit_management::models::unit::unit_id::unit_id::UnitId;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use uuid::Uuid;
+
+    // 攻撃側は(10,10)から下方向（方位角180）を向く
+    // 防御側の(25,24)は攻撃側から見て(10,11)となり、攻撃側の1マス下にいる
+    // 防御側から見た攻撃側も下方向にいるため、防御側は方位角180で攻撃側を向く
+    const ATTACKER_BASE_ATTACK: i32 = 10;
+    const DEFENDER_BASE_DEFENSE: i32 = 5;
+    const DEFENDER_BASE_AVOID: i32 = 5;
+    const FACING_AZIMUTH: i32 = 180;
+    const AWAY_AZIMUTH: i32 = 0;
+
+    fn preview(
+        defender_position: Position,
+        defender_sub_trigger_id: &str,
+        defender_main_azimuth: i32,
+        defender_sub_azimuth: i32,
+        main_trigger_hp: i32,
+        sub_trigger_hp: i32,
+    ) -> Option<CombatPreview> {
+        CombatPreview::calculate(
+            &attacker(),
+            &CombatDefender::new(
+                UnitId::new(Uuid::new_v4().to_string()),
+                defender_position,
+                TriggerId::new("KOGETSU".to_string()),
+                TriggerId::new(defender_sub_trigger_id.to_string()),
+                TriggerAzimuth::new(defender_main_azimuth),
+                TriggerAzimuth::new(defender_sub_azimuth),
+                main_trigger_hp,
+                sub_trigger_hp,
+                DEFENDER_BASE_DEFENSE,
+                DEFENDER_BASE_AVOID,
+            ),
+        )
+    }
+
+    fn attacker() -> CombatAttacker {
+        CombatAttacker::new(
+            UnitId::new(Uuid::new_v4().to_string()),
+            Position::new(10, 10),
+            TriggerId::new("KOGETSU".to_string()),
+            TriggerId::new("KOGETSU".to_string()),
+            TriggerAzimuth::new(FACING_AZIMUTH),
+            TriggerAzimuth::new(FACING_AZIMUTH),
+            ATTACKER_BASE_ATTACK,
+        )
+    }
+
+    /// 回避率の重みを変更した設定でテストを実行
+    fn with_avoid_weight<T>(avoid_weight: i32, f: impl FnOnce() -> T) -> T {
+        let config = GameConfig::get_game_config();
+        let damage_weight = config.damage_weight();
+        let defend_weight = config.defend_weight();
+        let min_damage = config.min_damage();
+        GameConfig::set_thread_override(Some(config.with_combat_weights(
+            avoid_weight,
+            damage_weight,
+            defend_weight,
+            min_damage,
+        )));
+        let result = f();
+        GameConfig::set_thread_override(None);
+        result
+    }
+
+    #[test]
+    fn test_out_of_range_returns_none() {
+        let result = preview(
+            Position::new(0, 0),
+            "RAYGUST",
+            FACING_AZIMUTH,
+            FACING_AZIMUTH,
+            100,
+            100,
+        );
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_not_facing_attacker_is_certain_bailout() {
+        let result = preview(
+            Position::new(25, 24),
+            "RAYGUST",
+            AWAY_AZIMUTH,
+            AWAY_AZIMUTH,
+            100,
+            100,
+        )
+        .unwrap();
+        assert_eq!(result.hit_chance(), 1.0);
+        assert_eq!(result.main_trigger_damage(), 0);
+        assert_eq!(result.sub_trigger_damage(), 0);
+        assert_eq!(result.bailout_chance(), 1.0);
+    }
+
+    #[test]
+    fn test_avoid_chance_over_one_never_hits() {
+        // 既定の設定では回避率 5 × (5 + 3) / 2 が1を超えるため必ず回避する
+        let result = preview(
+            Position::new(25, 24),
+            "RAYGUST",
+            FACING_AZIMUTH,
+            FACING_AZIMUTH,
+            100,
+            100,
+        )
+        .unwrap();
+        assert_eq!(result.hit_chance(), 0.0);
+        assert_eq!(result.expected_main_trigger_damage(), 0.0);
+        assert_eq!(result.expected_sub_trigger_damage(), 0.0);
+        assert_eq!(result.bailout_chance(), 0.0);
+    }
+
+    #[test]
+    fn test_full_guard_expected_damage() {
+        // 回避率 5 × (5 + 3) / 100 = 0.4
+        // 攻撃1回のダメージ 10 × 8 - 5 × (0 + 10) = 30 をHPの比率で分散
+        let result = with_avoid_weight(100, || {
+            preview(
+                Position::new(25, 24),
+                "RAYGUST",
+                FACING_AZIMUTH,
+                FACING_AZIMUTH,
+                100,
+                100,
+            )
+        })
+        .unwrap();
+        assert_eq!(result.attacking_trigger_ids().len(), 2);
+        assert!((result.hit_chance() - 0.6).abs() < 1e-9);
+        assert_eq!(result.main_trigger_damage(), 30);
+        assert_eq!(result.sub_trigger_damage(), 30);
+        assert!((result.expected_main_trigger_damage() - 18.0).abs() < 1e-9);
+        assert!((result.expected_sub_trigger_damage() - 18.0).abs() < 1e-9);
+        assert_eq!(result.bailout_chance(), 0.0);
+    }
+
+    #[test]
+    fn test_full_guard_bailout_requires_both_hits() {
+        // 1回目の命中で残HP5、2回目も命中すると撃墜
+        let result = with_avoid_weight(100, || {
+            preview(
+                Position::new(25, 24),
+                "RAYGUST",
+                FACING_AZIMUTH,
+                FACING_AZIMUTH,
+                20,
+                20,
+            )
+        })
+        .unwrap();
+        assert!((result.bailout_chance() - 0.36).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_partial_guard_damages_facing_trigger_only() {
+        // メイントリガーのみ防御：回避率 5 × 5 / 100 = 0.25
+        // 攻撃1回のダメージ 10 × 8 - 5 × 0 = 80
+        let result = with_avoid_weight(100, || {
+            preview(
+                Position::new(25, 24),
+                "RAYGUST",
+                FACING_AZIMUTH,
+                AWAY_AZIMUTH,
+                100,
+                100,
+            )
+        })
+        .unwrap();
+        assert_eq!(result.main_trigger_damage(), 160);
+        assert_eq!(result.sub_trigger_damage(), 0);
+        assert!((result.expected_main_trigger_damage() - 120.0).abs() < 1e-9);
+        // 片方防御ではトリガーHPが0以下になっても撃墜しない
+        assert_eq!(result.bailout_chance(), 0.0);
+    }
+
+    #[test]
+    fn test_preview_matches_combat_when_hit_is_certain() {
+        // 防御側の基本回避値が0の場合は必ず命中するため、実際の戦闘と同じダメージになる
+        let attacker = attacker();
+        let defender = CombatDefender::new(
+            UnitId::new(Uuid::new_v4().to_string()),
+            Position::new(25, 24),
+            TriggerId::new("KOGETSU".to_string()),
+            TriggerId::new("RAYGUST".to_string()),
+            TriggerAzimuth::new(FACING_AZIMUTH),
+            TriggerAzimuth::new(FACING_AZIMUTH),
+            100,
+            80,
+            DEFENDER_BASE_DEFENSE,
+            0,
+        );
+        let preview = CombatPreview::calculate(&attacker, &defender).unwrap();
+        let combat = Combat::create(&attacker, &defender, &mut StdRng::seed_from_u64(0)).unwrap();
+        assert_eq!(preview.hit_chance(), 1.0);
+        assert!(!combat.is_avoided().value());
+        assert_eq!(preview.main_trigger_damage(), combat.main_trigger_damage());
+        assert_eq!(preview.sub_trigger_damage(), combat.sub_trigger_damage());
+        assert_eq!(preview.bailout_chance() == 1.0, combat.is_defeated());
+    }
+}
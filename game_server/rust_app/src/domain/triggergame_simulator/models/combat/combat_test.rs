@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod tests {
     use super::super::combat::Combat;
+    use super::super::combatant::{CombatAttacker, CombatDefender};
     use crate::domain::triggergame_simulator::models::action::trigger_azimuth::trigger_azimuth::TriggerAzimuth;
     use crate::domain::unit_management::models::unit::position::position::Position;
     use crate::domain::unit_management::models::unit::trigger_id::trigger_id::TriggerId;
@@ -26,26 +27,106 @@ mod tests {
     #[test]
     fn test_create_combat_returns_option() {
         let combat = Combat::create(
-            create_test_unit_id(),
-            create_test_position(),
-            create_test_trigger_id(),
-            create_test_trigger_id(),
-            create_test_trigger_azimuth(),
-            create_test_trigger_azimuth(),
-            10,
-            create_test_unit_id(),
-            Position::new(100, 100),
-            create_test_trigger_id(),
-            create_test_trigger_id(),
-            100,
-            100,
-            create_test_trigger_azimuth(),
-            create_test_trigger_azimuth(),
-            5,
-            2,
+            &CombatAttacker::new(
+                create_test_unit_id(),
+                create_test_position(),
+                create_test_trigger_id(),
+                create_test_trigger_id(),
+                create_test_trigger_azimuth(),
+                create_test_trigger_azimuth(),
+                10,
+            ),
+            &CombatDefender::new(
+                create_test_unit_id(),
+                Position::new(100, 100),
+                create_test_trigger_id(),
+                create_test_trigger_id(),
+                create_test_trigger_azimuth(),
+                create_test_trigger_azimuth(),
+                100,
+                100,
+                5,
+                2,
+            ),
+            &mut rand::rng(),
         );
 
         // Combatの生成に成功するか（射程や角度等の条件により失敗する可能性あり）
         assert!(combat.is_some() || combat.is_none());
     }
+
+    /// 攻撃側(10,10)から見て真下にいる防御側と戦闘を行う
+    /// 防御側の基本回避値を0とし、全ての攻撃を命中させる
+    /// defender_row: 防御側の自陣視点での行（24で攻撃側の1マス下、22で3マス下）
+    fn create_certain_hit_combat(
+        attacker_main_trigger_id: &str,
+        attacker_sub_trigger_id: &str,
+        defender_row: i32,
+        main_trigger_hp: i32,
+        sub_trigger_hp: i32,
+    ) -> Option<Combat> {
+        Combat::create(
+            &CombatAttacker::new(
+                create_test_unit_id(),
+                Position::new(10, 10),
+                TriggerId::new(attacker_main_trigger_id.to_string()),
+                TriggerId::new(attacker_sub_trigger_id.to_string()),
+                TriggerAzimuth::new(180),
+                TriggerAzimuth::new(180),
+                10,
+            ),
+            &CombatDefender::new(
+                create_test_unit_id(),
+                Position::new(25, defender_row),
+                TriggerId::new("KOGETSU".to_string()),
+                TriggerId::new("RAYGUST".to_string()),
+                TriggerAzimuth::new(180),
+                TriggerAzimuth::new(180),
+                main_trigger_hp,
+                sub_trigger_hp,
+                5,
+                0,
+            ),
+            &mut rand::rng(),
+        )
+    }
+
+    #[test]
+    fn test_each_trigger_hits_separately() {
+        // 攻撃1回のダメージ 10 × 8 - 5 × (0 + 10) = 30 をHPの比率で分散
+        let combat = create_certain_hit_combat("KOGETSU", "KOGETSU", 24, 100, 100).unwrap();
+        let hits = combat.hits();
+        assert_eq!(hits.len(), 2);
+        for hit in hits {
+            assert_eq!(hit.trigger_id().value(), "KOGETSU");
+            assert!(hit.is_avoided().is_hit());
+            assert_eq!(hit.main_trigger_damage(), 15);
+            assert_eq!(hit.sub_trigger_damage(), 15);
+        }
+        assert_eq!(combat.main_trigger_damage(), 30);
+        assert_eq!(combat.sub_trigger_damage(), 30);
+        assert_eq!(combat.main_trigger_hp(), 70);
+        assert_eq!(combat.sub_trigger_hp(), 70);
+        assert!(combat.is_avoided().is_hit());
+        assert!(!combat.is_defeated());
+    }
+
+    #[test]
+    fn test_only_trigger_in_range_attacks() {
+        // 3マス先の防御側にはASTEROID（射程5）のみ届き、KOGETSU（射程2）の攻撃力は加算しない
+        // ダメージ 10 × 4 - 5 × (0 + 10) は最低ダメージ20となる
+        let combat = create_certain_hit_combat("ASTEROID", "KOGETSU", 22, 100, 100).unwrap();
+        let hits = combat.hits();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].trigger_id().value(), "ASTEROID");
+        assert_eq!(combat.main_trigger_damage(), 10);
+        assert_eq!(combat.sub_trigger_damage(), 10);
+    }
+
+    #[test]
+    fn test_full_guard_defeat_stops_following_hits() {
+        let combat = create_certain_hit_combat("KOGETSU", "KOGETSU", 24, 10, 10).unwrap();
+        assert_eq!(combat.hits().len(), 1);
+        assert!(combat.is_defeated());
+    }
 }
@@ -0,0 +1,157 @@
+use crate::domain::triggergame_simulator::models::action::trigger_azimuth::trigger_azimuth::TriggerAzimuth;
+use crate::domain::unit_management::models::unit::position::position::Position;
+use crate::domain::unit_management::models::unit::trigger_id::trigger_id::TriggerId;
+use crate::domain::unit_management::models::unit::unit_id::unit_id::UnitId;
+
+/// 戦闘の解決に使用する攻撃側の情報
+#[derive(Debug, Clone)]
+pub struct CombatAttacker {
+    unit_id: UnitId,
+    position: Position,
+    main_trigger_id: TriggerId,
+    sub_trigger_id: TriggerId,
+    main_trigger_azimuth: TriggerAzimuth,
+    sub_trigger_azimuth: TriggerAzimuth,
+    /// 状態効果の補正を反映した基本攻撃力
+    base_attack: i32,
+}
+
+impl CombatAttacker {
+    pub fn new(
+        unit_id: UnitId,
+        position: Position,
+        main_trigger_id: TriggerId,
+        sub_trigger_id: TriggerId,
+        main_trigger_azimuth: TriggerAzimuth,
+        sub_trigger_azimuth: TriggerAzimuth,
+        base_attack: i32,
+    ) -> Self {
+        Self {
+            unit_id,
+            position,
+            main_trigger_id,
+            sub_trigger_id,
+            main_trigger_azimuth,
+            sub_trigger_azimuth,
+            base_attack,
+        }
+    }
+
+    // ゲッター
+    pub fn unit_id(&self) -> &UnitId {
+        &self.unit_id
+    }
+
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+
+    pub fn main_trigger_id(&self) -> &TriggerId {
+        &self.main_trigger_id
+    }
+
+    pub fn sub_trigger_id(&self) -> &TriggerId {
+        &self.sub_trigger_id
+    }
+
+    pub fn main_trigger_azimuth(&self) -> &TriggerAzimuth {
+        &self.main_trigger_azimuth
+    }
+
+    pub fn sub_trigger_azimuth(&self) -> &TriggerAzimuth {
+        &self.sub_trigger_azimuth
+    }
+
+    pub fn base_attack(&self) -> i32 {
+        self.base_attack
+    }
+}
+
+/// 戦闘の解決に使用する防御側の情報
+#[derive(Debug, Clone)]
+pub struct CombatDefender {
+    unit_id: UnitId,
+    position: Position,
+    main_trigger_id: TriggerId,
+    sub_trigger_id: TriggerId,
+    main_trigger_azimuth: TriggerAzimuth,
+    sub_trigger_azimuth: TriggerAzimuth,
+    /// 戦闘前のメイントリガーの残HP
+    main_trigger_hp: i32,
+    /// 戦闘前のサブトリガーの残HP
+    sub_trigger_hp: i32,
+    /// 状態効果の補正を反映した基本防御力
+    base_defense: i32,
+    /// 状態効果の補正を反映した基本回避値
+    base_avoid: i32,
+}
+
+impl CombatDefender {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        unit_id: UnitId,
+        position: Position,
+        main_trigger_id: TriggerId,
+        sub_trigger_id: TriggerId,
+        main_trigger_azimuth: TriggerAzimuth,
+        sub_trigger_azimuth: TriggerAzimuth,
+        main_trigger_hp: i32,
+        sub_trigger_hp: i32,
+        base_defense: i32,
+        base_avoid: i32,
+    ) -> Self {
+        Self {
+            unit_id,
+            position,
+            main_trigger_id,
+            sub_trigger_id,
+            main_trigger_azimuth,
+            sub_trigger_azimuth,
+            main_trigger_hp,
+            sub_trigger_hp,
+            base_defense,
+            base_avoid,
+        }
+    }
+
+    // ゲッター
+    pub fn unit_id(&self) -> &UnitId {
+        &self.unit_id
+    }
+
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+
+    pub fn main_trigger_id(&self) -> &TriggerId {
+        &self.main_trigger_id
+    }
+
+    pub fn sub_trigger_id(&self) -> &TriggerId {
+        &self.sub_trigger_id
+    }
+
+    pub fn main_trigger_azimuth(&self) -> &TriggerAzimuth {
+        &self.main_trigger_azimuth
+    }
+
+    pub fn sub_trigger_azimuth(&self) -> &TriggerAzimuth {
+        &self.sub_trigger_azimuth
+    }
+
+    pub fn main_trigger_hp(&self) -> i32 {
+        self.main_trigger_hp
+    }
+
+    pub fn sub_trigger_hp(&self) -> i32 {
+        self.sub_trigger_hp
+    }
+
+    pub fn base_defense(&self) -> i32 {
+        self.base_defense
+    }
+
+    pub fn base_avoid(&self) -> i32 {
+        self.base_avoid
+    }
+}
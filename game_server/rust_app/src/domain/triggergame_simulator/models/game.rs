@@ -1,5 +1,12 @@
 pub mod current_turn_number;
 pub mod game;
 pub mod game_id;
+pub mod game_permission;
+pub mod game_result;
+pub mod game_status;
 pub mod game_test;
+pub mod game_version;
+pub mod simulation_seed;
+pub mod spectator;
+pub mod spectator_delay;
 pub mod unit_id;
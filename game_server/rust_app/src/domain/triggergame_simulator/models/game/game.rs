@@ -2,7 +2,14 @@ use crate::domain::player_management::models::player::player_id::player_id::Play
 
 use super::current_turn_number::current_turn_number::CurrentTurnNumber;
 use super::game_id::game_id::GameId;
+use super::game_permission::game_permission::GamePermission;
+use super::game_status::game_status::{GameStatus, GameStatusValue};
+use super::game_version::game_version::GameVersion;
+use super::simulation_seed::simulation_seed::SimulationSeed;
+use super::spectator::spectator::Spectator;
 use super::unit_id::unit_id::UnitId;
+use crate::domain::triggergame_simulator::configs::game_config::GameConfig;
+use chrono::{DateTime, Duration, Utc};
 use uuid::Uuid;
 
 /// Game集約
@@ -13,46 +20,90 @@ pub struct Game {
     current_turn_number: CurrentTurnNumber,
     player1_id: PlayerId,
     player2_id: PlayerId,
+    version: GameVersion,
+    /// 現在のターンが開始された日時
+    turn_started_at: DateTime<Utc>,
+    /// 観戦者一覧
+    spectators: Vec<Spectator>,
+    /// 対戦の進行状態
+    status: GameStatus,
+    /// 戦闘演算で使用する乱数のシード（対戦終了まではプレイヤーに公開しない）
+    simulation_seed: SimulationSeed,
 }
 
 impl Game {
     const MAX_TURNS: i32 = 6;
+    /// 1ゲームあたりの観戦者の上限
+    const MAX_SPECTATORS: usize = 100;
 
     // privateなコンストラクタ
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         game_id: GameId,
         current_turn_number: CurrentTurnNumber,
         player1_id: PlayerId,
         player2_id: PlayerId,
+        version: GameVersion,
+        turn_started_at: DateTime<Utc>,
+        spectators: Vec<Spectator>,
+        status: GameStatus,
+        simulation_seed: SimulationSeed,
     ) -> Self {
         Self {
             game_id,
             current_turn_number,
             player1_id,
             player2_id,
+            version,
+            turn_started_at,
+            spectators,
+            status,
+            simulation_seed,
         }
     }
 
     /// 新規ゲームの生成
     pub fn create(game_id: GameId, player1_id: &PlayerId, player2_id: &PlayerId) -> Self {
         let current_turn_number = CurrentTurnNumber::initial();
+        let version = GameVersion::initial();
 
         Self::new(
             game_id,
             current_turn_number,
             player1_id.clone(),
             player2_id.clone(),
+            version,
+            Utc::now(),
+            Vec::new(),
+            GameStatus::initial(),
+            SimulationSeed::generate(),
         )
     }
 
     /// ゲームの再構築（リポジトリから取得時に使用）
+    #[allow(clippy::too_many_arguments)]
     pub fn reconstruct(
         game_id: GameId,
         current_turn_number: CurrentTurnNumber,
         player1_id: PlayerId,
         player2_id: PlayerId,
+        version: GameVersion,
+        turn_started_at: DateTime<Utc>,
+        spectators: Vec<Spectator>,
+        status: GameStatus,
+        simulation_seed: SimulationSeed,
     ) -> Self {
-        Self::new(game_id, current_turn_number, player1_id, player2_id)
+        Self::new(
+            game_id,
+            current_turn_number,
+            player1_id,
+            player2_id,
+            version,
+            turn_started_at,
+            spectators,
+            status,
+            simulation_seed,
+        )
     }
 
     /// 次のターンへ進める
@@ -63,9 +114,34 @@ impl Game {
 
         let next_turn_value = self.current_turn_number.value() + 1;
         self.current_turn_number = CurrentTurnNumber::new(next_turn_value);
+        self.turn_started_at = Utc::now();
         Ok(())
     }
 
+    /// 対戦を終了する
+    /// 最終ターンの演算結果を反映する際に使用し、以降は再接続時の復帰対象にならない
+    pub fn finish(&mut self) -> Result<(), String> {
+        if !self.is_game_finished() {
+            return Err("ゲームはまだ最終ターンに達していません".to_string());
+        }
+        if self.status.is_finished() {
+            return Err("ゲームは既に終了しています".to_string());
+        }
+        self.status = GameStatus::new(GameStatusValue::Finished);
+        Ok(())
+    }
+
+    /// 現在のターンの残り時間を取得（制限時間を過ぎている場合は0）
+    pub fn remaining_turn_time(&self, now: DateTime<Utc>) -> Duration {
+        let time_limit = Duration::seconds(GameConfig::get_game_config().turn_time_limit_seconds());
+        let remaining = self.turn_started_at + time_limit - now;
+        if remaining < Duration::zero() {
+            Duration::zero()
+        } else {
+            remaining
+        }
+    }
+
     /// ゲームが終了しているかどうか（最終ターンに達しているか）
     pub fn is_game_finished(&self) -> bool {
         self.current_turn_number.value() >= Self::MAX_TURNS
@@ -88,6 +164,101 @@ impl Game {
         &self.player2_id
     }
 
+    /// リポジトリから取得した時点のバージョン
+    pub fn version(&self) -> &GameVersion {
+        &self.version
+    }
+
+    pub fn turn_started_at(&self) -> &DateTime<Utc> {
+        &self.turn_started_at
+    }
+
+    pub fn spectators(&self) -> &[Spectator] {
+        &self.spectators
+    }
+
+    pub fn status(&self) -> &GameStatus {
+        &self.status
+    }
+
+    pub fn simulation_seed(&self) -> &SimulationSeed {
+        &self.simulation_seed
+    }
+
+    /// 観戦者を追加
+    /// 既に観戦中の場合は視点を変更する
+    ///
+    /// # Business Rules
+    /// - 対戦者は自分のゲームを観戦者として登録できない
+    /// - 対戦者の視点で観戦する場合、視点はこのゲームの対戦者でなければならない
+    /// - 観戦者数には上限がある
+    pub fn add_spectator(&mut self, spectator: Spectator) -> Result<(), String> {
+        if self.is_participant(spectator.player_id()) {
+            return Err("対戦者は自分のゲームを観戦できません".to_string());
+        }
+        if let Some(side_player_id) = spectator.side_player_id() {
+            if !self.is_participant(side_player_id) {
+                return Err(
+                    "観戦する視点のプレイヤーがこのゲームの参加者ではありません".to_string()
+                );
+            }
+        }
+        if let Some(existing) = self.spectators.iter_mut().find(|s| *s == &spectator) {
+            *existing = spectator;
+            return Ok(());
+        }
+        if self.spectators.len() >= Self::MAX_SPECTATORS {
+            return Err(format!(
+                "観戦者数が上限（{}人）に達しています",
+                Self::MAX_SPECTATORS
+            ));
+        }
+        self.spectators.push(spectator);
+        Ok(())
+    }
+
+    /// 観戦者を削除
+    pub fn remove_spectator(&mut self, player_id: &PlayerId) -> Result<(), String> {
+        if !self.is_spectator(player_id) {
+            return Err("指定されたプレイヤーIDはこのゲームを観戦していません".to_string());
+        }
+        self.spectators.retain(|s| s.player_id() != player_id);
+        Ok(())
+    }
+
+    /// 指定されたプレイヤーがこのゲームの観戦者かどうか
+    pub fn is_spectator(&self, player_id: &PlayerId) -> bool {
+        self.spectators.iter().any(|s| s.player_id() == player_id)
+    }
+
+    /// 指定されたプレイヤーがこのゲームの対戦者かどうか
+    pub fn is_participant(&self, player_id: &PlayerId) -> bool {
+        player_id == self.player1_id() || player_id == self.player2_id()
+    }
+
+    /// 指定されたプレイヤーがこのゲームを観戦できるかどうか
+    /// 対戦者は自分のゲームを観戦者と同じ視点でも閲覧できる
+    pub fn can_spectate(&self, player_id: &PlayerId) -> bool {
+        self.is_participant(player_id) || self.is_spectator(player_id)
+    }
+
+    /// 指定されたプレイヤーが権限を持つか確認
+    pub fn authorize(
+        &self,
+        player_id: &PlayerId,
+        permission: GamePermission,
+    ) -> Result<(), String> {
+        match permission {
+            GamePermission::Play if !self.is_participant(player_id) => {
+                Err("指定されたプレイヤーIDはこのゲームの参加者ではありません".to_string())
+            }
+            GamePermission::Spectate if !self.can_spectate(player_id) => {
+                Err("指定されたプレイヤーIDはこのゲームを観戦できません".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// 指定されたプレイヤーIDに対応する対戦相手のプレイヤーIDを取得
     pub fn get_opponent_player_id(&self, player_id: &PlayerId) -> Result<PlayerId, String> {
         if player_id == self.player1_id() {
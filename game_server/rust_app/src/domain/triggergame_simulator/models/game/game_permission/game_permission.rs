@@ -0,0 +1,8 @@
+/// ゲームに対する操作の権限
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GamePermission {
+    /// 対戦者としての操作（ターンの登録、自陣営視点のゲーム状態・ターン結果の取得）
+    Play,
+    /// 観戦者としての閲覧（リプレイの取得など、陣営に依存しない情報の閲覧）
+    Spectate,
+}
@@ -0,0 +1,2 @@
+pub mod game_result;
+pub mod game_result_test;
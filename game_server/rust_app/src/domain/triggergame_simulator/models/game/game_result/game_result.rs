@@ -0,0 +1,52 @@
+use crate::domain::{
+    player_management::models::player::player_id::player_id::PlayerId,
+    triggergame_simulator::models::game::game::Game, unit_management::models::unit::Unit,
+};
+
+/// 対戦結果
+/// 最終ターン終了時点でベイルアウトしていないユニットが多いプレイヤーを勝者とする
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameResult {
+    /// 勝者が決まった場合
+    Winner(PlayerId),
+    /// 残りユニット数が同じ場合
+    Draw,
+}
+
+impl GameResult {
+    /// 勝利時のスコア
+    const WIN_SCORE: f64 = 1.0;
+    /// 引き分け時のスコア
+    const DRAW_SCORE: f64 = 0.5;
+    /// 敗北時のスコア
+    const LOSE_SCORE: f64 = 0.0;
+
+    /// ゲーム終了時点のユニットから対戦結果を判定
+    pub fn judge(game: &Game, units: &[Unit]) -> Self {
+        let count_active_units = |player_id: &PlayerId| {
+            units
+                .iter()
+                .filter(|unit| unit.owner_player_id() == player_id && unit.is_active())
+                .count()
+        };
+        let player1_units = count_active_units(game.player1_id());
+        let player2_units = count_active_units(game.player2_id());
+
+        if player1_units > player2_units {
+            GameResult::Winner(game.player1_id().clone())
+        } else if player2_units > player1_units {
+            GameResult::Winner(game.player2_id().clone())
+        } else {
+            GameResult::Draw
+        }
+    }
+
+    /// 指定されたプレイヤーのスコア（勝利: 1.0、引き分け: 0.5、敗北: 0.0）を取得
+    pub fn score_for(&self, player_id: &PlayerId) -> f64 {
+        match self {
+            GameResult::Winner(winner_id) if winner_id == player_id => Self::WIN_SCORE,
+            GameResult::Winner(_) => Self::LOSE_SCORE,
+            GameResult::Draw => Self::DRAW_SCORE,
+        }
+    }
+}
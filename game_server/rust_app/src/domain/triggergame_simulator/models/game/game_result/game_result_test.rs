@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod tests {
+    use super::super::game_result::GameResult;
+    use crate::domain::player_management::models::player::player_id::player_id::PlayerId;
+    use crate::domain::triggergame_simulator::models::game::{
+        game::Game, game_id::game_id::GameId,
+    };
+    use crate::domain::unit_management::models::unit::{
+        having_trigger_ids::having_trigger_ids::HavingTriggerIds, position::position::Position,
+        trigger_id::trigger_id::TriggerId, unit_type_id::unit_type_id::UnitTypeId, Unit,
+    };
+    use uuid::Uuid;
+
+    fn create_game() -> Game {
+        Game::create(
+            GameId::new(Uuid::new_v4().to_string()),
+            &PlayerId::new(Uuid::new_v4().to_string()),
+            &PlayerId::new(Uuid::new_v4().to_string()),
+        )
+    }
+
+    fn create_unit(game: &Game, owner_player_id: &PlayerId, is_bailed_out: bool) -> Unit {
+        let mut unit = Unit::create(
+            UnitTypeId::new("MIKUMO_OSAMU".to_string()),
+            game.game_id().clone(),
+            owner_player_id.clone(),
+            Position::new(1, 1),
+            TriggerId::new("main_trigger_001".to_string()),
+            TriggerId::new("sub_trigger_001".to_string()),
+            HavingTriggerIds::new(vec![TriggerId::new("main_trigger_001".to_string())]),
+            HavingTriggerIds::new(vec![TriggerId::new("sub_trigger_001".to_string())]),
+            100,
+            100,
+            8,
+            13,
+        );
+        if is_bailed_out {
+            unit.bailout();
+        }
+        unit
+    }
+
+    #[test]
+    fn test_judge_winner_by_active_units() {
+        let game = create_game();
+        let units = vec![
+            create_unit(&game, game.player1_id(), false),
+            create_unit(&game, game.player1_id(), true),
+            create_unit(&game, game.player2_id(), false),
+            create_unit(&game, game.player2_id(), false),
+        ];
+
+        let result = GameResult::judge(&game, &units);
+
+        assert_eq!(result, GameResult::Winner(game.player2_id().clone()));
+        assert_eq!(result.score_for(game.player2_id()), 1.0);
+        assert_eq!(result.score_for(game.player1_id()), 0.0);
+    }
+
+    #[test]
+    fn test_judge_draw() {
+        let game = create_game();
+        let units = vec![
+            create_unit(&game, game.player1_id(), false),
+            create_unit(&game, game.player2_id(), false),
+        ];
+
+        let result = GameResult::judge(&game, &units);
+
+        assert_eq!(result, GameResult::Draw);
+        assert_eq!(result.score_for(game.player1_id()), 0.5);
+        assert_eq!(result.score_for(game.player2_id()), 0.5);
+    }
+
+    #[test]
+    fn test_judge_draw_when_all_units_bailed_out() {
+        let game = create_game();
+        let units = vec![
+            create_unit(&game, game.player1_id(), true),
+            create_unit(&game, game.player2_id(), true),
+        ];
+
+        assert_eq!(GameResult::judge(&game, &units), GameResult::Draw);
+    }
+}
@@ -0,0 +1,2 @@
+pub mod game_status;
+pub mod game_status_test;
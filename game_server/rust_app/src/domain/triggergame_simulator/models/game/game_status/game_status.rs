@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GameStatusValue {
+    InProgress, // 対戦中
+    Finished,   // 対戦終了
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct GameStatus {
+    value: GameStatusValue,
+}
+
+impl GameStatus {
+    pub fn new(value: GameStatusValue) -> Self {
+        Self { value }
+    }
+    /// 文字列から新しいインスタンスを生成
+    /// データベースから取得した値からの変換に使用
+    pub fn new_string(value: &str) -> Self {
+        let status_value = match value {
+            "InProgress" => GameStatusValue::InProgress,
+            "Finished" => GameStatusValue::Finished,
+            _ => panic!("Invalid GameStatusValue string"),
+        };
+        Self {
+            value: status_value,
+        }
+    }
+
+    /// 対戦開始時の状態
+    pub fn initial() -> Self {
+        Self::new(GameStatusValue::InProgress)
+    }
+
+    pub fn fmt_value(&self) -> String {
+        match self.value {
+            GameStatusValue::InProgress => "InProgress".to_string(),
+            GameStatusValue::Finished => "Finished".to_string(),
+        }
+    }
+
+    pub fn is_in_progress(&self) -> bool {
+        matches!(self.value, GameStatusValue::InProgress)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        matches!(self.value, GameStatusValue::Finished)
+    }
+}
+
+impl PartialEq for GameStatus {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for GameStatus {}
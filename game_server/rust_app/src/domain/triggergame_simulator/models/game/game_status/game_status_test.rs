@@ -0,0 +1,42 @@
+#[cfg(test)]
+mod tests {
+    use super::super::game_status::{GameStatus, GameStatusValue};
+
+    #[test]
+    fn test_initial() {
+        let status = GameStatus::initial();
+        assert!(status.is_in_progress());
+        assert!(!status.is_finished());
+    }
+
+    #[test]
+    fn test_finished() {
+        let status = GameStatus::new(GameStatusValue::Finished);
+        assert!(!status.is_in_progress());
+        assert!(status.is_finished());
+    }
+
+    #[test]
+    fn test_new_string() {
+        for status in [
+            GameStatus::new(GameStatusValue::InProgress),
+            GameStatus::new(GameStatusValue::Finished),
+        ] {
+            assert_eq!(GameStatus::new_string(&status.fmt_value()), status);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid GameStatusValue string")]
+    fn test_new_string_invalid() {
+        GameStatus::new_string("Unknown");
+    }
+
+    #[test]
+    fn test_equality() {
+        let status1 = GameStatus::new(GameStatusValue::Finished);
+        let status2 = GameStatus::new(GameStatusValue::Finished);
+        assert_eq!(status1, status2);
+        assert_ne!(status1, GameStatus::initial());
+    }
+}
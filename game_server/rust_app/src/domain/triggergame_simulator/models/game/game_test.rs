@@ -3,7 +3,13 @@ mod tests {
     use super::super::current_turn_number::current_turn_number::CurrentTurnNumber;
     use super::super::game::Game;
     use super::super::game_id::game_id::GameId;
+    use super::super::game_permission::game_permission::GamePermission;
+    use super::super::game_status::game_status::{GameStatus, GameStatusValue};
+    use super::super::game_version::game_version::GameVersion;
+    use super::super::simulation_seed::simulation_seed::SimulationSeed;
+    use super::super::spectator::spectator::{Spectator, SpectatorView};
     use crate::domain::player_management::models::player::player_id::player_id::PlayerId;
+    use chrono::{Duration, Utc};
     use uuid::Uuid;
 
     fn create_player_id() -> PlayerId {
@@ -19,6 +25,7 @@ mod tests {
 
         assert_eq!(game.game_id(), &game_id);
         assert_eq!(game.current_turn_number().value(), 1);
+        assert_eq!(game.version(), &GameVersion::initial());
         assert!(!game.is_game_finished());
     }
 
@@ -63,7 +70,17 @@ mod tests {
         let player1_id = create_player_id();
         let player2_id = create_player_id();
 
-        let mut game = Game::reconstruct(game_id, current_turn_number, player1_id, player2_id);
+        let mut game = Game::reconstruct(
+            game_id,
+            current_turn_number,
+            player1_id,
+            player2_id,
+            GameVersion::initial(),
+            Utc::now(),
+            Vec::new(),
+            GameStatus::initial(),
+            SimulationSeed::generate(),
+        );
         assert!(game.is_game_finished());
 
         let result = game.advance_to_next_turn();
@@ -71,6 +88,45 @@ mod tests {
         assert_eq!(result.unwrap_err(), "ゲームは既に最終ターンに達しています");
     }
 
+    #[test]
+    fn test_finish() {
+        let mut game = Game::reconstruct(
+            GameId::new(Uuid::new_v4().to_string()),
+            CurrentTurnNumber::new(6),
+            create_player_id(),
+            create_player_id(),
+            GameVersion::initial(),
+            Utc::now(),
+            Vec::new(),
+            GameStatus::initial(),
+            SimulationSeed::generate(),
+        );
+        assert!(game.status().is_in_progress());
+
+        game.finish().unwrap();
+        assert_eq!(game.status(), &GameStatus::new(GameStatusValue::Finished));
+
+        // 終了済みのゲームは再度終了できない
+        let result = game.finish();
+        assert_eq!(result.unwrap_err(), "ゲームは既に終了しています");
+    }
+
+    #[test]
+    fn test_finish_before_final_turn() {
+        let mut game = Game::create(
+            GameId::new(Uuid::new_v4().to_string()),
+            &create_player_id(),
+            &create_player_id(),
+        );
+
+        let result = game.finish();
+        assert_eq!(
+            result.unwrap_err(),
+            "ゲームはまだ最終ターンに達していません"
+        );
+        assert!(game.status().is_in_progress());
+    }
+
     #[test]
     fn test_reconstruct_game() {
         let game_id = GameId::new(Uuid::new_v4().to_string());
@@ -83,10 +139,16 @@ mod tests {
             current_turn_number.clone(),
             player1_id.clone(),
             player2_id.clone(),
+            GameVersion::new(2),
+            Utc::now(),
+            Vec::new(),
+            GameStatus::initial(),
+            SimulationSeed::generate(),
         );
 
         assert_eq!(game.game_id(), &game_id);
         assert_eq!(game.current_turn_number(), &current_turn_number);
+        assert_eq!(game.version().value(), 2);
     }
 
     #[test]
@@ -101,13 +163,201 @@ mod tests {
             current_turn_number.clone(),
             player1_id.clone(),
             player2_id.clone(),
+            GameVersion::new(2),
+            Utc::now(),
+            Vec::new(),
+            GameStatus::initial(),
+            SimulationSeed::generate(),
         );
         let game2 = Game::reconstruct(
             game_id.clone(),
             current_turn_number.clone(),
             player1_id.clone(),
             player2_id.clone(),
+            GameVersion::new(2),
+            Utc::now(),
+            Vec::new(),
+            GameStatus::initial(),
+            SimulationSeed::generate(),
         );
         assert_eq!(game1, game2);
     }
+
+    #[test]
+    fn test_remaining_turn_time() {
+        let started_at = Utc::now();
+        let game = Game::reconstruct(
+            GameId::new(Uuid::new_v4().to_string()),
+            CurrentTurnNumber::new(2),
+            create_player_id(),
+            create_player_id(),
+            GameVersion::initial(),
+            started_at,
+            Vec::new(),
+            GameStatus::initial(),
+            SimulationSeed::generate(),
+        );
+
+        assert_eq!(
+            game.remaining_turn_time(started_at + Duration::seconds(30)),
+            Duration::seconds(90)
+        );
+        // 制限時間を過ぎている場合は0になる
+        assert_eq!(
+            game.remaining_turn_time(started_at + Duration::seconds(300)),
+            Duration::zero()
+        );
+    }
+
+    #[test]
+    fn test_advance_to_next_turn_resets_turn_started_at() {
+        let started_at = Utc::now() - Duration::seconds(600);
+        let mut game = Game::reconstruct(
+            GameId::new(Uuid::new_v4().to_string()),
+            CurrentTurnNumber::new(2),
+            create_player_id(),
+            create_player_id(),
+            GameVersion::initial(),
+            started_at,
+            Vec::new(),
+            GameStatus::initial(),
+            SimulationSeed::generate(),
+        );
+
+        game.advance_to_next_turn().unwrap();
+        assert!(game.turn_started_at() > &started_at);
+        assert!(game.remaining_turn_time(Utc::now()) > Duration::zero());
+    }
+
+    #[test]
+    fn test_authorize_participant() {
+        let player1_id = create_player_id();
+        let player2_id = create_player_id();
+        let game = Game::create(
+            GameId::new(Uuid::new_v4().to_string()),
+            &player1_id,
+            &player2_id,
+        );
+
+        // 対戦者は対戦・観戦どちらの権限も持つ
+        for player_id in [&player1_id, &player2_id] {
+            assert!(game.is_participant(player_id));
+            assert!(game.authorize(player_id, GamePermission::Play).is_ok());
+            assert!(game.authorize(player_id, GamePermission::Spectate).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_authorize_non_participant() {
+        let game = Game::create(
+            GameId::new(Uuid::new_v4().to_string()),
+            &create_player_id(),
+            &create_player_id(),
+        );
+        let outsider_id = create_player_id();
+
+        assert!(!game.is_participant(&outsider_id));
+        assert_eq!(
+            game.authorize(&outsider_id, GamePermission::Play)
+                .unwrap_err(),
+            "指定されたプレイヤーIDはこのゲームの参加者ではありません"
+        );
+        assert_eq!(
+            game.authorize(&outsider_id, GamePermission::Spectate)
+                .unwrap_err(),
+            "指定されたプレイヤーIDはこのゲームを観戦できません"
+        );
+    }
+
+    #[test]
+    fn test_add_spectator() {
+        let player1_id = create_player_id();
+        let mut game = Game::create(
+            GameId::new(Uuid::new_v4().to_string()),
+            &player1_id,
+            &create_player_id(),
+        );
+        let spectator_id = create_player_id();
+
+        assert!(!game.can_spectate(&spectator_id));
+        game.add_spectator(Spectator::create(
+            spectator_id.clone(),
+            SpectatorView::Omniscient,
+        ))
+        .unwrap();
+
+        assert!(game.is_spectator(&spectator_id));
+        assert!(game.can_spectate(&spectator_id));
+        assert!(game
+            .authorize(&spectator_id, GamePermission::Spectate)
+            .is_ok());
+        assert!(game.authorize(&spectator_id, GamePermission::Play).is_err());
+
+        // 再登録すると視点が置き換わり、観戦者は重複しない
+        game.add_spectator(Spectator::create(
+            spectator_id.clone(),
+            SpectatorView::Side {
+                player_id: player1_id.clone(),
+            },
+        ))
+        .unwrap();
+        assert_eq!(game.spectators().len(), 1);
+        assert_eq!(game.spectators()[0].side_player_id(), Some(&player1_id));
+    }
+
+    #[test]
+    fn test_add_spectator_rejects_participant() {
+        let player1_id = create_player_id();
+        let mut game = Game::create(
+            GameId::new(Uuid::new_v4().to_string()),
+            &player1_id,
+            &create_player_id(),
+        );
+
+        let result = game.add_spectator(Spectator::create(player1_id, SpectatorView::Omniscient));
+        assert_eq!(result.unwrap_err(), "対戦者は自分のゲームを観戦できません");
+        assert!(game.spectators().is_empty());
+    }
+
+    #[test]
+    fn test_add_spectator_rejects_side_of_non_participant() {
+        let mut game = Game::create(
+            GameId::new(Uuid::new_v4().to_string()),
+            &create_player_id(),
+            &create_player_id(),
+        );
+
+        let result = game.add_spectator(Spectator::create(
+            create_player_id(),
+            SpectatorView::Side {
+                player_id: create_player_id(),
+            },
+        ));
+        assert_eq!(
+            result.unwrap_err(),
+            "観戦する視点のプレイヤーがこのゲームの参加者ではありません"
+        );
+    }
+
+    #[test]
+    fn test_remove_spectator() {
+        let mut game = Game::create(
+            GameId::new(Uuid::new_v4().to_string()),
+            &create_player_id(),
+            &create_player_id(),
+        );
+        let spectator_id = create_player_id();
+        game.add_spectator(Spectator::create(
+            spectator_id.clone(),
+            SpectatorView::Omniscient,
+        ))
+        .unwrap();
+
+        assert!(game.remove_spectator(&spectator_id).is_ok());
+        assert!(!game.is_spectator(&spectator_id));
+        assert_eq!(
+            game.remove_spectator(&spectator_id).unwrap_err(),
+            "指定されたプレイヤーIDはこのゲームを観戦していません"
+        );
+    }
 }
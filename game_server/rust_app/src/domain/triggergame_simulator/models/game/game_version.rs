@@ -0,0 +1,2 @@
+pub mod game_version;
+pub mod game_version_test;
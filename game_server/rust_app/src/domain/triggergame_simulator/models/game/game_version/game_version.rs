@@ -0,0 +1,42 @@
+/// ゲーム情報のバージョン
+/// 楽観的ロックによる同時更新の検知に使用する
+#[derive(Debug, Clone)]
+pub struct GameVersion {
+    value: i32,
+}
+
+impl GameVersion {
+    pub fn new(value: i32) -> Self {
+        Self::validate(value);
+        Self { value }
+    }
+
+    pub fn value(&self) -> i32 {
+        self.value
+    }
+
+    pub fn initial() -> Self {
+        Self { value: 1 }
+    }
+
+    /// 更新後のバージョンを取得
+    pub fn next(&self) -> Self {
+        Self {
+            value: self.value + 1,
+        }
+    }
+
+    fn validate(value: i32) {
+        if value < 1 {
+            panic!("GameVersionは1以上である必要があります");
+        }
+    }
+}
+
+impl PartialEq for GameVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for GameVersion {}
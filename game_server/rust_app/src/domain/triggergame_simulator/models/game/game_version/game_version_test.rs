@@ -0,0 +1,24 @@
+#[cfg(test)]
+mod tests {
+    use super::super::game_version::GameVersion;
+
+    #[test]
+    fn test_initial() {
+        let version = GameVersion::initial();
+        assert_eq!(version.value(), 1);
+    }
+
+    #[test]
+    fn test_next() {
+        let version = GameVersion::new(3);
+        assert_eq!(version.next().value(), 4);
+        // 元のバージョンは変わらない
+        assert_eq!(version.value(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "GameVersionは1以上である必要があります")]
+    fn test_invalid_version() {
+        GameVersion::new(0);
+    }
+}
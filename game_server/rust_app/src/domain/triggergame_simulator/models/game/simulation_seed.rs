@@ -0,0 +1,2 @@
+pub mod simulation_seed;
+pub mod simulation_seed_test;
@@ -0,0 +1,62 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::triggergame_simulator::models::game::game_id::game_id::GameId;
+use crate::domain::triggergame_simulator::models::turn::turn_number::turn_number::TurnNumber;
+
+/// 戦闘演算で使用する乱数のシード
+/// 対戦成立時にランダムに生成してゲームに保存し、同じゲーム・同じターンの演算は常に同じ乱数列で行われる
+/// シードが分かると全ての回避判定を予測できるため、対戦終了まではプレイヤーに公開しない
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SimulationSeed {
+    value: u32,
+}
+
+impl SimulationSeed {
+    // FNV-1a(32bit)のパラメータ
+    const FNV_OFFSET_BASIS: u32 = 0x811c9dc5;
+    const FNV_PRIME: u32 = 0x01000193;
+
+    pub fn new(value: u32) -> Self {
+        Self { value }
+    }
+
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    /// 新規ゲーム用のシードをランダムに生成
+    pub fn generate() -> Self {
+        Self {
+            value: rand::random(),
+        }
+    }
+
+    /// ゲームIDからシードを生成
+    /// シードの保存を導入する前に作成されたゲームの演算にのみ使用する
+    /// 実行環境に依存しないよう、標準のHasherではなくFNV-1aで計算する
+    pub fn from_game_id(game_id: &GameId) -> Self {
+        let value = game_id
+            .value()
+            .bytes()
+            .fold(Self::FNV_OFFSET_BASIS, |hash, byte| {
+                (hash ^ byte as u32).wrapping_mul(Self::FNV_PRIME)
+            });
+        Self { value }
+    }
+
+    /// 指定したターンの演算に使用する乱数生成器を取得
+    pub fn rng_for_turn(&self, turn_number: &TurnNumber) -> StdRng {
+        StdRng::seed_from_u64(((self.value as u64) << 32) | turn_number.value() as u64)
+    }
+}
+
+impl PartialEq for SimulationSeed {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for SimulationSeed {}
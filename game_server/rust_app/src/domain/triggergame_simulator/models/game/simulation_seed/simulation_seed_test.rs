@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod tests {
+    use super::super::simulation_seed::SimulationSeed;
+    use crate::domain::triggergame_simulator::models::game::game_id::game_id::GameId;
+    use crate::domain::triggergame_simulator::models::turn::turn_number::turn_number::TurnNumber;
+    use rand::Rng;
+
+    #[test]
+    fn test_generate() {
+        // ゲームごとに異なるシードが生成されること
+        let seeds: Vec<SimulationSeed> = (0..10).map(|_| SimulationSeed::generate()).collect();
+        assert!(seeds.iter().any(|seed| seed != &seeds[0]));
+    }
+
+    #[test]
+    fn test_from_game_id() {
+        let game_id = GameId::new("550e8400-e29b-41d4-a716-446655440000".to_string());
+        let other_game_id = GameId::new("550e8400-e29b-41d4-a716-446655440001".to_string());
+
+        // 同じゲームIDからは常に同じシードが生成されること
+        assert_eq!(
+            SimulationSeed::from_game_id(&game_id),
+            SimulationSeed::from_game_id(&game_id)
+        );
+        assert_ne!(
+            SimulationSeed::from_game_id(&game_id),
+            SimulationSeed::from_game_id(&other_game_id)
+        );
+    }
+
+    #[test]
+    fn test_rng_for_turn() {
+        let seed = SimulationSeed::new(12345);
+
+        let mut rng = seed.rng_for_turn(&TurnNumber::new(1));
+        let mut same_rng = seed.rng_for_turn(&TurnNumber::new(1));
+        let mut other_turn_rng = seed.rng_for_turn(&TurnNumber::new(2));
+
+        let values: Vec<f64> = (0..5).map(|_| rng.random()).collect();
+        let same_values: Vec<f64> = (0..5).map(|_| same_rng.random()).collect();
+        let other_turn_values: Vec<f64> = (0..5).map(|_| other_turn_rng.random()).collect();
+        // 同じシード・同じターンでは同じ乱数列になること
+        assert_eq!(values, same_values);
+        assert_ne!(values, other_turn_values);
+    }
+}
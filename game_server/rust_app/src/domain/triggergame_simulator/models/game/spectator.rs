@@ -0,0 +1,2 @@
+pub mod spectator;
+pub mod spectator_test;
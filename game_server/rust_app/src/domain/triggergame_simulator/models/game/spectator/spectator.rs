@@ -0,0 +1,73 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::player_management::models::player::player_id::player_id::PlayerId;
+
+/// 観戦時の視点
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(
+    tag = "type",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum SpectatorView {
+    /// 両陣営の情報をすべて表示する
+    #[default]
+    Omniscient,
+    /// 指定した対戦者の視点で表示する（相手陣営には対戦者と同じ索敵制限がかかる）
+    Side { player_id: PlayerId },
+}
+
+/// ゲームの観戦者
+#[derive(Debug, Clone)]
+pub struct Spectator {
+    player_id: PlayerId,
+    view: SpectatorView,
+    joined_at: DateTime<Utc>,
+}
+
+impl Spectator {
+    pub fn new(player_id: PlayerId, view: SpectatorView, joined_at: DateTime<Utc>) -> Self {
+        Self {
+            player_id,
+            view,
+            joined_at,
+        }
+    }
+
+    /// 新規観戦者の生成
+    pub fn create(player_id: PlayerId, view: SpectatorView) -> Self {
+        Self::new(player_id, view, Utc::now())
+    }
+
+    /// 観戦者の視点で表示する対戦者のプレイヤーID
+    /// 両陣営を表示する場合はNoneを返す
+    pub fn side_player_id(&self) -> Option<&PlayerId> {
+        match &self.view {
+            SpectatorView::Omniscient => None,
+            SpectatorView::Side { player_id } => Some(player_id),
+        }
+    }
+
+    // ゲッター
+    pub fn player_id(&self) -> &PlayerId {
+        &self.player_id
+    }
+
+    pub fn view(&self) -> &SpectatorView {
+        &self.view
+    }
+
+    pub fn joined_at(&self) -> &DateTime<Utc> {
+        &self.joined_at
+    }
+}
+
+// 等価性の比較を実装（同一性はPlayerIdで判定）
+impl PartialEq for Spectator {
+    fn eq(&self, other: &Self) -> bool {
+        self.player_id == other.player_id
+    }
+}
+
+impl Eq for Spectator {}
@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod tests {
+    use super::super::spectator::{Spectator, SpectatorView};
+    use crate::domain::player_management::models::player::player_id::player_id::PlayerId;
+    use uuid::Uuid;
+
+    fn create_player_id() -> PlayerId {
+        PlayerId::new(Uuid::new_v4().to_string())
+    }
+
+    #[test]
+    fn test_side_player_id() {
+        let side_player_id = create_player_id();
+        let omniscient = Spectator::create(create_player_id(), SpectatorView::Omniscient);
+        let side = Spectator::create(
+            create_player_id(),
+            SpectatorView::Side {
+                player_id: side_player_id.clone(),
+            },
+        );
+
+        assert_eq!(omniscient.side_player_id(), None);
+        assert_eq!(side.side_player_id(), Some(&side_player_id));
+    }
+
+    #[test]
+    fn test_equality_by_player_id() {
+        let player_id = create_player_id();
+        let spectator1 = Spectator::create(player_id.clone(), SpectatorView::Omniscient);
+        let spectator2 = Spectator::create(
+            player_id,
+            SpectatorView::Side {
+                player_id: create_player_id(),
+            },
+        );
+
+        assert_eq!(spectator1, spectator2);
+        assert_ne!(
+            spectator1,
+            Spectator::create(create_player_id(), SpectatorView::Omniscient)
+        );
+    }
+
+    #[test]
+    fn test_view_deserialize() {
+        let player_id = create_player_id();
+        let omniscient: SpectatorView = serde_json::from_str(r#"{"type":"omniscient"}"#).unwrap();
+        let side: SpectatorView = serde_json::from_str(&format!(
+            r#"{{"type":"side","playerId":"{}"}}"#,
+            player_id.value()
+        ))
+        .unwrap();
+
+        assert_eq!(omniscient, SpectatorView::Omniscient);
+        assert_eq!(side, SpectatorView::Side { player_id });
+    }
+}
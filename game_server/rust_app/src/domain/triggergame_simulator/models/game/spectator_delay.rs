@@ -0,0 +1,2 @@
+pub mod spectator_delay;
+pub mod spectator_delay_test;
@@ -0,0 +1,68 @@
+/// 観戦者への配信の遅延（ターン数）
+/// 観戦者から対戦者へ情報が漏れないよう、演算済みのターンを指定ターン数遅らせて配信する
+#[derive(Debug, Clone, Copy)]
+pub struct SpectatorDelay {
+    turns: i32,
+}
+
+impl SpectatorDelay {
+    /// 遅延ターン数の既定値
+    const DEFAULT_TURNS: i32 = 1;
+
+    pub fn new(turns: i32) -> Self {
+        if turns < 0 {
+            panic!("SpectatorDelayは0以上である必要があります");
+        }
+        Self { turns }
+    }
+
+    pub fn turns(&self) -> i32 {
+        self.turns
+    }
+
+    /// ターンの演算完了時に観戦者へ配信するターン番号
+    /// ゲーム終了時は遅延させていた残りのターンもまとめて配信する
+    ///
+    /// # Arguments
+    /// * `resolved_turn_number` - 演算が完了したターン番号
+    /// * `is_game_over` - ゲームが終了したかどうか
+    pub fn turns_to_broadcast(&self, resolved_turn_number: i32, is_game_over: bool) -> Vec<i32> {
+        let first = (resolved_turn_number - self.turns).max(1);
+        if is_game_over {
+            (first..=resolved_turn_number).collect()
+        } else if resolved_turn_number - self.turns >= 1 {
+            vec![first]
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// 観戦者が閲覧できる最新のターン番号
+    /// 閲覧できるターンがない場合は0を返す
+    ///
+    /// # Arguments
+    /// * `latest_resolved_turn_number` - 演算が完了している最新のターン番号（未完了の場合は0）
+    /// * `is_game_over` - ゲームが終了したかどうか
+    pub fn latest_visible_turn(&self, latest_resolved_turn_number: i32, is_game_over: bool) -> i32 {
+        if is_game_over {
+            latest_resolved_turn_number
+        } else {
+            (latest_resolved_turn_number - self.turns).max(0)
+        }
+    }
+}
+
+impl Default for SpectatorDelay {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_TURNS)
+    }
+}
+
+// 等価性の比較を実装
+impl PartialEq for SpectatorDelay {
+    fn eq(&self, other: &Self) -> bool {
+        self.turns == other.turns
+    }
+}
+
+impl Eq for SpectatorDelay {}
@@ -0,0 +1,50 @@
+#[cfg(test)]
+mod tests {
+    use super::super::spectator_delay::SpectatorDelay;
+
+    #[test]
+    fn test_default() {
+        assert_eq!(SpectatorDelay::default().turns(), 1);
+    }
+
+    #[test]
+    fn test_turns_to_broadcast_without_delay() {
+        let delay = SpectatorDelay::new(0);
+        assert_eq!(delay.turns_to_broadcast(1, false), vec![1]);
+        assert_eq!(delay.turns_to_broadcast(6, true), vec![6]);
+    }
+
+    #[test]
+    fn test_turns_to_broadcast_with_delay() {
+        let delay = SpectatorDelay::new(2);
+        // 遅延ターン数に達するまでは配信しない
+        assert!(delay.turns_to_broadcast(1, false).is_empty());
+        assert!(delay.turns_to_broadcast(2, false).is_empty());
+        assert_eq!(delay.turns_to_broadcast(3, false), vec![1]);
+        assert_eq!(delay.turns_to_broadcast(5, false), vec![3]);
+    }
+
+    #[test]
+    fn test_turns_to_broadcast_flushes_on_game_over() {
+        let delay = SpectatorDelay::new(2);
+        // 遅延させていた残りのターンをまとめて配信する
+        assert_eq!(delay.turns_to_broadcast(6, true), vec![4, 5, 6]);
+        // 遅延ターン数より短いゲームでもすべて配信する
+        assert_eq!(delay.turns_to_broadcast(1, true), vec![1]);
+    }
+
+    #[test]
+    fn test_latest_visible_turn() {
+        let delay = SpectatorDelay::new(2);
+        assert_eq!(delay.latest_visible_turn(0, false), 0);
+        assert_eq!(delay.latest_visible_turn(1, false), 0);
+        assert_eq!(delay.latest_visible_turn(4, false), 2);
+        assert_eq!(delay.latest_visible_turn(6, true), 6);
+    }
+
+    #[test]
+    #[should_panic(expected = "SpectatorDelayは0以上である必要があります")]
+    fn test_negative() {
+        SpectatorDelay::new(-1);
+    }
+}
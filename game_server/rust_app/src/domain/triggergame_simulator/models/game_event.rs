@@ -0,0 +1,6 @@
+pub mod event_sequence;
+pub mod game_event;
+pub mod game_event_payload;
+pub mod game_event_projector;
+pub mod game_event_projector_test;
+pub mod game_event_test;
@@ -0,0 +1,2 @@
+pub mod event_sequence;
+pub mod event_sequence_test;
@@ -0,0 +1,42 @@
+/// ゲームイベントの通し番号
+/// ゲームごとに1から順に採番され、イベントの発生順を表す
+#[derive(Debug, Clone)]
+pub struct EventSequence {
+    value: i32,
+}
+
+impl EventSequence {
+    pub fn new(value: i32) -> Self {
+        Self::validate(value);
+        Self { value }
+    }
+
+    pub fn value(&self) -> i32 {
+        self.value
+    }
+
+    pub fn initial() -> Self {
+        Self { value: 1 }
+    }
+
+    /// 次の通し番号を取得
+    pub fn next(&self) -> Self {
+        Self {
+            value: self.value + 1,
+        }
+    }
+
+    fn validate(value: i32) {
+        if value < 1 {
+            panic!("EventSequenceは1以上である必要があります");
+        }
+    }
+}
+
+impl PartialEq for EventSequence {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for EventSequence {}
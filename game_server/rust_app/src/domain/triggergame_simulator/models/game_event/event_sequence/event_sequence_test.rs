@@ -0,0 +1,22 @@
+#[cfg(test)]
+mod tests {
+    use super::super::event_sequence::EventSequence;
+
+    #[test]
+    fn test_initial() {
+        let sequence = EventSequence::initial();
+        assert_eq!(sequence.value(), 1);
+    }
+
+    #[test]
+    fn test_next() {
+        let sequence = EventSequence::new(5);
+        assert_eq!(sequence.next().value(), 6);
+    }
+
+    #[test]
+    #[should_panic(expected = "EventSequenceは1以上である必要があります")]
+    fn test_invalid_sequence() {
+        EventSequence::new(0);
+    }
+}
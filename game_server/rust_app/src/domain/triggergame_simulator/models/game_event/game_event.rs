@@ -0,0 +1,72 @@
+use crate::domain::triggergame_simulator::models::game::game_id::game_id::GameId;
+use chrono::{DateTime, Utc};
+
+use super::event_sequence::event_sequence::EventSequence;
+use super::game_event_payload::GameEventPayload;
+
+/// GameEventエンティティ
+/// ゲーム中に発生した出来事を表す追記専用の記録
+#[derive(Debug, Clone)]
+pub struct GameEvent {
+    game_id: GameId,
+    sequence: EventSequence,
+    occurred_at: DateTime<Utc>,
+    payload: GameEventPayload,
+}
+
+impl GameEvent {
+    // privateなコンストラクタ
+    fn new(
+        game_id: GameId,
+        sequence: EventSequence,
+        occurred_at: DateTime<Utc>,
+        payload: GameEventPayload,
+    ) -> Self {
+        Self {
+            game_id,
+            sequence,
+            occurred_at,
+            payload,
+        }
+    }
+
+    /// 新規イベントの生成
+    pub fn create(game_id: GameId, sequence: EventSequence, payload: GameEventPayload) -> Self {
+        Self::new(game_id, sequence, Utc::now(), payload)
+    }
+
+    /// イベントの再構築（リポジトリから取得時に使用）
+    pub fn reconstruct(
+        game_id: GameId,
+        sequence: EventSequence,
+        occurred_at: DateTime<Utc>,
+        payload: GameEventPayload,
+    ) -> Self {
+        Self::new(game_id, sequence, occurred_at, payload)
+    }
+
+    // ゲッター
+    pub fn game_id(&self) -> &GameId {
+        &self.game_id
+    }
+
+    pub fn sequence(&self) -> &EventSequence {
+        &self.sequence
+    }
+
+    pub fn occurred_at(&self) -> &DateTime<Utc> {
+        &self.occurred_at
+    }
+
+    pub fn payload(&self) -> &GameEventPayload {
+        &self.payload
+    }
+}
+
+impl PartialEq for GameEvent {
+    fn eq(&self, other: &Self) -> bool {
+        self.game_id == other.game_id && self.sequence == other.sequence
+    }
+}
+
+impl Eq for GameEvent {}
@@ -0,0 +1,127 @@
+use crate::domain::player_management::models::player::player_id::player_id::PlayerId;
+use crate::domain::triggergame_simulator::models::action::trigger_azimuth::trigger_azimuth::TriggerAzimuth;
+use crate::domain::triggergame_simulator::models::combat::Combat;
+use crate::domain::triggergame_simulator::models::game::simulation_seed::simulation_seed::SimulationSeed;
+use crate::domain::triggergame_simulator::models::turn::turn_number::turn_number::TurnNumber;
+use crate::domain::unit_management::models::unit::{
+    position::position::Position, status_effect::status_effect::StatusEffect,
+    trigger_id::trigger_id::TriggerId, unit_id::unit_id::UnitId,
+    unit_type_id::unit_type_id::UnitTypeId, Unit,
+};
+use serde::{Deserialize, Serialize};
+
+/// ゲームイベントの内容
+/// typeにイベントの種類を設定することで、シリアライズが以下のようになります
+///
+/// {
+///     "type": "UnitBailedOut",
+///     "unitId": "unit_123"
+/// }
+///
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(
+    tag = "type",
+    rename_all_fields = "camelCase" // unit_id -> unitId
+)]
+pub enum GameEventPayload {
+    /// 対戦が成立した
+    /// simulation_seedはシードの保存を導入する前のイベントには含まれない
+    GameStarted {
+        player1_id: PlayerId,
+        player2_id: PlayerId,
+        #[serde(default)]
+        simulation_seed: Option<SimulationSeed>,
+    },
+
+    /// ユニットが配置された
+    UnitDeployed {
+        unit_id: UnitId,
+        unit_type_id: UnitTypeId,
+        owner_player_id: PlayerId,
+        position: Position,
+        using_main_trigger_id: TriggerId,
+        using_sub_trigger_id: TriggerId,
+        having_main_trigger_ids: Vec<TriggerId>,
+        having_sub_trigger_ids: Vec<TriggerId>,
+        main_trigger_hp: i32,
+        sub_trigger_hp: i32,
+        sight_range: i32,
+        wait_time: i32,
+        action_points: i32,
+    },
+
+    /// プレイヤーがターンの行動を登録した
+    TurnSubmitted {
+        player_id: PlayerId,
+        turn_number: TurnNumber,
+    },
+
+    /// ユニットが移動した
+    UnitMoved {
+        unit_id: UnitId,
+        from: Position,
+        to: Position,
+    },
+
+    /// ユニットの使用トリガーまたはトリガーの向きが変わった
+    TriggerSwitched {
+        unit_id: UnitId,
+        main_trigger_id: TriggerId,
+        sub_trigger_id: TriggerId,
+        main_trigger_azimuth: TriggerAzimuth,
+        sub_trigger_azimuth: TriggerAzimuth,
+    },
+
+    /// 戦闘が発生した
+    CombatResolved { combat: Combat },
+
+    /// ユニットがベイルアウトした
+    UnitBailedOut { unit_id: UnitId },
+
+    /// ステップ中にユニットの状態効果が付与・更新・解除された
+    /// status_effectsはステップ終了時点の全ての状態効果
+    StatusEffectsChanged {
+        unit_id: UnitId,
+        status_effects: Vec<StatusEffect>,
+    },
+
+    /// 最終ターンの演算が完了し、ゲームが終了した
+    GameFinished { final_turn_number: TurnNumber },
+}
+
+impl GameEventPayload {
+    /// 配置時点のユニット情報からイベントを生成
+    pub fn unit_deployed(unit: &Unit) -> Self {
+        Self::UnitDeployed {
+            unit_id: unit.unit_id().clone(),
+            unit_type_id: unit.unit_type_id().clone(),
+            owner_player_id: unit.owner_player_id().clone(),
+            position: unit.position().clone(),
+            using_main_trigger_id: unit.using_main_trigger_id().clone(),
+            using_sub_trigger_id: unit.using_sub_trigger_id().clone(),
+            having_main_trigger_ids: unit.having_main_trigger_ids().value().clone(),
+            having_sub_trigger_ids: unit.having_sub_trigger_ids().value().clone(),
+            main_trigger_hp: unit.main_trigger_hp().value(),
+            sub_trigger_hp: unit.sub_trigger_hp().value(),
+            sight_range: unit.sight_range().value(),
+            wait_time: unit.wait_time().value(),
+            action_points: unit.current_action_points().value(),
+        }
+    }
+
+    /// イベントの種類名
+    /// DynamoDBの属性など、種類だけを参照したい場合に使用
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            Self::GameStarted { .. } => "GameStarted",
+            Self::UnitDeployed { .. } => "UnitDeployed",
+            Self::TurnSubmitted { .. } => "TurnSubmitted",
+            Self::UnitMoved { .. } => "UnitMoved",
+            Self::TriggerSwitched { .. } => "TriggerSwitched",
+            Self::CombatResolved { .. } => "CombatResolved",
+            Self::UnitBailedOut { .. } => "UnitBailedOut",
+            Self::StatusEffectsChanged { .. } => "StatusEffectsChanged",
+            Self::GameFinished { .. } => "GameFinished",
+        }
+    }
+}
@@ -0,0 +1,230 @@
+use std::collections::HashMap;
+
+use crate::domain::player_management::models::player::player_id::player_id::PlayerId;
+use crate::domain::triggergame_simulator::models::action::trigger_azimuth::trigger_azimuth::TriggerAzimuth;
+use crate::domain::triggergame_simulator::models::game::game::Game;
+use crate::domain::triggergame_simulator::models::game::game_version::game_version::GameVersion;
+use crate::domain::triggergame_simulator::models::game::simulation_seed::simulation_seed::SimulationSeed;
+use crate::domain::unit_management::models::unit::{
+    current_action_points::current_action_points::CurrentActionPoints,
+    having_trigger_ids::having_trigger_ids::HavingTriggerIds, is_bailout::is_bailout::IsBailout,
+    main_trigger_hp::main_trigger_hp::MainTriggerHP, sight_range::sight_range::SightRange,
+    sub_trigger_hp::sub_trigger_hp::SubTriggerHP, unit_id::unit_id::UnitId,
+    wait_time::wait_time::WaitTime, Unit,
+};
+
+use super::game_event::GameEvent;
+use super::game_event_payload::GameEventPayload;
+
+/// ゲームイベントからGameとUnitの状態を再構築するプロジェクター
+/// 監査やデバッグのため、イベントを発生順に再生して各時点の状態を復元する
+///
+/// 両プレイヤーのターン登録が揃った時点でターンの演算が開始されたものとみなし、
+/// シミュレーターと同じくユニットの行動ポイントをリセットしてから後続のイベントを適用する
+pub struct GameEventProjector;
+
+impl GameEventProjector {
+    /// イベント列からゲーム情報を再構築
+    pub fn project_game(events: &[GameEvent]) -> Result<Game, String> {
+        let first_event = events
+            .first()
+            .ok_or("ゲームイベントが存在しません".to_string())?;
+        let game_id = first_event.game_id().clone();
+
+        let mut game: Option<Game> = None;
+        // ターン番号ごとに行動を登録したプレイヤー
+        let mut submitted_players: HashMap<i32, Vec<PlayerId>> = HashMap::new();
+        let mut resolved_turn_count = 0;
+        // 現在のターンが開始された日時（対戦開始またはターン演算のイベント発生日時）
+        let mut turn_started_at = *first_event.occurred_at();
+        // シードの保存を導入する前のイベントはゲームIDから生成したシードで演算されている
+        let mut simulation_seed = SimulationSeed::from_game_id(&game_id);
+
+        for event in Self::sorted(events)? {
+            match event.payload() {
+                GameEventPayload::GameStarted {
+                    player1_id,
+                    player2_id,
+                    simulation_seed: started_simulation_seed,
+                } => {
+                    game = Some(Game::create(game_id.clone(), player1_id, player2_id));
+                    if let Some(started_simulation_seed) = started_simulation_seed {
+                        simulation_seed = started_simulation_seed.clone();
+                    }
+                    turn_started_at = *event.occurred_at();
+                }
+                GameEventPayload::TurnSubmitted {
+                    player_id,
+                    turn_number,
+                } => {
+                    let game = game
+                        .as_mut()
+                        .ok_or("対戦開始前にターンが登録されています".to_string())?;
+                    if Self::submit(&mut submitted_players, player_id, turn_number.value()) {
+                        // 両プレイヤーの登録が揃ったターンは演算済みとなり、次のターンへ進む
+                        resolved_turn_count += 1;
+                        if !game.is_game_finished() {
+                            game.advance_to_next_turn()?;
+                            turn_started_at = *event.occurred_at();
+                        }
+                    }
+                }
+                GameEventPayload::GameFinished { .. } => {
+                    game.as_mut()
+                        .ok_or("対戦開始前にゲームが終了しています".to_string())?
+                        .finish()?;
+                }
+                _ => {}
+            }
+        }
+
+        let game = game.ok_or("対戦開始のイベントが存在しません".to_string())?;
+        // ターンの演算が反映されるたびにバージョンが1つ進む
+        Ok(Game::reconstruct(
+            game.game_id().clone(),
+            game.current_turn_number().clone(),
+            game.player1_id().clone(),
+            game.player2_id().clone(),
+            GameVersion::new(GameVersion::initial().value() + resolved_turn_count),
+            turn_started_at,
+            // 観戦者はイベントログに記録されないため、対戦開始時点（観戦者なし）のまま
+            game.spectators().to_vec(),
+            game.status().clone(),
+            simulation_seed,
+        ))
+    }
+
+    /// イベント列からユニット情報を再構築
+    pub fn project_units(events: &[GameEvent]) -> Result<Vec<Unit>, String> {
+        let mut units: Vec<Unit> = Vec::new();
+        let mut submitted_players: HashMap<i32, Vec<PlayerId>> = HashMap::new();
+
+        for event in Self::sorted(events)? {
+            match event.payload() {
+                GameEventPayload::UnitDeployed {
+                    unit_id,
+                    unit_type_id,
+                    owner_player_id,
+                    position,
+                    using_main_trigger_id,
+                    using_sub_trigger_id,
+                    having_main_trigger_ids,
+                    having_sub_trigger_ids,
+                    main_trigger_hp,
+                    sub_trigger_hp,
+                    sight_range,
+                    wait_time,
+                    action_points,
+                } => {
+                    units.push(Unit::reconstruct(
+                        unit_id.clone(),
+                        unit_type_id.clone(),
+                        event.game_id().clone(),
+                        owner_player_id.clone(),
+                        CurrentActionPoints::new(*action_points),
+                        WaitTime::new(*wait_time),
+                        position.clone(),
+                        using_main_trigger_id.clone(),
+                        using_sub_trigger_id.clone(),
+                        HavingTriggerIds::new(having_main_trigger_ids.clone()),
+                        HavingTriggerIds::new(having_sub_trigger_ids.clone()),
+                        MainTriggerHP::new(*main_trigger_hp),
+                        SubTriggerHP::new(*sub_trigger_hp),
+                        // 配置直後のトリガーは初期方向を向く
+                        TriggerAzimuth::new(0),
+                        TriggerAzimuth::new(0),
+                        SightRange::new(*sight_range),
+                        IsBailout::new(false),
+                        vec![],
+                    ));
+                }
+                GameEventPayload::TurnSubmitted {
+                    player_id,
+                    turn_number,
+                } => {
+                    if Self::submit(&mut submitted_players, player_id, turn_number.value()) {
+                        // ターン開始時にユニットの行動ポイントをリセット
+                        units.iter_mut().for_each(|u| u.reset_action_points());
+                    }
+                }
+                GameEventPayload::UnitMoved { unit_id, to, .. } => {
+                    let unit = Self::find_unit(&mut units, unit_id)?;
+                    unit.move_to(to.clone());
+                }
+                GameEventPayload::TriggerSwitched {
+                    unit_id,
+                    main_trigger_id,
+                    sub_trigger_id,
+                    main_trigger_azimuth,
+                    sub_trigger_azimuth,
+                } => {
+                    let unit = Self::find_unit(&mut units, unit_id)?;
+                    unit.set_using_triggers(main_trigger_id, sub_trigger_id)?;
+                    unit.set_main_trigger_azimuth(main_trigger_azimuth.clone());
+                    unit.set_sub_trigger_azimuth(sub_trigger_azimuth.clone());
+                }
+                GameEventPayload::UnitBailedOut { unit_id } => {
+                    let unit = Self::find_unit(&mut units, unit_id)?;
+                    unit.bailout();
+                }
+                GameEventPayload::StatusEffectsChanged {
+                    unit_id,
+                    status_effects,
+                } => {
+                    let unit = Self::find_unit(&mut units, unit_id)?;
+                    unit.set_status_effects(status_effects.clone());
+                }
+                // 命中したダメージのみトリガーHPに反映する（撃墜はUnitBailedOutで表す）
+                GameEventPayload::CombatResolved { combat } => {
+                    let unit = Self::find_unit(&mut units, combat.defending_unit_id())?;
+                    for hit in combat.hits() {
+                        if hit.is_avoided().value() {
+                            continue;
+                        }
+                        unit.apply_damage(hit.main_trigger_damage(), hit.sub_trigger_damage());
+                    }
+                }
+                GameEventPayload::GameStarted { .. } | GameEventPayload::GameFinished { .. } => {}
+            }
+        }
+
+        Ok(units)
+    }
+
+    /// 通し番号順に並べ替え、同じゲームのイベントのみであることを確認
+    fn sorted(events: &[GameEvent]) -> Result<Vec<&GameEvent>, String> {
+        let mut sorted: Vec<&GameEvent> = events.iter().collect();
+        if let Some(first) = sorted.first() {
+            let game_id = first.game_id().clone();
+            if sorted.iter().any(|e| e.game_id() != &game_id) {
+                return Err("異なるゲームのイベントが含まれています".to_string());
+            }
+        }
+        sorted.sort_by_key(|e| e.sequence().value());
+        Ok(sorted)
+    }
+
+    /// ターン登録を記録し、両プレイヤーの登録が揃った場合にtrueを返す
+    fn submit(
+        submitted_players: &mut HashMap<i32, Vec<PlayerId>>,
+        player_id: &PlayerId,
+        turn_number: i32,
+    ) -> bool {
+        let players = submitted_players.entry(turn_number).or_default();
+        if players.contains(player_id) {
+            return false;
+        }
+        players.push(player_id.clone());
+        players.len() == 2
+    }
+
+    fn find_unit<'a>(units: &'a mut [Unit], unit_id: &UnitId) -> Result<&'a mut Unit, String> {
+        units
+            .iter_mut()
+            .find(|u| u.unit_id() == unit_id)
+            .ok_or(format!(
+                "ユニットID {:?} が配置されていません",
+                unit_id.value()
+            ))
+    }
+}
@@ -0,0 +1,202 @@
+#[cfg(test)]
+mod tests {
+    use super::super::event_sequence::event_sequence::EventSequence;
+    use super::super::game_event::GameEvent;
+    use super::super::game_event_payload::GameEventPayload;
+    use super::super::game_event_projector::GameEventProjector;
+    use crate::domain::player_management::models::player::player_id::player_id::PlayerId;
+    use crate::domain::triggergame_simulator::models::action::trigger_azimuth::trigger_azimuth::TriggerAzimuth;
+    use crate::domain::triggergame_simulator::models::game::game_id::game_id::GameId;
+    use crate::domain::triggergame_simulator::models::game::simulation_seed::simulation_seed::SimulationSeed;
+    use crate::domain::triggergame_simulator::models::turn::turn_number::turn_number::TurnNumber;
+    use crate::domain::unit_management::models::unit::{
+        having_trigger_ids::having_trigger_ids::HavingTriggerIds, position::position::Position,
+        status_effect::status_effect::StatusEffect, trigger_id::trigger_id::TriggerId,
+        unit_type_id::unit_type_id::UnitTypeId, Unit,
+    };
+    use uuid::Uuid;
+
+    fn create_unit(game_id: &GameId, owner_player_id: &PlayerId) -> Unit {
+        Unit::create(
+            UnitTypeId::new("MIKUMO_OSAMU".to_string()),
+            game_id.clone(),
+            owner_player_id.clone(),
+            Position::new(1, 1),
+            TriggerId::new("main_trigger_001".to_string()),
+            TriggerId::new("sub_trigger_001".to_string()),
+            HavingTriggerIds::new(vec![
+                TriggerId::new("main_trigger_001".to_string()),
+                TriggerId::new("main_trigger_002".to_string()),
+            ]),
+            HavingTriggerIds::new(vec![TriggerId::new("sub_trigger_001".to_string())]),
+            100,
+            100,
+            8,
+            13,
+        )
+    }
+
+    /// 発生順に通し番号を振ったイベント列を作成
+    fn create_events(game_id: &GameId, payloads: Vec<GameEventPayload>) -> Vec<GameEvent> {
+        let mut sequence = EventSequence::initial();
+        let mut events = Vec::new();
+        for payload in payloads {
+            events.push(GameEvent::create(
+                game_id.clone(),
+                sequence.clone(),
+                payload,
+            ));
+            sequence = sequence.next();
+        }
+        events
+    }
+
+    #[test]
+    fn test_project_game_and_units() {
+        let game_id = GameId::new(Uuid::new_v4().to_string());
+        let player1_id = PlayerId::new(Uuid::new_v4().to_string());
+        let player2_id = PlayerId::new(Uuid::new_v4().to_string());
+        let unit1 = create_unit(&game_id, &player1_id);
+        let unit2 = create_unit(&game_id, &player2_id);
+
+        let mut events = create_events(
+            &game_id,
+            vec![
+                GameEventPayload::GameStarted {
+                    player1_id: player1_id.clone(),
+                    player2_id: player2_id.clone(),
+                    simulation_seed: Some(SimulationSeed::new(12345)),
+                },
+                GameEventPayload::unit_deployed(&unit1),
+                GameEventPayload::unit_deployed(&unit2),
+                GameEventPayload::TurnSubmitted {
+                    player_id: player1_id.clone(),
+                    turn_number: TurnNumber::new(1),
+                },
+                GameEventPayload::TurnSubmitted {
+                    player_id: player2_id.clone(),
+                    turn_number: TurnNumber::new(1),
+                },
+                GameEventPayload::UnitMoved {
+                    unit_id: unit1.unit_id().clone(),
+                    from: Position::new(1, 1),
+                    to: Position::new(1, 2),
+                },
+                GameEventPayload::TriggerSwitched {
+                    unit_id: unit1.unit_id().clone(),
+                    main_trigger_id: TriggerId::new("main_trigger_002".to_string()),
+                    sub_trigger_id: TriggerId::new("sub_trigger_001".to_string()),
+                    main_trigger_azimuth: TriggerAzimuth::new(90),
+                    sub_trigger_azimuth: TriggerAzimuth::new(180),
+                },
+                GameEventPayload::StatusEffectsChanged {
+                    unit_id: unit1.unit_id().clone(),
+                    status_effects: vec![StatusEffect::new("LEAD_WEIGHT".to_string(), 2, 1)],
+                },
+                GameEventPayload::UnitBailedOut {
+                    unit_id: unit2.unit_id().clone(),
+                },
+            ],
+        );
+        // 取得順に関わらず通し番号順に再生されること
+        events.reverse();
+
+        let game = GameEventProjector::project_game(&events).unwrap();
+        assert_eq!(game.player1_id(), &player1_id);
+        assert_eq!(game.player2_id(), &player2_id);
+        assert_eq!(game.current_turn_number().value(), 2);
+        assert_eq!(game.version().value(), 2);
+        assert_eq!(game.simulation_seed(), &SimulationSeed::new(12345));
+
+        let units = GameEventProjector::project_units(&events).unwrap();
+        assert_eq!(units.len(), 2);
+        let projected_unit1 = units.iter().find(|u| u == &&unit1).unwrap();
+        assert_eq!(projected_unit1.position(), &Position::new(1, 2));
+        assert_eq!(
+            projected_unit1.using_main_trigger_id().value(),
+            "main_trigger_002"
+        );
+        assert_eq!(projected_unit1.main_trigger_azimuth().value(), 90);
+        assert_eq!(projected_unit1.sub_trigger_azimuth().value(), 180);
+        assert_eq!(
+            projected_unit1.status_effects(),
+            &[StatusEffect::new("LEAD_WEIGHT".to_string(), 2, 1)]
+        );
+        assert!(projected_unit1.is_active());
+        let projected_unit2 = units.iter().find(|u| u == &&unit2).unwrap();
+        assert!(projected_unit2.is_bailed_out());
+    }
+
+    #[test]
+    fn test_project_game_waiting_opponent_turn() {
+        let game_id = GameId::new(Uuid::new_v4().to_string());
+        let player1_id = PlayerId::new(Uuid::new_v4().to_string());
+        let player2_id = PlayerId::new(Uuid::new_v4().to_string());
+        let events = create_events(
+            &game_id,
+            vec![
+                GameEventPayload::GameStarted {
+                    player1_id: player1_id.clone(),
+                    player2_id,
+                    simulation_seed: None,
+                },
+                GameEventPayload::TurnSubmitted {
+                    player_id: player1_id,
+                    turn_number: TurnNumber::new(1),
+                },
+            ],
+        );
+
+        // 片方のプレイヤーのみ登録済みの場合はターンが進まないこと
+        let game = GameEventProjector::project_game(&events).unwrap();
+        assert_eq!(game.current_turn_number().value(), 1);
+        assert_eq!(game.version().value(), 1);
+        // シードを含まないイベントはゲームIDから生成したシードとして扱う
+        assert_eq!(
+            game.simulation_seed(),
+            &SimulationSeed::from_game_id(&game_id)
+        );
+    }
+
+    #[test]
+    fn test_project_game_finished() {
+        let game_id = GameId::new(Uuid::new_v4().to_string());
+        let player1_id = PlayerId::new(Uuid::new_v4().to_string());
+        let player2_id = PlayerId::new(Uuid::new_v4().to_string());
+        let mut payloads = vec![GameEventPayload::GameStarted {
+            player1_id: player1_id.clone(),
+            player2_id: player2_id.clone(),
+            simulation_seed: Some(SimulationSeed::generate()),
+        }];
+        for turn_number in 1..=6 {
+            for player_id in [&player1_id, &player2_id] {
+                payloads.push(GameEventPayload::TurnSubmitted {
+                    player_id: player_id.clone(),
+                    turn_number: TurnNumber::new(turn_number),
+                });
+            }
+        }
+        payloads.push(GameEventPayload::GameFinished {
+            final_turn_number: TurnNumber::new(6),
+        });
+        let events = create_events(&game_id, payloads);
+
+        // 最終ターンの演算後は対戦終了となること
+        let game = GameEventProjector::project_game(&events).unwrap();
+        assert_eq!(game.current_turn_number().value(), 6);
+        assert!(game.status().is_finished());
+    }
+
+    #[test]
+    fn test_project_game_without_start_event() {
+        let game_id = GameId::new(Uuid::new_v4().to_string());
+        let events = create_events(
+            &game_id,
+            vec![GameEventPayload::GameFinished {
+                final_turn_number: TurnNumber::new(6),
+            }],
+        );
+        assert!(GameEventProjector::project_game(&events).is_err());
+        assert!(GameEventProjector::project_game(&[]).is_err());
+    }
+}
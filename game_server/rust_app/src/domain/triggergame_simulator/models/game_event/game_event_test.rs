@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod tests {
+    use super::super::event_sequence::event_sequence::EventSequence;
+    use super::super::game_event::GameEvent;
+    use super::super::game_event_payload::GameEventPayload;
+    use crate::domain::triggergame_simulator::models::game::game_id::game_id::GameId;
+    use crate::domain::unit_management::models::unit::{
+        position::position::Position, unit_id::unit_id::UnitId,
+    };
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    fn create_unit_moved() -> GameEventPayload {
+        GameEventPayload::UnitMoved {
+            unit_id: UnitId::new(Uuid::new_v4().to_string()),
+            from: Position::new(1, 1),
+            to: Position::new(1, 2),
+        }
+    }
+
+    #[test]
+    fn test_create_game_event() {
+        let game_id = GameId::new(Uuid::new_v4().to_string());
+        let event = GameEvent::create(
+            game_id.clone(),
+            EventSequence::initial(),
+            create_unit_moved(),
+        );
+
+        assert_eq!(event.game_id(), &game_id);
+        assert_eq!(event.sequence(), &EventSequence::initial());
+        assert_eq!(event.payload().event_type(), "UnitMoved");
+    }
+
+    #[test]
+    fn test_reconstruct_game_event() {
+        let game_id = GameId::new(Uuid::new_v4().to_string());
+        let occurred_at = Utc::now();
+        let event = GameEvent::reconstruct(
+            game_id.clone(),
+            EventSequence::new(3),
+            occurred_at,
+            create_unit_moved(),
+        );
+
+        assert_eq!(event.sequence().value(), 3);
+        assert_eq!(event.occurred_at(), &occurred_at);
+        // 同じゲーム・同じ通し番号のイベントは同一とみなす
+        let other = GameEvent::create(game_id, EventSequence::new(3), create_unit_moved());
+        assert_eq!(event, other);
+    }
+
+    #[test]
+    fn test_payload_serialize() {
+        let unit_id = UnitId::new(Uuid::new_v4().to_string());
+        let payload = GameEventPayload::UnitBailedOut {
+            unit_id: unit_id.clone(),
+        };
+
+        let json = serde_json::to_value(&payload).unwrap();
+        assert_eq!(json["type"], "UnitBailedOut");
+        assert_eq!(json["unitId"], unit_id.value());
+
+        let deserialized: GameEventPayload = serde_json::from_value(json).unwrap();
+        match deserialized {
+            GameEventPayload::UnitBailedOut { unit_id: actual } => assert_eq!(actual, unit_id),
+            _ => panic!("UnitBailedOutとして復元されていません"),
+        }
+    }
+}
@@ -0,0 +1,6 @@
+pub mod replay;
+pub mod replay_frame;
+pub mod replay_runner;
+pub mod replay_runner_test;
+pub mod replay_test;
+pub mod unit_snapshot;
@@ -0,0 +1,159 @@
+use crate::domain::player_management::models::player::player_id::player_id::PlayerId;
+use crate::domain::triggergame_simulator::configs::game_config::GameConfig;
+use crate::domain::triggergame_simulator::models::game::game::Game;
+use crate::domain::triggergame_simulator::models::game::game_id::game_id::GameId;
+use crate::domain::triggergame_simulator::models::game::simulation_seed::simulation_seed::SimulationSeed;
+use crate::domain::triggergame_simulator::models::turn::Turn;
+use crate::domain::unit_management::models::unit::Unit;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::unit_snapshot::UnitSnapshot;
+
+/// Replay集約
+/// 終了したゲームを再演算するために必要な情報をまとめたもの
+///
+/// 対戦開始時のユニット配置・乱数のシード・演算済みの全ターンを保持し、
+/// 同じマスターデータのバージョンであれば対戦中と同じ戦闘結果を再現できる
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Replay {
+    format_version: u32,
+    game_id: GameId,
+    master_data_version: String,
+    rng_seed: SimulationSeed,
+    player1_id: PlayerId,
+    player2_id: PlayerId,
+    initial_units: Vec<UnitSnapshot>,
+    turns: Vec<Turn>,
+    created_at: DateTime<Utc>,
+}
+
+impl Replay {
+    /// リプレイ形式のバージョン
+    /// 形式を変更した場合は更新する
+    pub const FORMAT_VERSION: u32 = 2;
+
+    // privateなコンストラクタ
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        format_version: u32,
+        game_id: GameId,
+        master_data_version: String,
+        rng_seed: SimulationSeed,
+        player1_id: PlayerId,
+        player2_id: PlayerId,
+        initial_units: Vec<UnitSnapshot>,
+        turns: Vec<Turn>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            format_version,
+            game_id,
+            master_data_version,
+            rng_seed,
+            player1_id,
+            player2_id,
+            initial_units,
+            turns,
+            created_at,
+        }
+    }
+
+    /// 終了したゲームからリプレイを生成
+    /// initial_units: 対戦開始時点のユニット
+    /// turns: 演算済みのターン情報（両プレイヤー分）
+    pub fn create(
+        game: &Game,
+        initial_units: &[Unit],
+        mut turns: Vec<Turn>,
+    ) -> Result<Self, String> {
+        if !game.is_game_finished() {
+            return Err("終了していないゲームのリプレイは作成できません".to_string());
+        }
+        if turns.iter().any(|turn| !turn.is_completed()) {
+            return Err("演算が完了していないターンが含まれています".to_string());
+        }
+        if turns.iter().any(|turn| turn.game_id() != game.game_id()) {
+            return Err("異なるゲームのターンが含まれています".to_string());
+        }
+        // ターン数の順に並べておく
+        turns.sort_by_key(|turn| turn.turn_number().value());
+
+        Ok(Self::new(
+            Self::FORMAT_VERSION,
+            game.game_id().clone(),
+            GameConfig::get_game_config()
+                .master_data_version()
+                .to_string(),
+            game.simulation_seed().clone(),
+            game.player1_id().clone(),
+            game.player2_id().clone(),
+            initial_units.iter().map(UnitSnapshot::from_unit).collect(),
+            turns,
+            Utc::now(),
+        ))
+    }
+
+    /// JSONからリプレイを読み込む
+    /// 対応していない形式のバージョンの場合はエラーを返す
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let replay = serde_json::from_str::<Self>(json)
+            .map_err(|e| format!("リプレイの読み込みに失敗しました: {}", e))?;
+        if replay.format_version != Self::FORMAT_VERSION {
+            return Err(format!(
+                "対応していないリプレイ形式のバージョンです: {}",
+                replay.format_version
+            ));
+        }
+        Ok(replay)
+    }
+
+    /// リプレイをJSONに変換
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string(self).map_err(|e| format!("リプレイの変換に失敗しました: {}", e))
+    }
+
+    /// 記録時と現在のマスターデータのバージョンが一致するか
+    /// 一致しない場合、再演算の結果が対戦中と異なる可能性がある
+    pub fn is_current_master_data(&self) -> bool {
+        self.master_data_version == GameConfig::get_game_config().master_data_version()
+    }
+
+    // ゲッター
+    pub fn format_version(&self) -> u32 {
+        self.format_version
+    }
+
+    pub fn game_id(&self) -> &GameId {
+        &self.game_id
+    }
+
+    pub fn master_data_version(&self) -> &str {
+        &self.master_data_version
+    }
+
+    pub fn rng_seed(&self) -> &SimulationSeed {
+        &self.rng_seed
+    }
+
+    pub fn player1_id(&self) -> &PlayerId {
+        &self.player1_id
+    }
+
+    pub fn player2_id(&self) -> &PlayerId {
+        &self.player2_id
+    }
+
+    pub fn initial_units(&self) -> &Vec<UnitSnapshot> {
+        &self.initial_units
+    }
+
+    pub fn turns(&self) -> &Vec<Turn> {
+        &self.turns
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+}
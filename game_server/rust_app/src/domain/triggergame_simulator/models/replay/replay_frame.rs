@@ -0,0 +1,50 @@
+use crate::domain::triggergame_simulator::models::combat::Combat;
+use crate::domain::triggergame_simulator::models::turn::turn_number::turn_number::TurnNumber;
+use serde::{Deserialize, Serialize};
+
+use super::unit_snapshot::UnitSnapshot;
+
+/// リプレイの1コマ
+/// ステップの演算が完了した時点の盤面と、そのステップで発生した戦闘を表す
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayFrame {
+    turn_number: TurnNumber,
+    /// ターン内のステップの順番（0始まり）
+    step_index: usize,
+    units: Vec<UnitSnapshot>,
+    combats: Vec<Combat>,
+}
+
+impl ReplayFrame {
+    pub fn new(
+        turn_number: TurnNumber,
+        step_index: usize,
+        units: Vec<UnitSnapshot>,
+        combats: Vec<Combat>,
+    ) -> Self {
+        Self {
+            turn_number,
+            step_index,
+            units,
+            combats,
+        }
+    }
+
+    // ゲッター
+    pub fn turn_number(&self) -> &TurnNumber {
+        &self.turn_number
+    }
+
+    pub fn step_index(&self) -> usize {
+        self.step_index
+    }
+
+    pub fn units(&self) -> &Vec<UnitSnapshot> {
+        &self.units
+    }
+
+    pub fn combats(&self) -> &Vec<Combat> {
+        &self.combats
+    }
+}
@@ -0,0 +1,107 @@
+use crate::domain::triggergame_simulator::models::combat::Combat;
+use crate::domain::triggergame_simulator::models::step::step::Step;
+use crate::domain::triggergame_simulator::models::turn::Turn;
+use crate::domain::unit_management::models::unit::Unit;
+
+use super::replay::Replay;
+use super::replay_frame::ReplayFrame;
+use super::unit_snapshot::UnitSnapshot;
+
+/// リプレイを再演算するランナー
+/// 外部の状態に依存せず、リプレイの内容だけからステップごとの盤面を再現する
+///
+/// 対戦中と同じく、ターン開始時に行動ポイントをリセットしてから
+/// ターン数ごとのシードの乱数生成器で各ステップを順に演算する
+pub struct ReplayRunner;
+
+impl ReplayRunner {
+    /// リプレイを再演算し、ステップごとの盤面を発生順に返す
+    pub fn run(replay: &Replay) -> Result<Vec<ReplayFrame>, String> {
+        let mut units: Vec<Unit> = replay
+            .initial_units()
+            .iter()
+            .map(|snapshot| snapshot.to_unit(replay.game_id()))
+            .collect();
+        let mut frames = Vec::new();
+
+        for turn in Self::distinct_turns(replay) {
+            let mut rng = replay.rng_seed().rng_for_turn(turn.turn_number());
+            // ターン開始時にユニットの行動ポイントをリセット
+            units.iter_mut().for_each(|u| u.reset_action_points());
+
+            for (step_index, recorded_step) in turn.steps().iter().enumerate() {
+                // 記録された戦闘結果は使わず、アクションのみから再演算する
+                let mut step = Step::new(
+                    recorded_step.step_id().clone(),
+                    recorded_step.actions().clone(),
+                    vec![],
+                    vec![],
+                );
+                step.step_start(&mut units, &mut rng)?;
+                frames.push(ReplayFrame::new(
+                    turn.turn_number().clone(),
+                    step_index,
+                    units.iter().map(UnitSnapshot::from_unit).collect(),
+                    step.combats().clone(),
+                ));
+            }
+        }
+
+        Ok(frames)
+    }
+
+    /// リプレイを再演算し、戦闘結果が対戦中に記録されたものと一致するか検証
+    /// 一致した場合はステップごとの盤面を返す
+    pub fn verify(replay: &Replay) -> Result<Vec<ReplayFrame>, String> {
+        let frames = Self::run(replay)?;
+        let recorded_steps = Self::distinct_turns(replay)
+            .into_iter()
+            .flat_map(|turn| turn.steps().iter());
+
+        for (frame, recorded_step) in frames.iter().zip(recorded_steps) {
+            let is_same = frame.combats().len() == recorded_step.combats().len()
+                && frame
+                    .combats()
+                    .iter()
+                    .zip(recorded_step.combats())
+                    .all(|(replayed, recorded)| Self::is_same_outcome(replayed, recorded));
+            if !is_same {
+                return Err(format!(
+                    "ターン{}のステップ{}の戦闘結果が記録と一致しません",
+                    frame.turn_number().value(),
+                    frame.step_index() + 1
+                ));
+            }
+        }
+
+        Ok(frames)
+    }
+
+    /// ターン数ごとに1件のターン情報を取得
+    /// 演算済みのターン情報は両プレイヤーに同じ演算結果が保存されているため、先頭の1件を使用する
+    fn distinct_turns(replay: &Replay) -> Vec<&Turn> {
+        let mut turns: Vec<&Turn> = Vec::new();
+        for turn in replay.turns() {
+            if turns
+                .iter()
+                .all(|t| t.turn_number().value() != turn.turn_number().value())
+            {
+                turns.push(turn);
+            }
+        }
+        turns.sort_by_key(|turn| turn.turn_number().value());
+        turns
+    }
+
+    /// 戦闘の結果が同じかどうか
+    /// 戦闘IDは演算のたびに採番されるため比較しない
+    fn is_same_outcome(replayed: &Combat, recorded: &Combat) -> bool {
+        replayed.attacking_unit_id() == recorded.attacking_unit_id()
+            && replayed.defending_unit_id() == recorded.defending_unit_id()
+            && replayed.main_trigger_damage() == recorded.main_trigger_damage()
+            && replayed.sub_trigger_damage() == recorded.sub_trigger_damage()
+            && replayed.is_avoided().value() == recorded.is_avoided().value()
+            && replayed.is_defeated() == recorded.is_defeated()
+            && replayed.hits() == recorded.hits()
+    }
+}
@@ -0,0 +1,173 @@
+#[cfg(test)]
+mod tests {
+    use super::super::replay::Replay;
+    use super::super::replay_runner::ReplayRunner;
+    use crate::domain::player_management::models::player::player_id::player_id::PlayerId;
+    use crate::domain::triggergame_simulator::models::action::action_type::action_type::{
+        ActionType, ActionTypeValue,
+    };
+    use crate::domain::triggergame_simulator::models::action::trigger_azimuth::trigger_azimuth::TriggerAzimuth;
+    use crate::domain::triggergame_simulator::models::action::Action;
+    use crate::domain::triggergame_simulator::models::game::current_turn_number::current_turn_number::CurrentTurnNumber;
+    use crate::domain::triggergame_simulator::models::game::game::Game;
+    use crate::domain::triggergame_simulator::models::game::game_id::game_id::GameId;
+    use crate::domain::triggergame_simulator::models::game::game_status::game_status::GameStatus;
+    use crate::domain::triggergame_simulator::models::game::game_version::game_version::GameVersion;
+    use crate::domain::triggergame_simulator::models::game::simulation_seed::simulation_seed::SimulationSeed;
+    use crate::domain::triggergame_simulator::models::step::step::Step;
+    use crate::domain::triggergame_simulator::models::step::step_id::step_id::StepId;
+    use crate::domain::triggergame_simulator::models::turn::turn_id::turn_id::TurnId;
+    use crate::domain::triggergame_simulator::models::turn::turn_number::turn_number::TurnNumber;
+    use crate::domain::triggergame_simulator::models::turn::turn_start_datetime::turn_start_datetime::TurnStartDatetime;
+    use crate::domain::triggergame_simulator::models::turn::turn_status::turn_status::{
+        TurnStatus, TurnStatusValue,
+    };
+    use crate::domain::triggergame_simulator::models::turn::Turn;
+    use crate::domain::unit_management::models::unit::{
+        having_trigger_ids::having_trigger_ids::HavingTriggerIds, position::position::Position,
+        trigger_id::trigger_id::TriggerId, unit_type_id::unit_type_id::UnitTypeId, Unit,
+    };
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    fn create_unit(game: &Game, owner_player_id: &PlayerId, position: Position) -> Unit {
+        Unit::create(
+            UnitTypeId::new("MIKUMO_OSAMU".to_string()),
+            game.game_id().clone(),
+            owner_player_id.clone(),
+            position,
+            TriggerId::new("KOGETSU".to_string()),
+            TriggerId::new("RAYGUST".to_string()),
+            HavingTriggerIds::new(vec![TriggerId::new("KOGETSU".to_string())]),
+            HavingTriggerIds::new(vec![TriggerId::new("RAYGUST".to_string())]),
+            100,
+            100,
+            8,
+            13,
+        )
+    }
+
+    /// その場でトリガーを正面に向けるアクション
+    fn create_attack_action(unit: &Unit) -> Action {
+        Action::create(
+            ActionType::new(ActionTypeValue::Wait),
+            unit.unit_id().clone(),
+            unit.unit_type_id().clone(),
+            unit.position().clone(),
+            unit.using_main_trigger_id().clone(),
+            unit.using_sub_trigger_id().clone(),
+            TriggerAzimuth::new(90),
+            TriggerAzimuth::new(90),
+        )
+    }
+
+    fn create_turn(game: &Game, player_id: &PlayerId, unit: &Unit) -> Turn {
+        let steps = (0..3)
+            .map(|_| {
+                Step::new(
+                    StepId::new(Uuid::new_v4().to_string()),
+                    vec![create_attack_action(unit)],
+                    vec![],
+                    vec![],
+                )
+            })
+            .collect();
+        Turn::new(
+            TurnId::new(Uuid::new_v4().to_string()),
+            game.game_id().clone(),
+            player_id.clone(),
+            TurnNumber::new(1),
+            TurnStartDatetime::new(Utc::now()),
+            TurnStatus::new(TurnStatusValue::StepSetting),
+            steps,
+        )
+    }
+
+    /// 対戦中と同じ手順でターンを演算し、記録されたリプレイを生成
+    fn play_game() -> Replay {
+        let game = Game::reconstruct(
+            GameId::new(Uuid::new_v4().to_string()),
+            CurrentTurnNumber::new(6),
+            PlayerId::new(Uuid::new_v4().to_string()),
+            PlayerId::new(Uuid::new_v4().to_string()),
+            GameVersion::initial(),
+            Utc::now(),
+            Vec::new(),
+            GameStatus::initial(),
+            SimulationSeed::generate(),
+        );
+        // 互いの座標系で同じセルに位置し、正面を向けば戦闘が発生する配置
+        let unit1 = create_unit(&game, game.player1_id(), Position::new(17, 17));
+        let unit2 = create_unit(&game, game.player2_id(), Position::new(18, 18));
+        let initial_units = vec![unit1.clone(), unit2.clone()];
+
+        let opponent_turn = create_turn(&game, game.player1_id(), &unit1);
+        let mut turn = create_turn(&game, game.player2_id(), &unit2);
+        let mut units = initial_units.clone();
+        let mut rng = game.simulation_seed().rng_for_turn(turn.turn_number());
+        turn.turn_start(&mut units, &opponent_turn, &mut rng)
+            .unwrap();
+        turn.complete(Utc::now()).unwrap();
+        let opponent_resolved_turn = turn.resolved_turn_for(&opponent_turn).unwrap();
+
+        Replay::create(&game, &initial_units, vec![turn, opponent_resolved_turn]).unwrap()
+    }
+
+    #[test]
+    fn test_run_replay() {
+        let replay = play_game();
+
+        let frames = ReplayRunner::run(&replay).unwrap();
+        // 両プレイヤーのターン情報は同じ演算結果のため、1ターン分のステップのみ再演算されること
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[0].turn_number().value(), 1);
+        assert_eq!(frames[0].step_index(), 0);
+        assert_eq!(frames[2].step_index(), 2);
+        assert_eq!(frames[0].units().len(), 2);
+        assert!(!frames[0].combats().is_empty());
+        assert_eq!(frames[0].units()[0].main_trigger_azimuth().value(), 90);
+    }
+
+    #[test]
+    fn test_verify_replay_matches_live_combats() {
+        let replay = play_game();
+
+        // 再演算の戦闘結果が対戦中の戦闘結果と一致すること
+        let result = ReplayRunner::verify(&replay);
+        assert!(result.is_ok(), "{:?}", result.err());
+        // 何度再演算しても同じ結果になること
+        let frames = ReplayRunner::run(&replay).unwrap();
+        let frames_again = ReplayRunner::run(&replay).unwrap();
+        for (frame, frame_again) in frames.iter().zip(frames_again.iter()) {
+            let outcomes: Vec<bool> = frame
+                .combats()
+                .iter()
+                .map(|c| c.is_avoided().value())
+                .collect();
+            let outcomes_again: Vec<bool> = frame_again
+                .combats()
+                .iter()
+                .map(|c| c.is_avoided().value())
+                .collect();
+            assert_eq!(outcomes, outcomes_again);
+        }
+    }
+
+    #[test]
+    fn test_verify_replay_with_tampered_combat() {
+        let replay = play_game();
+        let mut json: serde_json::Value = serde_json::from_str(&replay.to_json().unwrap()).unwrap();
+        // 記録された戦闘結果を書き換える
+        let is_avoided = json["turns"][0]["steps"][0]["combats"][0]["isAvoided"]
+            .as_bool()
+            .unwrap();
+        json["turns"][0]["steps"][0]["combats"][0]["isAvoided"] = serde_json::json!(!is_avoided);
+        let tampered = Replay::from_json(&json.to_string()).unwrap();
+
+        let result = ReplayRunner::verify(&tampered);
+        assert_eq!(
+            result.unwrap_err(),
+            "ターン1のステップ1の戦闘結果が記録と一致しません"
+        );
+    }
+}
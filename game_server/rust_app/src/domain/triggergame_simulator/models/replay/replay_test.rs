@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod tests {
+    use super::super::replay::Replay;
+    use crate::domain::player_management::models::player::player_id::player_id::PlayerId;
+    use crate::domain::triggergame_simulator::models::game::current_turn_number::current_turn_number::CurrentTurnNumber;
+    use crate::domain::triggergame_simulator::models::game::game::Game;
+    use crate::domain::triggergame_simulator::models::game::game_id::game_id::GameId;
+    use crate::domain::triggergame_simulator::models::game::game_status::game_status::GameStatus;
+    use crate::domain::triggergame_simulator::models::game::game_version::game_version::GameVersion;
+    use crate::domain::triggergame_simulator::models::game::simulation_seed::simulation_seed::SimulationSeed;
+    use crate::domain::triggergame_simulator::models::turn::turn_number::turn_number::TurnNumber;
+    use crate::domain::triggergame_simulator::models::turn::Turn;
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    fn create_game(current_turn_number: i32) -> Game {
+        Game::reconstruct(
+            GameId::new(Uuid::new_v4().to_string()),
+            CurrentTurnNumber::new(current_turn_number),
+            PlayerId::new(Uuid::new_v4().to_string()),
+            PlayerId::new(Uuid::new_v4().to_string()),
+            GameVersion::initial(),
+            Utc::now(),
+            Vec::new(),
+            GameStatus::initial(),
+            SimulationSeed::generate(),
+        )
+    }
+
+    fn create_completed_turn(game: &Game, turn_number: i32) -> Turn {
+        let mut turn = Turn::create(
+            game.game_id().clone(),
+            game.player1_id().clone(),
+            TurnNumber::new(turn_number),
+            Utc::now(),
+        );
+        turn.complete(Utc::now()).unwrap();
+        turn
+    }
+
+    #[test]
+    fn test_create_replay() {
+        let game = create_game(6);
+        let turns = vec![
+            create_completed_turn(&game, 2),
+            create_completed_turn(&game, 1),
+        ];
+
+        let replay = Replay::create(&game, &[], turns).unwrap();
+        assert_eq!(replay.format_version(), Replay::FORMAT_VERSION);
+        assert_eq!(replay.game_id(), game.game_id());
+        // 対戦中の演算と同じく、ゲームに保存されたシードが記録されること
+        assert_eq!(replay.rng_seed(), game.simulation_seed());
+        assert_eq!(replay.master_data_version(), "2");
+        assert!(replay.is_current_master_data());
+        // ターン数の順に並べ替えられていること
+        assert_eq!(replay.turns()[0].turn_number().value(), 1);
+        assert_eq!(replay.turns()[1].turn_number().value(), 2);
+    }
+
+    #[test]
+    fn test_create_replay_not_finished_game() {
+        let game = create_game(3);
+        let result = Replay::create(&game, &[], vec![]);
+        assert_eq!(
+            result.unwrap_err(),
+            "終了していないゲームのリプレイは作成できません"
+        );
+    }
+
+    #[test]
+    fn test_create_replay_with_uncompleted_turn() {
+        let game = create_game(6);
+        let turn = Turn::create(
+            game.game_id().clone(),
+            game.player1_id().clone(),
+            TurnNumber::new(1),
+            Utc::now(),
+        );
+        let result = Replay::create(&game, &[], vec![turn]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let game = create_game(6);
+        let replay = Replay::create(&game, &[], vec![create_completed_turn(&game, 1)]).unwrap();
+
+        let json = replay.to_json().unwrap();
+        let restored = Replay::from_json(&json).unwrap();
+        assert_eq!(restored.game_id(), replay.game_id());
+        assert_eq!(restored.rng_seed(), replay.rng_seed());
+        assert_eq!(restored.player1_id(), replay.player1_id());
+        assert_eq!(restored.turns().len(), 1);
+    }
+
+    #[test]
+    fn test_replay_with_previous_master_data() {
+        // 状態効果の導入前のマスターデータで記録されたリプレイ
+        let game = create_game(6);
+        let replay = Replay::create(&game, &[], vec![]).unwrap();
+        let mut json: serde_json::Value = serde_json::from_str(&replay.to_json().unwrap()).unwrap();
+        json["masterDataVersion"] = serde_json::json!("1");
+
+        let restored = Replay::from_json(&json.to_string()).unwrap();
+        assert_eq!(restored.master_data_version(), "1");
+        assert!(!restored.is_current_master_data());
+    }
+
+    #[test]
+    fn test_from_json_unsupported_version() {
+        let game = create_game(6);
+        let replay = Replay::create(&game, &[], vec![]).unwrap();
+        let mut json: serde_json::Value = serde_json::from_str(&replay.to_json().unwrap()).unwrap();
+        json["formatVersion"] = serde_json::json!(Replay::FORMAT_VERSION + 1);
+
+        let result = Replay::from_json(&json.to_string());
+        assert!(result.is_err());
+    }
+}
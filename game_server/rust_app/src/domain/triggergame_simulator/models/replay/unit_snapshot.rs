@@ -0,0 +1,119 @@
+use crate::domain::player_management::models::player::player_id::player_id::PlayerId;
+use crate::domain::triggergame_simulator::models::action::trigger_azimuth::trigger_azimuth::TriggerAzimuth;
+use crate::domain::triggergame_simulator::models::game::game_id::game_id::GameId;
+use crate::domain::unit_management::models::unit::{
+    current_action_points::current_action_points::CurrentActionPoints,
+    having_trigger_ids::having_trigger_ids::HavingTriggerIds, is_bailout::is_bailout::IsBailout,
+    main_trigger_hp::main_trigger_hp::MainTriggerHP, position::position::Position,
+    sight_range::sight_range::SightRange, status_effect::status_effect::StatusEffect,
+    sub_trigger_hp::sub_trigger_hp::SubTriggerHP,
+    trigger_id::trigger_id::TriggerId, unit_id::unit_id::UnitId,
+    unit_type_id::unit_type_id::UnitTypeId, wait_time::wait_time::WaitTime, Unit,
+};
+use serde::{Deserialize, Serialize};
+
+/// リプレイに記録するユニットの状態
+/// ある時点の盤面上のユニットを表す
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnitSnapshot {
+    unit_id: UnitId,
+    unit_type_id: UnitTypeId,
+    owner_player_id: PlayerId,
+    position: Position,
+    using_main_trigger_id: TriggerId,
+    using_sub_trigger_id: TriggerId,
+    having_main_trigger_ids: Vec<TriggerId>,
+    having_sub_trigger_ids: Vec<TriggerId>,
+    main_trigger_hp: i32,
+    sub_trigger_hp: i32,
+    main_trigger_azimuth: TriggerAzimuth,
+    sub_trigger_azimuth: TriggerAzimuth,
+    sight_range: i32,
+    wait_time: i32,
+    action_points: i32,
+    is_bailout: bool,
+    status_effects: Vec<StatusEffect>,
+}
+
+impl UnitSnapshot {
+    /// ユニットの現在の状態を記録
+    pub fn from_unit(unit: &Unit) -> Self {
+        Self {
+            unit_id: unit.unit_id().clone(),
+            unit_type_id: unit.unit_type_id().clone(),
+            owner_player_id: unit.owner_player_id().clone(),
+            position: unit.position().clone(),
+            using_main_trigger_id: unit.using_main_trigger_id().clone(),
+            using_sub_trigger_id: unit.using_sub_trigger_id().clone(),
+            having_main_trigger_ids: unit.having_main_trigger_ids().value().clone(),
+            having_sub_trigger_ids: unit.having_sub_trigger_ids().value().clone(),
+            main_trigger_hp: unit.main_trigger_hp().value(),
+            sub_trigger_hp: unit.sub_trigger_hp().value(),
+            main_trigger_azimuth: unit.main_trigger_azimuth().clone(),
+            sub_trigger_azimuth: unit.sub_trigger_azimuth().clone(),
+            sight_range: unit.sight_range().value(),
+            wait_time: unit.wait_time().value(),
+            action_points: unit.current_action_points().value(),
+            is_bailout: unit.is_bailed_out(),
+            status_effects: unit.status_effects().to_vec(),
+        }
+    }
+
+    /// 記録した状態からユニットを復元
+    pub fn to_unit(&self, game_id: &GameId) -> Unit {
+        Unit::reconstruct(
+            self.unit_id.clone(),
+            self.unit_type_id.clone(),
+            game_id.clone(),
+            self.owner_player_id.clone(),
+            CurrentActionPoints::new(self.action_points),
+            WaitTime::new(self.wait_time),
+            self.position.clone(),
+            self.using_main_trigger_id.clone(),
+            self.using_sub_trigger_id.clone(),
+            HavingTriggerIds::new(self.having_main_trigger_ids.clone()),
+            HavingTriggerIds::new(self.having_sub_trigger_ids.clone()),
+            MainTriggerHP::new(self.main_trigger_hp),
+            SubTriggerHP::new(self.sub_trigger_hp),
+            self.main_trigger_azimuth.clone(),
+            self.sub_trigger_azimuth.clone(),
+            SightRange::new(self.sight_range),
+            IsBailout::new(self.is_bailout),
+            self.status_effects.clone(),
+        )
+    }
+
+    // ゲッター
+    pub fn unit_id(&self) -> &UnitId {
+        &self.unit_id
+    }
+
+    pub fn owner_player_id(&self) -> &PlayerId {
+        &self.owner_player_id
+    }
+
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+
+    pub fn using_main_trigger_id(&self) -> &TriggerId {
+        &self.using_main_trigger_id
+    }
+
+    pub fn using_sub_trigger_id(&self) -> &TriggerId {
+        &self.using_sub_trigger_id
+    }
+
+    pub fn main_trigger_azimuth(&self) -> &TriggerAzimuth {
+        &self.main_trigger_azimuth
+    }
+
+    pub fn sub_trigger_azimuth(&self) -> &TriggerAzimuth {
+        &self.sub_trigger_azimuth
+    }
+
+    pub fn is_bailout(&self) -> bool {
+        self.is_bailout
+    }
+}
@@ -1,3 +1,4 @@
+pub mod status_effect_change;
 pub mod step;
 pub mod step_id;
 pub mod step_test;
@@ -0,0 +1,2 @@
+pub mod status_effect_change;
+pub mod status_effect_change_test;
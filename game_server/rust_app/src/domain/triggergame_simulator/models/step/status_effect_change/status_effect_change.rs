@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::unit_management::models::unit::{
+    status_effect::status_effect::StatusEffect, unit_id::unit_id::UnitId,
+};
+
+/// ステップ中に状態効果が変化したユニットの、ステップ終了時点の状態効果
+/// 全ての状態効果が切れた場合は空のリストとなる
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusEffectChange {
+    unit_id: UnitId,
+    status_effects: Vec<StatusEffect>,
+}
+
+impl StatusEffectChange {
+    pub fn new(unit_id: UnitId, status_effects: Vec<StatusEffect>) -> Self {
+        Self {
+            unit_id,
+            status_effects,
+        }
+    }
+
+    pub fn unit_id(&self) -> &UnitId {
+        &self.unit_id
+    }
+
+    pub fn status_effects(&self) -> &[StatusEffect] {
+        &self.status_effects
+    }
+}
@@ -0,0 +1,34 @@
+#[cfg(test)]
+mod tests {
+    use super::super::status_effect_change::StatusEffectChange;
+    use crate::domain::unit_management::models::unit::{
+        status_effect::status_effect::StatusEffect, unit_id::unit_id::UnitId,
+    };
+    use uuid::Uuid;
+
+    #[test]
+    fn test_new() {
+        let unit_id = UnitId::new(Uuid::new_v4().to_string());
+        let change = StatusEffectChange::new(
+            unit_id.clone(),
+            vec![StatusEffect::new("BOOST_SEAL".to_string(), 2, 1)],
+        );
+        assert_eq!(change.unit_id(), &unit_id);
+        assert_eq!(change.status_effects().len(), 1);
+        assert_eq!(change.status_effects()[0].status_effect_id(), "BOOST_SEAL");
+    }
+
+    #[test]
+    fn test_serialize() {
+        let unit_id = UnitId::new(Uuid::new_v4().to_string());
+        let change = StatusEffectChange::new(
+            unit_id.clone(),
+            vec![StatusEffect::new("LEAD_WEIGHT".to_string(), 1, 1)],
+        );
+        let json = serde_json::to_value(&change).unwrap();
+        assert_eq!(json["unitId"], unit_id.value());
+        assert_eq!(json["statusEffects"][0]["statusEffectId"], "LEAD_WEIGHT");
+        assert_eq!(json["statusEffects"][0]["remainingSteps"], 1);
+        assert_eq!(json["statusEffects"][0]["stacks"], 1);
+    }
+}
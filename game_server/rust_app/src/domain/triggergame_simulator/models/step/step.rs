@@ -1,13 +1,18 @@
 use std::collections::HashMap;
 use std::hash::Hash;
 
+use crate::domain::triggergame_simulator::configs::trigger_status::TriggerStatus;
 use crate::domain::triggergame_simulator::models::action::Action;
 use crate::domain::triggergame_simulator::models::combat::Combat;
+use crate::domain::triggergame_simulator::models::game_event::game_event_payload::GameEventPayload;
+use crate::domain::triggergame_simulator::models::step::status_effect_change::status_effect_change::StatusEffectChange;
 use crate::domain::triggergame_simulator::models::step::step_id::step_id::StepId;
 use crate::domain::unit_management::models::unit::{
     position::position::Position, trigger_id::trigger_id::TriggerId, unit_id::unit_id::UnitId, Unit,
 };
+use crate::domain::unit_management::models::unit_type::unit_type_spec::UnitTypeSpec;
 
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 /// Step集約
@@ -18,25 +23,40 @@ pub struct Step {
     step_id: StepId,
     actions: Vec<Action>,
     combats: Vec<Combat>,
+    /// ステップ中に状態効果が変化したユニットごとの、ステップ終了時点の状態効果
+    status_effect_changes: Vec<StatusEffectChange>,
 }
 
 impl Step {
     // privateなコンストラクタ
-    pub fn new(step_id: StepId, actions: Vec<Action>, combats: Vec<Combat>) -> Self {
+    pub fn new(
+        step_id: StepId,
+        actions: Vec<Action>,
+        combats: Vec<Combat>,
+        status_effect_changes: Vec<StatusEffectChange>,
+    ) -> Self {
         Self {
             step_id,
             actions,
             combats,
+            status_effect_changes,
         }
     }
 
     /// 新規ステップの生成
     pub fn create(step_id: StepId, actions: Vec<Action>, combats: Vec<Combat>) -> Self {
-        Self::new(step_id, actions, combats)
+        Self::new(step_id, actions, combats, Vec::new())
     }
 
     /// 戦闘演算の開始
-    pub fn step_start(&mut self, units: &mut Vec<Unit>) -> Result<(), String> {
+    /// 演算中に発生したゲームイベントを発生順に返す
+    pub fn step_start(
+        &mut self,
+        units: &mut Vec<Unit>,
+        rng: &mut impl Rng,
+    ) -> Result<Vec<GameEventPayload>, String> {
+        let mut events: Vec<GameEventPayload> = Vec::new();
+
         // 1. アクションとユニットの整合性チェック
         for action in &self.actions {
             // 対応するユニットが存在しなければエラー
@@ -49,7 +69,34 @@ impl Step {
             }
         }
 
-        // 2. アクションに従ってユニットの移動と使用トリガーの設定、を行う
+        // ステップ開始時点の状態効果（ステップ終了時に変化したユニットを判定するため）
+        let status_effects_at_start: Vec<(UnitId, Vec<_>)> = units
+            .iter()
+            .map(|u| (u.unit_id().clone(), u.status_effects().to_vec()))
+            .collect();
+
+        // 2. ステップ開始時の状態効果の付与
+        // ユニークコマンドのアクションは、ユニットタイプごとの状態効果を自身に付与する
+        // 付与した状態効果は、このステップの戦闘から反映される
+        for action in &self.actions {
+            if !action.action_type().is_unique_command() {
+                continue;
+            }
+            let unit = units
+                .iter_mut()
+                .find(|u| u.unit_id() == action.unit_id())
+                .unwrap();
+            if unit.is_bailed_out() {
+                continue;
+            }
+            let status_effect_id = UnitTypeSpec::get_spec(unit.unit_type_id().value())
+                .and_then(|spec| spec.unique_command_status_effect_id().map(str::to_string));
+            if let Some(status_effect_id) = status_effect_id {
+                unit.apply_status_effect(&status_effect_id)?;
+            }
+        }
+
+        // 3. アクションに従ってユニットの移動と使用トリガーの設定、を行う
         for action in &self.actions {
             let unit = units
                 .iter_mut()
@@ -60,10 +107,18 @@ impl Step {
                 continue;
             }
             // ユニットの位置を更新
-            unit.move_to(action.position().clone());
+            let from = unit.position().clone();
+            if unit.move_to(action.position().clone()) {
+                events.push(GameEventPayload::UnitMoved {
+                    unit_id: unit.unit_id().clone(),
+                    from,
+                    to: unit.position().clone(),
+                });
+            }
 
             const ACTION_POINT_CAN_UPDATE_TRIGGER: i32 = 1; // 消費はしないが、トリガーの更新が可能な行動ポイントの閾値
             if unit.current_action_points().value() >= ACTION_POINT_CAN_UPDATE_TRIGGER {
+                let before = Self::trigger_state(unit);
                 // 使用中のメイントリガーを更新
                 let _ = unit.set_using_triggers(
                     &action.using_main_trigger_id(),
@@ -72,17 +127,28 @@ impl Step {
                 // トリガーの向きを更新
                 unit.set_main_trigger_azimuth(action.main_trigger_azimuth().clone());
                 unit.set_sub_trigger_azimuth(action.sub_trigger_azimuth().clone());
+                if before != Self::trigger_state(unit) {
+                    events.push(GameEventPayload::TriggerSwitched {
+                        unit_id: unit.unit_id().clone(),
+                        main_trigger_id: unit.using_main_trigger_id().clone(),
+                        sub_trigger_id: unit.using_sub_trigger_id().clone(),
+                        main_trigger_azimuth: unit.main_trigger_azimuth().clone(),
+                        sub_trigger_azimuth: unit.sub_trigger_azimuth().clone(),
+                    });
+                }
             } else {
                 print!("トリガーの更新に必要な行動ポイントが不足しています。unit_id={:?}, current_action_points={}, required_action_points={}", unit.unit_id(), unit.current_action_points().value(), ACTION_POINT_CAN_UPDATE_TRIGGER);
             }
         }
 
-        // 3. トリガー範囲内に敵キャラクターがいるか確認し、combatの初期化までを行う
-        // attacker_unit検索用にクローンしておく
-        let attack_units = units.clone();
+        // 4. トリガー範囲内に敵キャラクターがいるか確認し、combatの生成までを行う
+        // 同じステップ内の攻撃は同時に行われるものとし、全てのcombatを移動後の状態に対して生成する
+        // （先に処理したアクションで撃墜されたユニットも、このステップでは攻撃・被弾する）
+        let snapshot = units.clone();
+        let mut combats: Vec<Combat> = Vec::new();
         for action in &self.actions {
             const ACTION_POINT_CAN_ATTACK: i32 = 1; // 攻撃で消費する行動ポイントの閾値
-            let attack_unit = attack_units
+            let attack_unit = snapshot
                 .iter()
                 .find(|u| u.unit_id() == action.unit_id())
                 .unwrap();
@@ -94,7 +160,7 @@ impl Step {
                 println!("ユニットID {:?} の攻撃をスキップ", attack_unit.unit_id());
                 continue;
             }
-            for defence_unit in units.iter_mut() {
+            for defence_unit in snapshot.iter() {
                 // 自ユニットはスキップ
                 if attack_unit.owner_player_id() == defence_unit.owner_player_id() {
                     continue;
@@ -104,12 +170,101 @@ impl Step {
                     continue;
                 }
                 // 射程やトリガーの有効範囲の判定は、Actionのcreate内で行う
-                if let Some(combat) = action.generate_combats(defence_unit) {
-                    self.combats.push(combat);
+                if let Some(combat) = action.generate_combats(attack_unit, defence_unit, rng) {
+                    combats.push(combat);
                 }
             }
         }
-        Ok(())
+
+        // 5. 生成した全てのcombatの結果をユニットに反映する
+        // 各combatのダメージは移動後の状態のトリガーHPに対して計算しているため、
+        // 防御側ごとに命中したダメージを合算してから反映する
+        // 命中したトリガーが付与する状態効果は、ステップ終了時に付与する
+        let mut inflicted_damages: HashMap<UnitId, (i32, i32)> = HashMap::new();
+        let mut defeated_unit_ids: Vec<UnitId> = Vec::new();
+        let mut inflicted_status_effects: Vec<(UnitId, String)> = Vec::new();
+        for combat in combats {
+            events.push(GameEventPayload::CombatResolved {
+                combat: combat.clone(),
+            });
+            for hit in combat.hits() {
+                if hit.is_avoided().value() {
+                    continue;
+                }
+                let damage = inflicted_damages
+                    .entry(combat.defending_unit_id().clone())
+                    .or_insert((0, 0));
+                damage.0 += hit.main_trigger_damage();
+                damage.1 += hit.sub_trigger_damage();
+                if let Some(status_effect_id) =
+                    TriggerStatus::get_trigger_status(hit.trigger_id().value()).status_effect_id()
+                {
+                    inflicted_status_effects.push((
+                        combat.defending_unit_id().clone(),
+                        status_effect_id.to_string(),
+                    ));
+                }
+            }
+            if combat.is_defeated() {
+                defeated_unit_ids.push(combat.defending_unit_id().clone());
+            }
+            self.combats.push(combat);
+        }
+        for defence_unit in units.iter_mut() {
+            if let Some((main_trigger_damage, sub_trigger_damage)) =
+                inflicted_damages.get(defence_unit.unit_id())
+            {
+                defence_unit.apply_damage(*main_trigger_damage, *sub_trigger_damage);
+            }
+            // 1回の攻撃で撃墜された場合に加え、合算したダメージでトリガーHPが0になった場合も撃墜
+            // 同じステップで複数の攻撃に撃墜された場合も、ベイルアウトは1回のみ記録する
+            let is_defeated = defeated_unit_ids.contains(defence_unit.unit_id())
+                || (inflicted_damages.contains_key(defence_unit.unit_id())
+                    && defence_unit.is_trigger_hp_depleted());
+            if is_defeated && !defence_unit.is_bailed_out() {
+                defence_unit.bailout();
+                events.push(GameEventPayload::UnitBailedOut {
+                    unit_id: defence_unit.unit_id().clone(),
+                });
+            }
+        }
+
+        // 6. ステップ終了時の状態効果の処理
+        // このステップで効果のあった状態効果を1ステップ経過させてから、命中による状態効果を付与する
+        // （命中で付与された状態効果は、次のステップから効果ステップ数の間反映される）
+        units.iter_mut().for_each(|u| u.tick_status_effects());
+        for (unit_id, status_effect_id) in inflicted_status_effects {
+            let unit = units.iter_mut().find(|u| u.unit_id() == &unit_id).unwrap();
+            if unit.is_bailed_out() {
+                continue;
+            }
+            unit.apply_status_effect(&status_effect_id)?;
+        }
+        for (unit_id, status_effects) in status_effects_at_start {
+            let unit = units.iter().find(|u| u.unit_id() == &unit_id).unwrap();
+            if unit.status_effects() == status_effects.as_slice() {
+                continue;
+            }
+            events.push(GameEventPayload::StatusEffectsChanged {
+                unit_id: unit_id.clone(),
+                status_effects: unit.status_effects().to_vec(),
+            });
+            self.status_effect_changes.push(StatusEffectChange::new(
+                unit_id,
+                unit.status_effects().to_vec(),
+            ));
+        }
+        Ok(events)
+    }
+
+    /// トリガーの変更検知用に、使用中のトリガーと向きを取得
+    fn trigger_state(unit: &Unit) -> (String, String, i32, i32) {
+        (
+            unit.using_main_trigger_id().value().to_string(),
+            unit.using_sub_trigger_id().value().to_string(),
+            unit.main_trigger_azimuth().value(),
+            unit.sub_trigger_azimuth().value(),
+        )
     }
 
     /// 他のステップのアクションを結合
@@ -118,6 +273,8 @@ impl Step {
         self.actions.extend(other.actions.clone());
         // 他のステップの戦闘を自分の戦闘リストに追加(多分今は必要なし。今後出てくるかも。)
         self.combats.extend(other.combats.clone());
+        self.status_effect_changes
+            .extend(other.status_effect_changes.clone());
         Ok(())
     }
 
@@ -129,6 +286,14 @@ impl Step {
     pub fn actions(&self) -> &Vec<Action> {
         &self.actions
     }
+
+    pub fn combats(&self) -> &Vec<Combat> {
+        &self.combats
+    }
+
+    pub fn status_effect_changes(&self) -> &Vec<StatusEffectChange> {
+        &self.status_effect_changes
+    }
 }
 
 impl PartialEq for Step {
@@ -1 +1,361 @@
+#[cfg(test)]
+mod tests {
+    use super::super::step::Step;
+    use super::super::step_id::step_id::StepId;
+    use crate::domain::player_management::models::player::player_id::player_id::PlayerId;
+    use crate::domain::triggergame_simulator::configs::game_config::GameConfig;
+    use crate::domain::triggergame_simulator::models::action::action_type::action_type::{
+        ActionType, ActionTypeValue,
+    };
+    use crate::domain::triggergame_simulator::models::action::trigger_azimuth::trigger_azimuth::TriggerAzimuth;
+    use crate::domain::triggergame_simulator::models::action::Action;
+    use crate::domain::triggergame_simulator::models::game::game_id::game_id::GameId;
+    use crate::domain::triggergame_simulator::models::game_event::game_event_payload::GameEventPayload;
+    use crate::domain::unit_management::models::unit::{
+        having_trigger_ids::having_trigger_ids::HavingTriggerIds, position::position::Position,
+        status_effect::status_effect::StatusEffect, trigger_id::trigger_id::TriggerId,
+        unit_type_id::unit_type_id::UnitTypeId, Unit,
+    };
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use uuid::Uuid;
 
+    /// 1回の命中で撃墜される、トリガーHPの低いユニット
+    fn create_unit(game_id: &GameId, owner_player_id: &PlayerId, position: Position) -> Unit {
+        create_unit_with(
+            "MIKUMO_OSAMU",
+            "KOGETSU",
+            game_id,
+            owner_player_id,
+            position,
+            10,
+        )
+    }
+
+    /// ユニットタイプ・メイントリガー・トリガーHPを指定したユニット
+    fn create_unit_with(
+        unit_type_id: &str,
+        main_trigger_id: &str,
+        game_id: &GameId,
+        owner_player_id: &PlayerId,
+        position: Position,
+        trigger_hp: i32,
+    ) -> Unit {
+        Unit::create(
+            UnitTypeId::new(unit_type_id.to_string()),
+            game_id.clone(),
+            owner_player_id.clone(),
+            position,
+            TriggerId::new(main_trigger_id.to_string()),
+            TriggerId::new("RAYGUST".to_string()),
+            HavingTriggerIds::new(vec![TriggerId::new(main_trigger_id.to_string())]),
+            HavingTriggerIds::new(vec![TriggerId::new("RAYGUST".to_string())]),
+            trigger_hp,
+            trigger_hp,
+            8,
+            13,
+        )
+    }
+
+    /// その場でトリガーを正面に向けるアクション
+    fn create_attack_action(unit: &Unit) -> Action {
+        create_action(unit, ActionTypeValue::Wait)
+    }
+
+    /// その場でトリガーを正面に向け、指定した種類の行動を行うアクション
+    fn create_action(unit: &Unit, action_type: ActionTypeValue) -> Action {
+        Action::create(
+            ActionType::new(action_type),
+            unit.unit_id().clone(),
+            unit.unit_type_id().clone(),
+            unit.position().clone(),
+            unit.using_main_trigger_id().clone(),
+            unit.using_sub_trigger_id().clone(),
+            TriggerAzimuth::new(90),
+            TriggerAzimuth::new(90),
+        )
+    }
+
+    /// 回避がほぼ発生しない設定でステップを演算し、演算後のステップと発生したゲームイベントを返す
+    fn run_step(actions: Vec<Action>, units: &mut Vec<Unit>) -> (Step, Vec<GameEventPayload>) {
+        let config = GameConfig::get_game_config();
+        let damage_weight = config.damage_weight();
+        let defend_weight = config.defend_weight();
+        let min_damage = config.min_damage();
+        GameConfig::set_thread_override(Some(config.with_combat_weights(
+            i32::MAX,
+            damage_weight,
+            defend_weight,
+            min_damage,
+        )));
+        let mut step = Step::create(StepId::new(Uuid::new_v4().to_string()), actions, vec![]);
+        let events = step
+            .step_start(units, &mut StdRng::seed_from_u64(0))
+            .unwrap();
+        GameConfig::set_thread_override(None);
+        (step, events)
+    }
+
+    /// 状態効果が変化したイベントのうち、指定したユニットのもの
+    fn status_effects_changed(events: &[GameEventPayload], unit: &Unit) -> Vec<Vec<StatusEffect>> {
+        events
+            .iter()
+            .filter_map(|e| match e {
+                GameEventPayload::StatusEffectsChanged {
+                    unit_id,
+                    status_effects,
+                } if unit_id == unit.unit_id() => Some(status_effects.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn count_bailouts(events: &[GameEventPayload]) -> usize {
+        events
+            .iter()
+            .filter(|e| matches!(e, GameEventPayload::UnitBailedOut { .. }))
+            .count()
+    }
+
+    #[test]
+    fn test_simultaneous_exchange_is_symmetric() {
+        let game_id = GameId::new(Uuid::new_v4().to_string());
+        let player1_id = PlayerId::new(Uuid::new_v4().to_string());
+        let player2_id = PlayerId::new(Uuid::new_v4().to_string());
+        // 互いの座標系で同じセルに位置し、正面を向けば戦闘が発生する配置
+        let unit1 = create_unit(&game_id, &player1_id, Position::new(17, 17));
+        let unit2 = create_unit(&game_id, &player2_id, Position::new(18, 18));
+
+        // アクションの順序にかかわらず、相打ちになること
+        for actions in [
+            vec![create_attack_action(&unit1), create_attack_action(&unit2)],
+            vec![create_attack_action(&unit2), create_attack_action(&unit1)],
+        ] {
+            let mut units = vec![unit1.clone(), unit2.clone()];
+            let (_, events) = run_step(actions, &mut units);
+            assert!(units.iter().all(|u| u.is_bailed_out()));
+            assert_eq!(count_bailouts(&events), 2);
+        }
+    }
+
+    #[test]
+    fn test_unit_defeated_in_step_is_still_attacked_by_later_actions() {
+        let game_id = GameId::new(Uuid::new_v4().to_string());
+        let player1_id = PlayerId::new(Uuid::new_v4().to_string());
+        let player2_id = PlayerId::new(Uuid::new_v4().to_string());
+        let attacker1 = create_unit(&game_id, &player1_id, Position::new(17, 17));
+        let attacker2 = create_unit(&game_id, &player1_id, Position::new(17, 17));
+        let defender = create_unit(&game_id, &player2_id, Position::new(18, 18));
+        let mut units = vec![attacker1.clone(), attacker2.clone(), defender.clone()];
+
+        let (step, events) = run_step(
+            vec![
+                create_attack_action(&attacker1),
+                create_attack_action(&attacker2),
+                create_attack_action(&defender),
+            ],
+            &mut units,
+        );
+
+        // 防御側は2体からの攻撃を受け、撃墜された後も同じステップで反撃する
+        let combats_against_defender = step
+            .combats()
+            .iter()
+            .filter(|c| c.defending_unit_id() == defender.unit_id())
+            .count();
+        assert_eq!(combats_against_defender, 2);
+        assert_eq!(
+            step.combats()
+                .iter()
+                .filter(|c| c.attacking_unit_id() == defender.unit_id())
+                .count(),
+            2
+        );
+        // ベイルアウトはユニットごとに1回のみ記録される
+        assert_eq!(count_bailouts(&events), 3);
+        assert!(units.iter().all(|u| u.is_bailed_out()));
+    }
+
+    #[test]
+    fn test_damage_from_multiple_combats_is_accumulated() {
+        let game_id = GameId::new(Uuid::new_v4().to_string());
+        let player1_id = PlayerId::new(Uuid::new_v4().to_string());
+        let player2_id = PlayerId::new(Uuid::new_v4().to_string());
+        let create_attacker = || {
+            create_unit_with(
+                "MIKUMO_OSAMU",
+                "KOGETSU",
+                &game_id,
+                &player1_id,
+                Position::new(17, 17),
+                1000,
+            )
+        };
+        let attacker1 = create_attacker();
+        let attacker2 = create_attacker();
+        // 1回の戦闘では撃墜されないトリガーHPのユニット
+        let defender = create_unit_with(
+            "MIKUMO_OSAMU",
+            "KOGETSU",
+            &game_id,
+            &player2_id,
+            Position::new(18, 18),
+            30,
+        );
+
+        // 1体からの攻撃では、ダメージを受けるが撃墜されない
+        let mut units = vec![attacker1.clone(), defender.clone()];
+        let (step, events) = run_step(
+            vec![
+                create_attack_action(&attacker1),
+                create_attack_action(&defender),
+            ],
+            &mut units,
+        );
+        let combat = step
+            .combats()
+            .iter()
+            .find(|c| c.defending_unit_id() == defender.unit_id())
+            .unwrap();
+        assert!(!combat.is_defeated());
+        let damaged_defender = units.iter().find(|u| u == &&defender).unwrap();
+        assert_eq!(
+            damaged_defender.main_trigger_hp().value(),
+            30 - combat.main_trigger_damage()
+        );
+        assert_eq!(
+            damaged_defender.sub_trigger_hp().value(),
+            30 - combat.sub_trigger_damage()
+        );
+        assert!(damaged_defender.is_active());
+        assert_eq!(count_bailouts(&events), 0);
+
+        // 2体からの攻撃は、それぞれ撃墜に届かなくても合算したダメージで撃墜される
+        let mut units = vec![attacker1.clone(), attacker2.clone(), defender.clone()];
+        let (step, events) = run_step(
+            vec![
+                create_attack_action(&attacker1),
+                create_attack_action(&attacker2),
+                create_attack_action(&defender),
+            ],
+            &mut units,
+        );
+        let combats_against_defender: Vec<_> = step
+            .combats()
+            .iter()
+            .filter(|c| c.defending_unit_id() == defender.unit_id())
+            .collect();
+        assert_eq!(combats_against_defender.len(), 2);
+        assert!(combats_against_defender.iter().all(|c| !c.is_defeated()));
+        let defeated_defender = units.iter().find(|u| u == &&defender).unwrap();
+        assert!(defeated_defender.is_trigger_hp_depleted());
+        assert!(defeated_defender.is_bailed_out());
+        assert_eq!(count_bailouts(&events), 1);
+    }
+
+    #[test]
+    fn test_unique_command_applies_status_effect_from_same_step() {
+        let game_id = GameId::new(Uuid::new_v4().to_string());
+        let player1_id = PlayerId::new(Uuid::new_v4().to_string());
+        let player2_id = PlayerId::new(Uuid::new_v4().to_string());
+        let yuma = create_unit_with(
+            "KUGA_YUMA",
+            "KOGETSU",
+            &game_id,
+            &player1_id,
+            Position::new(17, 17),
+            1000,
+        );
+        let defender = create_unit_with(
+            "MIKUMO_OSAMU",
+            "KOGETSU",
+            &game_id,
+            &player2_id,
+            Position::new(18, 18),
+            1000,
+        );
+        let mut units = vec![yuma.clone(), defender.clone()];
+
+        let (step, events) = run_step(
+            vec![create_action(&yuma, ActionTypeValue::UniqueCommand)],
+            &mut units,
+        );
+
+        // 強印の攻撃力+2が、付与したステップの戦闘から反映される
+        let combat = step
+            .combats()
+            .iter()
+            .find(|c| c.attacking_unit_id() == yuma.unit_id())
+            .unwrap();
+        assert_eq!(combat.attacker_base_attack(), 9 + 2);
+        // ステップ終了時に1ステップ経過した状態効果が記録される
+        let expected = vec![StatusEffect::new("BOOST_SEAL".to_string(), 2, 1)];
+        assert_eq!(
+            status_effects_changed(&events, &yuma),
+            vec![expected.clone()]
+        );
+        assert_eq!(step.status_effect_changes().len(), 1);
+        assert_eq!(step.status_effect_changes()[0].unit_id(), yuma.unit_id());
+        assert_eq!(step.status_effect_changes()[0].status_effects(), expected);
+        assert!(status_effects_changed(&events, &defender).is_empty());
+    }
+
+    #[test]
+    fn test_trigger_hit_applies_status_effect_until_expired() {
+        let game_id = GameId::new(Uuid::new_v4().to_string());
+        let player1_id = PlayerId::new(Uuid::new_v4().to_string());
+        let player2_id = PlayerId::new(Uuid::new_v4().to_string());
+        let attacker = create_unit_with(
+            "MIKUMO_OSAMU",
+            "LEAD_BULLET",
+            &game_id,
+            &player1_id,
+            Position::new(10, 10),
+            1000,
+        );
+        // 攻撃側から見て1マス下に位置し、攻撃側にトリガーを向けて防御する防御側
+        let mut defender = create_unit_with(
+            "MIKUMO_OSAMU",
+            "KOGETSU",
+            &game_id,
+            &player2_id,
+            Position::new(25, 24),
+            1000,
+        );
+        defender.set_main_trigger_azimuth(TriggerAzimuth::new(180));
+        defender.set_sub_trigger_azimuth(TriggerAzimuth::new(180));
+        let mut units = vec![attacker.clone(), defender.clone()];
+        // 射程の角度が狭い鉛弾を、下方向の防御側に向ける
+        let action = Action::create(
+            ActionType::new(ActionTypeValue::Wait),
+            attacker.unit_id().clone(),
+            attacker.unit_type_id().clone(),
+            attacker.position().clone(),
+            attacker.using_main_trigger_id().clone(),
+            attacker.using_sub_trigger_id().clone(),
+            TriggerAzimuth::new(180),
+            TriggerAzimuth::new(180),
+        );
+
+        // 命中したステップの終了時に付与され、次のステップから2ステップの間効果が続く
+        let (_, events) = run_step(vec![action], &mut units);
+        assert_eq!(
+            status_effects_changed(&events, &defender),
+            vec![vec![StatusEffect::new("LEAD_WEIGHT".to_string(), 2, 1)]]
+        );
+        assert!(status_effects_changed(&events, &attacker).is_empty());
+        assert_eq!(units[1].status_modifier().avoid(), -3);
+
+        let (_, events) = run_step(vec![], &mut units);
+        assert_eq!(
+            status_effects_changed(&events, &defender),
+            vec![vec![StatusEffect::new("LEAD_WEIGHT".to_string(), 1, 1)]]
+        );
+
+        // 効果が切れたステップでは空の状態効果が記録される
+        let (step, events) = run_step(vec![], &mut units);
+        assert_eq!(status_effects_changed(&events, &defender), vec![vec![]]);
+        assert!(step.status_effect_changes()[0].status_effects().is_empty());
+        assert!(units[1].status_effects().is_empty());
+    }
+}
@@ -1,5 +1,6 @@
 use crate::domain::player_management::models::player::player_id::player_id::PlayerId;
 use crate::domain::triggergame_simulator::models::game::game_id::game_id::GameId;
+use crate::domain::triggergame_simulator::models::game_event::game_event_payload::GameEventPayload;
 use crate::domain::triggergame_simulator::models::step::step::Step;
 use crate::domain::unit_management::models::unit::Unit;
 
@@ -9,6 +10,7 @@ use super::turn_number::turn_number::TurnNumber;
 use super::turn_start_datetime::turn_start_datetime::TurnStartDatetime;
 use super::turn_status::turn_status::{TurnStatus, TurnStatusValue};
 use chrono::{DateTime, Utc};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -107,11 +109,14 @@ impl Turn {
     }
 
     /// ターンの戦闘処理を開始
+    /// 演算中に発生したゲームイベントを発生順に返す
+    /// 回避判定の乱数はrngから取得するため、同じシードの乱数生成器で再演算すると同じ結果になる
     pub fn turn_start(
         &mut self,
         units: &mut Vec<Unit>,
         opponent_turn: &Turn,
-    ) -> Result<(), String> {
+        rng: &mut impl Rng,
+    ) -> Result<Vec<GameEventPayload>, String> {
         print!(
             "ターン開始: {:?} のターン{:?}, {:?}が開始されました",
             self.player_id,
@@ -136,11 +141,12 @@ impl Turn {
         units.iter_mut().for_each(|u| u.reset_action_points());
 
         // 各ステップの戦闘演算を開始
+        let mut events = Vec::new();
         for step in &mut self.steps {
-            step.step_start(units)?;
+            events.extend(step.step_start(units, rng)?);
         }
 
-        Ok(())
+        Ok(events)
     }
 
     /// ターンをユニット行動中ステータスに変更
@@ -161,6 +167,23 @@ impl Turn {
         Ok(())
     }
 
+    /// 完了したターンの演算結果を対戦相手のターン情報に反映したものを生成
+    /// 両プレイヤーのターン情報に同じ演算結果を保存するために使用
+    pub fn resolved_turn_for(&self, opponent_turn: &Turn) -> Result<Turn, String> {
+        if !self.turn_status.is_completed() {
+            return Err("完了していないターンの演算結果は反映できません".to_string());
+        }
+        Ok(Self::new(
+            opponent_turn.turn_id.clone(),
+            opponent_turn.game_id.clone(),
+            opponent_turn.player_id.clone(),
+            opponent_turn.turn_number.clone(),
+            opponent_turn.turn_start_datetime.clone(),
+            self.turn_status.clone(),
+            self.steps.clone(),
+        ))
+    }
+
     /// プレイヤーを引数として各プレイヤー向けのターン情報を生成
     pub fn generate_player_turn(&mut self, player_id: &PlayerId) -> Turn {
         // ターンの情報をプレイヤーごとに向けた形に修正
@@ -72,6 +72,39 @@ mod tests {
         assert_eq!(result.unwrap_err(), "既にターンは完了しています");
     }
 
+    #[test]
+    fn test_resolved_turn_for_opponent() {
+        let game_id = GameId::new(Uuid::new_v4().to_string());
+        let player_id = PlayerId::new(Uuid::new_v4().to_string());
+        let opponent_player_id = PlayerId::new(Uuid::new_v4().to_string());
+        let turn_number = TurnNumber::new(1);
+
+        let mut turn = Turn::create(
+            game_id.clone(),
+            player_id.clone(),
+            turn_number.clone(),
+            Utc::now(),
+        );
+        let opponent_turn = Turn::create(
+            game_id.clone(),
+            opponent_player_id.clone(),
+            turn_number.clone(),
+            Utc::now(),
+        );
+
+        // 完了前は反映できない
+        assert!(turn.resolved_turn_for(&opponent_turn).is_err());
+
+        turn.complete(Utc::now()).unwrap();
+        let resolved = turn.resolved_turn_for(&opponent_turn).unwrap();
+
+        // 対戦相手のターンとして完了状態の演算結果を持つ
+        assert_eq!(resolved.turn_id(), opponent_turn.turn_id());
+        assert_eq!(resolved.player_id(), &opponent_player_id);
+        assert!(resolved.is_completed());
+        assert_eq!(resolved.steps().len(), turn.steps().len());
+    }
+
     #[test]
     fn test_reconstruct_turn() {
         let turn_id = TurnId::new(Uuid::new_v4().to_string());
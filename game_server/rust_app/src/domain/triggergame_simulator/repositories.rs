@@ -1,2 +1,5 @@
+pub mod game_event_repository;
 pub mod game_repository;
+pub mod replay_repository;
 pub mod turn_repository;
+pub mod turn_unit_of_work;
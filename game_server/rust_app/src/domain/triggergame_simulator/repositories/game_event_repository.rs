@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+
+use crate::domain::triggergame_simulator::models::{
+    game::game_id::game_id::GameId,
+    game_event::{event_sequence::event_sequence::EventSequence, game_event::GameEvent},
+};
+
+/// 同じ通し番号のイベントが既に登録されていた場合のエラーメッセージ
+/// 他のリクエストが先にイベントを追記したことを表す
+pub const GAME_EVENT_SEQUENCE_CONFLICT_ERROR: &str =
+    "ゲームイベントが他のリクエストによって追記されています";
+
+/// GameEventリポジトリのトレイト
+/// イベントは追記のみ可能で、更新・削除は行わない
+#[async_trait]
+pub trait GameEventRepository: Send + Sync {
+    /// イベントを追記
+    /// 同じ通し番号のイベントが既に登録されている場合は`GAME_EVENT_SEQUENCE_CONFLICT_ERROR`を返す
+    async fn append(&self, events: &[GameEvent]) -> Result<(), String>;
+
+    /// 指定したゲームの最新の通し番号を取得
+    async fn get_latest_sequence(&self, game_id: &GameId)
+        -> Result<Option<EventSequence>, String>;
+
+    /// 指定したゲームのイベントを通し番号順に取得
+    async fn get_events(&self, game_id: &GameId) -> Result<Vec<GameEvent>, String>;
+}
@@ -1,9 +1,16 @@
 use crate::domain::{
     matching_management::models::matching::Matching,
-    triggergame_simulator::models::game::{game::Game, game_id::game_id::GameId},
+    player_management::models::player::player_id::player_id::PlayerId,
+    triggergame_simulator::models::game::{
+        game::Game, game_id::game_id::GameId, spectator::spectator::Spectator,
+    },
 };
 use async_trait::async_trait;
 
+/// 楽観的ロックでバージョンが一致しなかった場合のエラーメッセージ
+/// 他のリクエストが先にゲーム情報を更新したことを表す
+pub const GAME_VERSION_CONFLICT_ERROR: &str = "ゲーム情報が他のリクエストによって更新されています";
+
 /// Gameリポジトリのトレイト
 #[async_trait]
 pub trait GameRepository: Send + Sync {
@@ -11,8 +18,20 @@ pub trait GameRepository: Send + Sync {
     async fn save(&self, game: &Game) -> Result<(), String>;
 
     /// ゲーム情報を更新
+    /// 取得時のバージョンと一致しない場合は`GAME_VERSION_CONFLICT_ERROR`を返す
     async fn update_current_turn(&self, game: &Game) -> Result<(), String>;
 
     /// 指定したゲームIDの情報を取得
     async fn get_game_by_id(&self, game_id: &GameId) -> Result<Game, String>;
+
+    /// 指定したプレイヤーが参加している最新のゲーム情報を取得
+    /// 参加しているゲームが存在しない場合、または最新のゲームが終了済みの場合は`None`を返す
+    async fn find_latest_by_player_id(&self, player_id: &PlayerId) -> Result<Option<Game>, String>;
+
+    /// 観戦者を追加（既に観戦中の場合は上書き）
+    /// 観戦者の増減はターンの進行と競合しないよう、バージョンを更新せずに反映する
+    async fn add_spectator(&self, game_id: &GameId, spectator: &Spectator) -> Result<(), String>;
+
+    /// 観戦者を削除
+    async fn remove_spectator(&self, game_id: &GameId, player_id: &PlayerId) -> Result<(), String>;
 }
@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+
+use crate::domain::triggergame_simulator::models::{
+    game::game_id::game_id::GameId, replay::replay::Replay,
+};
+
+/// Replayリポジトリのトレイト
+#[async_trait]
+pub trait ReplayRepository: Send + Sync {
+    /// リプレイを保存
+    /// 同じゲームのリプレイが既に存在する場合は上書きする
+    async fn save(&self, replay: &Replay) -> Result<(), String>;
+
+    /// 指定したゲームIDのリプレイを取得
+    async fn find_by_game_id(&self, game_id: &GameId) -> Result<Option<Replay>, String>;
+}
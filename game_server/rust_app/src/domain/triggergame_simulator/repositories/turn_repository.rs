@@ -8,10 +8,14 @@ use crate::domain::{
     },
 };
 
+/// 同じターン情報が既に登録されていた場合のエラーメッセージ
+pub const TURN_ALREADY_EXISTS_ERROR: &str = "このターンの情報はすでに登録されています。";
+
 /// Turnリポジトリのトレイト
 #[async_trait]
 pub trait TurnRepository: Send + Sync {
     /// ゲーム情報を保存
+    /// 同じターンIDが既に登録されている場合は`TURN_ALREADY_EXISTS_ERROR`を返す
     async fn save(&self, turn: &Turn) -> Result<(), String>;
 
     /// ゲーム情報を更新
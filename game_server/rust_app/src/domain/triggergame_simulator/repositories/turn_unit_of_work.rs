@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    triggergame_simulator::models::{
+        game::game::Game, game_event::game_event::GameEvent, turn::Turn,
+    },
+    unit_management::models::unit::Unit,
+};
+
+/// ターン演算結果をまとめて反映するユニットオブワークのトレイト
+/// 演算済みのターン・更新後のユニット・進行後のゲーム情報・発生したゲームイベントは
+/// すべて反映されるか、いずれも反映されないかのどちらかになる
+#[async_trait]
+pub trait TurnUnitOfWork: Send + Sync {
+    /// プレイヤーのターン登録をコミット
+    /// ターン情報の登録と、登録を表すゲームイベントの追記をまとめて行う
+    /// 同じプレイヤーの同じターンが登録済みの場合は`TURN_ALREADY_EXISTS_ERROR`、
+    /// イベントの通し番号が他のリクエストと競合した場合は`GAME_EVENT_SEQUENCE_CONFLICT_ERROR`を返す
+    async fn submit_turn(&self, turn: &Turn, events: &[GameEvent]) -> Result<(), String>;
+
+    /// ターンの演算結果をコミット
+    /// 両プレイヤーのターン情報に同じ演算結果を保存するため、ターンは複数受け取る
+    /// ゲーム情報のバージョンが取得時と一致しない場合は`GAME_VERSION_CONFLICT_ERROR`、
+    /// イベントの通し番号が他のリクエストと競合した場合は`GAME_EVENT_SEQUENCE_CONFLICT_ERROR`を返す
+    async fn commit_turn(
+        &self,
+        turns: &[Turn],
+        units: &[Unit],
+        game: &Game,
+        events: &[GameEvent],
+    ) -> Result<(), String>;
+}
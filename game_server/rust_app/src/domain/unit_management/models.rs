@@ -1,2 +1,3 @@
+pub mod status_effect_type;
 pub mod unit;
 pub mod unit_type;
@@ -0,0 +1 @@
+pub mod status_effect_spec;
@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// 同じ状態効果が重ねて付与された場合の扱い
+#[derive(Debug, Clone, PartialEq)]
+pub enum StackingRule {
+    /// 効果量は変えずに、残りステップ数を付与時の値に戻す
+    Refresh,
+    /// 最大重ね掛け数まで効果量を加算し、残りステップ数を付与時の値に戻す
+    Stack { max_stacks: i32 },
+}
+
+/// 状態効果によるステータスの補正値
+/// 各値はユニットタイプの基本値への加算値（負の値で減少）
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusModifier {
+    attack: i32,
+    defense: i32,
+    avoid: i32,
+    action_points: i32,
+    sight_range: i32,
+}
+
+impl StatusModifier {
+    pub fn new(
+        attack: i32,
+        defense: i32,
+        avoid: i32,
+        action_points: i32,
+        sight_range: i32,
+    ) -> Self {
+        Self {
+            attack,
+            defense,
+            avoid,
+            action_points,
+            sight_range,
+        }
+    }
+
+    /// 重ね掛け数に応じた補正値
+    pub fn scaled(&self, stacks: i32) -> Self {
+        Self::new(
+            self.attack * stacks,
+            self.defense * stacks,
+            self.avoid * stacks,
+            self.action_points * stacks,
+            self.sight_range * stacks,
+        )
+    }
+
+    /// 他の補正値と合算
+    pub fn combined(&self, other: &StatusModifier) -> Self {
+        Self::new(
+            self.attack + other.attack,
+            self.defense + other.defense,
+            self.avoid + other.avoid,
+            self.action_points + other.action_points,
+            self.sight_range + other.sight_range,
+        )
+    }
+
+    // ゲッター
+    pub fn attack(&self) -> i32 {
+        self.attack
+    }
+
+    pub fn defense(&self) -> i32 {
+        self.defense
+    }
+
+    pub fn avoid(&self) -> i32 {
+        self.avoid
+    }
+
+    pub fn action_points(&self) -> i32 {
+        self.action_points
+    }
+
+    pub fn sight_range(&self) -> i32 {
+        self.sight_range
+    }
+}
+
+/// 状態効果のマスターデータ（値オブジェクト）
+/// トリガーの命中時やユニークコマンドの使用時に付与される
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusEffectSpec {
+    /// 状態効果ID
+    status_effect_id: String,
+    /// 付与時の効果ステップ数
+    duration_steps: i32,
+    stacking_rule: StackingRule,
+    /// 重ね掛け1回あたりの補正値
+    modifier: StatusModifier,
+}
+
+impl StatusEffectSpec {
+    /// マスターデータの定義
+    pub fn get_spec(status_effect_id: &str) -> Option<Self> {
+        let specs: HashMap<&str, StatusEffectSpec> = [
+            (
+                // 強印：ユニークコマンドで自身の攻撃力を上げる
+                "BOOST_SEAL",
+                StatusEffectSpec {
+                    status_effect_id: "BOOST_SEAL".to_string(),
+                    duration_steps: 3,
+                    stacking_rule: StackingRule::Stack { max_stacks: 3 },
+                    modifier: StatusModifier::new(2, 0, 0, 0, 0),
+                },
+            ),
+            (
+                // 鉛弾の重り：命中したユニットの回避力と行動ポイントを下げる
+                "LEAD_WEIGHT",
+                StatusEffectSpec {
+                    status_effect_id: "LEAD_WEIGHT".to_string(),
+                    duration_steps: 2,
+                    stacking_rule: StackingRule::Refresh,
+                    modifier: StatusModifier::new(0, 0, -3, -4, 0),
+                },
+            ),
+        ]
+        .iter()
+        .cloned()
+        .collect();
+
+        specs.get(status_effect_id).cloned()
+    }
+
+    // ゲッター
+    pub fn status_effect_id(&self) -> &str {
+        &self.status_effect_id
+    }
+
+    pub fn duration_steps(&self) -> i32 {
+        self.duration_steps
+    }
+
+    pub fn stacking_rule(&self) -> &StackingRule {
+        &self.stacking_rule
+    }
+
+    pub fn modifier(&self) -> &StatusModifier {
+        &self.modifier
+    }
+}
@@ -4,6 +4,7 @@ pub mod is_bailout;
 pub mod main_trigger_hp;
 pub mod position;
 pub mod sight_range;
+pub mod status_effect;
 pub mod sub_trigger_hp;
 pub mod trigger_id;
 pub mod unit_id;
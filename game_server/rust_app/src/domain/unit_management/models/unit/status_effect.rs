@@ -0,0 +1,2 @@
+pub mod status_effect;
+pub mod status_effect_test;
@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::unit_management::models::status_effect_type::status_effect_spec::{
+    StackingRule, StatusEffectSpec, StatusModifier,
+};
+
+/// ユニットに付与されている状態効果
+/// 効果量や重ね掛けのルールはStatusEffectSpecのマスターデータから取得する
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusEffect {
+    status_effect_id: String,
+    /// 効果が続く残りのステップ数
+    remaining_steps: i32,
+    /// 重ね掛け数
+    stacks: i32,
+}
+
+impl StatusEffect {
+    pub fn new(status_effect_id: String, remaining_steps: i32, stacks: i32) -> Self {
+        Self::validate(remaining_steps, stacks);
+        Self {
+            status_effect_id,
+            remaining_steps,
+            stacks,
+        }
+    }
+
+    /// マスターデータから付与直後の状態効果を生成
+    pub fn from_spec(spec: &StatusEffectSpec) -> Self {
+        Self::new(
+            spec.status_effect_id().to_string(),
+            spec.duration_steps(),
+            1,
+        )
+    }
+
+    /// 同じ状態効果を重ねて付与
+    /// 重ね掛けのルールに従って効果量を加算し、残りステップ数を付与時の値に戻す
+    pub fn reapply(&mut self, spec: &StatusEffectSpec) {
+        if let StackingRule::Stack { max_stacks } = spec.stacking_rule() {
+            self.stacks = (self.stacks + 1).min(*max_stacks);
+        }
+        self.remaining_steps = spec.duration_steps();
+    }
+
+    /// 1ステップ経過させる
+    /// 効果が切れた場合はNoneを返す
+    pub fn tick(&self) -> Option<Self> {
+        if self.remaining_steps <= 1 {
+            return None;
+        }
+        Some(Self::new(
+            self.status_effect_id.clone(),
+            self.remaining_steps - 1,
+            self.stacks,
+        ))
+    }
+
+    /// 重ね掛け数を反映した補正値
+    /// マスターデータに存在しない状態効果は補正なしとする
+    pub fn modifier(&self) -> StatusModifier {
+        StatusEffectSpec::get_spec(&self.status_effect_id)
+            .map(|spec| spec.modifier().scaled(self.stacks))
+            .unwrap_or_default()
+    }
+
+    // ゲッター
+    pub fn status_effect_id(&self) -> &str {
+        &self.status_effect_id
+    }
+
+    pub fn remaining_steps(&self) -> i32 {
+        self.remaining_steps
+    }
+
+    pub fn stacks(&self) -> i32 {
+        self.stacks
+    }
+
+    // バリデーションの実装
+    fn validate(remaining_steps: i32, stacks: i32) {
+        if remaining_steps < 1 {
+            panic!("StatusEffectの残りステップ数は1以上である必要があります");
+        }
+        if stacks < 1 {
+            panic!("StatusEffectの重ね掛け数は1以上である必要があります");
+        }
+    }
+}
@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod tests {
+    use super::super::status_effect::StatusEffect;
+    use crate::domain::unit_management::models::status_effect_type::status_effect_spec::StatusEffectSpec;
+
+    #[test]
+    fn test_from_spec() {
+        let spec = StatusEffectSpec::get_spec("BOOST_SEAL").unwrap();
+        let effect = StatusEffect::from_spec(&spec);
+        assert_eq!(effect.status_effect_id(), "BOOST_SEAL");
+        assert_eq!(effect.remaining_steps(), spec.duration_steps());
+        assert_eq!(effect.stacks(), 1);
+    }
+
+    #[test]
+    fn test_reapply_stack_up_to_max_stacks() {
+        let spec = StatusEffectSpec::get_spec("BOOST_SEAL").unwrap();
+        let mut effect = StatusEffect::new("BOOST_SEAL".to_string(), 1, 1);
+        effect.reapply(&spec);
+        assert_eq!(effect.stacks(), 2);
+        assert_eq!(effect.remaining_steps(), spec.duration_steps());
+        // 最大重ね掛け数（3）を超えて加算されない
+        effect.reapply(&spec);
+        effect.reapply(&spec);
+        assert_eq!(effect.stacks(), 3);
+        assert_eq!(effect.modifier().attack(), 6);
+    }
+
+    #[test]
+    fn test_reapply_refresh_keeps_stacks() {
+        let spec = StatusEffectSpec::get_spec("LEAD_WEIGHT").unwrap();
+        let mut effect = StatusEffect::new("LEAD_WEIGHT".to_string(), 1, 1);
+        effect.reapply(&spec);
+        assert_eq!(effect.stacks(), 1);
+        assert_eq!(effect.remaining_steps(), spec.duration_steps());
+        assert_eq!(effect.modifier().avoid(), -3);
+        assert_eq!(effect.modifier().action_points(), -4);
+    }
+
+    #[test]
+    fn test_tick() {
+        let effect = StatusEffect::new("LEAD_WEIGHT".to_string(), 2, 1);
+        let effect = effect.tick().unwrap();
+        assert_eq!(effect.remaining_steps(), 1);
+        // 残り1ステップの効果は経過後に切れる
+        assert!(effect.tick().is_none());
+    }
+
+    #[test]
+    fn test_unknown_status_effect_has_no_modifier() {
+        let effect = StatusEffect::new("UNKNOWN".to_string(), 1, 2);
+        assert_eq!(effect.modifier(), Default::default());
+    }
+
+    #[test]
+    #[should_panic(expected = "StatusEffectの残りステップ数は1以上である必要があります")]
+    fn test_zero_remaining_steps_panic() {
+        StatusEffect::new("BOOST_SEAL".to_string(), 0, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "StatusEffectの重ね掛け数は1以上である必要があります")]
+    fn test_zero_stacks_panic() {
+        StatusEffect::new("BOOST_SEAL".to_string(), 1, 0);
+    }
+}
@@ -1,6 +1,9 @@
 use crate::domain::player_management::models::player::player_id::player_id::PlayerId;
 use crate::domain::triggergame_simulator::models::action::trigger_azimuth::trigger_azimuth::TriggerAzimuth;
 use crate::domain::triggergame_simulator::models::game::game_id::game_id::GameId;
+use crate::domain::unit_management::models::status_effect_type::status_effect_spec::{
+    StatusEffectSpec, StatusModifier,
+};
 use crate::domain::unit_management::models::unit_type::unit_type_spec::UnitTypeSpec;
 
 use super::current_action_points::current_action_points::CurrentActionPoints;
@@ -9,6 +12,7 @@ use super::is_bailout::is_bailout::IsBailout;
 use super::main_trigger_hp::main_trigger_hp::MainTriggerHP;
 use super::position::position::Position;
 use super::sight_range::sight_range::SightRange;
+use super::status_effect::status_effect::StatusEffect;
 use super::sub_trigger_hp::sub_trigger_hp::SubTriggerHP;
 use super::trigger_id::trigger_id::TriggerId;
 use super::unit_id::unit_id::UnitId;
@@ -38,6 +42,7 @@ pub struct Unit {
     sub_trigger_azimuth: TriggerAzimuth,
     sight_range: SightRange,
     is_bailout: IsBailout,
+    status_effects: Vec<StatusEffect>,
 }
 
 impl Unit {
@@ -61,6 +66,7 @@ impl Unit {
         sub_trigger_azimuth: TriggerAzimuth,
         sight_range: SightRange,
         is_bailout: IsBailout,
+        status_effects: Vec<StatusEffect>,
     ) -> Self {
         Self {
             unit_id,
@@ -80,6 +86,7 @@ impl Unit {
             sub_trigger_azimuth,
             sight_range,
             is_bailout,
+            status_effects,
         }
     }
 
@@ -126,6 +133,7 @@ impl Unit {
             sub_trigger_azimuth,
             sight_range,
             is_bailout,
+            Vec::new(),
         )
     }
 
@@ -145,11 +153,12 @@ impl Unit {
         having_sub_trigger_ids: HavingTriggerIds,
         main_trigger_hp: MainTriggerHP,
         sub_trigger_hp: SubTriggerHP,
+        main_trigger_azimuth: TriggerAzimuth,
+        sub_trigger_azimuth: TriggerAzimuth,
         sight_range: SightRange,
         is_bailout: IsBailout,
+        status_effects: Vec<StatusEffect>,
     ) -> Self {
-        let main_trigger_azimuth = TriggerAzimuth::new(0); // 初期値は0にしておく
-        let sub_trigger_azimuth = TriggerAzimuth::new(0); // 初期値は0にしておく
         Self::new(
             unit_id,
             unit_type_id,
@@ -168,6 +177,7 @@ impl Unit {
             sub_trigger_azimuth,
             sight_range,
             is_bailout,
+            status_effects,
         )
     }
 
@@ -240,9 +250,10 @@ impl Unit {
     }
 
     /// 行動ポイントのリセット
+    /// 状態効果による行動ポイントの補正を反映する
     pub fn reset_action_points(&mut self) {
         let new_value = UnitTypeSpec::get_spec(&self.unit_type_id.value())
-            .map(|spec| spec.action_points())
+            .map(|spec| spec.with_modifier(&self.status_modifier()).action_points())
             .unwrap_or(0);
         self.current_action_points = CurrentActionPoints::new(new_value);
     }
@@ -255,6 +266,68 @@ impl Unit {
         self.sub_trigger_azimuth = azimuth;
     }
 
+    /// 状態効果の付与
+    ///
+    /// 付与済みの状態効果はマスターデータの重ね掛けのルールに従って更新する
+    ///
+    /// マスターデータに存在しない状態効果IDが指定された場合はエラーを返す
+    pub fn apply_status_effect(&mut self, status_effect_id: &str) -> Result<(), String> {
+        let spec = StatusEffectSpec::get_spec(status_effect_id).ok_or(format!(
+            "指定された状態効果IDが存在しません: {}",
+            status_effect_id
+        ))?;
+        match self
+            .status_effects
+            .iter_mut()
+            .find(|e| e.status_effect_id() == status_effect_id)
+        {
+            Some(effect) => effect.reapply(&spec),
+            None => self.status_effects.push(StatusEffect::from_spec(&spec)),
+        }
+        Ok(())
+    }
+
+    /// 状態効果を1ステップ経過させ、効果が切れたものを取り除く
+    pub fn tick_status_effects(&mut self) {
+        self.status_effects = self
+            .status_effects
+            .iter()
+            .filter_map(|e| e.tick())
+            .collect();
+    }
+
+    /// 状態効果の設定（ゲームイベントからの再構築時に使用）
+    pub fn set_status_effects(&mut self, status_effects: Vec<StatusEffect>) {
+        self.status_effects = status_effects;
+    }
+
+    /// 付与されている全ての状態効果の補正値を合算
+    pub fn status_modifier(&self) -> StatusModifier {
+        self.status_effects
+            .iter()
+            .fold(StatusModifier::default(), |total, e| {
+                total.combined(&e.modifier())
+            })
+    }
+
+    /// 状態効果の補正を反映した視界範囲
+    pub fn effective_sight_range(&self) -> i32 {
+        (self.sight_range.value() + self.status_modifier().sight_range()).max(1)
+    }
+
+    /// トリガーHPにダメージを反映（0未満にはならない）
+    pub fn apply_damage(&mut self, main_trigger_damage: i32, sub_trigger_damage: i32) {
+        self.main_trigger_hp =
+            MainTriggerHP::new((self.main_trigger_hp.value() - main_trigger_damage).max(0));
+        self.sub_trigger_hp =
+            SubTriggerHP::new((self.sub_trigger_hp.value() - sub_trigger_damage).max(0));
+    }
+
+    /// いずれかのトリガーHPが0になっているかどうか
+    pub fn is_trigger_hp_depleted(&self) -> bool {
+        self.main_trigger_hp.value() <= 0 || self.sub_trigger_hp.value() <= 0
+    }
+
     /// ベイルアウト
     pub fn bailout(&mut self) {
         self.is_bailout = IsBailout::new(true);
@@ -275,6 +348,14 @@ impl Unit {
         self.is_bailout.is_bailout()
     }
 
+    /// 敵プレイヤーから見えるかどうか
+    /// バグワーム装備中か脱出済みの場合は不可視となり、敵への表示や戦闘予測の対象にならない
+    pub fn is_visible_to_enemy(&self) -> bool {
+        !(self.using_main_trigger_id.is_bagworm()
+            || self.using_sub_trigger_id.is_bagworm()
+            || self.is_bailed_out())
+    }
+
     // ゲッター
     pub fn unit_id(&self) -> &UnitId {
         &self.unit_id
@@ -343,6 +424,10 @@ impl Unit {
     pub fn is_bailout_value(&self) -> &IsBailout {
         &self.is_bailout
     }
+
+    pub fn status_effects(&self) -> &[StatusEffect] {
+        &self.status_effects
+    }
 }
 
 impl PartialEq for Unit {
@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod tests {
     use crate::domain::player_management::models::player::player_id::player_id::PlayerId;
+    use crate::domain::triggergame_simulator::models::action::trigger_azimuth::trigger_azimuth::TriggerAzimuth;
     use crate::domain::triggergame_simulator::models::game::game_id::game_id::GameId;
     use crate::infrastructure::dynamodb::test_utils::{
         create_test_0_action_points_unit, create_test_unit,
@@ -93,6 +94,106 @@ mod tests {
         assert!(!unit.is_bailed_out());
     }
 
+    #[test]
+    fn test_is_visible_to_enemy() {
+        let mut unit = create_test_unit();
+        assert!(unit.is_visible_to_enemy());
+
+        // 脱出済みのユニットは不可視
+        unit.bailout();
+        assert!(!unit.is_visible_to_enemy());
+
+        // バグワーム装備中のユニットは不可視
+        let bagworm_unit = Unit::create(
+            UnitTypeId::new("unit_type_001".to_string()),
+            GameId::new(Uuid::new_v4().to_string()),
+            PlayerId::new(Uuid::new_v4().to_string()),
+            Position::new(5, 10),
+            TriggerId::new("main_trigger_001".to_string()),
+            TriggerId::new("BAGWORM".to_string()),
+            HavingTriggerIds::new(vec![TriggerId::new("main_trigger_001".to_string())]),
+            HavingTriggerIds::new(vec![TriggerId::new("BAGWORM".to_string())]),
+            100,
+            100,
+            8,
+            13,
+        );
+        assert!(!bagworm_unit.is_visible_to_enemy());
+    }
+
+    #[test]
+    fn test_apply_damage() {
+        let mut unit = create_test_unit();
+
+        unit.apply_damage(30, 0);
+        assert_eq!(unit.main_trigger_hp().value(), 70);
+        assert_eq!(unit.sub_trigger_hp().value(), 100);
+        assert!(!unit.is_trigger_hp_depleted());
+
+        // HPを超えるダメージでも0未満にはならない
+        unit.apply_damage(0, 150);
+        assert_eq!(unit.main_trigger_hp().value(), 70);
+        assert_eq!(unit.sub_trigger_hp().value(), 0);
+        assert!(unit.is_trigger_hp_depleted());
+    }
+
+    #[test]
+    fn test_apply_status_effect() {
+        let mut unit = create_test_unit();
+        unit.apply_status_effect("BOOST_SEAL").unwrap();
+        unit.apply_status_effect("BOOST_SEAL").unwrap();
+        unit.apply_status_effect("LEAD_WEIGHT").unwrap();
+
+        // 同じ状態効果は重ね掛けされ、異なる状態効果は別々に付与される
+        assert_eq!(unit.status_effects().len(), 2);
+        assert_eq!(unit.status_effects()[0].stacks(), 2);
+        assert_eq!(unit.status_modifier().attack(), 4);
+        assert_eq!(unit.status_modifier().avoid(), -3);
+
+        let result = unit.apply_status_effect("UNKNOWN");
+        assert!(result.is_err());
+        assert_eq!(unit.status_effects().len(), 2);
+    }
+
+    #[test]
+    fn test_tick_status_effects() {
+        let mut unit = create_test_unit();
+        unit.apply_status_effect("BOOST_SEAL").unwrap();
+        unit.apply_status_effect("LEAD_WEIGHT").unwrap();
+
+        // 効果ステップ数2のLEAD_WEIGHTのみが2ステップ経過後に切れる
+        unit.tick_status_effects();
+        unit.tick_status_effects();
+        assert_eq!(unit.status_effects().len(), 1);
+        assert_eq!(unit.status_effects()[0].status_effect_id(), "BOOST_SEAL");
+        assert_eq!(unit.status_effects()[0].remaining_steps(), 1);
+    }
+
+    #[test]
+    fn test_reset_action_points_with_status_effect() {
+        let mut unit = Unit::create(
+            UnitTypeId::new("MIKUMO_OSAMU".to_string()),
+            GameId::new(Uuid::new_v4().to_string()),
+            PlayerId::new(Uuid::new_v4().to_string()),
+            Position::new(5, 10),
+            TriggerId::new("KOGETSU".to_string()),
+            TriggerId::new("SHIELD".to_string()),
+            HavingTriggerIds::new(vec![TriggerId::new("KOGETSU".to_string())]),
+            HavingTriggerIds::new(vec![TriggerId::new("SHIELD".to_string())]),
+            100,
+            100,
+            8,
+            13,
+        );
+        unit.apply_status_effect("LEAD_WEIGHT").unwrap();
+
+        // 行動ポイントの補正はリセット時に反映される
+        unit.reset_action_points();
+        assert_eq!(unit.current_action_points().value(), 13 - 4);
+        // 視界範囲の補正がない状態効果では変わらない
+        assert_eq!(unit.effective_sight_range(), 8);
+    }
+
     #[test]
     fn test_reconstruct_unit() {
         let unit_id = UnitId::new(Uuid::new_v4().to_string());
@@ -108,6 +209,8 @@ mod tests {
         let having_sub_trigger_ids = HavingTriggerIds::new(vec![]);
         let main_trigger_hp = MainTriggerHP::new(80);
         let sub_trigger_hp = SubTriggerHP::new(40);
+        let main_trigger_azimuth = TriggerAzimuth::new(90);
+        let sub_trigger_azimuth = TriggerAzimuth::new(270);
         let sight_range = SightRange::new(7);
         let is_bailout = IsBailout::new(false);
 
@@ -125,8 +228,11 @@ mod tests {
             having_sub_trigger_ids,
             main_trigger_hp.clone(),
             sub_trigger_hp.clone(),
+            main_trigger_azimuth,
+            sub_trigger_azimuth,
             sight_range.clone(),
             is_bailout,
+            vec![],
         );
 
         assert_eq!(unit.unit_id(), &unit_id);
@@ -139,6 +245,8 @@ mod tests {
         assert_eq!(unit.using_sub_trigger_id(), &using_sub_trigger_id);
         assert_eq!(unit.main_trigger_hp(), &main_trigger_hp);
         assert_eq!(unit.sub_trigger_hp(), &sub_trigger_hp);
+        assert_eq!(unit.main_trigger_azimuth().value(), 90);
+        assert_eq!(unit.sub_trigger_azimuth().value(), 270);
         assert_eq!(unit.sight_range(), &sight_range);
     }
 
@@ -174,8 +282,11 @@ mod tests {
             having_sub_trigger_ids.clone(),
             main_trigger_hp.clone(),
             sub_trigger_hp.clone(),
+            TriggerAzimuth::new(0),
+            TriggerAzimuth::new(0),
             sight_range.clone(),
             is_bailout.clone(),
+            vec![],
         );
 
         let unit2 = Unit::reconstruct(
@@ -192,8 +303,11 @@ mod tests {
             having_sub_trigger_ids,
             main_trigger_hp,
             sub_trigger_hp,
+            TriggerAzimuth::new(0),
+            TriggerAzimuth::new(0),
             sight_range,
             is_bailout,
+            vec![],
         );
 
         assert_eq!(unit1, unit2);
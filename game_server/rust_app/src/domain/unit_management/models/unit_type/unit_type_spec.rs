@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+use crate::domain::unit_management::models::status_effect_type::status_effect_spec::StatusModifier;
+
 /// ユニットタイプのマスターデータ（値オブジェクト）
 #[derive(Debug, Clone, PartialEq)]
 pub struct UnitTypeSpec {
@@ -9,6 +11,8 @@ pub struct UnitTypeSpec {
     base_defense: i32,
     base_avoid: i32,
     action_points: i32,
+    /// ユニークコマンドで自身に付与する状態効果
+    unique_command_status_effect_id: Option<String>,
 }
 
 impl UnitTypeSpec {
@@ -23,6 +27,7 @@ impl UnitTypeSpec {
                     base_defense: 4,
                     base_avoid: 4,
                     action_points: 13,
+                    unique_command_status_effect_id: None,
                 },
             ),
             (
@@ -33,6 +38,7 @@ impl UnitTypeSpec {
                     base_defense: 8,
                     base_avoid: 10,
                     action_points: 16,
+                    unique_command_status_effect_id: Some("BOOST_SEAL".to_string()),
                 },
             ),
             (
@@ -43,6 +49,7 @@ impl UnitTypeSpec {
                     base_defense: 4,
                     base_avoid: 3,
                     action_points: 12,
+                    unique_command_status_effect_id: None,
                 },
             ),
             (
@@ -53,6 +60,7 @@ impl UnitTypeSpec {
                     base_defense: 9,
                     base_avoid: 7,
                     action_points: 15,
+                    unique_command_status_effect_id: None,
                 },
             ),
         ]
@@ -79,4 +87,20 @@ impl UnitTypeSpec {
     pub fn action_points(&self) -> i32 {
         self.action_points
     }
+
+    pub fn unique_command_status_effect_id(&self) -> Option<&str> {
+        self.unique_command_status_effect_id.as_deref()
+    }
+
+    /// 状態効果の補正値を反映したステータス
+    /// 補正後の値は0未満にならない
+    pub fn with_modifier(&self, modifier: &StatusModifier) -> Self {
+        Self {
+            base_attack: (self.base_attack + modifier.attack()).max(0),
+            base_defense: (self.base_defense + modifier.defense()).max(0),
+            base_avoid: (self.base_avoid + modifier.avoid()).max(0),
+            action_points: (self.action_points + modifier.action_points()).max(0),
+            ..self.clone()
+        }
+    }
 }
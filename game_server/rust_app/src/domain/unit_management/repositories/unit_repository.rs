@@ -19,4 +19,8 @@ pub trait UnitRepository: Send + Sync {
 
     /// 特定の対戦のユニットを取得
     async fn get_game_units(&self, game_id: &GameId) -> Result<Vec<Unit>, String>;
+
+    /// 特定の対戦のユニットをすべて削除
+    /// マッチングが中断された場合に、登録済みのユニットを片付けるために使用
+    async fn delete_game_units(&self, game_id: &GameId) -> Result<(), String>;
 }
@@ -0,0 +1,2 @@
+pub mod hmac_session_token_service;
+pub mod hmac_session_token_service_test;
@@ -0,0 +1,126 @@
+// infrastructure/auth/hmac_session_token_service.rs
+
+use crate::domain::player_management::{
+    models::{player::player_id::player_id::PlayerId, session_token::session_token::SessionToken},
+    services::session_token_service::SessionTokenService,
+};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, Duration, Utc};
+use hmac::{Hmac, KeyInit, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// JWTのヘッダー（HS256固定）
+const JWT_HEADER: &str = r#"{"alg":"HS256","typ":"JWT"}"#;
+
+/// JWTのペイロード
+#[derive(Debug, Serialize, Deserialize)]
+struct Claims {
+    /// プレイヤーID
+    sub: String,
+    /// 発行日時（UNIX時間）
+    iat: i64,
+    /// 有効期限（UNIX時間）
+    exp: i64,
+}
+
+/// HMAC-SHA256で署名したJWT形式のセッショントークンを発行・検証する
+pub struct HmacSessionTokenService {
+    secret: Vec<u8>,
+    time_to_live: Duration,
+}
+
+impl HmacSessionTokenService {
+    /// 署名鍵の最小バイト数
+    const MIN_SECRET_LENGTH: usize = 32;
+    /// トークンの有効期間（日）
+    const TIME_TO_LIVE_DAYS: i64 = 30;
+
+    pub fn new(secret: Vec<u8>, time_to_live: Duration) -> Result<Self, String> {
+        if secret.len() < Self::MIN_SECRET_LENGTH {
+            return Err(format!(
+                "セッショントークンの署名鍵は{}バイト以上である必要があります",
+                Self::MIN_SECRET_LENGTH
+            ));
+        }
+        Ok(Self {
+            secret,
+            time_to_live,
+        })
+    }
+
+    /// 環境変数 SESSION_TOKEN_SECRET の署名鍵から生成
+    pub fn from_env() -> Result<Self, String> {
+        let secret = std::env::var("SESSION_TOKEN_SECRET")
+            .map_err(|_| "環境変数 SESSION_TOKEN_SECRET が設定されていません".to_string())?;
+        Self::new(secret.into_bytes(), Duration::days(Self::TIME_TO_LIVE_DAYS))
+    }
+
+    // ヘルパーメソッド：署名対象の文字列に対するMACを生成
+    fn mac(&self, signing_input: &str) -> HmacSha256 {
+        let mut mac =
+            HmacSha256::new_from_slice(&self.secret).expect("HMACは任意の長さの鍵を受け付ける");
+        mac.update(signing_input.as_bytes());
+        mac
+    }
+}
+
+impl SessionTokenService for HmacSessionTokenService {
+    fn issue(&self, player_id: &PlayerId, now: DateTime<Utc>) -> Result<SessionToken, String> {
+        let expires_at = now + self.time_to_live;
+        let claims = Claims {
+            sub: player_id.value().to_string(),
+            iat: now.timestamp(),
+            exp: expires_at.timestamp(),
+        };
+        let payload = serde_json::to_string(&claims)
+            .map_err(|e| format!("セッショントークンの生成に失敗しました: {}", e))?;
+
+        let signing_input = format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(JWT_HEADER),
+            URL_SAFE_NO_PAD.encode(payload)
+        );
+        let signature = URL_SAFE_NO_PAD.encode(self.mac(&signing_input).finalize().into_bytes());
+
+        Ok(SessionToken::new(
+            format!("{}.{}", signing_input, signature),
+            expires_at,
+        ))
+    }
+
+    fn verify(&self, token: &str, now: DateTime<Utc>) -> Result<PlayerId, String> {
+        let parts: Vec<&str> = token.split('.').collect();
+        if parts.len() != 3 {
+            return Err("セッショントークンの形式が不正です".to_string());
+        }
+
+        // 署名を検証してからヘッダー・ペイロードを解釈する
+        let signature = URL_SAFE_NO_PAD
+            .decode(parts[2])
+            .map_err(|_| "セッショントークンの形式が不正です".to_string())?;
+        self.mac(&format!("{}.{}", parts[0], parts[1]))
+            .verify_slice(&signature)
+            .map_err(|_| "セッショントークンの署名が不正です".to_string())?;
+
+        let header = URL_SAFE_NO_PAD
+            .decode(parts[0])
+            .map_err(|_| "セッショントークンの形式が不正です".to_string())?;
+        if header != JWT_HEADER.as_bytes() {
+            return Err("セッショントークンの形式が不正です".to_string());
+        }
+
+        let payload = URL_SAFE_NO_PAD
+            .decode(parts[1])
+            .map_err(|_| "セッショントークンの形式が不正です".to_string())?;
+        let claims: Claims = serde_json::from_slice(&payload)
+            .map_err(|_| "セッショントークンの形式が不正です".to_string())?;
+        if claims.exp <= now.timestamp() {
+            return Err("セッショントークンの有効期限が切れています".to_string());
+        }
+
+        Ok(PlayerId::new(claims.sub))
+    }
+}
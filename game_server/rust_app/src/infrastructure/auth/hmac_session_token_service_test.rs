@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod tests {
+    use super::super::hmac_session_token_service::HmacSessionTokenService;
+    use crate::domain::player_management::{
+        models::player::player_id::player_id::PlayerId,
+        services::session_token_service::SessionTokenService,
+    };
+    use chrono::{Duration, Utc};
+    use uuid::Uuid;
+
+    const SECRET: &[u8] = b"test-secret-key-for-session-token!!";
+
+    fn create_service() -> HmacSessionTokenService {
+        HmacSessionTokenService::new(SECRET.to_vec(), Duration::hours(1)).unwrap()
+    }
+
+    #[test]
+    fn test_issue_and_verify() {
+        let service = create_service();
+        let player_id = PlayerId::new(Uuid::new_v4().to_string());
+        let now = Utc::now();
+
+        let token = service.issue(&player_id, now).unwrap();
+
+        assert_eq!(token.value().split('.').count(), 3);
+        assert_eq!(
+            token.expires_at().timestamp(),
+            (now + Duration::hours(1)).timestamp()
+        );
+        assert_eq!(service.verify(token.value(), now).unwrap(), player_id);
+    }
+
+    #[test]
+    fn test_verify_expired_token() {
+        let service = create_service();
+        let player_id = PlayerId::new(Uuid::new_v4().to_string());
+        let now = Utc::now();
+
+        let token = service.issue(&player_id, now).unwrap();
+
+        assert_eq!(
+            service
+                .verify(token.value(), now + Duration::hours(1))
+                .unwrap_err(),
+            "セッショントークンの有効期限が切れています"
+        );
+    }
+
+    #[test]
+    fn test_verify_token_signed_with_other_secret() {
+        let other_service = HmacSessionTokenService::new(
+            b"another-secret-key-for-session-token".to_vec(),
+            Duration::hours(1),
+        )
+        .unwrap();
+        let player_id = PlayerId::new(Uuid::new_v4().to_string());
+        let now = Utc::now();
+
+        let token = other_service.issue(&player_id, now).unwrap();
+
+        assert_eq!(
+            create_service().verify(token.value(), now).unwrap_err(),
+            "セッショントークンの署名が不正です"
+        );
+    }
+
+    #[test]
+    fn test_verify_tampered_payload() {
+        let service = create_service();
+        let now = Utc::now();
+        let token = service
+            .issue(&PlayerId::new(Uuid::new_v4().to_string()), now)
+            .unwrap();
+        let other_token = service
+            .issue(&PlayerId::new(Uuid::new_v4().to_string()), now)
+            .unwrap();
+
+        // 他のプレイヤーのペイロードに差し替えたトークンは署名が一致しない
+        let parts: Vec<&str> = token.value().split('.').collect();
+        let other_parts: Vec<&str> = other_token.value().split('.').collect();
+        let tampered = format!("{}.{}.{}", parts[0], other_parts[1], parts[2]);
+
+        assert_eq!(
+            service.verify(&tampered, now).unwrap_err(),
+            "セッショントークンの署名が不正です"
+        );
+    }
+
+    #[test]
+    fn test_verify_malformed_token() {
+        let service = create_service();
+
+        assert_eq!(
+            service.verify("not-a-token", Utc::now()).unwrap_err(),
+            "セッショントークンの形式が不正です"
+        );
+    }
+
+    #[test]
+    fn test_new_with_short_secret() {
+        let result = HmacSessionTokenService::new(b"short".to_vec(), Duration::hours(1));
+
+        assert!(result.is_err());
+    }
+}
@@ -2,7 +2,8 @@ use async_trait::async_trait;
 use aws_sdk_apigatewaymanagement::{primitives::Blob, Client};
 
 use crate::application::websocket::{
-    websocket_response::WebSocketResponse, websocket_sender::WebSocketSender,
+    websocket_response::WebSocketResponse,
+    websocket_sender::{DeliveryStatus, WebSocketSender},
 };
 
 pub struct WebSocketapiSender {
@@ -22,24 +23,42 @@ impl WebSocketSender for WebSocketapiSender {
         &self,
         connection_id: &str,
         response: &WebSocketResponse,
-    ) -> Result<(), String> {
+    ) -> Result<DeliveryStatus, String> {
         let data =
-            serde_json::to_vec(response).map_err(|e| format!("Serialization error: {}", e))?;
+            serde_json::to_string(response).map_err(|e| format!("Serialization error: {}", e))?;
+        self.send_json(connection_id, &data).await
+    }
 
-        self.client
+    /// シリアライズ済みのJSONを送信する
+    async fn send_json(
+        &self,
+        connection_id: &str,
+        payload: &str,
+    ) -> Result<DeliveryStatus, String> {
+        let result = self
+            .client
             .post_to_connection()
             .connection_id(connection_id)
-            .data(Blob::new(data))
+            .data(Blob::new(payload.as_bytes()))
             .send()
-            .await
-            .map_err(|e| format!("Failed to send message: {}", e))?;
+            .await;
+        if let Err(e) = result {
+            // 切断済みのコネクションはGoneExceptionになるため、オフラインとして扱う
+            if let Some(service_error) = e.as_service_error() {
+                if service_error.is_gone_exception() {
+                    println!("コネクションは切断済みです: {}", connection_id);
+                    return Ok(DeliveryStatus::Offline);
+                }
+            }
+            return Err(format!("Failed to send message: {}", e));
+        }
 
         // デバッグ用ログ
         // println!(
         //     "WebSocketメッセージを送信 {}, {:?}",
-        //     connection_id, response
+        //     connection_id, payload
         // );
 
-        Ok(())
+        Ok(DeliveryStatus::Delivered)
     }
 }
@@ -2,12 +2,20 @@ pub mod connection_dynamodb_repository;
 pub mod connection_dynamodb_repository_test;
 pub mod game_dynamodb_repository;
 pub mod game_dynamodb_repository_test;
+pub mod game_event_dynamodb_repository;
+pub mod game_event_dynamodb_repository_test;
 pub mod matching_dynamodb_repository;
 pub mod matching_dynamodb_repository_test;
+pub mod outbox_dynamodb_repository;
+pub mod outbox_dynamodb_repository_test;
 pub mod player_dynamodb_repository;
 pub mod player_dynamodb_repository_test;
+pub mod replay_dynamodb_repository;
+pub mod replay_dynamodb_repository_test;
 pub mod test_utils;
 pub mod turn_dynamodb_repository;
 pub mod turn_dynamodb_repository_test;
+pub mod turn_dynamodb_unit_of_work;
+pub mod turn_dynamodb_unit_of_work_test;
 pub mod unit_dynamodb_repository;
 pub mod unit_dynamodb_repository_test;
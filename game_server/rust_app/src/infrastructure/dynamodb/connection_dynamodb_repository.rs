@@ -14,15 +14,19 @@ use std::collections::HashMap;
 pub struct DynamoDbConnectionRepository {
     client: DynamoDbClient,
     connections_table: &'static str,
+    /// コネクションIDで逆引きするためのGSI名
+    connection_id_index: &'static str,
 }
 
 impl DynamoDbConnectionRepository {
     pub fn new(client: DynamoDbClient) -> Self {
         // テーブル名
         const CONNECTIONS_TABLE_NAME: &str = "Connections";
+        const CONNECTION_ID_INDEX_NAME: &str = "ConnectionIdIndex";
         Self {
             client,
             connections_table: CONNECTIONS_TABLE_NAME,
+            connection_id_index: CONNECTION_ID_INDEX_NAME,
         }
     }
 
@@ -86,4 +90,89 @@ impl ConnectionRepository for DynamoDbConnectionRepository {
 
         Ok(connection_id_str.to_string())
     }
+
+    /// コネクション情報を取得
+    /// コネクションが存在しない場合はNoneを返す
+    async fn find_connection_id(&self, player_id: &str) -> Result<Option<String>, String> {
+        let result = self
+            .client
+            .get_item()
+            .table_name(self.connections_table)
+            .key("player_id", AttributeValue::S(player_id.to_string()))
+            .send()
+            .await
+            .map_err(|e| format!("Failed to get connection: {}", e))?;
+
+        match result.item() {
+            Some(item) => {
+                let connection_id_str = item
+                    .get("connection_id")
+                    .and_then(|v| v.as_s().ok())
+                    .ok_or("connection_id not found")?;
+                Ok(Some(connection_id_str.to_string()))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// コネクションIDからプレイヤーIDを逆引き
+    /// ConnectionIdIndex（GSI）を使用
+    async fn find_player_id(&self, connection_id: &str) -> Result<Option<String>, String> {
+        let result = self
+            .client
+            .query()
+            .table_name(self.connections_table)
+            .index_name(self.connection_id_index) // GSI名
+            .key_condition_expression("connection_id = :connection_id")
+            .expression_attribute_values(
+                ":connection_id",
+                AttributeValue::S(connection_id.to_string()),
+            )
+            .limit(1)
+            .send()
+            .await
+            .map_err(|e| format!("Failed to query connection: {}", e))?;
+
+        match result.items().first() {
+            Some(item) => {
+                let player_id_str = item
+                    .get("player_id")
+                    .and_then(|v| v.as_s().ok())
+                    .ok_or("player_id not found")?;
+                Ok(Some(player_id_str.to_string()))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// コネクション情報を削除
+    /// 保存されているコネクションIDが一致する場合のみ削除する
+    async fn delete(&self, player_id: &str, connection_id: &str) -> Result<(), String> {
+        let result = self
+            .client
+            .delete_item()
+            .table_name(self.connections_table)
+            .key("player_id", AttributeValue::S(player_id.to_string()))
+            .condition_expression("connection_id = :connection_id")
+            .expression_attribute_values(
+                ":connection_id",
+                AttributeValue::S(connection_id.to_string()),
+            )
+            .send()
+            .await;
+
+        match result {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                if let Some(service_error) = e.as_service_error() {
+                    // 条件式を満たさない場合は既に再接続済み（または削除済み）のため何もしない
+                    if service_error.is_conditional_check_failed_exception() {
+                        return Ok(());
+                    }
+                    eprintln!("Service Error: {:?}", service_error);
+                }
+                Err(format!("コネクション情報の削除に失敗しました: {}", e))
+            }
+        }
+    }
 }
@@ -2,14 +2,19 @@
 mod tests {
     use super::super::connection_dynamodb_repository::DynamoDbConnectionRepository;
     use crate::domain::player_management::repositories::connection_repository::ConnectionRepository;
+    use aws_credential_types::Credentials;
     use aws_sdk_dynamodb::{
         config::{BehaviorVersion, Region},
+        operation::delete_item::{DeleteItemError, DeleteItemInput, DeleteItemOutput},
         operation::get_item::{GetItemInput, GetItemOutput},
         operation::put_item::{PutItemInput, PutItemOutput},
-        types::AttributeValue,
+        operation::query::{QueryInput, QueryOutput},
+        types::{error::ConditionalCheckFailedException, AttributeValue},
         Client, Config,
     };
-    use aws_smithy_mocks::{mock, MockResponseInterceptor, Rule, RuleMode};
+    use aws_smithy_mocks::{
+        create_mock_http_client, mock, MockResponseInterceptor, Rule, RuleMode,
+    };
     use std::collections::HashMap;
 
     /// モッククライアントをセットアップ
@@ -22,6 +27,9 @@ mod tests {
             .behavior_version(BehaviorVersion::latest())
             .region(Region::new("ap-northeast-1"))
             .interceptor(mock_interceptor)
+            // エラーレスポンスのモックにはHTTPクライアントと認証情報が必要
+            .http_client(create_mock_http_client())
+            .credentials_provider(Credentials::new("dummy", "dummy", None, None, "test"))
             .build();
 
         Client::from_conf(config)
@@ -110,4 +118,110 @@ mod tests {
             format!("Connectionが見つかりません: {}", player_id)
         );
     }
+
+    #[tokio::test]
+    async fn test_find_connection_id_offline() {
+        let player_id = "550e8400-e29b-41d4-a716-446655440001";
+
+        // コネクションが存在しない場合はエラーではなくNoneを返すこと
+        let get_item_rule = mock!(Client::get_item)
+            .match_requests(|_: &GetItemInput| true)
+            .then_output(|| GetItemOutput::builder().build());
+
+        let client = setup_mock_client(get_item_rule);
+        let repo = DynamoDbConnectionRepository::new(client);
+
+        let result = repo.find_connection_id(player_id).await;
+        assert_eq!(result.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_find_player_id() {
+        let player_id = "550e8400-e29b-41d4-a716-446655440001";
+        let connection_id = "test-connection-456";
+
+        let mut item = HashMap::new();
+        item.insert(
+            "connection_id".to_string(),
+            AttributeValue::S(connection_id.to_string()),
+        );
+        item.insert(
+            "player_id".to_string(),
+            AttributeValue::S(player_id.to_string()),
+        );
+
+        // コネクションIDのGSIで逆引きすること
+        let query_rule = mock!(Client::query)
+            .match_requests(|input: &QueryInput| {
+                input.index_name() == Some("ConnectionIdIndex")
+                    && input
+                        .expression_attribute_values()
+                        .and_then(|values| values.get(":connection_id"))
+                        == Some(&AttributeValue::S("test-connection-456".to_string()))
+            })
+            .then_output(move || QueryOutput::builder().items(item.clone()).build());
+
+        let client = setup_mock_client(query_rule);
+        let repo = DynamoDbConnectionRepository::new(client);
+
+        let result = repo.find_player_id(connection_id).await;
+        assert_eq!(result.unwrap(), Some(player_id.to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_find_player_id_not_found() {
+        let query_rule = mock!(Client::query)
+            .match_requests(|_: &QueryInput| true)
+            .then_output(|| QueryOutput::builder().build());
+
+        let client = setup_mock_client(query_rule);
+        let repo = DynamoDbConnectionRepository::new(client);
+
+        let result = repo.find_player_id("unknown-connection").await;
+        assert_eq!(result.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_delete_connection() {
+        let player_id = "550e8400-e29b-41d4-a716-446655440001";
+        let connection_id = "test-connection-456";
+
+        // 保存されているコネクションIDが一致する場合のみ削除すること
+        let delete_item_rule = mock!(Client::delete_item)
+            .match_requests(|input: &DeleteItemInput| {
+                input.condition_expression().is_some()
+                    && input
+                        .expression_attribute_values()
+                        .and_then(|values| values.get(":connection_id"))
+                        == Some(&AttributeValue::S("test-connection-456".to_string()))
+            })
+            .then_output(|| DeleteItemOutput::builder().build());
+
+        let client = setup_mock_client(delete_item_rule);
+        let repo = DynamoDbConnectionRepository::new(client);
+
+        let result = repo.delete(player_id, connection_id).await;
+        assert!(result.is_ok(), "Failed to delete: {:?}", result.err());
+    }
+
+    #[tokio::test]
+    async fn test_delete_connection_already_reconnected() {
+        let player_id = "550e8400-e29b-41d4-a716-446655440001";
+
+        // 別のコネクションで再接続済みの場合は条件式を満たさない
+        let delete_item_rule = mock!(Client::delete_item)
+            .match_requests(|_: &DeleteItemInput| true)
+            .then_error(|| {
+                DeleteItemError::ConditionalCheckFailedException(
+                    ConditionalCheckFailedException::builder().build(),
+                )
+            });
+
+        let client = setup_mock_client(delete_item_rule);
+        let repo = DynamoDbConnectionRepository::new(client);
+
+        // 新しいコネクション情報を残したまま正常終了すること
+        let result = repo.delete(player_id, "old-connection").await;
+        assert!(result.is_ok(), "Failed to delete: {:?}", result.err());
+    }
 }
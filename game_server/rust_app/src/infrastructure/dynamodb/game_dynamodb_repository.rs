@@ -5,24 +5,48 @@ use crate::domain::player_management::models::player::player_id::player_id::Play
 use crate::domain::triggergame_simulator::models::game::current_turn_number::current_turn_number::CurrentTurnNumber;
 use crate::domain::triggergame_simulator::models::game::game::Game;
 use crate::domain::triggergame_simulator::models::game::game_id::game_id::GameId;
-use crate::domain::triggergame_simulator::repositories::game_repository::GameRepository;
+use crate::domain::triggergame_simulator::models::game::game_status::game_status::GameStatus;
+use crate::domain::triggergame_simulator::models::game::game_version::game_version::GameVersion;
+use crate::domain::triggergame_simulator::models::game::simulation_seed::simulation_seed::SimulationSeed;
+use crate::domain::triggergame_simulator::models::game::spectator::spectator::{
+    Spectator, SpectatorView,
+};
+use crate::domain::triggergame_simulator::repositories::game_repository::{
+    GameRepository, GAME_VERSION_CONFLICT_ERROR,
+};
 use async_trait::async_trait;
-use aws_sdk_dynamodb::types::AttributeValue;
+use aws_sdk_dynamodb::types::{AttributeValue, TransactWriteItem, Update};
 use aws_sdk_dynamodb::Client as DynamoDbClient;
+use chrono::{DateTime, Utc};
 use std::collections::HashMap;
 
+/// ターン数の更新式
+/// 最終ターンの演算結果の反映時に対戦終了となるため、進行状態も合わせて更新する
+const CURRENT_TURN_UPDATE_EXPRESSION: &str =
+    "SET current_turn_number = :current_turn_number, turn_started_at = :turn_started_at, game_status = :game_status, #version = :next_version";
+/// 取得時のバージョンから変わっていない場合のみ更新する
+/// バージョン導入前のアイテムはversion属性を持たないため、存在しない場合も許可する
+const VERSION_CONDITION_EXPRESSION: &str = "attribute_not_exists(#version) OR #version = :version";
+
 pub struct DynamoDbGameRepository {
     client: DynamoDbClient,
     games_table: &'static str,
+    player1_id_index: &'static str,
+    player2_id_index: &'static str,
 }
 
 impl DynamoDbGameRepository {
     pub fn new(client: DynamoDbClient) -> Self {
         // テーブル名
         const GAMES_TABLE_NAME: &str = "Games";
+        // プレイヤーIDからゲームを検索するためのインデックス名
+        const PLAYER1_ID_INDEX_NAME: &str = "Player1IdIndex";
+        const PLAYER2_ID_INDEX_NAME: &str = "Player2IdIndex";
         Self {
             client: client,
             games_table: GAMES_TABLE_NAME,
+            player1_id_index: PLAYER1_ID_INDEX_NAME,
+            player2_id_index: PLAYER2_ID_INDEX_NAME,
         }
     }
 
@@ -45,8 +69,246 @@ impl DynamoDbGameRepository {
             "player2_id".to_string(),
             AttributeValue::S(game.player2_id().value().to_string()),
         );
+        item.insert(
+            "version".to_string(),
+            AttributeValue::N(game.version().value().to_string()),
+        );
+        item.insert(
+            "turn_started_at".to_string(),
+            AttributeValue::S(game.turn_started_at().to_rfc3339()),
+        );
+        item.insert(
+            "game_status".to_string(),
+            AttributeValue::S(game.status().fmt_value()),
+        );
+        item.insert(
+            "simulation_seed".to_string(),
+            AttributeValue::N(game.simulation_seed().value().to_string()),
+        );
+        // 観戦者は同時に追加されても上書きし合わないよう、プレイヤーIDをキーとしたマップで保存する
+        item.insert(
+            "spectators".to_string(),
+            AttributeValue::M(
+                game.spectators()
+                    .iter()
+                    .map(|spectator| {
+                        (
+                            spectator.player_id().value().to_string(),
+                            self.spectator_to_attribute(spectator),
+                        )
+                    })
+                    .collect(),
+            ),
+        );
         item
     }
+
+    // ヘルパーメソッド：Spectatorを属性値に変換
+    fn spectator_to_attribute(&self, spectator: &Spectator) -> AttributeValue {
+        let mut attribute = HashMap::new();
+        attribute.insert(
+            "player_id".to_string(),
+            AttributeValue::S(spectator.player_id().value().to_string()),
+        );
+        attribute.insert(
+            "joined_at".to_string(),
+            AttributeValue::S(spectator.joined_at().to_rfc3339()),
+        );
+        if let Some(side_player_id) = spectator.side_player_id() {
+            attribute.insert(
+                "side_player_id".to_string(),
+                AttributeValue::S(side_player_id.value().to_string()),
+            );
+        }
+        AttributeValue::M(attribute)
+    }
+
+    // ヘルパーメソッド：属性値をSpectatorに変換
+    fn attribute_to_spectator(&self, attribute: &AttributeValue) -> Result<Spectator, String> {
+        let attribute = attribute
+            .as_m()
+            .map_err(|_| "観戦者情報の形式が不正です。".to_string())?;
+        let player_id_str = attribute
+            .get("player_id")
+            .and_then(|v| v.as_s().ok())
+            .ok_or("観戦者のプレイヤーIDが見つかりませんでした。")?;
+        let joined_at_str = attribute
+            .get("joined_at")
+            .and_then(|v| v.as_s().ok())
+            .ok_or("観戦開始日時が見つかりませんでした。")?;
+        let joined_at = DateTime::parse_from_rfc3339(joined_at_str)
+            .map_err(|e| format!("観戦開始日時の解析に失敗しました: {}", e))?
+            .with_timezone(&Utc);
+        // 視点のプレイヤーIDがない場合は両陣営を表示する
+        let view = match attribute.get("side_player_id").and_then(|v| v.as_s().ok()) {
+            Some(side_player_id_str) => SpectatorView::Side {
+                player_id: PlayerId::new(side_player_id_str.to_string()),
+            },
+            None => SpectatorView::Omniscient,
+        };
+        Ok(Spectator::new(
+            PlayerId::new(player_id_str.to_string()),
+            view,
+            joined_at,
+        ))
+    }
+
+    // ヘルパーメソッド：ターン数更新時の属性値マップを作成
+    fn current_turn_update_values(&self, game: &Game) -> HashMap<String, AttributeValue> {
+        let mut values = HashMap::new();
+        values.insert(
+            ":current_turn_number".to_string(),
+            AttributeValue::N(game.current_turn_number().value().to_string()),
+        );
+        values.insert(
+            ":turn_started_at".to_string(),
+            AttributeValue::S(game.turn_started_at().to_rfc3339()),
+        );
+        values.insert(
+            ":game_status".to_string(),
+            AttributeValue::S(game.status().fmt_value()),
+        );
+        values.insert(
+            ":version".to_string(),
+            AttributeValue::N(game.version().value().to_string()),
+        );
+        values.insert(
+            ":next_version".to_string(),
+            AttributeValue::N(game.version().next().value().to_string()),
+        );
+        values
+    }
+
+    // ヘルパーメソッド：属性値マップをGameに変換
+    fn item_to_game(&self, game_item: &HashMap<String, AttributeValue>) -> Result<Game, String> {
+        // Gameの属性を抽出
+        let game_id_str = game_item
+            .get("game_id")
+            .and_then(|v| v.as_s().ok())
+            .ok_or("ゲームIDが見つかりませんでした。")?;
+        let current_turn_number_str = game_item
+            .get("current_turn_number")
+            .and_then(|v| v.as_n().ok())
+            .ok_or("現在のターン番号が見つかりませんでした。")?;
+        let player1_id_str = game_item
+            .get("player1_id")
+            .and_then(|v| v.as_s().ok())
+            .ok_or("プレイヤー1のIDが見つかりませんでした。")?;
+        let player2_id_str = game_item
+            .get("player2_id")
+            .and_then(|v| v.as_s().ok())
+            .ok_or("プレイヤー2のIDが見つかりませんでした。")?;
+        // バージョン導入前のアイテムは初期バージョンとして扱う
+        let version = match game_item.get("version").and_then(|v| v.as_n().ok()) {
+            Some(version_str) => GameVersion::new(
+                version_str
+                    .parse::<i32>()
+                    .map_err(|e| format!("バージョンの解析に失敗しました: {}", e))?,
+            ),
+            None => GameVersion::initial(),
+        };
+
+        // 開始日時の導入前のアイテムは取得時点で開始したものとして扱う
+        let turn_started_at = match game_item.get("turn_started_at").and_then(|v| v.as_s().ok()) {
+            Some(turn_started_at_str) => DateTime::parse_from_rfc3339(turn_started_at_str)
+                .map_err(|e| format!("ターン開始日時の解析に失敗しました: {}", e))?
+                .with_timezone(&Utc),
+            None => Utc::now(),
+        };
+
+        // 観戦機能の導入前のアイテムは観戦者なしとして扱う
+        let mut spectators = match game_item.get("spectators").and_then(|v| v.as_m().ok()) {
+            Some(spectators) => spectators
+                .values()
+                .map(|v| self.attribute_to_spectator(v))
+                .collect::<Result<Vec<_>, String>>()?,
+            None => Vec::new(),
+        };
+        spectators.sort_by_key(|spectator| *spectator.joined_at());
+
+        // 進行状態の導入前のアイテムは対戦中として扱う
+        let status = match game_item.get("game_status").and_then(|v| v.as_s().ok()) {
+            Some(status_str) => GameStatus::new_string(status_str),
+            None => GameStatus::initial(),
+        };
+
+        // シードの保存を導入する前のアイテムはゲームIDから生成したシードで演算する
+        let simulation_seed = match game_item.get("simulation_seed").and_then(|v| v.as_n().ok()) {
+            Some(simulation_seed_str) => SimulationSeed::new(
+                simulation_seed_str
+                    .parse::<u32>()
+                    .map_err(|e| format!("乱数のシードの解析に失敗しました: {}", e))?,
+            ),
+            None => SimulationSeed::from_game_id(&GameId::new(game_id_str.to_string())),
+        };
+
+        Ok(Game::reconstruct(
+            GameId::new(game_id_str.to_string()),
+            CurrentTurnNumber::new(
+                current_turn_number_str
+                    .parse::<i32>()
+                    .map_err(|e| format!("現在のターン番号の解析に失敗しました: {}", e))?,
+            ),
+            PlayerId::new(player1_id_str.to_string()),
+            PlayerId::new(player2_id_str.to_string()),
+            version,
+            turn_started_at,
+            spectators,
+            status,
+            simulation_seed,
+        ))
+    }
+
+    // ヘルパーメソッド：インデックスからプレイヤーの最新のゲームを1件取得
+    async fn query_latest_by_index(
+        &self,
+        index_name: &str,
+        player_id_attribute: &str,
+        player_id: &PlayerId,
+    ) -> Result<Option<Game>, String> {
+        let result = self
+            .client
+            .query()
+            .table_name(self.games_table)
+            .index_name(index_name)
+            .key_condition_expression(format!("{} = :player_id", player_id_attribute))
+            .expression_attribute_values(
+                ":player_id",
+                AttributeValue::S(player_id.value().to_string()),
+            )
+            // ターン開始日時の降順で最新の1件のみ取得
+            .scan_index_forward(false)
+            .limit(1)
+            .send()
+            .await
+            .map_err(|e| format!("プレイヤーのゲーム情報の取得に失敗しました: {}", e))?;
+
+        match result.items().first() {
+            Some(item) => Ok(Some(self.item_to_game(item)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// ターン数の更新をトランザクション書き込み用のアイテムとして作成
+    /// 条件式は`update_current_turn`と同じくバージョンの一致を要求する
+    pub fn update_current_turn_transact_item(
+        &self,
+        game: &Game,
+    ) -> Result<TransactWriteItem, String> {
+        let update = Update::builder()
+            .table_name(self.games_table)
+            .key(
+                "game_id",
+                AttributeValue::S(game.game_id().value().to_string()),
+            )
+            .update_expression(CURRENT_TURN_UPDATE_EXPRESSION)
+            .condition_expression(VERSION_CONDITION_EXPRESSION)
+            .expression_attribute_names("#version", "version")
+            .set_expression_attribute_values(Some(self.current_turn_update_values(game)))
+            .build()
+            .map_err(|e| format!("ゲーム情報の更新内容の作成に失敗しました: {}", e))?;
+        Ok(TransactWriteItem::builder().update(update).build())
+    }
 }
 
 #[async_trait]
@@ -65,8 +327,6 @@ impl GameRepository for DynamoDbGameRepository {
     }
 
     async fn update_current_turn(&self, game: &Game) -> Result<(), String> {
-        let update_expression = "SET current_turn_number = :current_turn_number";
-
         self.client
             .update_item()
             .table_name(self.games_table)
@@ -74,18 +334,21 @@ impl GameRepository for DynamoDbGameRepository {
                 "game_id",
                 AttributeValue::S(game.game_id().value().to_string()),
             )
-            .update_expression(update_expression)
-            .expression_attribute_values(
-                ":current_turn_number",
-                AttributeValue::N(game.current_turn_number().value().to_string()),
-            )
+            .update_expression(CURRENT_TURN_UPDATE_EXPRESSION)
+            .condition_expression(VERSION_CONDITION_EXPRESSION)
+            .expression_attribute_names("#version", "version")
+            .set_expression_attribute_values(Some(self.current_turn_update_values(game)))
             .send()
             .await
             .map_err(|e| {
-                println!("Failed to update game: {}", e);
                 if let Some(service_error) = e.as_service_error() {
+                    // 条件式を満たさない場合は他のリクエストによる更新との競合
+                    if service_error.is_conditional_check_failed_exception() {
+                        return GAME_VERSION_CONFLICT_ERROR.to_string();
+                    }
                     eprintln!("Service Error: {:?}", service_error);
                 }
+                println!("Failed to update game: {}", e);
                 format!("ゲーム情報の更新に失敗しました: {}", e)
             })?;
 
@@ -111,33 +374,86 @@ impl GameRepository for DynamoDbGameRepository {
             .item()
             .ok_or("ゲームが見つかりませんでした。".to_string())?;
 
-        // Gameの属性を抽出
-        let game_id_str = game_item
-            .get("game_id")
-            .and_then(|v| v.as_s().ok())
-            .ok_or("ゲームIDが見つかりませんでした。")?;
-        let current_turn_number_str = game_item
-            .get("current_turn_number")
-            .and_then(|v| v.as_n().ok())
-            .ok_or("現在のターン番号が見つかりませんでした。")?;
-        let player1_id_str = game_item
-            .get("player1_id")
-            .and_then(|v| v.as_s().ok())
-            .ok_or("プレイヤー1のIDが見つかりませんでした。")?;
-        let player2_id_str = game_item
-            .get("player2_id")
-            .and_then(|v| v.as_s().ok())
-            .ok_or("プレイヤー2のIDが見つかりませんでした。")?;
+        self.item_to_game(game_item)
+    }
 
-        Ok(Game::reconstruct(
-            GameId::new(game_id_str.to_string()),
-            CurrentTurnNumber::new(
-                current_turn_number_str
-                    .parse::<i32>()
-                    .map_err(|e| format!("現在のターン番号の解析に失敗しました: {}", e))?,
-            ),
-            PlayerId::new(player1_id_str.to_string()),
-            PlayerId::new(player2_id_str.to_string()),
-        ))
+    async fn find_latest_by_player_id(&self, player_id: &PlayerId) -> Result<Option<Game>, String> {
+        // プレイヤーはどちらの立場でも参加しうるため、両方のインデックスを検索する
+        let as_player1 = self
+            .query_latest_by_index(self.player1_id_index, "player1_id", player_id)
+            .await?;
+        let as_player2 = self
+            .query_latest_by_index(self.player2_id_index, "player2_id", player_id)
+            .await?;
+
+        let latest = match (as_player1, as_player2) {
+            (Some(game1), Some(game2)) => {
+                if game1.turn_started_at() >= game2.turn_started_at() {
+                    Some(game1)
+                } else {
+                    Some(game2)
+                }
+            }
+            (game1, game2) => game1.or(game2),
+        };
+        // 最新のゲームが終了済みの場合は、参加中のゲームはないものとする
+        // それより古い対戦中のゲームは放棄されたものであり、復帰の対象にしない
+        Ok(latest.filter(|game| !game.status().is_finished()))
+    }
+
+    async fn add_spectator(&self, game_id: &GameId, spectator: &Spectator) -> Result<(), String> {
+        let result = self
+            .client
+            .update_item()
+            .table_name(self.games_table)
+            .key("game_id", AttributeValue::S(game_id.value().to_string()))
+            .update_expression("SET spectators.#player_id = :spectator")
+            .condition_expression("attribute_exists(game_id) AND attribute_exists(spectators)")
+            .expression_attribute_names("#player_id", spectator.player_id().value())
+            .expression_attribute_values(":spectator", self.spectator_to_attribute(spectator))
+            .send()
+            .await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(e)
+                if e.as_service_error()
+                    .is_some_and(|e| e.is_conditional_check_failed_exception()) =>
+            {
+                // 観戦機能の導入前のアイテムは観戦者のマップを持たないため、マップごと作成する
+                let mut spectators = HashMap::new();
+                spectators.insert(
+                    spectator.player_id().value().to_string(),
+                    self.spectator_to_attribute(spectator),
+                );
+                self.client
+                    .update_item()
+                    .table_name(self.games_table)
+                    .key("game_id", AttributeValue::S(game_id.value().to_string()))
+                    .update_expression("SET spectators = :spectators")
+                    .condition_expression(
+                        "attribute_exists(game_id) AND attribute_not_exists(spectators)",
+                    )
+                    .expression_attribute_values(":spectators", AttributeValue::M(spectators))
+                    .send()
+                    .await
+                    .map_err(|e| format!("観戦者の追加に失敗しました: {}", e))?;
+                Ok(())
+            }
+            Err(e) => Err(format!("観戦者の追加に失敗しました: {}", e)),
+        }
+    }
+
+    async fn remove_spectator(&self, game_id: &GameId, player_id: &PlayerId) -> Result<(), String> {
+        self.client
+            .update_item()
+            .table_name(self.games_table)
+            .key("game_id", AttributeValue::S(game_id.value().to_string()))
+            .update_expression("REMOVE spectators.#player_id")
+            .condition_expression("attribute_exists(spectators)")
+            .expression_attribute_names("#player_id", player_id.value())
+            .send()
+            .await
+            .map_err(|e| format!("観戦者の削除に失敗しました: {}", e))?;
+        Ok(())
     }
 }
@@ -0,0 +1,207 @@
+// infrastructure/dynamodb/game_event_dynamodb_repository.rs
+
+use crate::domain::triggergame_simulator::models::game::game_id::game_id::GameId;
+use crate::domain::triggergame_simulator::models::game_event::event_sequence::event_sequence::EventSequence;
+use crate::domain::triggergame_simulator::models::game_event::game_event::GameEvent;
+use crate::domain::triggergame_simulator::models::game_event::game_event_payload::GameEventPayload;
+use crate::domain::triggergame_simulator::repositories::game_event_repository::{
+    GameEventRepository, GAME_EVENT_SEQUENCE_CONFLICT_ERROR,
+};
+use async_trait::async_trait;
+use aws_sdk_dynamodb::operation::transact_write_items::TransactWriteItemsError;
+use aws_sdk_dynamodb::types::{AttributeValue, Put, TransactWriteItem};
+use aws_sdk_dynamodb::Client as DynamoDbClient;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+/// TransactWriteItemsの1リクエストで扱えるアイテム数の上限
+const MAX_TRANSACT_ITEMS: usize = 100;
+
+pub struct DynamoDbGameEventRepository {
+    client: DynamoDbClient,
+    game_events_table: &'static str,
+}
+
+impl DynamoDbGameEventRepository {
+    pub fn new(client: DynamoDbClient) -> Self {
+        // テーブル名
+        const GAME_EVENTS_TABLE_NAME: &str = "GameEvents";
+        Self {
+            client,
+            game_events_table: GAME_EVENTS_TABLE_NAME,
+        }
+    }
+
+    // ヘルパーメソッド：GameEventを属性値マップに変換
+    fn game_event_to_item(
+        &self,
+        event: &GameEvent,
+    ) -> Result<HashMap<String, AttributeValue>, String> {
+        let payload = serde_json::to_string(event.payload())
+            .map_err(|e| format!("ゲームイベントのシリアライズに失敗しました: {}", e))?;
+
+        let mut item = HashMap::new();
+        item.insert(
+            "game_id".to_string(),
+            AttributeValue::S(event.game_id().value().to_string()),
+        );
+        item.insert(
+            "sequence".to_string(),
+            AttributeValue::N(event.sequence().value().to_string()),
+        );
+        item.insert(
+            "event_type".to_string(),
+            AttributeValue::S(event.payload().event_type().to_string()),
+        );
+        item.insert(
+            "occurred_at".to_string(),
+            AttributeValue::S(event.occurred_at().to_rfc3339()),
+        );
+        item.insert("payload".to_string(), AttributeValue::S(payload));
+        Ok(item)
+    }
+
+    /// イベントの追記をトランザクション書き込み用のアイテムとして作成
+    /// 追記専用のため、同じ通し番号のイベントが登録済みの場合は条件式を満たさない
+    pub fn append_transact_item(&self, event: &GameEvent) -> Result<TransactWriteItem, String> {
+        let put = Put::builder()
+            .table_name(self.game_events_table)
+            .set_item(Some(self.game_event_to_item(event)?))
+            .condition_expression("attribute_not_exists(#sequence)")
+            .expression_attribute_names("#sequence", "sequence")
+            .build()
+            .map_err(|e| format!("ゲームイベントの登録内容の作成に失敗しました: {}", e))?;
+        Ok(TransactWriteItem::builder().put(put).build())
+    }
+
+    /// ヘルパーメソッド：DynamoDBから取得したデータをGameEventエンティティに変換
+    fn from_dynamo_db_to_game_event(
+        &self,
+        item: &HashMap<String, AttributeValue>,
+    ) -> Result<GameEvent, String> {
+        let game_id = GameId::new(
+            item.get("game_id")
+                .and_then(|v| v.as_s().ok())
+                .ok_or("game_id not found or invalid")?
+                .to_string(),
+        );
+
+        let sequence = EventSequence::new(
+            item.get("sequence")
+                .and_then(|v| v.as_n().ok())
+                .and_then(|n| n.parse::<i32>().ok())
+                .ok_or("sequence not found or invalid")?,
+        );
+
+        let occurred_at = item
+            .get("occurred_at")
+            .and_then(|v| v.as_s().ok())
+            .ok_or("occurred_at not found or invalid")?
+            .parse::<DateTime<Utc>>()
+            .map_err(|e| format!("Failed to parse occurred_at: {}", e))?;
+
+        let payload = serde_json::from_str::<GameEventPayload>(
+            item.get("payload")
+                .and_then(|v| v.as_s().ok())
+                .ok_or("payload not found or invalid")?,
+        )
+        .map_err(|e| format!("ゲームイベントのデシリアライズに失敗しました: {}", e))?;
+
+        Ok(GameEvent::reconstruct(
+            game_id,
+            sequence,
+            occurred_at,
+            payload,
+        ))
+    }
+}
+
+#[async_trait]
+impl GameEventRepository for DynamoDbGameEventRepository {
+    async fn append(&self, events: &[GameEvent]) -> Result<(), String> {
+        // 上限を超える場合は分割して書き込む
+        for chunk in events.chunks(MAX_TRANSACT_ITEMS) {
+            let transact_items = chunk
+                .iter()
+                .map(|event| self.append_transact_item(event))
+                .collect::<Result<Vec<_>, String>>()?;
+
+            self.client
+                .transact_write_items()
+                .set_transact_items(Some(transact_items))
+                .send()
+                .await
+                .map_err(|e| {
+                    if let Some(service_error) = e.as_service_error() {
+                        if let TransactWriteItemsError::TransactionCanceledException(canceled) =
+                            service_error
+                        {
+                            if canceled
+                                .cancellation_reasons()
+                                .iter()
+                                .any(|reason| reason.code() == Some("ConditionalCheckFailed"))
+                            {
+                                return GAME_EVENT_SEQUENCE_CONFLICT_ERROR.to_string();
+                            }
+                        }
+                        eprintln!("Service Error: {:?}", service_error);
+                    }
+                    format!("ゲームイベントの登録に失敗しました: {}", e)
+                })?;
+        }
+        Ok(())
+    }
+
+    async fn get_latest_sequence(&self, game_id: &GameId) -> Result<Option<EventSequence>, String> {
+        let result = self
+            .client
+            .query()
+            .table_name(self.game_events_table)
+            .key_condition_expression("game_id = :game_id")
+            .expression_attribute_values(":game_id", AttributeValue::S(game_id.value().to_string()))
+            .scan_index_forward(false) // 降順（最も新しいイベントが先頭）
+            .limit(1) // 1件のみ取得
+            .send()
+            .await
+            .map_err(|e| format!("最新のゲームイベントの取得に失敗しました: {}", e))?;
+
+        match result.items().first() {
+            Some(item) => Ok(Some(
+                self.from_dynamo_db_to_game_event(item)?.sequence().clone(),
+            )),
+            None => Ok(None),
+        }
+    }
+
+    async fn get_events(&self, game_id: &GameId) -> Result<Vec<GameEvent>, String> {
+        let mut events: Vec<GameEvent> = Vec::new();
+        let mut exclusive_start_key = None;
+        // 1MBを超える場合はページングして全件取得
+        loop {
+            let result = self
+                .client
+                .query()
+                .table_name(self.game_events_table)
+                .key_condition_expression("game_id = :game_id")
+                .expression_attribute_values(
+                    ":game_id",
+                    AttributeValue::S(game_id.value().to_string()),
+                )
+                .scan_index_forward(true) // 昇順（発生順）
+                .set_exclusive_start_key(exclusive_start_key)
+                .send()
+                .await
+                .map_err(|e| format!("ゲームイベントの取得に失敗しました: {}", e))?;
+
+            for item in result.items() {
+                events.push(self.from_dynamo_db_to_game_event(item)?);
+            }
+
+            exclusive_start_key = result.last_evaluated_key().cloned();
+            if exclusive_start_key.is_none() {
+                break;
+            }
+        }
+        Ok(events)
+    }
+}
@@ -0,0 +1,171 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::player_management::models::player::player_id::player_id::PlayerId;
+    use crate::domain::triggergame_simulator::models::game::game_id::game_id::GameId;
+    use crate::domain::triggergame_simulator::models::game_event::event_sequence::event_sequence::EventSequence;
+    use crate::domain::triggergame_simulator::models::game_event::game_event::GameEvent;
+    use crate::domain::triggergame_simulator::models::game_event::game_event_payload::GameEventPayload;
+    use crate::domain::triggergame_simulator::models::turn::turn_number::turn_number::TurnNumber;
+    use crate::domain::triggergame_simulator::repositories::game_event_repository::{
+        GameEventRepository, GAME_EVENT_SEQUENCE_CONFLICT_ERROR,
+    };
+
+    use super::super::game_event_dynamodb_repository::DynamoDbGameEventRepository;
+    use aws_credential_types::Credentials;
+    use aws_sdk_dynamodb::{
+        config::{BehaviorVersion, Region},
+        operation::{
+            query::{QueryInput, QueryOutput},
+            transact_write_items::{
+                TransactWriteItemsError, TransactWriteItemsInput, TransactWriteItemsOutput,
+            },
+        },
+        types::{error::TransactionCanceledException, AttributeValue, CancellationReason},
+        Client, Config,
+    };
+    use aws_smithy_mocks::{
+        create_mock_http_client, mock, MockResponseInterceptor, Rule, RuleMode,
+    };
+    use std::collections::HashMap;
+    use uuid::Uuid;
+
+    /// モッククライアントをセットアップ
+    fn setup_mock_client(rule: Rule) -> Client {
+        let mock_interceptor = MockResponseInterceptor::new()
+            .rule_mode(RuleMode::MatchAny)
+            .with_rule(&rule);
+
+        let config = Config::builder()
+            .behavior_version(BehaviorVersion::latest())
+            .region(Region::new("ap-northeast-1"))
+            .interceptor(mock_interceptor)
+            // エラーレスポンスのモックにはHTTPクライアントと認証情報が必要
+            .http_client(create_mock_http_client())
+            .credentials_provider(Credentials::new("dummy", "dummy", None, None, "test"))
+            .build();
+
+        Client::from_conf(config)
+    }
+
+    fn create_test_events(game_id: &GameId) -> Vec<GameEvent> {
+        let player_id = PlayerId::new(Uuid::new_v4().to_string());
+        vec![
+            GameEvent::create(
+                game_id.clone(),
+                EventSequence::new(1),
+                GameEventPayload::TurnSubmitted {
+                    player_id,
+                    turn_number: TurnNumber::new(1),
+                },
+            ),
+            GameEvent::create(
+                game_id.clone(),
+                EventSequence::new(2),
+                GameEventPayload::GameFinished {
+                    final_turn_number: TurnNumber::new(1),
+                },
+            ),
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_append() {
+        let game_id = GameId::new(Uuid::new_v4().to_string());
+        let events = create_test_events(&game_id);
+
+        // 通し番号の重複を防ぐ条件付きでまとめて書き込まれること
+        let transact_rule = mock!(Client::transact_write_items)
+            .match_requests(|input: &TransactWriteItemsInput| {
+                let items = input.transact_items();
+                items.len() == 2
+                    && items.iter().all(|item| {
+                        let put = item.put().unwrap();
+                        put.table_name() == "GameEvents"
+                            && put.condition_expression().is_some()
+                            && put.item().contains_key("payload")
+                    })
+                    && items[1].put().unwrap().item().get("event_type")
+                        == Some(&AttributeValue::S("GameFinished".to_string()))
+            })
+            .then_output(|| TransactWriteItemsOutput::builder().build());
+
+        let client = setup_mock_client(transact_rule);
+        let repo = DynamoDbGameEventRepository::new(client);
+
+        let result = repo.append(&events).await;
+        assert!(result.is_ok(), "Failed to append: {:?}", result.err());
+    }
+
+    #[tokio::test]
+    async fn test_append_sequence_conflict() {
+        let game_id = GameId::new(Uuid::new_v4().to_string());
+        let events = create_test_events(&game_id);
+
+        // 同じ通し番号のイベントが既に存在する場合をモック
+        let transact_rule = mock!(Client::transact_write_items)
+            .match_requests(|_: &TransactWriteItemsInput| true)
+            .then_error(|| {
+                TransactWriteItemsError::TransactionCanceledException(
+                    TransactionCanceledException::builder()
+                        .cancellation_reasons(
+                            CancellationReason::builder()
+                                .code("ConditionalCheckFailed")
+                                .build(),
+                        )
+                        .cancellation_reasons(CancellationReason::builder().code("None").build())
+                        .build(),
+                )
+            });
+
+        let client = setup_mock_client(transact_rule);
+        let repo = DynamoDbGameEventRepository::new(client);
+
+        let result = repo.append(&events).await;
+        assert_eq!(result.unwrap_err(), GAME_EVENT_SEQUENCE_CONFLICT_ERROR);
+    }
+
+    #[tokio::test]
+    async fn test_get_events() {
+        let game_id = GameId::new(Uuid::new_v4().to_string());
+        let game_id_value = game_id.value().to_string();
+
+        let mut item = HashMap::new();
+        item.insert(
+            "game_id".to_string(),
+            AttributeValue::S(game_id_value.clone()),
+        );
+        item.insert("sequence".to_string(), AttributeValue::N("1".to_string()));
+        item.insert(
+            "event_type".to_string(),
+            AttributeValue::S("GameFinished".to_string()),
+        );
+        item.insert(
+            "occurred_at".to_string(),
+            AttributeValue::S("2024-01-01T00:00:00+00:00".to_string()),
+        );
+        item.insert(
+            "payload".to_string(),
+            AttributeValue::S(r#"{"type":"GameFinished","finalTurnNumber":6}"#.to_string()),
+        );
+
+        let query_rule = mock!(Client::query)
+            .match_requests(move |input: &QueryInput| {
+                input.table_name() == Some("GameEvents") && input.scan_index_forward() == Some(true)
+            })
+            .then_output(move || QueryOutput::builder().items(item.clone()).build());
+
+        let client = setup_mock_client(query_rule);
+        let repo = DynamoDbGameEventRepository::new(client);
+
+        let events = repo.get_events(&game_id).await.unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].game_id().value(), game_id_value);
+        assert_eq!(events[0].sequence().value(), 1);
+        match events[0].payload() {
+            GameEventPayload::GameFinished { final_turn_number } => {
+                assert_eq!(final_turn_number.value(), 6)
+            }
+            _ => panic!("GameFinishedとして復元されていません"),
+        }
+    }
+}
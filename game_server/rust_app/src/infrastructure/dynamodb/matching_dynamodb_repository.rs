@@ -2,15 +2,25 @@
 
 use crate::domain::matching_management::models::matching::{
     Matching, MatchingEndDatetime, MatchingId, MatchingStartDatetime, MatchingStatus,
-    MatchingStatusValue,
+    MatchingStatusValue, RoomCode,
+};
+use crate::domain::matching_management::repositories::matching_repository::{
+    MatchingRepository, MATCHING_CONFLICT_ERROR,
+};
+use crate::domain::player_management::models::player::{
+    player_id::player_id::PlayerId, rating::rating::Rating,
 };
-use crate::domain::matching_management::repositories::matching_repository::MatchingRepository;
-use crate::domain::player_management::models::player::player_id::player_id::PlayerId;
 use async_trait::async_trait;
 use aws_sdk_dynamodb::types::AttributeValue;
 use aws_sdk_dynamodb::Client as DynamoDbClient;
+use chrono::{DateTime, Utc};
 use std::collections::HashMap;
 
+/// 待機中（InProgressかつ対戦相手が未決定）のマッチングのみ更新する
+/// 同じマッチングに複数のプレイヤーが同時に参加することを防ぐ
+const WAITING_CONDITION_EXPRESSION: &str =
+    "matching_status = :in_progress AND attribute_not_exists(player2_id)";
+
 pub struct DynamoDbMatchingRepository {
     client: DynamoDbClient,
     matchings_table: &'static str,
@@ -37,6 +47,10 @@ impl DynamoDbMatchingRepository {
             "player1_id".to_string(),
             AttributeValue::S(matching.player1_id().value().to_string()),
         );
+        item.insert(
+            "player1_rating".to_string(),
+            AttributeValue::N(matching.player1_rating().value().to_string()),
+        );
         matching.player2_id().clone().map(|player2_id| {
             item.insert(
                 "player2_id".to_string(),
@@ -58,8 +72,85 @@ impl DynamoDbMatchingRepository {
             "matching_status".to_string(),
             AttributeValue::S(matching.matching_status().value().to_string()),
         );
+        // room_codeはプライベートルームのみ保存し、RoomCodeIndexに含まれるようにする
+        if let Some(room_code) = matching.room_code() {
+            item.insert(
+                "room_code".to_string(),
+                AttributeValue::S(room_code.value().to_string()),
+            );
+        }
         item
     }
+
+    // ヘルパーメソッド：属性値マップをMatchingに変換
+    fn item_to_matching(
+        &self,
+        matching_item: &HashMap<String, AttributeValue>,
+    ) -> Result<Matching, String> {
+        // Matchingの属性を抽出
+        let matching_id_str = matching_item
+            .get("matching_id")
+            .and_then(|v| v.as_s().ok())
+            .ok_or("matching_id not found")?;
+        let matching_start_datetime_str = matching_item
+            .get("matching_start_datetime")
+            .and_then(|v| v.as_s().ok())
+            .ok_or("matching_start_datetime not found")?;
+        let matching_status_str = matching_item
+            .get("matching_status")
+            .and_then(|v| v.as_s().ok())
+            .ok_or("matching_status not found")?;
+        let player1_id_str = matching_item
+            .get("player1_id")
+            .and_then(|v| v.as_s().ok())
+            .ok_or("player1_id not found")?;
+
+        // レーティング導入前のアイテムは初期値として扱う
+        let player1_rating = match matching_item
+            .get("player1_rating")
+            .and_then(|v| v.as_n().ok())
+        {
+            Some(rating_str) => Rating::new(
+                rating_str
+                    .parse::<i32>()
+                    .map_err(|e| format!("player1_ratingの解析に失敗しました: {}", e))?,
+            ),
+            None => Rating::initial(),
+        };
+
+        // player2_idはOption型
+        let player2_id = matching_item
+            .get("player2_id")
+            .and_then(|v| v.as_s().ok())
+            .map(|id| PlayerId::new(id.to_string()));
+
+        // 終了日時は中断・完了したマッチングのみ保持している
+        let matching_end_datetime = matching_item
+            .get("matching_end_datetime")
+            .and_then(|v| v.as_s().ok())
+            .map(|dt_str| {
+                DateTime::parse_from_rfc3339(dt_str)
+                    .map(|dt| dt.with_timezone(&Utc))
+                    .map_err(|e| format!("matching_end_datetimeの解析に失敗しました: {}", e))
+            })
+            .transpose()?;
+
+        let room_code = matching_item
+            .get("room_code")
+            .and_then(|v| v.as_s().ok())
+            .map(|code| RoomCode::new(code.to_string()));
+
+        Ok(Matching::new(
+            MatchingId::new(matching_id_str.to_string()),
+            PlayerId::new(player1_id_str.to_string()),
+            player1_rating,
+            player2_id,
+            MatchingStartDatetime::new_string(matching_start_datetime_str),
+            MatchingEndDatetime::new(matching_end_datetime),
+            MatchingStatus::new_string(matching_status_str),
+            room_code,
+        ))
+    }
 }
 
 #[async_trait]
@@ -107,6 +198,11 @@ impl MatchingRepository for DynamoDbMatchingRepository {
                 AttributeValue::S(matching.matching_id().value().to_string()),
             )
             .update_expression(update_expression)
+            .condition_expression(WAITING_CONDITION_EXPRESSION)
+            .expression_attribute_values(
+                ":in_progress",
+                AttributeValue::S(MatchingStatus::new(MatchingStatusValue::InProgress).fmt_value()),
+            )
             .expression_attribute_values(
                 ":player1_id",
                 AttributeValue::S(matching.player1_id().value().to_string()),
@@ -135,80 +231,139 @@ impl MatchingRepository for DynamoDbMatchingRepository {
             );
         }
 
-        let _ = request.send().await.map_err(|e| {
-            println!("Failed to update matching: {}", e);
-
-            // SDK のエラー詳細も出力
+        request.send().await.map_err(|e| {
             if let Some(service_error) = e.as_service_error() {
+                // 条件式を満たさない場合は他のリクエストによる更新との競合
+                if service_error.is_conditional_check_failed_exception() {
+                    return MATCHING_CONFLICT_ERROR.to_string();
+                }
+                // SDK のエラー詳細も出力
                 eprintln!("Service Error: {:?}", service_error);
             }
-        });
+            println!("Failed to update matching: {}", e);
+            format!("マッチング情報の更新に失敗しました: {}", e)
+        })?;
 
         Ok(())
     }
 
     /// マッチング待機中の最新情報を取得
     async fn get_latest_waiting_matching(&self) -> Result<Option<Matching>, String> {
-        println!("Querying for latest waiting matching...");
-        // Playerアイテムを取得
-        // GSIを使用してmatching_status=0のデータを
-        // matching_start_datetimeの昇順で1件取得
+        Ok(self.get_waiting_matchings(1).await?.into_iter().next())
+    }
+
+    async fn get_waiting_matchings(&self, limit: i32) -> Result<Vec<Matching>, String> {
+        println!("Querying for waiting matchings...");
+        // GSIを使用してmatching_status=InProgressのデータを
+        // matching_start_datetimeの昇順で取得
+        // プライベートルームはフィルター式で除外するため、件数に達するまでページングする
+        let mut matchings = Vec::new();
+        let mut exclusive_start_key = None;
+        loop {
+            let result = self
+                .client
+                .query()
+                .table_name(self.matchings_table)
+                .index_name("MatchingStatusIndex") // GSI名
+                .key_condition_expression("matching_status = :status")
+                .filter_expression("attribute_not_exists(room_code)")
+                .expression_attribute_values(
+                    ":status",
+                    AttributeValue::S(
+                        MatchingStatus::new(MatchingStatusValue::InProgress).fmt_value(),
+                    ), // InProgress
+                )
+                .scan_index_forward(true) // 昇順（最も古いデータが先頭）
+                .limit(limit)
+                .set_exclusive_start_key(exclusive_start_key)
+                .send()
+                .await
+                .map_err(|e| format!("Failed to query matching: {}", e))?;
+
+            println!("Query result: {:?}", result);
+
+            for matching_item in result.items() {
+                if matchings.len() >= limit as usize {
+                    return Ok(matchings);
+                }
+                matchings.push(self.item_to_matching(matching_item)?);
+            }
+
+            exclusive_start_key = result.last_evaluated_key().cloned();
+            if exclusive_start_key.is_none() || matchings.len() >= limit as usize {
+                return Ok(matchings);
+            }
+        }
+    }
+
+    async fn find_waiting_matching_by_player_id(
+        &self,
+        player_id: &PlayerId,
+    ) -> Result<Option<Matching>, String> {
+        // 待機中のマッチングのうち、指定したプレイヤーが作成したものを検索
+        // フィルター式は取得後に適用されるため、ページングして全件を確認する
+        let mut exclusive_start_key = None;
+        loop {
+            let result = self
+                .client
+                .query()
+                .table_name(self.matchings_table)
+                .index_name("MatchingStatusIndex") // GSI名
+                .key_condition_expression("matching_status = :status")
+                .filter_expression("player1_id = :player1_id")
+                .expression_attribute_values(
+                    ":status",
+                    AttributeValue::S(
+                        MatchingStatus::new(MatchingStatusValue::InProgress).fmt_value(),
+                    ),
+                )
+                .expression_attribute_values(
+                    ":player1_id",
+                    AttributeValue::S(player_id.value().to_string()),
+                )
+                .set_exclusive_start_key(exclusive_start_key)
+                .send()
+                .await
+                .map_err(|e| format!("Failed to query matching: {}", e))?;
+
+            if let Some(matching_item) = result.items().first() {
+                return self.item_to_matching(matching_item).map(Some);
+            }
+
+            exclusive_start_key = result.last_evaluated_key().cloned();
+            if exclusive_start_key.is_none() {
+                return Ok(None);
+            }
+        }
+    }
+
+    async fn find_waiting_matching_by_room_code(
+        &self,
+        room_code: &RoomCode,
+    ) -> Result<Option<Matching>, String> {
+        // 招待コードは待機中のルーム同士でのみ重複しないため、待機中のものに絞り込む
         let result = self
             .client
             .query()
             .table_name(self.matchings_table)
-            .index_name("MatchingStatusIndex") // GSI名
-            .key_condition_expression("matching_status = :status")
+            .index_name("RoomCodeIndex") // GSI名
+            .key_condition_expression("room_code = :room_code")
+            .filter_expression("matching_status = :status")
+            .expression_attribute_values(
+                ":room_code",
+                AttributeValue::S(room_code.value().to_string()),
+            )
             .expression_attribute_values(
                 ":status",
-                AttributeValue::S(MatchingStatus::new(MatchingStatusValue::InProgress).fmt_value()), // InProgress
+                AttributeValue::S(MatchingStatus::new(MatchingStatusValue::InProgress).fmt_value()),
             )
-            .scan_index_forward(true) // 昇順（最も古いデータが先頭）
-            .limit(1) // 1件のみ取得
             .send()
             .await
             .map_err(|e| format!("Failed to query matching: {}", e))?;
 
-        println!("Query result: {:?}", result);
-
-        let items = result.items();
-        if items.is_empty() {
-            return Ok(None);
+        match result.items().first() {
+            Some(matching_item) => self.item_to_matching(matching_item).map(Some),
+            None => Ok(None),
         }
-
-        let matching_item = &items[0];
-
-        // Matchingの属性を抽出
-        let matching_id_str = matching_item
-            .get("matching_id")
-            .and_then(|v| v.as_s().ok())
-            .ok_or("matching_id not found")?;
-        let matching_start_datetime_str = matching_item
-            .get("matching_start_datetime")
-            .and_then(|v| v.as_s().ok())
-            .ok_or("matching_start_datetime not found")?;
-        let matching_status_str = matching_item
-            .get("matching_status")
-            .and_then(|v| v.as_s().ok())
-            .ok_or("matching_status not found")?;
-        let player1_id_str = matching_item
-            .get("player1_id")
-            .and_then(|v| v.as_s().ok())
-            .ok_or("player1_id not found")?;
-
-        // player2_idはOption型
-        let player2_id = matching_item
-            .get("player2_id")
-            .and_then(|v| v.as_s().ok())
-            .map(|id| PlayerId::new(id.to_string()));
-
-        Ok(Some(Matching::new(
-            MatchingId::new(matching_id_str.to_string()),
-            PlayerId::new(player1_id_str.to_string()),
-            player2_id,
-            MatchingStartDatetime::new_string(matching_start_datetime_str),
-            MatchingEndDatetime::new(None),
-            MatchingStatus::new_string(matching_status_str),
-        )))
     }
 }
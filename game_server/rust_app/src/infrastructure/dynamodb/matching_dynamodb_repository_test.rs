@@ -4,25 +4,30 @@ mod tests {
         matching_management::{
             models::matching::{
                 Matching, MatchingEndDatetime, MatchingId, MatchingStartDatetime, MatchingStatus,
-                MatchingStatusValue,
+                MatchingStatusValue, RoomCode,
             },
-            repositories::matching_repository::MatchingRepository,
+            repositories::matching_repository::{MatchingRepository, MATCHING_CONFLICT_ERROR},
+        },
+        player_management::models::player::{
+            player_id::player_id::PlayerId, rating::rating::Rating,
         },
-        player_management::models::player::player_id::player_id::PlayerId,
     };
 
     use super::super::matching_dynamodb_repository::DynamoDbMatchingRepository;
+    use aws_credential_types::Credentials;
     use aws_sdk_dynamodb::{
         config::{BehaviorVersion, Region},
         operation::{
             put_item::{PutItemInput, PutItemOutput},
             query::{QueryInput, QueryOutput},
-            update_item::{UpdateItemInput, UpdateItemOutput},
+            update_item::{UpdateItemError, UpdateItemInput, UpdateItemOutput},
         },
-        types::AttributeValue,
+        types::{error::ConditionalCheckFailedException, AttributeValue},
         Client, Config,
     };
-    use aws_smithy_mocks::{mock, MockResponseInterceptor, Rule, RuleMode};
+    use aws_smithy_mocks::{
+        create_mock_http_client, mock, MockResponseInterceptor, Rule, RuleMode,
+    };
     use chrono::Utc;
     use std::collections::HashMap;
     use uuid::Uuid;
@@ -37,6 +42,9 @@ mod tests {
             .behavior_version(BehaviorVersion::latest())
             .region(Region::new("ap-northeast-1"))
             .interceptor(mock_interceptor)
+            // エラーレスポンスのモックにはHTTPクライアントと認証情報が必要
+            .http_client(create_mock_http_client())
+            .credentials_provider(Credentials::new("dummy", "dummy", None, None, "test"))
             .build();
 
         Client::from_conf(config)
@@ -49,10 +57,12 @@ mod tests {
         let matching = Matching::new(
             MatchingId::new(Uuid::new_v4().to_string()),
             PlayerId::new(uuid1.to_string()),
+            Rating::initial(),
             None,
             MatchingStartDatetime::new(datetime),
             MatchingEndDatetime::new(None),
             MatchingStatus::new(MatchingStatusValue::InProgress),
+            None,
         );
 
         // PutItemの成功レスポンスをモック
@@ -76,10 +86,12 @@ mod tests {
         let matching = Matching::new(
             MatchingId::new(Uuid::new_v4().to_string()),
             PlayerId::new(uuid1.to_string()),
+            Rating::initial(),
             Some(PlayerId::new(uuid2.to_string())),
             MatchingStartDatetime::new(datetime),
             MatchingEndDatetime::new(Some(datetime)),
             MatchingStatus::new(MatchingStatusValue::Completed),
+            None,
         );
 
         // UpdateItemの成功レスポンスをモック
@@ -94,6 +106,111 @@ mod tests {
         assert!(result.is_ok(), "Failed to update: {:?}", result.err());
     }
 
+    #[tokio::test]
+    async fn test_update_matching_uses_waiting_condition() {
+        let mut matching = Matching::create(
+            PlayerId::new("550e8400-e29b-41d4-a716-446655440001".to_string()),
+            Rating::initial(),
+        );
+        matching
+            .matchmaking(PlayerId::new(
+                "550e8400-e29b-41d4-a716-446655440002".to_string(),
+            ))
+            .unwrap();
+
+        // 待機中のマッチングのみを更新する条件式が指定されていること
+        let update_item_rule = mock!(Client::update_item)
+            .match_requests(|input: &UpdateItemInput| {
+                input.condition_expression()
+                    == Some("matching_status = :in_progress AND attribute_not_exists(player2_id)")
+                    && input
+                        .expression_attribute_values()
+                        .and_then(|values| values.get(":in_progress"))
+                        == Some(&AttributeValue::S("InProgress".to_string()))
+            })
+            .then_output(|| UpdateItemOutput::builder().build());
+
+        let client = setup_mock_client(update_item_rule);
+        let repo = DynamoDbMatchingRepository::new(client);
+
+        let result = repo.update(&matching).await;
+        assert!(result.is_ok(), "Failed to update: {:?}", result.err());
+    }
+
+    #[tokio::test]
+    async fn test_update_matching_conflict() {
+        let mut matching = Matching::create(
+            PlayerId::new("550e8400-e29b-41d4-a716-446655440001".to_string()),
+            Rating::initial(),
+        );
+        matching
+            .matchmaking(PlayerId::new(
+                "550e8400-e29b-41d4-a716-446655440002".to_string(),
+            ))
+            .unwrap();
+
+        // 他のプレイヤーが先に参加していた場合のエラーレスポンスをモック
+        let update_item_rule = mock!(Client::update_item)
+            .match_requests(|_: &UpdateItemInput| true)
+            .then_error(|| {
+                UpdateItemError::ConditionalCheckFailedException(
+                    ConditionalCheckFailedException::builder().build(),
+                )
+            });
+
+        let client = setup_mock_client(update_item_rule);
+        let repo = DynamoDbMatchingRepository::new(client);
+
+        let result = repo.update(&matching).await;
+        assert_eq!(result.unwrap_err(), MATCHING_CONFLICT_ERROR);
+    }
+
+    #[tokio::test]
+    async fn test_get_waiting_matchings() {
+        let player_ids = [
+            "550e8400-e29b-41d4-a716-446655440001",
+            "550e8400-e29b-41d4-a716-446655440002",
+        ];
+        let items: Vec<HashMap<String, AttributeValue>> = player_ids
+            .iter()
+            .map(|player_id| {
+                let mut item = HashMap::new();
+                item.insert(
+                    "matching_id".to_string(),
+                    AttributeValue::S(Uuid::new_v4().to_string()),
+                );
+                item.insert(
+                    "player1_id".to_string(),
+                    AttributeValue::S(player_id.to_string()),
+                );
+                item.insert(
+                    "matching_start_datetime".to_string(),
+                    AttributeValue::S(Utc::now().to_rfc3339()),
+                );
+                item.insert(
+                    "matching_status".to_string(),
+                    AttributeValue::S("InProgress".to_string()),
+                );
+                item
+            })
+            .collect();
+
+        let query_rule = mock!(Client::query)
+            .match_requests(|input: &QueryInput| input.limit() == Some(2))
+            .then_output(move || {
+                QueryOutput::builder()
+                    .set_items(Some(items.clone()))
+                    .build()
+            });
+        let client = setup_mock_client(query_rule);
+        let repo = DynamoDbMatchingRepository::new(client);
+
+        let matchings = repo.get_waiting_matchings(2).await.unwrap();
+        assert_eq!(matchings.len(), 2);
+        assert_eq!(matchings[0].player1_id().value(), player_ids[0]);
+        assert_eq!(matchings[1].player1_id().value(), player_ids[1]);
+    }
+
     #[tokio::test]
     async fn test_get_latest_waiting_matching_found() {
         let matching_id = MatchingId::new(Uuid::new_v4().to_string());
@@ -150,4 +267,172 @@ mod tests {
         assert!(result.is_ok());
         assert!(result.unwrap().is_none());
     }
+
+    #[tokio::test]
+    async fn test_find_waiting_matching_by_player_id_found() {
+        let matching_id = MatchingId::new(Uuid::new_v4().to_string());
+        let player_id = PlayerId::new("550e8400-e29b-41d4-a716-446655440001".to_string());
+
+        let mut item = HashMap::new();
+        item.insert(
+            "matching_id".to_string(),
+            AttributeValue::S(matching_id.value().to_string()),
+        );
+        item.insert(
+            "player1_id".to_string(),
+            AttributeValue::S(player_id.value().to_string()),
+        );
+        item.insert(
+            "matching_start_datetime".to_string(),
+            AttributeValue::S(Utc::now().to_rfc3339()),
+        );
+        item.insert(
+            "matching_status".to_string(),
+            AttributeValue::S("InProgress".to_string()),
+        );
+
+        // 待機中のマッチングをプレイヤーIDで絞り込むクエリのみ応答する
+        let expected_player_id = player_id.value().to_string();
+        let query_rule = mock!(Client::query)
+            .match_requests(move |input: &QueryInput| {
+                input
+                    .expression_attribute_values()
+                    .and_then(|values| values.get(":player1_id"))
+                    == Some(&AttributeValue::S(expected_player_id.clone()))
+            })
+            .then_output(move || {
+                QueryOutput::builder()
+                    .set_items(Some(vec![item.clone()]))
+                    .build()
+            });
+        let client = setup_mock_client(query_rule);
+        let repo = DynamoDbMatchingRepository::new(client);
+
+        let result = repo.find_waiting_matching_by_player_id(&player_id).await;
+        let matching = result.unwrap().expect("matching should be found");
+        assert_eq!(matching.matching_id(), &matching_id);
+        assert!(matching.is_in_progress());
+    }
+
+    #[tokio::test]
+    async fn test_find_waiting_matching_by_player_id_not_found() {
+        let query_rule = mock!(Client::query)
+            .match_requests(|_: &QueryInput| true)
+            .then_output(|| QueryOutput::builder().build());
+
+        let client = setup_mock_client(query_rule);
+        let repo = DynamoDbMatchingRepository::new(client);
+
+        let result = repo
+            .find_waiting_matching_by_player_id(&PlayerId::new(Uuid::new_v4().to_string()))
+            .await;
+        assert!(result.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_save_private_matching() {
+        let matching = Matching::create_private(
+            PlayerId::new(Uuid::new_v4().to_string()),
+            Rating::initial(),
+            RoomCode::new("ABC234".to_string()),
+        );
+
+        // 招待コードを含むアイテムの保存のみ応答する
+        let put_item_rule = mock!(Client::put_item)
+            .match_requests(|input: &PutItemInput| {
+                input.item().and_then(|item| item.get("room_code"))
+                    == Some(&AttributeValue::S("ABC234".to_string()))
+            })
+            .then_output(|| PutItemOutput::builder().build());
+
+        let client = setup_mock_client(put_item_rule);
+        let repo = DynamoDbMatchingRepository::new(client);
+
+        let result = repo.save(&matching).await;
+        assert!(result.is_ok(), "Failed to save: {:?}", result.err());
+    }
+
+    #[tokio::test]
+    async fn test_get_waiting_matchings_excludes_private_rooms() {
+        // 招待コードを持つマッチングを除外するクエリのみ応答する
+        let query_rule = mock!(Client::query)
+            .match_requests(|input: &QueryInput| {
+                input.filter_expression() == Some("attribute_not_exists(room_code)")
+            })
+            .then_output(|| QueryOutput::builder().build());
+        let client = setup_mock_client(query_rule);
+        let repo = DynamoDbMatchingRepository::new(client);
+
+        let matchings = repo.get_waiting_matchings(10).await.unwrap();
+        assert!(matchings.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_find_waiting_matching_by_room_code_found() {
+        let matching_id = MatchingId::new(Uuid::new_v4().to_string());
+        let room_code = RoomCode::new("XYZ789".to_string());
+
+        let mut item = HashMap::new();
+        item.insert(
+            "matching_id".to_string(),
+            AttributeValue::S(matching_id.value().to_string()),
+        );
+        item.insert(
+            "player1_id".to_string(),
+            AttributeValue::S(Uuid::new_v4().to_string()),
+        );
+        item.insert(
+            "matching_start_datetime".to_string(),
+            AttributeValue::S(Utc::now().to_rfc3339()),
+        );
+        item.insert(
+            "matching_status".to_string(),
+            AttributeValue::S("InProgress".to_string()),
+        );
+        item.insert(
+            "room_code".to_string(),
+            AttributeValue::S(room_code.value().to_string()),
+        );
+
+        // 招待コードのインデックスを検索するクエリのみ応答する
+        let query_rule = mock!(Client::query)
+            .match_requests(|input: &QueryInput| {
+                input.index_name() == Some("RoomCodeIndex")
+                    && input
+                        .expression_attribute_values()
+                        .and_then(|values| values.get(":room_code"))
+                        == Some(&AttributeValue::S("XYZ789".to_string()))
+            })
+            .then_output(move || {
+                QueryOutput::builder()
+                    .set_items(Some(vec![item.clone()]))
+                    .build()
+            });
+        let client = setup_mock_client(query_rule);
+        let repo = DynamoDbMatchingRepository::new(client);
+
+        let matching = repo
+            .find_waiting_matching_by_room_code(&room_code)
+            .await
+            .unwrap()
+            .expect("matching should be found");
+        assert_eq!(matching.matching_id(), &matching_id);
+        assert!(matching.is_private());
+        assert_eq!(matching.room_code(), &Some(room_code));
+    }
+
+    #[tokio::test]
+    async fn test_find_waiting_matching_by_room_code_not_found() {
+        let query_rule = mock!(Client::query)
+            .match_requests(|_: &QueryInput| true)
+            .then_output(|| QueryOutput::builder().build());
+
+        let client = setup_mock_client(query_rule);
+        let repo = DynamoDbMatchingRepository::new(client);
+
+        let result = repo
+            .find_waiting_matching_by_room_code(&RoomCode::new("XYZ789".to_string()))
+            .await;
+        assert!(result.unwrap().is_none());
+    }
 }
@@ -0,0 +1,190 @@
+// infrastructure/dynamodb/outbox_dynamodb_repository.rs
+
+use crate::domain::player_management::models::outbox_message::message_sequence::message_sequence::MessageSequence;
+use crate::domain::player_management::models::outbox_message::outbox_message::OutboxMessage;
+use crate::domain::player_management::models::player::player_id::player_id::PlayerId;
+use crate::domain::player_management::repositories::outbox_repository::OutboxRepository;
+use async_trait::async_trait;
+use aws_sdk_dynamodb::types::{AttributeValue, ReturnValue};
+use aws_sdk_dynamodb::Client as DynamoDbClient;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+/// 通し番号の採番用アイテムのソートキー
+/// 通知は1から採番されるため、0番を最新の通し番号の保持に使用する
+const SEQUENCE_COUNTER_KEY: &str = "0";
+
+pub struct DynamoDbOutboxRepository {
+    client: DynamoDbClient,
+    outbox_table: &'static str,
+}
+
+impl DynamoDbOutboxRepository {
+    pub fn new(client: DynamoDbClient) -> Self {
+        // テーブル名
+        const OUTBOX_TABLE_NAME: &str = "Outbox";
+        Self {
+            client,
+            outbox_table: OUTBOX_TABLE_NAME,
+        }
+    }
+
+    // ヘルパーメソッド：OutboxMessageを属性値マップに変換
+    fn outbox_message_to_item(&self, message: &OutboxMessage) -> HashMap<String, AttributeValue> {
+        let mut item = HashMap::new();
+        item.insert(
+            "player_id".to_string(),
+            AttributeValue::S(message.player_id().value().to_string()),
+        );
+        item.insert(
+            "sequence".to_string(),
+            AttributeValue::N(message.sequence().value().to_string()),
+        );
+        item.insert(
+            "payload".to_string(),
+            AttributeValue::S(message.payload().to_string()),
+        );
+        item.insert(
+            "created_at".to_string(),
+            AttributeValue::S(message.created_at().to_rfc3339()),
+        );
+        item
+    }
+
+    /// ヘルパーメソッド：DynamoDBから取得したデータをOutboxMessageエンティティに変換
+    fn from_dynamo_db_to_outbox_message(
+        &self,
+        item: &HashMap<String, AttributeValue>,
+    ) -> Result<OutboxMessage, String> {
+        let player_id = PlayerId::new(
+            item.get("player_id")
+                .and_then(|v| v.as_s().ok())
+                .ok_or("player_id not found or invalid")?
+                .to_string(),
+        );
+
+        let sequence = MessageSequence::new(
+            item.get("sequence")
+                .and_then(|v| v.as_n().ok())
+                .and_then(|n| n.parse::<i64>().ok())
+                .ok_or("sequence not found or invalid")?,
+        );
+
+        let payload = item
+            .get("payload")
+            .and_then(|v| v.as_s().ok())
+            .ok_or("payload not found or invalid")?
+            .to_string();
+
+        let created_at = item
+            .get("created_at")
+            .and_then(|v| v.as_s().ok())
+            .ok_or("created_at not found or invalid")?
+            .parse::<DateTime<Utc>>()
+            .map_err(|e| format!("Failed to parse created_at: {}", e))?;
+
+        Ok(OutboxMessage::reconstruct(
+            player_id, sequence, payload, created_at,
+        ))
+    }
+}
+
+#[async_trait]
+impl OutboxRepository for DynamoDbOutboxRepository {
+    async fn next_sequence(&self, player_id: &PlayerId) -> Result<MessageSequence, String> {
+        // 採番用アイテムの値をアトミックに加算し、加算後の値を通し番号とする
+        let result = self
+            .client
+            .update_item()
+            .table_name(self.outbox_table)
+            .key(
+                "player_id",
+                AttributeValue::S(player_id.value().to_string()),
+            )
+            .key(
+                "sequence",
+                AttributeValue::N(SEQUENCE_COUNTER_KEY.to_string()),
+            )
+            .update_expression("ADD last_sequence :one")
+            .expression_attribute_values(":one", AttributeValue::N("1".to_string()))
+            .return_values(ReturnValue::UpdatedNew)
+            .send()
+            .await
+            .map_err(|e| format!("通知の通し番号の採番に失敗しました: {}", e))?;
+
+        let last_sequence = result
+            .attributes()
+            .and_then(|attributes| attributes.get("last_sequence"))
+            .and_then(|v| v.as_n().ok())
+            .and_then(|n| n.parse::<i64>().ok())
+            .ok_or("採番した通し番号が見つかりませんでした。")?;
+        Ok(MessageSequence::new(last_sequence))
+    }
+
+    async fn save(&self, message: &OutboxMessage) -> Result<(), String> {
+        self.client
+            .put_item()
+            .table_name(self.outbox_table)
+            .set_item(Some(self.outbox_message_to_item(message)))
+            .send()
+            .await
+            .map_err(|e| format!("未送信の通知の保存に失敗しました: {}", e))?;
+        Ok(())
+    }
+
+    async fn get_messages(&self, player_id: &PlayerId) -> Result<Vec<OutboxMessage>, String> {
+        let mut messages: Vec<OutboxMessage> = Vec::new();
+        let mut exclusive_start_key = None;
+        // 1MBを超える場合はページングして全件取得
+        loop {
+            let result = self
+                .client
+                .query()
+                .table_name(self.outbox_table)
+                // 採番用アイテムを除いて取得
+                .key_condition_expression("player_id = :player_id AND #sequence > :counter")
+                .expression_attribute_names("#sequence", "sequence")
+                .expression_attribute_values(
+                    ":player_id",
+                    AttributeValue::S(player_id.value().to_string()),
+                )
+                .expression_attribute_values(
+                    ":counter",
+                    AttributeValue::N(SEQUENCE_COUNTER_KEY.to_string()),
+                )
+                .scan_index_forward(true) // 昇順（通し番号順）
+                .set_exclusive_start_key(exclusive_start_key)
+                .send()
+                .await
+                .map_err(|e| format!("未送信の通知の取得に失敗しました: {}", e))?;
+
+            for item in result.items() {
+                messages.push(self.from_dynamo_db_to_outbox_message(item)?);
+            }
+
+            exclusive_start_key = result.last_evaluated_key().cloned();
+            if exclusive_start_key.is_none() {
+                break;
+            }
+        }
+        Ok(messages)
+    }
+
+    async fn delete(&self, message: &OutboxMessage) -> Result<(), String> {
+        self.client
+            .delete_item()
+            .table_name(self.outbox_table)
+            .key(
+                "player_id",
+                AttributeValue::S(message.player_id().value().to_string()),
+            )
+            .key(
+                "sequence",
+                AttributeValue::N(message.sequence().value().to_string()),
+            )
+            .send()
+            .await
+            .map_err(|e| format!("送信済みの通知の削除に失敗しました: {}", e))?;
+        Ok(())
+    }
+}
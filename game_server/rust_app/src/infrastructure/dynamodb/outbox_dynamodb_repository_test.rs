@@ -0,0 +1,161 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::player_management::models::outbox_message::message_sequence::message_sequence::MessageSequence;
+    use crate::domain::player_management::models::outbox_message::outbox_message::OutboxMessage;
+    use crate::domain::player_management::models::player::player_id::player_id::PlayerId;
+    use crate::domain::player_management::repositories::outbox_repository::OutboxRepository;
+
+    use super::super::outbox_dynamodb_repository::DynamoDbOutboxRepository;
+    use aws_credential_types::Credentials;
+    use aws_sdk_dynamodb::{
+        config::{BehaviorVersion, Region},
+        operation::{
+            delete_item::{DeleteItemInput, DeleteItemOutput},
+            put_item::{PutItemInput, PutItemOutput},
+            query::{QueryInput, QueryOutput},
+            update_item::{UpdateItemInput, UpdateItemOutput},
+        },
+        types::AttributeValue,
+        Client, Config,
+    };
+    use aws_smithy_mocks::{
+        create_mock_http_client, mock, MockResponseInterceptor, Rule, RuleMode,
+    };
+    use std::collections::HashMap;
+    use uuid::Uuid;
+
+    /// モッククライアントをセットアップ
+    fn setup_mock_client(rule: Rule) -> Client {
+        let mock_interceptor = MockResponseInterceptor::new()
+            .rule_mode(RuleMode::MatchAny)
+            .with_rule(&rule);
+
+        let config = Config::builder()
+            .behavior_version(BehaviorVersion::latest())
+            .region(Region::new("ap-northeast-1"))
+            .interceptor(mock_interceptor)
+            .http_client(create_mock_http_client())
+            .credentials_provider(Credentials::new("dummy", "dummy", None, None, "test"))
+            .build();
+
+        Client::from_conf(config)
+    }
+
+    #[tokio::test]
+    async fn test_next_sequence() {
+        let update_item_rule = mock!(Client::update_item)
+            .match_requests(|input: &UpdateItemInput| {
+                // 採番用アイテム（0番）を加算する
+                input.key().and_then(|key| key.get("sequence"))
+                    == Some(&AttributeValue::N("0".to_string()))
+            })
+            .then_output(|| {
+                UpdateItemOutput::builder()
+                    .attributes("last_sequence", AttributeValue::N("4".to_string()))
+                    .build()
+            });
+
+        let client = setup_mock_client(update_item_rule);
+        let repo = DynamoDbOutboxRepository::new(client);
+
+        let result = repo
+            .next_sequence(&PlayerId::new(Uuid::new_v4().to_string()))
+            .await;
+        assert_eq!(result.unwrap(), MessageSequence::new(4));
+    }
+
+    #[tokio::test]
+    async fn test_save() {
+        let message = OutboxMessage::create(
+            PlayerId::new(Uuid::new_v4().to_string()),
+            MessageSequence::new(2),
+            r#"{"action":"pong"}"#.to_string(),
+        );
+
+        let put_item_rule = mock!(Client::put_item)
+            .match_requests(|input: &PutItemInput| {
+                input.item().and_then(|item| item.get("sequence"))
+                    == Some(&AttributeValue::N("2".to_string()))
+            })
+            .then_output(|| PutItemOutput::builder().build());
+
+        let client = setup_mock_client(put_item_rule);
+        let repo = DynamoDbOutboxRepository::new(client);
+
+        let result = repo.save(&message).await;
+        assert!(result.is_ok(), "Failed to save message: {:?}", result.err());
+    }
+
+    #[tokio::test]
+    async fn test_get_messages() {
+        let player_id = PlayerId::new(Uuid::new_v4().to_string());
+        let items: Vec<HashMap<String, AttributeValue>> = (1..=2)
+            .map(|sequence| {
+                let mut item = HashMap::new();
+                item.insert(
+                    "player_id".to_string(),
+                    AttributeValue::S(player_id.value().to_string()),
+                );
+                item.insert(
+                    "sequence".to_string(),
+                    AttributeValue::N(sequence.to_string()),
+                );
+                item.insert(
+                    "payload".to_string(),
+                    AttributeValue::S(r#"{"action":"pong"}"#.to_string()),
+                );
+                item.insert(
+                    "created_at".to_string(),
+                    AttributeValue::S("2025-01-01T00:00:00+00:00".to_string()),
+                );
+                item
+            })
+            .collect();
+
+        let query_rule = mock!(Client::query)
+            .match_requests(|input: &QueryInput| {
+                // 採番用アイテムを除外して通し番号順に取得する
+                input.scan_index_forward() == Some(true)
+                    && input
+                        .expression_attribute_values()
+                        .and_then(|values| values.get(":counter"))
+                        == Some(&AttributeValue::N("0".to_string()))
+            })
+            .then_output(move || {
+                QueryOutput::builder()
+                    .set_items(Some(items.clone()))
+                    .build()
+            });
+
+        let client = setup_mock_client(query_rule);
+        let repo = DynamoDbOutboxRepository::new(client);
+
+        let messages = repo.get_messages(&player_id).await.unwrap();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].sequence().value(), 1);
+        assert_eq!(messages[1].sequence().value(), 2);
+        assert_eq!(messages[0].payload(), r#"{"action":"pong"}"#);
+    }
+
+    #[tokio::test]
+    async fn test_delete() {
+        let message = OutboxMessage::create(
+            PlayerId::new(Uuid::new_v4().to_string()),
+            MessageSequence::new(3),
+            "{}".to_string(),
+        );
+
+        let delete_item_rule = mock!(Client::delete_item)
+            .match_requests(|input: &DeleteItemInput| {
+                input.key().and_then(|key| key.get("sequence"))
+                    == Some(&AttributeValue::N("3".to_string()))
+            })
+            .then_output(|| DeleteItemOutput::builder().build());
+
+        let client = setup_mock_client(delete_item_rule);
+        let repo = DynamoDbOutboxRepository::new(client);
+
+        let result = repo.delete(&message).await;
+        assert!(result.is_ok(), "Failed to delete message: {:?}", result.err());
+    }
+}
@@ -1,10 +1,18 @@
 // infrastructure/dynamodb/player_dynamodb_repository.rs
 
-use crate::domain::player_management::models::player::Player;
+use crate::domain::player_management::models::player::{
+    mfa_attempts::mfa_attempts::MfaAttempts,
+    mfa_authentication::mfa_authentication::MFAAuthentication, player_id::player_id::PlayerId,
+    player_name::player_name::PlayerName, rating::rating::Rating,
+    recovery_code::recovery_code::RecoveryCode,
+    registered_datetime::registered_datetime::RegisteredDatetime,
+    totp_secret::totp_secret::TotpSecret, Player,
+};
 use crate::domain::player_management::repositories::player_repository::PlayerRepository;
 use async_trait::async_trait;
 use aws_sdk_dynamodb::types::AttributeValue;
 use aws_sdk_dynamodb::Client as DynamoDbClient;
+use chrono::{DateTime, Utc};
 use std::collections::HashMap;
 
 pub struct DynamoDbPlayerRepository {
@@ -41,8 +49,138 @@ impl DynamoDbPlayerRepository {
             "mfa_authentication".to_string(),
             AttributeValue::S(player.mfa_authentication().value().to_string()),
         );
+        // TOTPシークレットは登録中・有効な場合のみ保存
+        if let Some(totp_secret) = player.mfa_authentication().totp_secret() {
+            item.insert(
+                "totp_secret".to_string(),
+                AttributeValue::S(totp_secret.value().to_string()),
+            );
+        }
+        // リカバリーコードはハッシュ値のみ保存
+        item.insert(
+            "recovery_codes".to_string(),
+            AttributeValue::L(
+                player
+                    .mfa_authentication()
+                    .recovery_codes()
+                    .iter()
+                    .map(|recovery_code| AttributeValue::S(recovery_code.hash().to_string()))
+                    .collect(),
+            ),
+        );
+        // ワンタイムパスワードの再利用を防ぐため、最後に受け付けたタイムステップを保存
+        if let Some(last_used_time_step) = player.mfa_authentication().last_used_time_step() {
+            item.insert(
+                "totp_last_used_time_step".to_string(),
+                AttributeValue::N(last_used_time_step.to_string()),
+            );
+        }
+        item.insert(
+            "mfa_failed_count".to_string(),
+            AttributeValue::N(player.mfa_attempts().failed_count().to_string()),
+        );
+        if let Some(locked_until) = player.mfa_attempts().locked_until() {
+            item.insert(
+                "mfa_locked_until".to_string(),
+                AttributeValue::S(locked_until.to_rfc3339()),
+            );
+        }
+        item.insert(
+            "rating".to_string(),
+            AttributeValue::N(player.rating().value().to_string()),
+        );
         item
     }
+
+    // ヘルパーメソッド：属性値マップをPlayerに変換
+    fn item_to_player(&self, item: &HashMap<String, AttributeValue>) -> Result<Player, String> {
+        let player_id_str = item
+            .get("player_id")
+            .and_then(|v| v.as_s().ok())
+            .ok_or("プレイヤーIDが見つかりませんでした。")?;
+        let player_name_str = item
+            .get("player_name")
+            .and_then(|v| v.as_s().ok())
+            .ok_or("プレイヤー名が見つかりませんでした。")?;
+        let registered_datetime_str = item
+            .get("registered_datetime")
+            .and_then(|v| v.as_s().ok())
+            .ok_or("登録日時が見つかりませんでした。")?;
+        let registered_datetime = DateTime::parse_from_rfc3339(registered_datetime_str)
+            .map_err(|e| format!("登録日時の解析に失敗しました: {}", e))?
+            .with_timezone(&Utc);
+        let mfa_authentication = item
+            .get("mfa_authentication")
+            .and_then(|v| v.as_s().ok())
+            .map(|v| v == "true")
+            .unwrap_or(false);
+        let totp_secret = item
+            .get("totp_secret")
+            .and_then(|v| v.as_s().ok())
+            .map(|v| TotpSecret::new(v.to_string()));
+        let recovery_codes = item
+            .get("recovery_codes")
+            .and_then(|v| v.as_l().ok())
+            .map(|codes| {
+                codes
+                    .iter()
+                    .filter_map(|v| v.as_s().ok())
+                    .map(|hash| RecoveryCode::new(hash.to_string()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let last_used_time_step = match item
+            .get("totp_last_used_time_step")
+            .and_then(|v| v.as_n().ok())
+        {
+            Some(step_str) => Some(
+                step_str
+                    .parse::<i64>()
+                    .map_err(|e| format!("TOTPのタイムステップの解析に失敗しました: {}", e))?,
+            ),
+            None => None,
+        };
+        // TOTP導入前に有効化されたアイテムはシークレットを持たず検証できないため、無効として扱う
+        let mfa_authentication = MFAAuthentication::new(
+            mfa_authentication && totp_secret.is_some(),
+            totp_secret,
+            recovery_codes,
+            last_used_time_step,
+        );
+        // 失敗回数の記録導入前のアイテムは失敗のない状態として扱う
+        let mfa_failed_count = match item.get("mfa_failed_count").and_then(|v| v.as_n().ok()) {
+            Some(count_str) => count_str
+                .parse::<i32>()
+                .map_err(|e| format!("MFA認証の失敗回数の解析に失敗しました: {}", e))?,
+            None => 0,
+        };
+        let mfa_locked_until = match item.get("mfa_locked_until").and_then(|v| v.as_s().ok()) {
+            Some(locked_until_str) => Some(
+                DateTime::parse_from_rfc3339(locked_until_str)
+                    .map_err(|e| format!("MFA認証のロック期限の解析に失敗しました: {}", e))?
+                    .with_timezone(&Utc),
+            ),
+            None => None,
+        };
+        // レーティング導入前のアイテムは初期値として扱う
+        let rating = match item.get("rating").and_then(|v| v.as_n().ok()) {
+            Some(rating_str) => Rating::new(
+                rating_str
+                    .parse::<i32>()
+                    .map_err(|e| format!("レーティングの解析に失敗しました: {}", e))?,
+            ),
+            None => Rating::initial(),
+        };
+
+        Ok(Player::reconstruct(
+            PlayerId::new(player_id_str.to_string()),
+            PlayerName::new(player_name_str.to_string()),
+            RegisteredDatetime::new(registered_datetime),
+            mfa_authentication,
+            MfaAttempts::new(mfa_failed_count, mfa_locked_until),
+            rating,
+        ))
+    }
 }
 
 #[async_trait]
@@ -59,4 +197,44 @@ impl PlayerRepository for DynamoDbPlayerRepository {
             .map_err(|e| format!("マッチング情報の保存に失敗しました: {}", e))?;
         Ok(())
     }
+
+    async fn update_rating(&self, player: &Player) -> Result<(), String> {
+        // レーティングの属性のみを更新し、存在しないプレイヤーのアイテムは作成しない
+        self.client
+            .update_item()
+            .table_name(self.players_table)
+            .key(
+                "player_id",
+                AttributeValue::S(player.player_id().value().to_string()),
+            )
+            .update_expression("SET rating = :rating")
+            .condition_expression("attribute_exists(player_id)")
+            .expression_attribute_values(
+                ":rating",
+                AttributeValue::N(player.rating().value().to_string()),
+            )
+            .send()
+            .await
+            .map_err(|e| format!("レーティングの更新に失敗しました: {}", e))?;
+        Ok(())
+    }
+
+    async fn find_by_id(&self, player_id: &PlayerId) -> Result<Option<Player>, String> {
+        let result = self
+            .client
+            .get_item()
+            .table_name(self.players_table)
+            .key(
+                "player_id",
+                AttributeValue::S(player_id.value().to_string()),
+            )
+            .send()
+            .await
+            .map_err(|e| format!("プレイヤー情報の取得に失敗しました: {}", e))?;
+
+        match result.item() {
+            Some(item) => Ok(Some(self.item_to_player(item)?)),
+            None => Ok(None),
+        }
+    }
 }
@@ -0,0 +1,86 @@
+// infrastructure/dynamodb/replay_dynamodb_repository.rs
+
+use crate::domain::triggergame_simulator::models::game::game_id::game_id::GameId;
+use crate::domain::triggergame_simulator::models::replay::replay::Replay;
+use crate::domain::triggergame_simulator::repositories::replay_repository::ReplayRepository;
+use async_trait::async_trait;
+use aws_sdk_dynamodb::types::AttributeValue;
+use aws_sdk_dynamodb::Client as DynamoDbClient;
+use std::collections::HashMap;
+
+pub struct DynamoDbReplayRepository {
+    client: DynamoDbClient,
+    replays_table: &'static str,
+}
+
+impl DynamoDbReplayRepository {
+    pub fn new(client: DynamoDbClient) -> Self {
+        // テーブル名
+        const REPLAYS_TABLE_NAME: &str = "Replays";
+        Self {
+            client,
+            replays_table: REPLAYS_TABLE_NAME,
+        }
+    }
+
+    // ヘルパーメソッド：Replayを属性値マップに変換
+    // リプレイ本体はバージョン付きのJSONとして保存し、検索用の属性のみ個別に持つ
+    fn replay_to_item(&self, replay: &Replay) -> Result<HashMap<String, AttributeValue>, String> {
+        let mut item = HashMap::new();
+        item.insert(
+            "game_id".to_string(),
+            AttributeValue::S(replay.game_id().value().to_string()),
+        );
+        item.insert(
+            "format_version".to_string(),
+            AttributeValue::N(replay.format_version().to_string()),
+        );
+        item.insert(
+            "master_data_version".to_string(),
+            AttributeValue::S(replay.master_data_version().to_string()),
+        );
+        item.insert(
+            "created_at".to_string(),
+            AttributeValue::S(replay.created_at().to_rfc3339()),
+        );
+        item.insert("replay".to_string(), AttributeValue::S(replay.to_json()?));
+        Ok(item)
+    }
+}
+
+#[async_trait]
+impl ReplayRepository for DynamoDbReplayRepository {
+    async fn save(&self, replay: &Replay) -> Result<(), String> {
+        let item = self.replay_to_item(replay)?;
+        self.client
+            .put_item()
+            .table_name(self.replays_table)
+            .set_item(Some(item))
+            .send()
+            .await
+            .map_err(|e| format!("リプレイの保存に失敗しました: {}", e))?;
+        Ok(())
+    }
+
+    async fn find_by_game_id(&self, game_id: &GameId) -> Result<Option<Replay>, String> {
+        let result = self
+            .client
+            .get_item()
+            .table_name(self.replays_table)
+            .key("game_id", AttributeValue::S(game_id.value().to_string()))
+            .send()
+            .await
+            .map_err(|e| format!("リプレイの取得に失敗しました: {}", e))?;
+
+        match result.item() {
+            Some(item) => {
+                let json = item
+                    .get("replay")
+                    .and_then(|v| v.as_s().ok())
+                    .ok_or("replay not found or invalid")?;
+                Ok(Some(Replay::from_json(json)?))
+            }
+            None => Ok(None),
+        }
+    }
+}
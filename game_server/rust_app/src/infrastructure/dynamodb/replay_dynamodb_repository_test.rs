@@ -0,0 +1,126 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::player_management::models::player::player_id::player_id::PlayerId;
+    use crate::domain::triggergame_simulator::models::game::current_turn_number::current_turn_number::CurrentTurnNumber;
+    use crate::domain::triggergame_simulator::models::game::game::Game;
+    use crate::domain::triggergame_simulator::models::game::game_id::game_id::GameId;
+    use crate::domain::triggergame_simulator::models::game::game_status::game_status::GameStatus;
+    use crate::domain::triggergame_simulator::models::game::game_version::game_version::GameVersion;
+    use crate::domain::triggergame_simulator::models::game::simulation_seed::simulation_seed::SimulationSeed;
+    use crate::domain::triggergame_simulator::models::replay::replay::Replay;
+    use crate::domain::triggergame_simulator::repositories::replay_repository::ReplayRepository;
+    use crate::infrastructure::dynamodb::test_utils::create_test_unit;
+
+    use super::super::replay_dynamodb_repository::DynamoDbReplayRepository;
+    use aws_sdk_dynamodb::{
+        config::{BehaviorVersion, Region},
+        operation::{
+            get_item::{GetItemInput, GetItemOutput},
+            put_item::{PutItemInput, PutItemOutput},
+        },
+        types::AttributeValue,
+        Client, Config,
+    };
+    use aws_smithy_mocks::{mock, MockResponseInterceptor, Rule, RuleMode};
+    use std::collections::HashMap;
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    /// モッククライアントをセットアップ
+    fn setup_mock_client(rule: Rule) -> Client {
+        let mock_interceptor = MockResponseInterceptor::new()
+            .rule_mode(RuleMode::MatchAny)
+            .with_rule(&rule);
+
+        let config = Config::builder()
+            .behavior_version(BehaviorVersion::latest())
+            .region(Region::new("ap-northeast-1"))
+            .interceptor(mock_interceptor)
+            .build();
+
+        Client::from_conf(config)
+    }
+
+    fn create_test_replay() -> Replay {
+        let game = Game::reconstruct(
+            GameId::new(Uuid::new_v4().to_string()),
+            CurrentTurnNumber::new(6),
+            PlayerId::new(Uuid::new_v4().to_string()),
+            PlayerId::new(Uuid::new_v4().to_string()),
+            GameVersion::initial(),
+            Utc::now(),
+            Vec::new(),
+            GameStatus::initial(),
+            SimulationSeed::generate(),
+        );
+        Replay::create(&game, &[create_test_unit()], vec![]).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_save_replay() {
+        let replay = create_test_replay();
+        let game_id = replay.game_id().value().to_string();
+
+        let put_item_rule = mock!(Client::put_item)
+            .match_requests(move |input: &PutItemInput| {
+                input.table_name() == Some("Replays")
+                    && input.item().and_then(|item| item.get("game_id"))
+                        == Some(&AttributeValue::S(game_id.clone()))
+                    && input.item().and_then(|item| item.get("format_version"))
+                        == Some(&AttributeValue::N(Replay::FORMAT_VERSION.to_string()))
+            })
+            .then_output(|| PutItemOutput::builder().build());
+
+        let client = setup_mock_client(put_item_rule);
+        let repo = DynamoDbReplayRepository::new(client);
+
+        let result = repo.save(&replay).await;
+        assert!(result.is_ok(), "Failed to save replay: {:?}", result.err());
+    }
+
+    #[tokio::test]
+    async fn test_find_by_game_id() {
+        let replay = create_test_replay();
+        let game_id = replay.game_id().clone();
+
+        let mut item = HashMap::new();
+        item.insert(
+            "game_id".to_string(),
+            AttributeValue::S(game_id.value().to_string()),
+        );
+        item.insert(
+            "replay".to_string(),
+            AttributeValue::S(replay.to_json().unwrap()),
+        );
+
+        let get_item_rule = mock!(Client::get_item)
+            .match_requests(|input: &GetItemInput| input.table_name() == Some("Replays"))
+            .then_output(move || {
+                GetItemOutput::builder()
+                    .set_item(Some(item.clone()))
+                    .build()
+            });
+
+        let client = setup_mock_client(get_item_rule);
+        let repo = DynamoDbReplayRepository::new(client);
+
+        let found = repo.find_by_game_id(&game_id).await.unwrap().unwrap();
+        assert_eq!(found.game_id(), &game_id);
+        assert_eq!(found.rng_seed(), replay.rng_seed());
+        assert_eq!(found.initial_units().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_find_by_game_id_not_found() {
+        let get_item_rule = mock!(Client::get_item)
+            .match_requests(|_: &GetItemInput| true)
+            .then_output(|| GetItemOutput::builder().build());
+
+        let client = setup_mock_client(get_item_rule);
+        let repo = DynamoDbReplayRepository::new(client);
+
+        let game_id = GameId::new(Uuid::new_v4().to_string());
+        let found = repo.find_by_game_id(&game_id).await.unwrap();
+        assert!(found.is_none());
+    }
+}
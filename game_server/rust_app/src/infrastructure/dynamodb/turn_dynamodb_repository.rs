@@ -1,7 +1,7 @@
 // infrastructure/dynamodb/player_dynamodb_repository.rs
 
 use async_trait::async_trait;
-use aws_sdk_dynamodb::types::AttributeValue;
+use aws_sdk_dynamodb::types::{AttributeValue, Put, TransactWriteItem, Update};
 use aws_sdk_dynamodb::Client as DynamoDbClient;
 use chrono::{DateTime, Utc};
 use std::collections::HashMap;
@@ -11,7 +11,12 @@ use crate::domain::triggergame_simulator::models::action::action_id::action_id::
 use crate::domain::triggergame_simulator::models::action::action_type::action_type::ActionType;
 use crate::domain::triggergame_simulator::models::action::trigger_azimuth::trigger_azimuth::TriggerAzimuth;
 use crate::domain::triggergame_simulator::models::action::Action;
+use crate::domain::triggergame_simulator::models::combat::combat_hit::combat_hit::CombatHit;
+use crate::domain::triggergame_simulator::models::combat::combat_id::combat_id::CombatId;
+use crate::domain::triggergame_simulator::models::combat::is_avoided::is_avoided::IsAvoided;
+use crate::domain::triggergame_simulator::models::combat::Combat;
 use crate::domain::triggergame_simulator::models::game::game_id::game_id::GameId;
+use crate::domain::triggergame_simulator::models::step::status_effect_change::status_effect_change::StatusEffectChange;
 use crate::domain::triggergame_simulator::models::step::step::Step;
 use crate::domain::triggergame_simulator::models::step::step_id::step_id::StepId;
 use crate::domain::triggergame_simulator::models::turn::turn_id::turn_id::TurnId;
@@ -19,12 +24,18 @@ use crate::domain::triggergame_simulator::models::turn::turn_number::turn_number
 use crate::domain::triggergame_simulator::models::turn::turn_start_datetime::turn_start_datetime::TurnStartDatetime;
 use crate::domain::triggergame_simulator::models::turn::turn_status::turn_status::TurnStatus;
 use crate::domain::triggergame_simulator::models::turn::Turn;
-use crate::domain::triggergame_simulator::repositories::turn_repository::TurnRepository;
+use crate::domain::triggergame_simulator::repositories::turn_repository::{
+    TurnRepository, TURN_ALREADY_EXISTS_ERROR,
+};
 
 use crate::domain::unit_management::models::unit::position::position::Position;
 use crate::domain::unit_management::models::unit::trigger_id::trigger_id::TriggerId;
 use crate::domain::unit_management::models::unit::unit_id::unit_id::UnitId;
 use crate::domain::unit_management::models::unit::unit_type_id::unit_type_id::UnitTypeId;
+use crate::infrastructure::dynamodb::unit_dynamodb_repository::DynamoDbUnitRepository;
+
+/// ターンの状態とステップの更新式
+const TURN_UPDATE_EXPRESSION: &str = "SET turn_status = :turn_status, steps = :steps";
 
 pub struct DynamoDbTurnRepository {
     client: DynamoDbClient,
@@ -167,6 +178,41 @@ impl DynamoDbTurnRepository {
                                         .collect(),
                                 ),
                             );
+                            step_map.insert(
+                                "combats".to_string(),
+                                AttributeValue::L(
+                                    step.combats()
+                                        .iter()
+                                        .map(|combat| {
+                                            AttributeValue::M(self.combat_to_item(combat))
+                                        })
+                                        .collect(),
+                                ),
+                            );
+                            step_map.insert(
+                                "status_effect_changes".to_string(),
+                                AttributeValue::L(
+                                    step.status_effect_changes()
+                                        .iter()
+                                        .map(|change| {
+                                            let mut change_map = HashMap::new();
+                                            change_map.insert(
+                                                "unit_id".to_string(),
+                                                AttributeValue::S(
+                                                    change.unit_id().value().to_string(),
+                                                ),
+                                            );
+                                            change_map.insert(
+                                                "status_effects".to_string(),
+                                                DynamoDbUnitRepository::status_effects_to_attribute(
+                                                    change.status_effects(),
+                                                ),
+                                            );
+                                            AttributeValue::M(change_map)
+                                        })
+                                        .collect(),
+                                ),
+                            );
                             step_map
                         })
                     })
@@ -175,6 +221,282 @@ impl DynamoDbTurnRepository {
         );
         item
     }
+
+    // ヘルパーメソッド：Combatを属性値マップに変換
+    fn combat_to_item(&self, combat: &Combat) -> HashMap<String, AttributeValue> {
+        let position_to_item = |position: &Position| {
+            let mut position_map = HashMap::new();
+            position_map.insert(
+                "col".to_string(),
+                AttributeValue::N(position.col().to_string()),
+            );
+            position_map.insert(
+                "row".to_string(),
+                AttributeValue::N(position.row().to_string()),
+            );
+            AttributeValue::M(position_map)
+        };
+
+        let mut item = HashMap::new();
+        item.insert(
+            "combat_id".to_string(),
+            AttributeValue::S(combat.combat_id().value().to_string()),
+        );
+        item.insert(
+            "attacking_unit_id".to_string(),
+            AttributeValue::S(combat.attacking_unit_id().value().to_string()),
+        );
+        item.insert(
+            "attacker_position".to_string(),
+            position_to_item(combat.attacker_position()),
+        );
+        item.insert(
+            "attacker_main_trigger_id".to_string(),
+            AttributeValue::S(combat.attacker_main_trigger_id().value().to_string()),
+        );
+        item.insert(
+            "attacker_sub_trigger_id".to_string(),
+            AttributeValue::S(combat.attacker_sub_trigger_id().value().to_string()),
+        );
+        item.insert(
+            "attacker_main_trigger_azimuth".to_string(),
+            AttributeValue::N(combat.attacker_main_trigger_azimuth().value().to_string()),
+        );
+        item.insert(
+            "attacker_sub_trigger_azimuth".to_string(),
+            AttributeValue::N(combat.attacker_sub_trigger_azimuth().value().to_string()),
+        );
+        item.insert(
+            "attacker_base_attack".to_string(),
+            AttributeValue::N(combat.attacker_base_attack().to_string()),
+        );
+        item.insert(
+            "defending_unit_id".to_string(),
+            AttributeValue::S(combat.defending_unit_id().value().to_string()),
+        );
+        item.insert(
+            "defender_position".to_string(),
+            position_to_item(combat.defender_position()),
+        );
+        item.insert(
+            "defender_main_trigger_id".to_string(),
+            AttributeValue::S(combat.defender_main_trigger_id().value().to_string()),
+        );
+        item.insert(
+            "defender_sub_trigger_id".to_string(),
+            AttributeValue::S(combat.defender_sub_trigger_id().value().to_string()),
+        );
+        item.insert(
+            "defender_main_trigger_azimuth".to_string(),
+            AttributeValue::N(combat.defender_main_trigger_azimuth().value().to_string()),
+        );
+        item.insert(
+            "defender_sub_trigger_azimuth".to_string(),
+            AttributeValue::N(combat.defender_sub_trigger_azimuth().value().to_string()),
+        );
+        item.insert(
+            "defender_base_defense".to_string(),
+            AttributeValue::N(combat.defender_base_defense().to_string()),
+        );
+        item.insert(
+            "defender_base_avoid".to_string(),
+            AttributeValue::N(combat.defender_base_avoid().to_string()),
+        );
+        item.insert(
+            "main_trigger_hp".to_string(),
+            AttributeValue::N(combat.main_trigger_hp().to_string()),
+        );
+        item.insert(
+            "sub_trigger_hp".to_string(),
+            AttributeValue::N(combat.sub_trigger_hp().to_string()),
+        );
+        item.insert(
+            "main_trigger_damage".to_string(),
+            AttributeValue::N(combat.main_trigger_damage().to_string()),
+        );
+        item.insert(
+            "sub_trigger_damage".to_string(),
+            AttributeValue::N(combat.sub_trigger_damage().to_string()),
+        );
+        item.insert(
+            "is_avoided".to_string(),
+            AttributeValue::Bool(combat.is_avoided().value()),
+        );
+        item.insert(
+            "is_defeated".to_string(),
+            AttributeValue::Bool(combat.is_defeated()),
+        );
+        item.insert(
+            "hits".to_string(),
+            AttributeValue::L(
+                combat
+                    .hits()
+                    .iter()
+                    .map(|hit| {
+                        AttributeValue::M(HashMap::from([
+                            (
+                                "trigger_id".to_string(),
+                                AttributeValue::S(hit.trigger_id().value().to_string()),
+                            ),
+                            (
+                                "is_avoided".to_string(),
+                                AttributeValue::Bool(hit.is_avoided().value()),
+                            ),
+                            (
+                                "main_trigger_damage".to_string(),
+                                AttributeValue::N(hit.main_trigger_damage().to_string()),
+                            ),
+                            (
+                                "sub_trigger_damage".to_string(),
+                                AttributeValue::N(hit.sub_trigger_damage().to_string()),
+                            ),
+                        ]))
+                    })
+                    .collect(),
+            ),
+        );
+        item
+    }
+
+    /// ヘルパーメソッド：DynamoDBから取得したデータをCombatエンティティに変換
+    fn from_dynamo_db_to_combat(
+        &self,
+        item: &HashMap<String, AttributeValue>,
+    ) -> Result<Combat, String> {
+        let get_s = |key: &str| -> Result<String, String> {
+            item.get(key)
+                .and_then(|v| v.as_s().ok())
+                .map(|s| s.to_string())
+                .ok_or(format!("{} not found or invalid", key))
+        };
+        let get_n = |key: &str| -> Result<i32, String> {
+            item.get(key)
+                .and_then(|v| v.as_n().ok())
+                .and_then(|n| n.parse::<i32>().ok())
+                .ok_or(format!("{} not found or invalid", key))
+        };
+        let get_bool = |key: &str| -> Result<bool, String> {
+            item.get(key)
+                .and_then(|v| v.as_bool().ok())
+                .copied()
+                .ok_or(format!("{} not found or invalid", key))
+        };
+        let get_position = |key: &str| -> Result<Position, String> {
+            let position_map = item
+                .get(key)
+                .and_then(|v| v.as_m().ok())
+                .ok_or(format!("{} not found or invalid", key))?;
+            let col = position_map
+                .get("col")
+                .and_then(|v| v.as_n().ok())
+                .and_then(|n| n.parse::<i32>().ok())
+                .ok_or(format!("{}.col not found or invalid", key))?;
+            let row = position_map
+                .get("row")
+                .and_then(|v| v.as_n().ok())
+                .and_then(|n| n.parse::<i32>().ok())
+                .ok_or(format!("{}.row not found or invalid", key))?;
+            Ok(Position::new(col, row))
+        };
+        // 攻撃ごとの結果を記録する前に保存された戦闘は空とする
+        let hits = match item.get("hits").and_then(|v| v.as_l().ok()) {
+            Some(hits) => hits
+                .iter()
+                .map(|hit| {
+                    let hit_map = hit.as_m().map_err(|_| "hits not found or invalid")?;
+                    let trigger_id = hit_map
+                        .get("trigger_id")
+                        .and_then(|v| v.as_s().ok())
+                        .ok_or("hits.trigger_id not found or invalid")?;
+                    let is_avoided = hit_map
+                        .get("is_avoided")
+                        .and_then(|v| v.as_bool().ok())
+                        .ok_or("hits.is_avoided not found or invalid")?;
+                    let get_damage = |key: &str| -> Result<i32, String> {
+                        hit_map
+                            .get(key)
+                            .and_then(|v| v.as_n().ok())
+                            .and_then(|n| n.parse::<i32>().ok())
+                            .ok_or(format!("hits.{} not found or invalid", key))
+                    };
+                    Ok(CombatHit::new(
+                        TriggerId::new(trigger_id.to_string()),
+                        IsAvoided::new(*is_avoided),
+                        get_damage("main_trigger_damage")?,
+                        get_damage("sub_trigger_damage")?,
+                    ))
+                })
+                .collect::<Result<Vec<CombatHit>, String>>()?,
+            None => Vec::new(),
+        };
+
+        Ok(Combat::reconstruct(
+            CombatId::new(get_s("combat_id")?),
+            UnitId::new(get_s("attacking_unit_id")?),
+            get_position("attacker_position")?,
+            TriggerId::new(get_s("attacker_main_trigger_id")?),
+            TriggerId::new(get_s("attacker_sub_trigger_id")?),
+            TriggerAzimuth::new(get_n("attacker_main_trigger_azimuth")?),
+            TriggerAzimuth::new(get_n("attacker_sub_trigger_azimuth")?),
+            get_n("attacker_base_attack")?,
+            UnitId::new(get_s("defending_unit_id")?),
+            get_position("defender_position")?,
+            TriggerId::new(get_s("defender_main_trigger_id")?),
+            TriggerId::new(get_s("defender_sub_trigger_id")?),
+            TriggerAzimuth::new(get_n("defender_main_trigger_azimuth")?),
+            TriggerAzimuth::new(get_n("defender_sub_trigger_azimuth")?),
+            get_n("defender_base_defense")?,
+            get_n("defender_base_avoid")?,
+            get_n("main_trigger_hp")?,
+            get_n("sub_trigger_hp")?,
+            get_n("main_trigger_damage")?,
+            get_n("sub_trigger_damage")?,
+            IsAvoided::new(get_bool("is_avoided")?),
+            get_bool("is_defeated")?,
+            hits,
+        ))
+    }
+
+    // ヘルパーメソッド：ターン更新時の属性値マップを作成
+    fn turn_update_values(&self, turn: &Turn) -> HashMap<String, AttributeValue> {
+        // turn_to_itemを使って属性値を取得
+        let mut item = self.turn_to_item(turn);
+        let mut values = HashMap::new();
+        if let Some(turn_status) = item.remove("turn_status") {
+            values.insert(":turn_status".to_string(), turn_status);
+        }
+        if let Some(steps) = item.remove("steps") {
+            values.insert(":steps".to_string(), steps);
+        }
+        values
+    }
+
+    /// ターンの更新をトランザクション書き込み用のアイテムとして作成
+    pub fn update_transact_item(&self, turn: &Turn) -> Result<TransactWriteItem, String> {
+        let update = Update::builder()
+            .table_name(self.turns_table)
+            .key(
+                "turn_id",
+                AttributeValue::S(turn.turn_id().value().to_string()),
+            )
+            .update_expression(TURN_UPDATE_EXPRESSION)
+            .set_expression_attribute_values(Some(self.turn_update_values(turn)))
+            .build()
+            .map_err(|e| format!("ターン情報の更新内容の作成に失敗しました: {}", e))?;
+        Ok(TransactWriteItem::builder().update(update).build())
+    }
+
+    /// ターンの登録をトランザクション書き込み用のアイテムとして作成
+    /// saveと同じく、同じプレイヤーの同じターンが登録済みの場合は条件式を満たさない
+    pub fn save_transact_item(&self, turn: &Turn) -> Result<TransactWriteItem, String> {
+        let put = Put::builder()
+            .table_name(self.turns_table)
+            .set_item(Some(self.turn_to_item(turn)))
+            .condition_expression("attribute_not_exists(turn_id)")
+            .build()
+            .map_err(|e| format!("ターン情報の登録内容の作成に失敗しました: {}", e))?;
+        Ok(TransactWriteItem::builder().put(put).build())
+    }
 }
 
 #[async_trait]
@@ -186,22 +508,22 @@ impl TurnRepository for DynamoDbTurnRepository {
             .put_item()
             .table_name(self.turns_table)
             .set_item(Some(turn_item))
+            // 同じプレイヤーの同じターンが二重に登録されないようにする
+            .condition_expression("attribute_not_exists(turn_id)")
             .send()
             .await
-            .map_err(|e| format!("ゲーム情報の保存に失敗しました: {}", e))?;
+            .map_err(|e| {
+                if let Some(service_error) = e.as_service_error() {
+                    if service_error.is_conditional_check_failed_exception() {
+                        return TURN_ALREADY_EXISTS_ERROR.to_string();
+                    }
+                }
+                format!("ゲーム情報の保存に失敗しました: {}", e)
+            })?;
         Ok(())
     }
 
     async fn update(&self, turn: &Turn) -> Result<(), String> {
-        // turn_to_itemを使って属性値を取得
-        let item = self.turn_to_item(turn);
-
-        let update_parts = vec!["turn_status = :turn_status", "steps = :steps"];
-        let update_expression = format!("SET {}", update_parts.join(", "));
-
-        let steps_value = item.get("steps").unwrap().clone();
-        let turn_status_value = item.get("turn_status").unwrap().clone();
-
         let request = self
             .client
             .update_item()
@@ -210,9 +532,8 @@ impl TurnRepository for DynamoDbTurnRepository {
                 "turn_id",
                 AttributeValue::S(turn.turn_id().value().to_string()),
             )
-            .update_expression(update_expression)
-            .expression_attribute_values(":turn_status", turn_status_value)
-            .expression_attribute_values(":steps", steps_value);
+            .update_expression(TURN_UPDATE_EXPRESSION)
+            .set_expression_attribute_values(Some(self.turn_update_values(turn)));
 
         let _ = request.send().await.map_err(|e| {
             println!("Failed to update turn: {}", e);
@@ -383,7 +704,46 @@ impl TurnRepository for DynamoDbTurnRepository {
                     }
                 }
 
-                let step = Step::new(StepId::new(step_id_str.to_string()), actions, vec![]);
+                // 演算前のターン情報は戦闘結果を持たない
+                let mut combats: Vec<Combat> = Vec::new();
+                if let Some(combats_attr) = step_map.get("combats").and_then(|v| v.as_l().ok()) {
+                    for combat_attr in combats_attr {
+                        let combat_map = combat_attr
+                            .as_m()
+                            .map_err(|_| "combat is not a map".to_string())?;
+                        combats.push(self.from_dynamo_db_to_combat(combat_map)?);
+                    }
+                }
+
+                // 状態効果を記録する前のターン情報には属性がない
+                let mut status_effect_changes: Vec<StatusEffectChange> = Vec::new();
+                if let Some(changes_attr) = step_map
+                    .get("status_effect_changes")
+                    .and_then(|v| v.as_l().ok())
+                {
+                    for change_attr in changes_attr {
+                        let change_map = change_attr
+                            .as_m()
+                            .map_err(|_| "status_effect_change is not a map".to_string())?;
+                        let unit_id_str = change_map
+                            .get("unit_id")
+                            .and_then(|v| v.as_s().ok())
+                            .ok_or("status_effect_change unit_id not found")?;
+                        status_effect_changes.push(StatusEffectChange::new(
+                            UnitId::new(unit_id_str.to_string()),
+                            DynamoDbUnitRepository::from_dynamo_db_to_status_effects(
+                                change_map.get("status_effects"),
+                            )?,
+                        ));
+                    }
+                }
+
+                let step = Step::new(
+                    StepId::new(step_id_str.to_string()),
+                    actions,
+                    combats,
+                    status_effect_changes,
+                );
                 steps.push(step);
             }
         }
@@ -4,6 +4,12 @@ mod tests {
 	use crate::application::game;
 use crate::domain::player_management::models::player::player_id::player_id::PlayerId;
 	use crate::domain::triggergame_simulator::models::game::game_id::game_id::GameId;
+	use crate::domain::triggergame_simulator::models::action::trigger_azimuth::trigger_azimuth::TriggerAzimuth;
+	use crate::domain::triggergame_simulator::models::combat::combat_hit::combat_hit::CombatHit;
+	use crate::domain::triggergame_simulator::models::combat::combat_id::combat_id::CombatId;
+	use crate::domain::triggergame_simulator::models::combat::is_avoided::is_avoided::IsAvoided;
+	use crate::domain::triggergame_simulator::models::combat::Combat;
+	use crate::domain::triggergame_simulator::models::step::status_effect_change::status_effect_change::StatusEffectChange;
 	use crate::domain::triggergame_simulator::models::step::step::Step;
 use crate::domain::triggergame_simulator::models::step::step_id::step_id::StepId;
 	use crate::domain::triggergame_simulator::models::turn::turn_id::turn_id::TurnId;
@@ -13,19 +19,29 @@ use crate::domain::triggergame_simulator::models::step::step_id::step_id::StepId
 		TurnStatus, TurnStatusValue,
 	};
 	use crate::domain::triggergame_simulator::models::turn::Turn;
-	use crate::domain::triggergame_simulator::repositories::turn_repository::TurnRepository;
+	use crate::domain::triggergame_simulator::repositories::turn_repository::{
+		TurnRepository, TURN_ALREADY_EXISTS_ERROR,
+	};
+use crate::domain::unit_management::models::unit::position::position::Position;
+use crate::domain::unit_management::models::unit::status_effect::status_effect::StatusEffect;
+use crate::domain::unit_management::models::unit::trigger_id::trigger_id::TriggerId;
+use crate::domain::unit_management::models::unit::unit_id::unit_id::UnitId;
 use crate::infrastructure::dynamodb::test_utils::create_test_unit;
+	use aws_credential_types::Credentials;
 	use aws_sdk_dynamodb::{
 		config::{BehaviorVersion, Region},
 		operation::{
-			put_item::{PutItemInput, PutItemOutput},
+			get_item::{GetItemInput, GetItemOutput},
+			put_item::{PutItemError, PutItemInput, PutItemOutput},
 			query::{QueryInput, QueryOutput},
 			update_item::{UpdateItemInput, UpdateItemOutput},
 		},
-		types::AttributeValue,
+		types::{error::ConditionalCheckFailedException, AttributeValue},
 		Client, Config,
 	};
-	use aws_smithy_mocks::{mock, MockResponseInterceptor, Rule, RuleMode};
+	use aws_smithy_mocks::{
+        create_mock_http_client, mock, MockResponseInterceptor, Rule, RuleMode,
+    };
 	use chrono::Utc;
 	use std::collections::HashMap;
 	use uuid::Uuid;
@@ -40,6 +56,9 @@ use crate::infrastructure::dynamodb::test_utils::create_test_unit;
             .behavior_version(BehaviorVersion::latest())
             .region(Region::new("ap-northeast-1"))
             .interceptor(mock_interceptor)
+            // エラーレスポンスのモックにはHTTPクライアントと認証情報が必要
+            .http_client(create_mock_http_client())
+            .credentials_provider(Credentials::new("dummy", "dummy", None, None, "test"))
             .build();
 
         Client::from_conf(config)
@@ -52,7 +71,12 @@ use crate::infrastructure::dynamodb::test_utils::create_test_unit;
         let turn_number = TurnNumber::new(1);
         let turn_start_datetime = TurnStartDatetime::new(Utc::now());
         let turn_status = TurnStatus::new(TurnStatusValue::StepSetting);
-        let step = Step::new(StepId::new(Uuid::new_v4().to_string()), vec![], vec![]);
+        let step = Step::new(
+            StepId::new(Uuid::new_v4().to_string()),
+            vec![],
+            vec![],
+            vec![],
+        );
 
         Turn::new(
             turn_id,
@@ -80,6 +104,28 @@ use crate::infrastructure::dynamodb::test_utils::create_test_unit;
         assert!(result.is_ok(), "Failed to save turn: {:?}", result.err());
     }
 
+    #[tokio::test]
+    async fn test_save_turn_already_exists() {
+        let turn = create_test_turn();
+
+        // 既に同じターンIDが登録されている場合のエラーレスポンスをモック
+        let put_item_rule = mock!(Client::put_item)
+            .match_requests(|input: &PutItemInput| {
+                input.condition_expression() == Some("attribute_not_exists(turn_id)")
+            })
+            .then_error(|| {
+                PutItemError::ConditionalCheckFailedException(
+                    ConditionalCheckFailedException::builder().build(),
+                )
+            });
+
+        let client = setup_mock_client(put_item_rule);
+        let repo = DynamoDbTurnRepository::new(client);
+
+        let result = repo.save(&turn).await;
+        assert_eq!(result.unwrap_err(), TURN_ALREADY_EXISTS_ERROR);
+    }
+
     #[tokio::test]
     async fn test_get_turn_data_found() {
         let game_id = GameId::new(Uuid::new_v4().to_string());
@@ -149,7 +195,7 @@ use crate::infrastructure::dynamodb::test_utils::create_test_unit;
         );
         action_map2.insert(
             "action_type".to_string(),
-            AttributeValue::S("WAIT".to_string()),
+            AttributeValue::S("Wait".to_string()),
         );
         action_map2.insert(
             "unit_id".to_string(),
@@ -234,15 +280,15 @@ use crate::infrastructure::dynamodb::test_utils::create_test_unit;
             AttributeValue::L(vec![AttributeValue::M(step_map)]),
         );
 
-        let query_rule = mock!(Client::query)
-            .match_requests(|_: &QueryInput| true)
+        let get_item_rule = mock!(Client::get_item)
+            .match_requests(|_: &GetItemInput| true)
             .then_output(move || {
-                QueryOutput::builder()
-                    .set_items(Some(vec![item.clone()]))
+                GetItemOutput::builder()
+                    .set_item(Some(item.clone()))
                     .build()
             });
 
-        let client = setup_mock_client(query_rule);
+        let client = setup_mock_client(get_item_rule);
         let repo = DynamoDbTurnRepository::new(client);
 
         let result = repo.get_turn_data(&game_id, &player_id, &turn_number).await;
@@ -263,14 +309,151 @@ use crate::infrastructure::dynamodb::test_utils::create_test_unit;
         let player_id = PlayerId::new(Uuid::new_v4().to_string());
         let turn_number = TurnNumber::new(1);
 
-        let query_rule = mock!(Client::query)
-            .match_requests(|_: &QueryInput| true)
-            .then_output(|| QueryOutput::builder().build());
+        let get_item_rule = mock!(Client::get_item)
+            .match_requests(|_: &GetItemInput| true)
+            .then_output(|| GetItemOutput::builder().build());
 
-        let client = setup_mock_client(query_rule);
+        let client = setup_mock_client(get_item_rule);
         let repo = DynamoDbTurnRepository::new(client);
 
         let result = repo.get_turn_data(&game_id, &player_id, &turn_number).await;
-        assert_ne!(result.iter().len(), 0);
+        assert!(result.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_resolved_turn_round_trip() {
+        let game_id = GameId::new(Uuid::new_v4().to_string());
+        let player_id = PlayerId::new(Uuid::new_v4().to_string());
+        let turn_number = TurnNumber::new(2);
+        let turn_id = TurnId::new(Uuid::new_v4().to_string());
+        let start_datetime = Utc::now();
+
+        // 戦闘結果を持つ演算済みのターンを作成
+        let combat = Combat::reconstruct(
+            CombatId::new(Uuid::new_v4().to_string()),
+            UnitId::new(Uuid::new_v4().to_string()),
+            Position::new(3, 4),
+            TriggerId::new("KOGETSU".to_string()),
+            TriggerId::new("SHIELD".to_string()),
+            TriggerAzimuth::new(60),
+            TriggerAzimuth::new(120),
+            8,
+            UnitId::new(Uuid::new_v4().to_string()),
+            Position::new(3, 6),
+            TriggerId::new("ASTEROID".to_string()),
+            TriggerId::new("SHIELD".to_string()),
+            TriggerAzimuth::new(240),
+            TriggerAzimuth::new(300),
+            5,
+            3,
+            70,
+            85,
+            30,
+            15,
+            IsAvoided::new(false),
+            true,
+            vec![CombatHit::new(
+                TriggerId::new("KOGETSU".to_string()),
+                IsAvoided::new(false),
+                30,
+                15,
+            )],
+        );
+        let defending_unit_id = combat.defending_unit_id().clone();
+        let step = Step::new(
+            StepId::new(Uuid::new_v4().to_string()),
+            vec![],
+            vec![combat],
+            vec![StatusEffectChange::new(
+                defending_unit_id.clone(),
+                vec![StatusEffect::new("LEAD_WEIGHT".to_string(), 2, 1)],
+            )],
+        );
+        let turn = Turn::new(
+            turn_id.clone(),
+            game_id.clone(),
+            player_id.clone(),
+            turn_number.clone(),
+            TurnStartDatetime::new(start_datetime),
+            TurnStatus::new(TurnStatusValue::Completed),
+            vec![step],
+        );
+
+        // 更新時に書き込まれる属性値から取得結果のアイテムを組み立てる
+        let client = setup_mock_client(
+            mock!(Client::get_item).then_output(|| GetItemOutput::builder().build()),
+        );
+        let update = DynamoDbTurnRepository::new(client)
+            .update_transact_item(&turn)
+            .unwrap()
+            .update()
+            .unwrap()
+            .clone();
+        let values = update.expression_attribute_values().unwrap();
+        let mut item = HashMap::new();
+        item.insert(
+            "turn_id".to_string(),
+            AttributeValue::S(turn_id.value().to_string()),
+        );
+        item.insert(
+            "game_id".to_string(),
+            AttributeValue::S(game_id.value().to_string()),
+        );
+        item.insert(
+            "player_id".to_string(),
+            AttributeValue::S(player_id.value().to_string()),
+        );
+        item.insert(
+            "turn_number".to_string(),
+            AttributeValue::N(turn_number.value().to_string()),
+        );
+        item.insert(
+            "turn_start_datetime".to_string(),
+            AttributeValue::S(start_datetime.to_rfc3339()),
+        );
+        item.insert(
+            "turn_status".to_string(),
+            values.get(":turn_status").unwrap().clone(),
+        );
+        item.insert("steps".to_string(), values.get(":steps").unwrap().clone());
+
+        let get_item_rule = mock!(Client::get_item)
+            .match_requests(|_: &GetItemInput| true)
+            .then_output(move || {
+                GetItemOutput::builder()
+                    .set_item(Some(item.clone()))
+                    .build()
+            });
+        let client = setup_mock_client(get_item_rule);
+        let repo = DynamoDbTurnRepository::new(client);
+
+        let result = repo
+            .get_turn_data(&game_id, &player_id, &turn_number)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(result.is_completed());
+        let combats = result.steps()[0].combats();
+        assert_eq!(combats.len(), 1);
+        assert_eq!(combats[0].main_trigger_hp(), 70);
+        assert_eq!(combats[0].sub_trigger_hp(), 85);
+        assert_eq!(combats[0].main_trigger_damage(), 30);
+        assert_eq!(combats[0].sub_trigger_damage(), 15);
+        assert_eq!(combats[0].defender_position(), &Position::new(3, 6));
+        assert!(combats[0].is_avoided().is_hit());
+        assert!(combats[0].is_defeated());
+        let hits = combats[0].hits();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].trigger_id().value(), "KOGETSU");
+        assert!(hits[0].is_avoided().is_hit());
+        assert_eq!(hits[0].main_trigger_damage(), 30);
+        assert_eq!(hits[0].sub_trigger_damage(), 15);
+        let changes = result.steps()[0].status_effect_changes();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].unit_id(), &defending_unit_id);
+        assert_eq!(
+            changes[0].status_effects(),
+            &[StatusEffect::new("LEAD_WEIGHT".to_string(), 2, 1)]
+        );
     }
 }
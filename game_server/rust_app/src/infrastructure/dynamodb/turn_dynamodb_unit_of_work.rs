@@ -0,0 +1,148 @@
+// infrastructure/dynamodb/turn_dynamodb_unit_of_work.rs
+
+use crate::domain::triggergame_simulator::models::game::game::Game;
+use crate::domain::triggergame_simulator::models::game_event::game_event::GameEvent;
+use crate::domain::triggergame_simulator::models::turn::Turn;
+use crate::domain::triggergame_simulator::repositories::game_event_repository::GAME_EVENT_SEQUENCE_CONFLICT_ERROR;
+use crate::domain::triggergame_simulator::repositories::game_repository::GAME_VERSION_CONFLICT_ERROR;
+use crate::domain::triggergame_simulator::repositories::turn_repository::TURN_ALREADY_EXISTS_ERROR;
+use crate::domain::triggergame_simulator::repositories::turn_unit_of_work::TurnUnitOfWork;
+use crate::domain::unit_management::models::unit::Unit;
+use crate::infrastructure::dynamodb::game_dynamodb_repository::DynamoDbGameRepository;
+use crate::infrastructure::dynamodb::game_event_dynamodb_repository::DynamoDbGameEventRepository;
+use crate::infrastructure::dynamodb::turn_dynamodb_repository::DynamoDbTurnRepository;
+use crate::infrastructure::dynamodb::unit_dynamodb_repository::DynamoDbUnitRepository;
+use async_trait::async_trait;
+use aws_sdk_dynamodb::operation::transact_write_items::TransactWriteItemsError;
+use aws_sdk_dynamodb::types::TransactWriteItem;
+use aws_sdk_dynamodb::Client as DynamoDbClient;
+
+/// TransactWriteItemsの1リクエストで扱えるアイテム数の上限
+const MAX_TRANSACT_ITEMS: usize = 100;
+
+pub struct DynamoDbTurnUnitOfWork {
+    client: DynamoDbClient,
+    game_repository: DynamoDbGameRepository,
+    game_event_repository: DynamoDbGameEventRepository,
+    turn_repository: DynamoDbTurnRepository,
+    unit_repository: DynamoDbUnitRepository,
+}
+
+impl DynamoDbTurnUnitOfWork {
+    pub fn new(client: DynamoDbClient) -> Self {
+        Self {
+            client: client.clone(),
+            game_repository: DynamoDbGameRepository::new(client.clone()),
+            game_event_repository: DynamoDbGameEventRepository::new(client.clone()),
+            turn_repository: DynamoDbTurnRepository::new(client.clone()),
+            unit_repository: DynamoDbUnitRepository::new(client),
+        }
+    }
+
+    /// 更新内容を1つのトランザクションで書き込む
+    /// 条件式を満たさずにキャンセルされた場合は、条件を満たさなかったアイテムの位置を返す
+    async fn transact_write(
+        &self,
+        transact_items: Vec<TransactWriteItem>,
+    ) -> Result<Result<(), Vec<usize>>, String> {
+        if transact_items.len() > MAX_TRANSACT_ITEMS {
+            return Err(format!(
+                "トランザクションで更新できるアイテム数の上限を超えています: {}",
+                transact_items.len()
+            ));
+        }
+
+        let result = self
+            .client
+            .transact_write_items()
+            .set_transact_items(Some(transact_items))
+            .send()
+            .await;
+        let e = match result {
+            Ok(_) => return Ok(Ok(())),
+            Err(e) => e,
+        };
+        if let Some(service_error) = e.as_service_error() {
+            // キャンセル理由はトランザクションのアイテムと同じ順に並ぶ
+            if let TransactWriteItemsError::TransactionCanceledException(canceled) = service_error {
+                let failed_indexes: Vec<usize> = canceled
+                    .cancellation_reasons()
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, reason)| reason.code() == Some("ConditionalCheckFailed"))
+                    .map(|(index, _)| index)
+                    .collect();
+                if !failed_indexes.is_empty() {
+                    return Ok(Err(failed_indexes));
+                }
+            }
+            eprintln!("Service Error: {:?}", service_error);
+        }
+        Err(e.to_string())
+    }
+
+    /// イベントの追記をトランザクション書き込み用のアイテムとして作成
+    fn event_transact_items(&self, events: &[GameEvent]) -> Result<Vec<TransactWriteItem>, String> {
+        events
+            .iter()
+            .map(|event| self.game_event_repository.append_transact_item(event))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl TurnUnitOfWork for DynamoDbTurnUnitOfWork {
+    async fn submit_turn(&self, turn: &Turn, events: &[GameEvent]) -> Result<(), String> {
+        let mut transact_items = vec![self.turn_repository.save_transact_item(turn)?];
+        transact_items.extend(self.event_transact_items(events)?);
+
+        match self.transact_write(transact_items).await {
+            Ok(Ok(())) => Ok(()),
+            // ターン情報の条件式を満たさない場合は、同じターンが登録済み
+            Ok(Err(failed_indexes)) if failed_indexes.contains(&0) => {
+                Err(TURN_ALREADY_EXISTS_ERROR.to_string())
+            }
+            Ok(Err(_)) => Err(GAME_EVENT_SEQUENCE_CONFLICT_ERROR.to_string()),
+            Err(e) => {
+                println!("Failed to submit turn: {}", e);
+                Err(format!("ターン情報の登録に失敗しました: {}", e))
+            }
+        }
+    }
+
+    async fn commit_turn(
+        &self,
+        turns: &[Turn],
+        units: &[Unit],
+        game: &Game,
+        events: &[GameEvent],
+    ) -> Result<(), String> {
+        // 各リポジトリの更新内容を1つのトランザクションにまとめる
+        let mut transact_items = Vec::new();
+        for turn in turns {
+            transact_items.push(self.turn_repository.update_transact_item(turn)?);
+        }
+        for unit in units {
+            transact_items.push(self.unit_repository.update_transact_item(unit)?);
+        }
+        let game_index = transact_items.len();
+        transact_items.push(
+            self.game_repository
+                .update_current_turn_transact_item(game)?,
+        );
+        transact_items.extend(self.event_transact_items(events)?);
+
+        match self.transact_write(transact_items).await {
+            Ok(Ok(())) => Ok(()),
+            // ゲーム情報のバージョン条件を満たさない場合は、他のリクエストがターンを進めている
+            Ok(Err(failed_indexes)) if failed_indexes.contains(&game_index) => {
+                Err(GAME_VERSION_CONFLICT_ERROR.to_string())
+            }
+            Ok(Err(_)) => Err(GAME_EVENT_SEQUENCE_CONFLICT_ERROR.to_string()),
+            Err(e) => {
+                println!("Failed to commit turn: {}", e);
+                Err(format!("ターンの演算結果の反映に失敗しました: {}", e))
+            }
+        }
+    }
+}
@@ -0,0 +1,274 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::player_management::models::player::player_id::player_id::PlayerId;
+    use crate::domain::triggergame_simulator::models::game::current_turn_number::current_turn_number::CurrentTurnNumber;
+    use crate::domain::triggergame_simulator::models::game::game::Game;
+    use crate::domain::triggergame_simulator::models::game::game_id::game_id::GameId;
+    use crate::domain::triggergame_simulator::models::game::game_status::game_status::GameStatus;
+    use crate::domain::triggergame_simulator::models::game::game_version::game_version::GameVersion;
+    use crate::domain::triggergame_simulator::models::game::simulation_seed::simulation_seed::SimulationSeed;
+    use crate::domain::triggergame_simulator::models::game_event::event_sequence::event_sequence::EventSequence;
+    use crate::domain::triggergame_simulator::models::game_event::game_event::GameEvent;
+    use crate::domain::triggergame_simulator::models::game_event::game_event_payload::GameEventPayload;
+    use crate::domain::triggergame_simulator::models::step::step::Step;
+    use crate::domain::triggergame_simulator::models::step::step_id::step_id::StepId;
+    use crate::domain::triggergame_simulator::models::turn::turn_id::turn_id::TurnId;
+    use crate::domain::triggergame_simulator::models::turn::turn_number::turn_number::TurnNumber;
+    use crate::domain::triggergame_simulator::models::turn::turn_start_datetime::turn_start_datetime::TurnStartDatetime;
+    use crate::domain::triggergame_simulator::models::turn::turn_status::turn_status::{
+        TurnStatus, TurnStatusValue,
+    };
+    use crate::domain::triggergame_simulator::models::turn::Turn;
+    use crate::domain::triggergame_simulator::repositories::game_event_repository::GAME_EVENT_SEQUENCE_CONFLICT_ERROR;
+    use crate::domain::triggergame_simulator::repositories::game_repository::GAME_VERSION_CONFLICT_ERROR;
+    use crate::domain::triggergame_simulator::repositories::turn_repository::TURN_ALREADY_EXISTS_ERROR;
+    use crate::domain::triggergame_simulator::repositories::turn_unit_of_work::TurnUnitOfWork;
+    use crate::infrastructure::dynamodb::test_utils::create_test_unit;
+
+    use super::super::turn_dynamodb_unit_of_work::DynamoDbTurnUnitOfWork;
+    use aws_credential_types::Credentials;
+    use aws_sdk_dynamodb::{
+        config::{BehaviorVersion, Region},
+        operation::transact_write_items::{
+            TransactWriteItemsError, TransactWriteItemsInput, TransactWriteItemsOutput,
+        },
+        types::{error::TransactionCanceledException, CancellationReason},
+        Client, Config,
+    };
+    use aws_smithy_mocks::{
+        create_mock_http_client, mock, MockResponseInterceptor, Rule, RuleMode,
+    };
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    /// モッククライアントをセットアップ
+    fn setup_mock_client(rule: Rule) -> Client {
+        let mock_interceptor = MockResponseInterceptor::new()
+            .rule_mode(RuleMode::MatchAny)
+            .with_rule(&rule);
+
+        let config = Config::builder()
+            .behavior_version(BehaviorVersion::latest())
+            .region(Region::new("ap-northeast-1"))
+            .interceptor(mock_interceptor)
+            // エラーレスポンスのモックにはHTTPクライアントと認証情報が必要
+            .http_client(create_mock_http_client())
+            .credentials_provider(Credentials::new("dummy", "dummy", None, None, "test"))
+            .build();
+
+        Client::from_conf(config)
+    }
+
+    fn create_test_game() -> Game {
+        Game::new(
+            GameId::new(Uuid::new_v4().to_string()),
+            CurrentTurnNumber::new(2),
+            PlayerId::new(Uuid::new_v4().to_string()),
+            PlayerId::new(Uuid::new_v4().to_string()),
+            GameVersion::initial(),
+            Utc::now(),
+            Vec::new(),
+            GameStatus::initial(),
+            SimulationSeed::generate(),
+        )
+    }
+
+    fn create_test_turn(game: &Game) -> Turn {
+        Turn::new(
+            TurnId::new(Uuid::new_v4().to_string()),
+            game.game_id().clone(),
+            game.player1_id().clone(),
+            TurnNumber::new(1),
+            TurnStartDatetime::new(Utc::now()),
+            TurnStatus::new(TurnStatusValue::Completed),
+            vec![Step::new(
+                StepId::new(Uuid::new_v4().to_string()),
+                vec![],
+                vec![],
+                vec![],
+            )],
+        )
+    }
+
+    /// ターン登録のゲームイベント
+    fn create_test_event(turn: &Turn) -> GameEvent {
+        GameEvent::create(
+            turn.game_id().clone(),
+            EventSequence::initial(),
+            GameEventPayload::TurnSubmitted {
+                player_id: turn.player_id().clone(),
+                turn_number: turn.turn_number().clone(),
+            },
+        )
+    }
+
+    /// 指定した位置のアイテムが条件式を満たさずにキャンセルされたトランザクションのモック
+    fn canceled_transact_rule(item_count: usize, failed_index: usize) -> Rule {
+        mock!(Client::transact_write_items)
+            .match_requests(|_: &TransactWriteItemsInput| true)
+            .then_error(move || {
+                let mut canceled = TransactionCanceledException::builder();
+                for index in 0..item_count {
+                    let code = if index == failed_index {
+                        "ConditionalCheckFailed"
+                    } else {
+                        "None"
+                    };
+                    canceled = canceled
+                        .cancellation_reasons(CancellationReason::builder().code(code).build());
+                }
+                TransactWriteItemsError::TransactionCanceledException(canceled.build())
+            })
+    }
+
+    #[tokio::test]
+    async fn test_submit_turn() {
+        let game = create_test_game();
+        let turn = create_test_turn(&game);
+        let events = vec![create_test_event(&turn)];
+
+        // ターンの登録とイベントの追記が1つのトランザクションにまとめられていること
+        let transact_rule = mock!(Client::transact_write_items)
+            .match_requests(|input: &TransactWriteItemsInput| {
+                let items = input.transact_items();
+                items.len() == 2
+                    && items[0].put().unwrap().table_name() == "Turns"
+                    && items[0].put().unwrap().condition_expression().is_some()
+                    && items[1].put().unwrap().table_name() == "GameEvents"
+                    && items[1].put().unwrap().condition_expression().is_some()
+            })
+            .then_output(|| TransactWriteItemsOutput::builder().build());
+
+        let client = setup_mock_client(transact_rule);
+        let unit_of_work = DynamoDbTurnUnitOfWork::new(client);
+
+        let result = unit_of_work.submit_turn(&turn, &events).await;
+        assert!(result.is_ok(), "Failed to submit: {:?}", result.err());
+    }
+
+    #[tokio::test]
+    async fn test_submit_turn_already_exists() {
+        let game = create_test_game();
+        let turn = create_test_turn(&game);
+        let events = vec![create_test_event(&turn)];
+
+        let client = setup_mock_client(canceled_transact_rule(2, 0));
+        let unit_of_work = DynamoDbTurnUnitOfWork::new(client);
+
+        let result = unit_of_work.submit_turn(&turn, &events).await;
+        assert_eq!(result.unwrap_err(), TURN_ALREADY_EXISTS_ERROR);
+    }
+
+    #[tokio::test]
+    async fn test_submit_turn_event_sequence_conflict() {
+        let game = create_test_game();
+        let turn = create_test_turn(&game);
+        let events = vec![create_test_event(&turn)];
+
+        let client = setup_mock_client(canceled_transact_rule(2, 1));
+        let unit_of_work = DynamoDbTurnUnitOfWork::new(client);
+
+        let result = unit_of_work.submit_turn(&turn, &events).await;
+        assert_eq!(result.unwrap_err(), GAME_EVENT_SEQUENCE_CONFLICT_ERROR);
+    }
+
+    #[tokio::test]
+    async fn test_commit_turn() {
+        let game = create_test_game();
+        let turns = vec![create_test_turn(&game), create_test_turn(&game)];
+        let units = vec![create_test_unit(), create_test_unit()];
+
+        // ターン2件・ユニット2件・ゲーム1件が1つのトランザクションにまとめられていること
+        let transact_rule = mock!(Client::transact_write_items)
+            .match_requests(|input: &TransactWriteItemsInput| {
+                let items = input.transact_items();
+                items.len() == 5
+                    && items.iter().all(|item| item.update().is_some())
+                    && items[0].update().unwrap().table_name() == "Turns"
+                    && items[1].update().unwrap().table_name() == "Turns"
+                    && items[2].update().unwrap().table_name() == "Units"
+                    && items[4].update().unwrap().table_name() == "Games"
+                    && items[4].update().unwrap().condition_expression().is_some()
+            })
+            .then_output(|| TransactWriteItemsOutput::builder().build());
+
+        let client = setup_mock_client(transact_rule);
+        let unit_of_work = DynamoDbTurnUnitOfWork::new(client);
+
+        let result = unit_of_work.commit_turn(&turns, &units, &game, &[]).await;
+        assert!(result.is_ok(), "Failed to commit: {:?}", result.err());
+    }
+
+    #[tokio::test]
+    async fn test_commit_turn_version_conflict() {
+        let game = create_test_game();
+        let turns = vec![create_test_turn(&game)];
+        let units = vec![create_test_unit()];
+
+        // ゲーム情報の条件式を満たさずにトランザクションがキャンセルされた場合をモック
+        let transact_rule = mock!(Client::transact_write_items)
+            .match_requests(|_: &TransactWriteItemsInput| true)
+            .then_error(|| {
+                TransactWriteItemsError::TransactionCanceledException(
+                    TransactionCanceledException::builder()
+                        .cancellation_reasons(CancellationReason::builder().code("None").build())
+                        .cancellation_reasons(CancellationReason::builder().code("None").build())
+                        .cancellation_reasons(
+                            CancellationReason::builder()
+                                .code("ConditionalCheckFailed")
+                                .build(),
+                        )
+                        .build(),
+                )
+            });
+
+        let client = setup_mock_client(transact_rule);
+        let unit_of_work = DynamoDbTurnUnitOfWork::new(client);
+
+        let result = unit_of_work.commit_turn(&turns, &units, &game, &[]).await;
+        assert_eq!(result.unwrap_err(), GAME_VERSION_CONFLICT_ERROR);
+    }
+
+    #[tokio::test]
+    async fn test_commit_turn_with_events() {
+        let game = create_test_game();
+        let turns = vec![create_test_turn(&game)];
+        let units = vec![create_test_unit()];
+        let events = vec![create_test_event(&turns[0])];
+
+        // 演算中に発生したイベントもゲーム情報の後に同じトランザクションで追記されること
+        let transact_rule = mock!(Client::transact_write_items)
+            .match_requests(|input: &TransactWriteItemsInput| {
+                let items = input.transact_items();
+                items.len() == 4
+                    && items[2].update().unwrap().table_name() == "Games"
+                    && items[3].put().unwrap().table_name() == "GameEvents"
+            })
+            .then_output(|| TransactWriteItemsOutput::builder().build());
+
+        let client = setup_mock_client(transact_rule);
+        let unit_of_work = DynamoDbTurnUnitOfWork::new(client);
+
+        let result = unit_of_work
+            .commit_turn(&turns, &units, &game, &events)
+            .await;
+        assert!(result.is_ok(), "Failed to commit: {:?}", result.err());
+    }
+
+    #[tokio::test]
+    async fn test_commit_turn_event_sequence_conflict() {
+        let game = create_test_game();
+        let turns = vec![create_test_turn(&game)];
+        let units = vec![create_test_unit()];
+        let events = vec![create_test_event(&turns[0])];
+
+        // ゲーム情報ではなくイベントの条件式を満たさなかった場合は、通し番号の競合として扱う
+        let client = setup_mock_client(canceled_transact_rule(4, 3));
+        let unit_of_work = DynamoDbTurnUnitOfWork::new(client);
+
+        let result = unit_of_work
+            .commit_turn(&turns, &units, &game, &events)
+            .await;
+        assert_eq!(result.unwrap_err(), GAME_EVENT_SEQUENCE_CONFLICT_ERROR);
+    }
+}
@@ -6,6 +6,7 @@ use crate::domain::matching_management::models::matching::{
 };
 use crate::domain::matching_management::repositories::matching_repository::MatchingRepository;
 use crate::domain::player_management::models::player::player_id::player_id::PlayerId;
+use crate::domain::triggergame_simulator::models::action::trigger_azimuth::trigger_azimuth::TriggerAzimuth;
 use crate::domain::triggergame_simulator::models::game::game_id::game_id::GameId;
 use crate::domain::unit_management::models::unit::current_action_points::current_action_points::CurrentActionPoints;
 use crate::domain::unit_management::models::unit::having_trigger_ids::having_trigger_ids::HavingTriggerIds;
@@ -13,6 +14,7 @@ use crate::domain::unit_management::models::unit::is_bailout::is_bailout::IsBail
 use crate::domain::unit_management::models::unit::main_trigger_hp::main_trigger_hp::MainTriggerHP;
 use crate::domain::unit_management::models::unit::position::position::Position;
 use crate::domain::unit_management::models::unit::sight_range::sight_range::SightRange;
+use crate::domain::unit_management::models::unit::status_effect::status_effect::StatusEffect;
 use crate::domain::unit_management::models::unit::sub_trigger_hp::sub_trigger_hp::SubTriggerHP;
 use crate::domain::unit_management::models::unit::trigger_id::trigger_id::TriggerId;
 use crate::domain::unit_management::models::unit::unit_id::unit_id::UnitId;
@@ -21,7 +23,7 @@ use crate::domain::unit_management::models::unit::wait_time::wait_time::WaitTime
 use crate::domain::unit_management::models::unit::Unit;
 use crate::domain::unit_management::repositories::unit_repository::UnitRepository;
 use async_trait::async_trait;
-use aws_sdk_dynamodb::types::AttributeValue;
+use aws_sdk_dynamodb::types::{AttributeValue, TransactWriteItem, Update};
 use aws_sdk_dynamodb::Client as DynamoDbClient;
 use std::collections::HashMap;
 
@@ -114,6 +116,14 @@ impl DynamoDbUnitRepository {
             "sub_trigger_hp".to_string(),
             AttributeValue::N(unit.sub_trigger_hp().value().to_string()),
         );
+        item.insert(
+            "main_trigger_azimuth".to_string(),
+            AttributeValue::N(unit.main_trigger_azimuth().value().to_string()),
+        );
+        item.insert(
+            "sub_trigger_azimuth".to_string(),
+            AttributeValue::N(unit.sub_trigger_azimuth().value().to_string()),
+        );
         item.insert(
             "sight_range".to_string(),
             AttributeValue::N(unit.sight_range().value().to_string()),
@@ -122,9 +132,78 @@ impl DynamoDbUnitRepository {
             "is_bailout".to_string(),
             AttributeValue::Bool(unit.is_bailout_value().value()),
         );
+        item.insert(
+            "status_effects".to_string(),
+            Self::status_effects_to_attribute(unit.status_effects()),
+        );
         item
     }
 
+    /// ヘルパーメソッド：状態効果のリストを属性値に変換
+    /// ターン情報に記録する状態効果の変化も同じ形式で保存する
+    pub fn status_effects_to_attribute(status_effects: &[StatusEffect]) -> AttributeValue {
+        AttributeValue::L(
+            status_effects
+                .iter()
+                .map(|effect| {
+                    let mut effect_map = HashMap::new();
+                    effect_map.insert(
+                        "status_effect_id".to_string(),
+                        AttributeValue::S(effect.status_effect_id().to_string()),
+                    );
+                    effect_map.insert(
+                        "remaining_steps".to_string(),
+                        AttributeValue::N(effect.remaining_steps().to_string()),
+                    );
+                    effect_map.insert(
+                        "stacks".to_string(),
+                        AttributeValue::N(effect.stacks().to_string()),
+                    );
+                    AttributeValue::M(effect_map)
+                })
+                .collect(),
+        )
+    }
+
+    /// ヘルパーメソッド：属性値から状態効果のリストに変換
+    /// 状態効果を保存する前に作成されたデータには属性がないため、状態効果なしとする
+    pub fn from_dynamo_db_to_status_effects(
+        value: Option<&AttributeValue>,
+    ) -> Result<Vec<StatusEffect>, String> {
+        let Some(value) = value else {
+            return Ok(vec![]);
+        };
+        let effects = value
+            .as_l()
+            .map_err(|_| "status_effects is not a list".to_string())?;
+        let mut status_effects = Vec::new();
+        for effect in effects {
+            let effect_map = effect
+                .as_m()
+                .map_err(|_| "status_effect is not a map".to_string())?;
+            let status_effect_id = effect_map
+                .get("status_effect_id")
+                .and_then(|v| v.as_s().ok())
+                .ok_or("status_effect_id not found or invalid")?;
+            let remaining_steps = effect_map
+                .get("remaining_steps")
+                .and_then(|v| v.as_n().ok())
+                .and_then(|n| n.parse::<i32>().ok())
+                .ok_or("remaining_steps not found or invalid")?;
+            let stacks = effect_map
+                .get("stacks")
+                .and_then(|v| v.as_n().ok())
+                .and_then(|n| n.parse::<i32>().ok())
+                .ok_or("stacks not found or invalid")?;
+            status_effects.push(StatusEffect::new(
+                status_effect_id.to_string(),
+                remaining_steps,
+                stacks,
+            ));
+        }
+        Ok(status_effects)
+    }
+
     /// ヘルパーメソッド：DynamoDBから取得したデータをUnitエンティティに変換
     pub fn from_dynamo_db_to_unit(
         &self,
@@ -236,6 +315,21 @@ impl DynamoDbUnitRepository {
                 .ok_or("sub_trigger_hp not found or invalid")?,
         );
 
+        // 方位角を保存する前に作成されたユニットには属性がないため、初期値の0とする
+        let main_trigger_azimuth = TriggerAzimuth::new(
+            item.get("main_trigger_azimuth")
+                .and_then(|v| v.as_n().ok())
+                .and_then(|n| n.parse::<i32>().ok())
+                .unwrap_or(0),
+        );
+
+        let sub_trigger_azimuth = TriggerAzimuth::new(
+            item.get("sub_trigger_azimuth")
+                .and_then(|v| v.as_n().ok())
+                .and_then(|n| n.parse::<i32>().ok())
+                .unwrap_or(0),
+        );
+
         let sight_range = SightRange::new(
             item.get("sight_range")
                 .and_then(|v| v.as_n().ok())
@@ -250,6 +344,8 @@ impl DynamoDbUnitRepository {
                 .ok_or("is_bailout not found or invalid")?,
         );
 
+        let status_effects = Self::from_dynamo_db_to_status_effects(item.get("status_effects"))?;
+
         Ok(Unit::reconstruct(
             unit_id,
             unit_type_id,
@@ -264,44 +360,35 @@ impl DynamoDbUnitRepository {
             having_sub_trigger_ids,
             main_trigger_hp,
             sub_trigger_hp,
+            main_trigger_azimuth,
+            sub_trigger_azimuth,
             sight_range,
             is_bailout,
+            status_effects,
         ))
     }
-}
-
-#[async_trait]
-impl UnitRepository for DynamoDbUnitRepository {
-    /// ユニット情報の保存
-    async fn save(&self, unit: &Unit) -> Result<(), String> {
-        // Unitアイテムを保存
-        let unit_item = self.unit_to_item(unit);
-        self.client
-            .put_item()
-            .table_name(self.units_table)
-            .set_item(Some(unit_item))
-            .send()
-            .await
-            .map_err(|e| format!("ユニット情報の保存に失敗しました: {}", e))?;
-        Ok(())
-    }
-
-    async fn update(&self, unit: &Unit) -> Result<(), String> {
-        // DynamoDBでは put_item で上書き更新
-        // または update_item を使用して部分更新
 
+    // ヘルパーメソッド：ユニット更新時のUPDATE式を作成
+    fn unit_update_expression(&self) -> String {
         // UPDATE式を動的に構築
         let update_parts = vec![
             "using_main_trigger_id = :using_main_trigger_id",
             "using_sub_trigger_id = :using_sub_trigger_id",
             "current_action_points = :current_action_points",
             "#position = :position",
+            "main_trigger_azimuth = :main_trigger_azimuth",
+            "sub_trigger_azimuth = :sub_trigger_azimuth",
+            "main_trigger_hp = :main_trigger_hp",
+            "sub_trigger_hp = :sub_trigger_hp",
             "sight_range = :sight_range",
             "is_bailout = :is_bailout",
+            "status_effects = :status_effects",
         ];
+        format!("SET {}", update_parts.join(", "))
+    }
 
-        let update_expression = format!("SET {}", update_parts.join(", "));
-
+    // ヘルパーメソッド：ユニット更新時の属性値マップを作成
+    fn unit_update_values(&self, unit: &Unit) -> HashMap<String, AttributeValue> {
         // ポジションのオブジェクトを作る
         let mut position_map = HashMap::new();
         position_map.insert(
@@ -313,6 +400,87 @@ impl UnitRepository for DynamoDbUnitRepository {
             AttributeValue::N(unit.position().row().to_string()),
         );
 
+        let mut values = HashMap::new();
+        values.insert(
+            ":current_action_points".to_string(),
+            AttributeValue::N(unit.current_action_points().value().to_string()),
+        );
+        values.insert(":position".to_string(), AttributeValue::M(position_map));
+        values.insert(
+            ":main_trigger_azimuth".to_string(),
+            AttributeValue::N(unit.main_trigger_azimuth().value().to_string()),
+        );
+        values.insert(
+            ":sub_trigger_azimuth".to_string(),
+            AttributeValue::N(unit.sub_trigger_azimuth().value().to_string()),
+        );
+        values.insert(
+            ":main_trigger_hp".to_string(),
+            AttributeValue::N(unit.main_trigger_hp().value().to_string()),
+        );
+        values.insert(
+            ":sub_trigger_hp".to_string(),
+            AttributeValue::N(unit.sub_trigger_hp().value().to_string()),
+        );
+        values.insert(
+            ":sight_range".to_string(),
+            AttributeValue::N(unit.sight_range().value().to_string()),
+        );
+        values.insert(
+            ":is_bailout".to_string(),
+            AttributeValue::Bool(unit.is_bailed_out()),
+        );
+        values.insert(
+            ":status_effects".to_string(),
+            Self::status_effects_to_attribute(unit.status_effects()),
+        );
+        values.insert(
+            ":using_main_trigger_id".to_string(),
+            AttributeValue::S(unit.using_main_trigger_id().value().to_string()),
+        );
+        values.insert(
+            ":using_sub_trigger_id".to_string(),
+            AttributeValue::S(unit.using_sub_trigger_id().value().to_string()),
+        );
+        values
+    }
+
+    /// ユニットの更新をトランザクション書き込み用のアイテムとして作成
+    pub fn update_transact_item(&self, unit: &Unit) -> Result<TransactWriteItem, String> {
+        let update = Update::builder()
+            .table_name(self.units_table)
+            .key(
+                "unit_id",
+                AttributeValue::S(unit.unit_id().value().to_string()),
+            )
+            .update_expression(self.unit_update_expression())
+            .expression_attribute_names("#position", "position")
+            .set_expression_attribute_values(Some(self.unit_update_values(unit)))
+            .build()
+            .map_err(|e| format!("ユニット情報の更新内容の作成に失敗しました: {}", e))?;
+        Ok(TransactWriteItem::builder().update(update).build())
+    }
+}
+
+#[async_trait]
+impl UnitRepository for DynamoDbUnitRepository {
+    /// ユニット情報の保存
+    async fn save(&self, unit: &Unit) -> Result<(), String> {
+        // Unitアイテムを保存
+        let unit_item = self.unit_to_item(unit);
+        self.client
+            .put_item()
+            .table_name(self.units_table)
+            .set_item(Some(unit_item))
+            .send()
+            .await
+            .map_err(|e| format!("ユニット情報の保存に失敗しました: {}", e))?;
+        Ok(())
+    }
+
+    async fn update(&self, unit: &Unit) -> Result<(), String> {
+        // DynamoDBでは put_item で上書き更新
+        // または update_item を使用して部分更新
         self.client
             .update_item()
             .table_name(self.units_table)
@@ -320,26 +488,9 @@ impl UnitRepository for DynamoDbUnitRepository {
                 "unit_id",
                 AttributeValue::S(unit.unit_id().value().to_string()),
             )
-            .update_expression(update_expression)
+            .update_expression(self.unit_update_expression())
             .expression_attribute_names("#position", "position")
-            .expression_attribute_values(
-                ":current_action_points",
-                AttributeValue::N(unit.current_action_points().value().to_string()),
-            )
-            .expression_attribute_values(":position", AttributeValue::M(position_map))
-            .expression_attribute_values(
-                ":sight_range",
-                AttributeValue::N(unit.sight_range().value().to_string()),
-            )
-            .expression_attribute_values(":is_bailout", AttributeValue::Bool(unit.is_bailed_out()))
-            .expression_attribute_values(
-                ":using_main_trigger_id",
-                AttributeValue::S(unit.using_main_trigger_id().value().to_string()),
-            )
-            .expression_attribute_values(
-                ":using_sub_trigger_id",
-                AttributeValue::S(unit.using_sub_trigger_id().value().to_string()),
-            )
+            .set_expression_attribute_values(Some(self.unit_update_values(unit)))
             .send()
             .await
             .map_err(|e| {
@@ -391,4 +542,26 @@ impl UnitRepository for DynamoDbUnitRepository {
         }
         Ok(units)
     }
+
+    async fn delete_game_units(&self, game_id: &GameId) -> Result<(), String> {
+        let units = self.get_game_units(game_id).await?;
+        for unit in &units {
+            self.client
+                .delete_item()
+                .table_name(self.units_table)
+                .key(
+                    "unit_id",
+                    AttributeValue::S(unit.unit_id().value().to_string()),
+                )
+                .send()
+                .await
+                .map_err(|e| format!("ユニット情報の削除に失敗しました: {}", e))?;
+        }
+        println!(
+            "ユニット情報を削除しました ゲームID: {}, 件数: {}",
+            game_id.value(),
+            units.len()
+        );
+        Ok(())
+    }
 }
@@ -3,7 +3,10 @@ mod tests {
     use crate::{
         domain::{
             player_management::models::player::player_id::player_id::PlayerId,
-            triggergame_simulator::models::game::game_id::game_id::GameId,
+            triggergame_simulator::models::{
+                action::trigger_azimuth::trigger_azimuth::TriggerAzimuth,
+                game::game_id::game_id::GameId,
+            },
             unit_management::{
                 models::unit::{
                     current_action_points::current_action_points::CurrentActionPoints,
@@ -25,6 +28,7 @@ mod tests {
     use aws_sdk_dynamodb::{
         config::{BehaviorVersion, Region},
         operation::{
+            delete_item::{DeleteItemInput, DeleteItemOutput},
             put_item::{PutItemInput, PutItemOutput},
             query::{QueryInput, QueryOutput},
             update_item::{UpdateItemInput, UpdateItemOutput},
@@ -51,6 +55,83 @@ mod tests {
         Client::from_conf(config)
     }
 
+    /// 複数のルールを持つモッククライアントをセットアップ
+    fn setup_mock_client_with_rules(rules: &[Rule]) -> Client {
+        let mut mock_interceptor = MockResponseInterceptor::new().rule_mode(RuleMode::MatchAny);
+        for rule in rules {
+            mock_interceptor = mock_interceptor.with_rule(rule);
+        }
+
+        let config = Config::builder()
+            .behavior_version(BehaviorVersion::latest())
+            .region(Region::new("ap-northeast-1"))
+            .interceptor(mock_interceptor)
+            .build();
+
+        Client::from_conf(config)
+    }
+
+    /// GetGameUnitsで返却するユニットのアイテムを構築
+    fn create_unit_item(unit_id: &UnitId, game_id: &GameId) -> HashMap<String, AttributeValue> {
+        let mut position_map = HashMap::new();
+        position_map.insert("col".to_string(), AttributeValue::N("5".to_string()));
+        position_map.insert("row".to_string(), AttributeValue::N("10".to_string()));
+
+        let mut item = HashMap::new();
+        item.insert(
+            "unit_id".to_string(),
+            AttributeValue::S(unit_id.value().to_string()),
+        );
+        item.insert(
+            "game_id".to_string(),
+            AttributeValue::S(game_id.value().to_string()),
+        );
+        item.insert(
+            "unit_type_id".to_string(),
+            AttributeValue::S("MIKUMO_OSAMU".to_string()),
+        );
+        item.insert(
+            "owner_player_id".to_string(),
+            AttributeValue::S("550e8400-e29b-41d4-a716-446655440001".to_string()),
+        );
+        item.insert(
+            "current_action_points".to_string(),
+            AttributeValue::N("2".to_string()),
+        );
+        item.insert("wait_time".to_string(), AttributeValue::N("0".to_string()));
+        item.insert("position".to_string(), AttributeValue::M(position_map));
+        item.insert(
+            "using_main_trigger_id".to_string(),
+            AttributeValue::S("KOGETSU".to_string()),
+        );
+        item.insert(
+            "using_sub_trigger_id".to_string(),
+            AttributeValue::S("SHIELD".to_string()),
+        );
+        item.insert(
+            "having_main_trigger_ids".to_string(),
+            AttributeValue::L(vec![AttributeValue::S("KOGETSU".to_string())]),
+        );
+        item.insert(
+            "having_sub_trigger_ids".to_string(),
+            AttributeValue::L(vec![AttributeValue::S("SHIELD".to_string())]),
+        );
+        item.insert(
+            "main_trigger_hp".to_string(),
+            AttributeValue::N("100".to_string()),
+        );
+        item.insert(
+            "sub_trigger_hp".to_string(),
+            AttributeValue::N("100".to_string()),
+        );
+        item.insert(
+            "sight_range".to_string(),
+            AttributeValue::N("3".to_string()),
+        );
+        item.insert("is_bailout".to_string(), AttributeValue::Bool(false));
+        item
+    }
+
     #[tokio::test]
     async fn test_save_unit() {
         let unit = create_test_unit();
@@ -88,6 +169,153 @@ mod tests {
         assert!(result.is_ok(), "Failed to update: {:?}", result.err());
     }
 
+    #[tokio::test]
+    async fn test_update_unit_writes_trigger_azimuths() {
+        let mut unit = create_test_unit();
+        unit.set_main_trigger_azimuth(TriggerAzimuth::new(120));
+        unit.set_sub_trigger_azimuth(TriggerAzimuth::new(300));
+
+        // 更新内容にトリガーの方位角が含まれること
+        let update_item_rule = mock!(Client::update_item)
+            .match_requests(|input: &UpdateItemInput| {
+                let values = input.expression_attribute_values();
+                let azimuth = |key: &str| {
+                    values
+                        .and_then(|v| v.get(key))
+                        .and_then(|v| v.as_n().ok())
+                        .cloned()
+                };
+                azimuth(":main_trigger_azimuth") == Some("120".to_string())
+                    && azimuth(":sub_trigger_azimuth") == Some("300".to_string())
+            })
+            .then_output(|| UpdateItemOutput::builder().build());
+
+        let client = setup_mock_client(update_item_rule.clone());
+        let repo = DynamoDbUnitRepository::new(client);
+
+        let result = repo.update(&unit).await;
+        assert!(result.is_ok(), "Failed to update: {:?}", result.err());
+        assert_eq!(update_item_rule.num_calls(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_update_unit_writes_trigger_hp() {
+        let mut unit = create_test_unit();
+        unit.apply_damage(30, 45);
+
+        // 更新内容に被弾後のトリガーHPが含まれること
+        let update_item_rule = mock!(Client::update_item)
+            .match_requests(|input: &UpdateItemInput| {
+                let hp = |key: &str| {
+                    input
+                        .expression_attribute_values()
+                        .and_then(|v| v.get(key))
+                        .and_then(|v| v.as_n().ok())
+                        .cloned()
+                };
+                input
+                    .update_expression()
+                    .is_some_and(|e| e.contains("main_trigger_hp = :main_trigger_hp"))
+                    && hp(":main_trigger_hp") == Some("70".to_string())
+                    && hp(":sub_trigger_hp") == Some("55".to_string())
+            })
+            .then_output(|| UpdateItemOutput::builder().build());
+
+        let client = setup_mock_client(update_item_rule.clone());
+        let repo = DynamoDbUnitRepository::new(client);
+
+        let result = repo.update(&unit).await;
+        assert!(result.is_ok(), "Failed to update: {:?}", result.err());
+        assert_eq!(update_item_rule.num_calls(), 1);
+    }
+
+    #[test]
+    fn test_trigger_azimuths_round_trip() {
+        let mut unit = create_test_unit();
+        unit.set_main_trigger_azimuth(TriggerAzimuth::new(60));
+        unit.set_sub_trigger_azimuth(TriggerAzimuth::new(240));
+
+        // 保存時のアイテムからユニットを復元する
+        let client = setup_mock_client(
+            mock!(Client::put_item).then_output(|| PutItemOutput::builder().build()),
+        );
+        let repo = DynamoDbUnitRepository::new(client);
+        let item = repo.unit_to_item(&unit);
+        let restored = repo.from_dynamo_db_to_unit(&item).unwrap();
+
+        assert_eq!(restored.main_trigger_azimuth().value(), 60);
+        assert_eq!(restored.sub_trigger_azimuth().value(), 240);
+        assert_eq!(restored.position(), unit.position());
+    }
+
+    #[test]
+    fn test_status_effects_round_trip() {
+        let mut unit = create_test_unit();
+        unit.apply_status_effect("BOOST_SEAL").unwrap();
+        unit.apply_status_effect("BOOST_SEAL").unwrap();
+        unit.apply_status_effect("LEAD_WEIGHT").unwrap();
+        unit.tick_status_effects();
+
+        let client = setup_mock_client(
+            mock!(Client::put_item).then_output(|| PutItemOutput::builder().build()),
+        );
+        let repo = DynamoDbUnitRepository::new(client);
+        let item = repo.unit_to_item(&unit);
+        let restored = repo.from_dynamo_db_to_unit(&item).unwrap();
+
+        assert_eq!(restored.status_effects(), unit.status_effects());
+        assert_eq!(restored.status_effects()[0].stacks(), 2);
+        assert_eq!(restored.status_effects()[1].remaining_steps(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_update_unit_writes_status_effects() {
+        let mut unit = create_test_unit();
+        unit.apply_status_effect("LEAD_WEIGHT").unwrap();
+
+        // 更新内容に状態効果が含まれること
+        let update_item_rule = mock!(Client::update_item)
+            .match_requests(|input: &UpdateItemInput| {
+                input
+                    .expression_attribute_values()
+                    .and_then(|v| v.get(":status_effects"))
+                    .and_then(|v| v.as_l().ok())
+                    .map(|effects| effects.len() == 1)
+                    .unwrap_or(false)
+            })
+            .then_output(|| UpdateItemOutput::builder().build());
+
+        let client = setup_mock_client(update_item_rule.clone());
+        let repo = DynamoDbUnitRepository::new(client);
+
+        let result = repo.update(&unit).await;
+        assert!(result.is_ok(), "Failed to update: {:?}", result.err());
+        assert_eq!(update_item_rule.num_calls(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_game_units_without_trigger_azimuths() {
+        // 方位角の属性がない既存のアイテムは初期値の0として取得する
+        let game_id = GameId::new(Uuid::new_v4().to_string());
+        let item = create_unit_item(&UnitId::new(Uuid::new_v4().to_string()), &game_id);
+        let query_rule = mock!(Client::query)
+            .match_requests(|_: &QueryInput| true)
+            .then_output(move || {
+                QueryOutput::builder()
+                    .set_items(Some(vec![item.clone()]))
+                    .build()
+            });
+        let client = setup_mock_client(query_rule);
+        let repo = DynamoDbUnitRepository::new(client);
+
+        let units = repo.get_game_units(&game_id).await.unwrap();
+        assert_eq!(units.len(), 1);
+        assert_eq!(units[0].main_trigger_azimuth().value(), 0);
+        assert_eq!(units[0].sub_trigger_azimuth().value(), 0);
+        // 状態効果の属性がない既存のアイテムは状態効果なしとして取得する
+        assert!(units[0].status_effects().is_empty());
+    }
+
     #[tokio::test]
     async fn test_get_match_units_found() {
         let game_id = GameId::new(Uuid::new_v4().to_string());
@@ -342,4 +570,42 @@ mod tests {
         assert_eq!(units[0].unit_id().value(), unit_id_1.value());
         assert_eq!(units[1].unit_id().value(), unit_id_2.value());
     }
+
+    #[tokio::test]
+    async fn test_delete_game_units() {
+        let game_id = GameId::new(Uuid::new_v4().to_string());
+        let unit_id1 = UnitId::new(Uuid::new_v4().to_string());
+        let unit_id2 = UnitId::new(Uuid::new_v4().to_string());
+        let items = vec![
+            create_unit_item(&unit_id1, &game_id),
+            create_unit_item(&unit_id2, &game_id),
+        ];
+
+        let query_rule = mock!(Client::query)
+            .match_requests(|_: &QueryInput| true)
+            .then_output(move || {
+                QueryOutput::builder()
+                    .set_items(Some(items.clone()))
+                    .build()
+            });
+        // 取得したユニットごとに削除する
+        let expected_unit_ids = [unit_id1.value().to_string(), unit_id2.value().to_string()];
+        let delete_item_rule = mock!(Client::delete_item)
+            .match_requests(move |input: &DeleteItemInput| {
+                input
+                    .key()
+                    .and_then(|key| key.get("unit_id"))
+                    .and_then(|v| v.as_s().ok())
+                    .map(|unit_id| expected_unit_ids.contains(unit_id))
+                    .unwrap_or(false)
+            })
+            .then_output(|| DeleteItemOutput::builder().build());
+
+        let client = setup_mock_client_with_rules(&[query_rule, delete_item_rule.clone()]);
+        let repo = DynamoDbUnitRepository::new(client);
+
+        let result = repo.delete_game_units(&game_id).await;
+        assert!(result.is_ok(), "Failed to delete units: {:?}", result.err());
+        assert_eq!(delete_item_rule.num_calls(), 2);
+    }
 }
@@ -1,55 +1,81 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use aws_sdk_apigatewaymanagement::primitives::Blob;
+use aws_sdk_dynamodb::Client as DynamoDbClient;
 use lambda_runtime::{service_fn, Error, LambdaEvent};
 use serde::{Deserialize, Serialize};
 
-use infrastructure::aws::{
-    apigateway_client::create_apigateway_client, dynamodb_client::create_dynamodb_client,
-};
-
-use crate::{
+use game_server::{
     application::{
+        bot::{bot_turn_submitter::BotTurnSubmitter, play_vs_bot_usecase::PlayVsBotUseCase},
         game::{
-            get_game_state_usecase::GetGameStateUseCase, process_turn_usecase::ProcessTurnUseCase,
+            get_game_state_usecase::GetGameStateUseCase, get_replay_usecase::GetReplayUseCase,
+            get_turn_result_usecase::GetTurnResultUseCase,
+            preview_combat_usecase::PreviewCombatUseCase,
+            process_turn_usecase::ProcessTurnUseCase, resume_session_usecase::ResumeSessionUseCase,
+            spectate_game_usecase::SpectateGameUseCase,
+            step_request_dto::StepRequestDto,
+            stop_spectating_usecase::StopSpectatingUseCase,
+        },
+        matchmaking::{
+            cancel_matchmaking_usecase::CancelMatchmakingUseCase,
+            matchmaking_application_service::MatchmakingApplicationService,
+        },
+        player::{
+            disable_mfa_usecase::DisableMfaUseCase, enable_mfa_usecase::EnableMfaUseCase,
+            get_profile_usecase::GetProfileUseCase, login_usecase::LoginUseCase,
+            register_player_usecase::RegisterPlayerUseCase,
+            rename_player_usecase::RenamePlayerUseCase, setup_mfa_usecase::SetupMfaUseCase,
         },
-        matchmaking::matchmaking_application_service::MatchmakingApplicationService,
         websocket::{
+            disconnect_usecase::DisconnectUseCase, request_authenticator::RequestAuthenticator,
             websocket_request::WebSocketRequest, websocket_response::WebSocketResponse,
             websocket_sender::WebSocketSender,
         },
     },
     domain::{
+        matching_management::services::matchmaking_strategy::{
+            FifoMatchmakingStrategy, MatchmakingStrategy, RatingWindowMatchmakingStrategy,
+        },
         player_management::{
-            models::player::player_id::player_id::PlayerId,
-            repositories::connection_repository::ConnectionRepository,
+            repositories::{
+                connection_repository::ConnectionRepository, player_repository::PlayerRepository,
+            },
+            services::session_token_service::SessionTokenService,
         },
-        triggergame_simulator::{
-            models::game::game_id::game_id::GameId, repositories::game_repository::GameRepository,
+        triggergame_simulator::models::game::{
+            game_id::game_id::GameId, spectator_delay::spectator_delay::SpectatorDelay,
         },
     },
     infrastructure::{
-        aws::websocketapi_sender::WebSocketapiSender,
+        auth::hmac_session_token_service::HmacSessionTokenService,
+        aws::{
+            apigateway_client::create_apigateway_client, dynamodb_client::create_dynamodb_client,
+            websocketapi_sender::WebSocketapiSender,
+        },
         dynamodb::{
             connection_dynamodb_repository::DynamoDbConnectionRepository,
             game_dynamodb_repository::DynamoDbGameRepository,
+            game_event_dynamodb_repository::DynamoDbGameEventRepository,
             matching_dynamodb_repository::DynamoDbMatchingRepository,
+            outbox_dynamodb_repository::DynamoDbOutboxRepository,
+            player_dynamodb_repository::DynamoDbPlayerRepository,
+            replay_dynamodb_repository::DynamoDbReplayRepository,
             turn_dynamodb_repository::DynamoDbTurnRepository,
+            turn_dynamodb_unit_of_work::DynamoDbTurnUnitOfWork,
             unit_dynamodb_repository::DynamoDbUnitRepository,
         },
     },
 };
 
-mod application;
-mod domain;
-mod infrastructure;
-
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct WebSocketEvent {
     #[serde(rename = "requestContext")]
     request_context: RequestContext,
     body: Option<String>,
+    /// 接続時のクエリパラメータ（$connectのみ）
+    query_string_parameters: Option<HashMap<String, String>>,
 }
 
 #[derive(Deserialize)]
@@ -68,6 +94,67 @@ struct Response {
     body: String,
 }
 
+/// マッチメイキングの戦略を環境変数 MATCHMAKING_STRATEGY から選択する
+/// "fifo" の場合は先着順、それ以外はレーティング帯によるマッチメイキングを行う
+fn create_matchmaking_strategy() -> Arc<dyn MatchmakingStrategy> {
+    match std::env::var("MATCHMAKING_STRATEGY").as_deref() {
+        Ok("fifo") => Arc::new(FifoMatchmakingStrategy),
+        _ => Arc::new(RatingWindowMatchmakingStrategy),
+    }
+}
+
+/// 観戦者への配信の遅延ターン数を環境変数 SPECTATOR_DELAY_TURNS から取得する
+/// 未設定・不正な値の場合は既定値を使用する
+fn create_spectator_delay() -> SpectatorDelay {
+    match std::env::var("SPECTATOR_DELAY_TURNS")
+        .ok()
+        .and_then(|turns| turns.parse::<i32>().ok())
+    {
+        Some(turns) if turns >= 0 => SpectatorDelay::new(turns),
+        _ => SpectatorDelay::default(),
+    }
+}
+
+/// ターン演算のユースケースを生成する
+fn create_process_turn_usecase(
+    dynamo_client: &DynamoDbClient,
+    websocket_sender: Arc<dyn WebSocketSender>,
+) -> ProcessTurnUseCase {
+    ProcessTurnUseCase::new(
+        Arc::new(DynamoDbConnectionRepository::new(dynamo_client.clone())),
+        Arc::new(DynamoDbGameRepository::new(dynamo_client.clone())),
+        Arc::new(DynamoDbTurnRepository::new(dynamo_client.clone())),
+        Arc::new(DynamoDbUnitRepository::new(dynamo_client.clone())),
+        // ターンの演算結果をまとめて反映するユニットオブワーク
+        Arc::new(DynamoDbTurnUnitOfWork::new(dynamo_client.clone())),
+        Arc::new(DynamoDbGameEventRepository::new(dynamo_client.clone())),
+        Arc::new(DynamoDbReplayRepository::new(dynamo_client.clone())),
+        Arc::new(DynamoDbPlayerRepository::new(dynamo_client.clone())),
+        Arc::new(DynamoDbOutboxRepository::new(dynamo_client.clone())),
+        websocket_sender,
+        create_spectator_delay(),
+    )
+}
+
+/// ボットのターン設定を生成する
+/// ボットの行動もプレイヤーと同じターン演算のユースケースを通して登録する
+fn create_bot_turn_submitter(
+    dynamo_client: &DynamoDbClient,
+    websocket_sender: Arc<dyn WebSocketSender>,
+) -> BotTurnSubmitter {
+    BotTurnSubmitter::new(
+        Arc::new(DynamoDbGameRepository::new(dynamo_client.clone())),
+        Arc::new(DynamoDbTurnRepository::new(dynamo_client.clone())),
+        Arc::new(DynamoDbUnitRepository::new(dynamo_client.clone())),
+        create_process_turn_usecase(dynamo_client, websocket_sender),
+    )
+}
+
+/// 環境変数の署名鍵からセッショントークンサービスを生成する
+fn create_session_token_service() -> Result<Arc<dyn SessionTokenService>, Error> {
+    Ok(Arc::new(HmacSessionTokenService::from_env()?))
+}
+
 async fn handler(event: LambdaEvent<WebSocketEvent>) -> Result<Response, Error> {
     println!("Received event");
     let (event, _context) = event.into_parts();
@@ -82,12 +169,50 @@ async fn handler(event: LambdaEvent<WebSocketEvent>) -> Result<Response, Error>
     match event.request_context.route_key.as_str() {
         "$connect" => {
             println!("Client connected: {}", event.request_context.connection_id);
+            // クエリパラメータでセッショントークンが渡された場合は接続時に認証する
+            // （MFA認証が有効なプレイヤーはクエリパラメータ`mfaCode`も必要）
+            // 渡されなかった場合は、接続後のRegisterPlayer/Loginリクエストで認証する
+            let params = event.query_string_parameters.unwrap_or_default();
+            if let Some(token) = params.get("token") {
+                let dynamo_client = create_dynamodb_client().await;
+                let authenticator = RequestAuthenticator::new(
+                    Arc::new(DynamoDbConnectionRepository::new(dynamo_client.clone())),
+                    Arc::new(DynamoDbPlayerRepository::new(dynamo_client)),
+                    create_session_token_service()?,
+                );
+                if let Err(e) = authenticator
+                    .authenticate(
+                        token,
+                        params.get("mfaCode").map(String::as_str),
+                        &event.request_context.connection_id,
+                    )
+                    .await
+                {
+                    println!("Failed to authenticate connection: {}", e);
+                    return Ok(Response {
+                        status_code: 401,
+                        body: "unauthorized".to_string(),
+                    });
+                }
+            }
         }
         "$disconnect" => {
             println!(
                 "Client disconnected: {}",
                 event.request_context.connection_id
             );
+            let dynamo_client = create_dynamodb_client().await;
+            let connection_repository = DynamoDbConnectionRepository::new(dynamo_client.clone());
+            let matching_repository = DynamoDbMatchingRepository::new(dynamo_client.clone());
+            let unit_repository = DynamoDbUnitRepository::new(dynamo_client);
+            let service = DisconnectUseCase::new(
+                Arc::new(connection_repository),
+                Arc::new(matching_repository),
+                Arc::new(unit_repository),
+            );
+            service
+                .execute(&event.request_context.connection_id)
+                .await?;
         }
         "$default" => {
             // メッセージ受信時の処理
@@ -126,33 +251,149 @@ async fn handler(event: LambdaEvent<WebSocketEvent>) -> Result<Response, Error>
                 // DynamoDBクライアントの作成
                 let dynamo_client = create_dynamodb_client().await;
                 // コネクションIDを保存するリポジトリ
-                let connection_repository =
-                    DynamoDbConnectionRepository::new(dynamo_client.clone());
+                let connection_repository: Arc<dyn ConnectionRepository> =
+                    Arc::new(DynamoDbConnectionRepository::new(dynamo_client.clone()));
                 // ユニット情報を保存するリポジトリ
                 let unit_repository = DynamoDbUnitRepository::new(dynamo_client.clone());
                 // ゲーム情報を保存するリポジトリ
                 let game_repository = DynamoDbGameRepository::new(dynamo_client.clone());
                 // ターン情報を保存するリポジトリ
                 let turn_repository = DynamoDbTurnRepository::new(dynamo_client.clone());
+                // ゲームイベントを記録するリポジトリ
+                let game_event_repository = DynamoDbGameEventRepository::new(dynamo_client.clone());
+                // 届けられなかった通知を保持するリポジトリ
+                let outbox_repository = DynamoDbOutboxRepository::new(dynamo_client.clone());
+                // プレイヤー情報を保存するリポジトリ
+                let player_repository: Arc<dyn PlayerRepository> =
+                    Arc::new(DynamoDbPlayerRepository::new(dynamo_client.clone()));
+                // セッショントークンの発行・検証
+                let session_token_service = create_session_token_service()?;
+
+                // プレイヤーIDを指定するリクエストは、コネクションで認証済みのプレイヤー本人か確認する
+                if let Some(player_id) = message.player_id() {
+                    let authenticator = RequestAuthenticator::new(
+                        connection_repository.clone(),
+                        player_repository.clone(),
+                        session_token_service.clone(),
+                    );
+                    if let Err(e) = authenticator
+                        .authorize(&event.request_context.connection_id, player_id)
+                        .await
+                    {
+                        println!("Unauthorized request: {}", e);
+                        let error_response = WebSocketResponse::Error { message: e };
+                        websocket_sender
+                            .send_message(&event.request_context.connection_id, &error_response)
+                            .await?;
+                        return Ok(Response {
+                            status_code: 401,
+                            body: "unauthorized".to_string(),
+                        });
+                    }
+                }
 
                 // アクションごとの処理
                 match message {
                     // NOTE: ここに他のアクションも追加していく
-                    // マッチメイキングリクエストの処理
-                    WebSocketRequest::Matchmaking { player_id, units } => {
-                        // コネクションIDとPlayerIDの紐付けを保存
-                        connection_repository
-                            .save(&player_id, &event.request_context.connection_id)
+                    // プレイヤー登録リクエストの処理
+                    WebSocketRequest::RegisterPlayer { player_name } => {
+                        let service = RegisterPlayerUseCase::new(
+                            connection_repository,
+                            player_repository,
+                            session_token_service,
+                            Arc::new(websocket_sender),
+                        );
+                        service
+                            .execute(&player_name, &event.request_context.connection_id)
                             .await?;
+                    }
 
+                    // ログインリクエストの処理
+                    WebSocketRequest::Login {
+                        session_token,
+                        mfa_code,
+                    } => {
+                        let service = LoginUseCase::new(
+                            connection_repository,
+                            player_repository,
+                            session_token_service,
+                            Arc::new(websocket_sender),
+                        );
+                        service
+                            .execute(
+                                &session_token,
+                                mfa_code.as_deref(),
+                                &event.request_context.connection_id,
+                            )
+                            .await?;
+                    }
+
+                    // プレイヤー情報取得リクエストの処理
+                    WebSocketRequest::GetProfile { player_id } => {
+                        let service =
+                            GetProfileUseCase::new(player_repository, Arc::new(websocket_sender));
+                        service
+                            .execute(player_id, &event.request_context.connection_id)
+                            .await?;
+                    }
+
+                    // プレイヤー名変更リクエストの処理
+                    WebSocketRequest::RenamePlayer {
+                        player_id,
+                        player_name,
+                    } => {
+                        let service =
+                            RenamePlayerUseCase::new(player_repository, Arc::new(websocket_sender));
+                        service
+                            .execute(
+                                player_id,
+                                &player_name,
+                                &event.request_context.connection_id,
+                            )
+                            .await?;
+                    }
+
+                    // MFA認証の登録開始リクエストの処理
+                    WebSocketRequest::SetupMfa { player_id } => {
+                        let service =
+                            SetupMfaUseCase::new(player_repository, Arc::new(websocket_sender));
+                        service
+                            .execute(player_id, &event.request_context.connection_id)
+                            .await?;
+                    }
+
+                    // MFA認証の有効化リクエストの処理
+                    WebSocketRequest::EnableMfa { player_id, code } => {
+                        let service =
+                            EnableMfaUseCase::new(player_repository, Arc::new(websocket_sender));
+                        service
+                            .execute(player_id, &code, &event.request_context.connection_id)
+                            .await?;
+                    }
+
+                    // MFA認証の無効化リクエストの処理
+                    WebSocketRequest::DisableMfa { player_id, code } => {
+                        let service =
+                            DisableMfaUseCase::new(player_repository, Arc::new(websocket_sender));
+                        service
+                            .execute(player_id, &code, &event.request_context.connection_id)
+                            .await?;
+                    }
+
+                    // マッチメイキングリクエストの処理
+                    WebSocketRequest::Matchmaking { player_id, units } => {
                         // マッチングリポジトリとサービスの作成
                         let matching_repository =
                             DynamoDbMatchingRepository::new(dynamo_client.clone());
                         let service = MatchmakingApplicationService::new(
                             Arc::new(matching_repository),
-                            Arc::new(connection_repository),
+                            connection_repository,
                             Arc::new(unit_repository),
                             Arc::new(game_repository),
+                            player_repository,
+                            create_matchmaking_strategy(),
+                            Arc::new(game_event_repository),
+                            Arc::new(outbox_repository),
                             Arc::new(websocket_sender),
                         );
                         // マッチメイキング処理を実行
@@ -161,14 +402,88 @@ async fn handler(event: LambdaEvent<WebSocketEvent>) -> Result<Response, Error>
                             .await?;
                     }
 
+                    // ボット対戦リクエストの処理
+                    WebSocketRequest::PlayVsBot { player_id, units } => {
+                        let websocket_sender: Arc<dyn WebSocketSender> = Arc::new(websocket_sender);
+                        let service = PlayVsBotUseCase::new(
+                            Arc::new(game_repository),
+                            Arc::new(unit_repository),
+                            Arc::new(game_event_repository),
+                            create_bot_turn_submitter(&dynamo_client, websocket_sender.clone()),
+                            websocket_sender,
+                        );
+                        service
+                            .execute(&player_id, &event.request_context.connection_id, units)
+                            .await?;
+                    }
+
+                    // プライベートルーム作成リクエストの処理
+                    WebSocketRequest::CreateRoom { player_id, units } => {
+                        let matching_repository =
+                            DynamoDbMatchingRepository::new(dynamo_client.clone());
+                        let service = MatchmakingApplicationService::new(
+                            Arc::new(matching_repository),
+                            connection_repository,
+                            Arc::new(unit_repository),
+                            Arc::new(game_repository),
+                            player_repository,
+                            create_matchmaking_strategy(),
+                            Arc::new(game_event_repository),
+                            Arc::new(outbox_repository),
+                            Arc::new(websocket_sender),
+                        );
+                        service
+                            .create_room(&player_id, &event.request_context.connection_id, units)
+                            .await?;
+                    }
+
+                    // プライベートルーム参加リクエストの処理
+                    WebSocketRequest::JoinRoom {
+                        player_id,
+                        code,
+                        units,
+                    } => {
+                        let matching_repository =
+                            DynamoDbMatchingRepository::new(dynamo_client.clone());
+                        let service = MatchmakingApplicationService::new(
+                            Arc::new(matching_repository),
+                            connection_repository,
+                            Arc::new(unit_repository),
+                            Arc::new(game_repository),
+                            player_repository,
+                            create_matchmaking_strategy(),
+                            Arc::new(game_event_repository),
+                            Arc::new(outbox_repository),
+                            Arc::new(websocket_sender),
+                        );
+                        service
+                            .join_room(
+                                &player_id,
+                                &event.request_context.connection_id,
+                                &code,
+                                units,
+                            )
+                            .await?;
+                    }
+
+                    // マッチングキャンセルリクエストの処理
+                    WebSocketRequest::CancelMatchmaking { player_id } => {
+                        let matching_repository =
+                            DynamoDbMatchingRepository::new(dynamo_client.clone());
+                        let service = CancelMatchmakingUseCase::new(
+                            Arc::new(matching_repository),
+                            Arc::new(unit_repository),
+                            Arc::new(websocket_sender),
+                        );
+                        service
+                            .execute(player_id, &event.request_context.connection_id)
+                            .await?;
+                    }
+
                     // ゲーム状態取得リクエストの処理
                     WebSocketRequest::GetGameState { player_id, game_id } => {
-                        // コネクションIDとPlayerIDの紐付けを保存
-                        connection_repository
-                            .save(player_id.value(), &event.request_context.connection_id)
-                            .await?;
                         let service = GetGameStateUseCase::new(
-                            Arc::new(connection_repository),
+                            connection_repository,
                             Arc::new(game_repository),
                             Arc::new(unit_repository),
                             Arc::new(websocket_sender),
@@ -182,14 +497,108 @@ async fn handler(event: LambdaEvent<WebSocketEvent>) -> Result<Response, Error>
                         player_id,
                         steps,
                     } => {
-                        let service = ProcessTurnUseCase::new(
-                            Arc::new(connection_repository),
+                        let websocket_sender: Arc<dyn WebSocketSender> = Arc::new(websocket_sender);
+                        let service =
+                            create_process_turn_usecase(&dynamo_client, websocket_sender.clone());
+                        service
+                            .execute(game_id.clone(), player_id, StepRequestDto::to_steps(&steps))
+                            .await?;
+                        // ボット対戦の場合は、ボットが次のターンの行動を登録する
+                        create_bot_turn_submitter(&dynamo_client, websocket_sender)
+                            .submit(&GameId::new(game_id))
+                            .await?;
+                    }
+
+                    // ターン結果取得リクエストの処理
+                    WebSocketRequest::GetTurnResult {
+                        player_id,
+                        game_id,
+                        turn_number,
+                    } => {
+                        let service = GetTurnResultUseCase::new(
+                            connection_repository,
+                            Arc::new(game_repository),
+                            Arc::new(turn_repository),
+                            Arc::new(websocket_sender),
+                        );
+                        service.execute(game_id, player_id, turn_number).await?;
+                    }
+
+                    // 戦闘予測リクエストの処理
+                    WebSocketRequest::PreviewCombat {
+                        player_id,
+                        game_id,
+                        attacker_action,
+                        defending_unit_id,
+                    } => {
+                        let service = PreviewCombatUseCase::new(
+                            connection_repository,
+                            Arc::new(game_repository),
+                            Arc::new(unit_repository),
+                            Arc::new(websocket_sender),
+                        );
+                        service
+                            .execute(game_id, player_id, attacker_action, defending_unit_id)
+                            .await?;
+                    }
+
+                    // リプレイ取得リクエストの処理
+                    WebSocketRequest::GetReplay { player_id, game_id } => {
+                        let replay_repository =
+                            DynamoDbReplayRepository::new(dynamo_client.clone());
+                        let service = GetReplayUseCase::new(
+                            connection_repository,
+                            Arc::new(game_repository),
+                            Arc::new(replay_repository),
+                            Arc::new(websocket_sender),
+                        );
+                        service.execute(game_id, player_id).await?;
+                    }
+
+                    // 観戦開始リクエストの処理
+                    WebSocketRequest::SpectateGame {
+                        player_id,
+                        game_id,
+                        view,
+                    } => {
+                        let service = SpectateGameUseCase::new(
+                            Arc::new(game_repository),
+                            Arc::new(turn_repository),
+                            Arc::new(websocket_sender),
+                            create_spectator_delay(),
+                        );
+                        service
+                            .execute(
+                                game_id,
+                                player_id,
+                                view,
+                                &event.request_context.connection_id,
+                            )
+                            .await?;
+                    }
+
+                    // 観戦終了リクエストの処理
+                    WebSocketRequest::StopSpectating { player_id, game_id } => {
+                        let service = StopSpectatingUseCase::new(
+                            Arc::new(game_repository),
+                            Arc::new(websocket_sender),
+                        );
+                        service
+                            .execute(game_id, player_id, &event.request_context.connection_id)
+                            .await?;
+                    }
+
+                    // セッション再開リクエストの処理
+                    WebSocketRequest::ResumeSession { player_id } => {
+                        let service = ResumeSessionUseCase::new(
+                            connection_repository,
                             Arc::new(game_repository),
                             Arc::new(turn_repository),
                             Arc::new(unit_repository),
+                            Arc::new(outbox_repository),
                             Arc::new(websocket_sender),
                         );
-                        service.execute(game_id, player_id, steps).await?;
+                        service.execute(player_id).await?;
                     }
 
                     WebSocketRequest::Ping => {
@@ -68,6 +68,7 @@ func createTables(ctx context.Context, client *dynamodb.Client) error {
 			{AttributeName: aws.String("matching_id"), AttributeType: types.ScalarAttributeTypeS},
 			{AttributeName: aws.String("matching_status"), AttributeType: types.ScalarAttributeTypeS},
 			{AttributeName: aws.String("matching_start_datetime"), AttributeType: types.ScalarAttributeTypeS},
+			{AttributeName: aws.String("room_code"), AttributeType: types.ScalarAttributeTypeS},
 		},
 		KeySchema: []types.KeySchemaElement{
 			{AttributeName: aws.String("matching_id"), KeyType: types.KeyTypeHash},
@@ -81,6 +82,14 @@ func createTables(ctx context.Context, client *dynamodb.Client) error {
 				},
 				Projection: &types.Projection{ProjectionType: types.ProjectionTypeAll},
 			},
+			// プライベートルームの招待コードによる検索用（room_codeを持つアイテムのみ含まれる）
+			{
+				IndexName: aws.String("RoomCodeIndex"),
+				KeySchema: []types.KeySchemaElement{
+					{AttributeName: aws.String("room_code"), KeyType: types.KeyTypeHash},
+				},
+				Projection: &types.Projection{ProjectionType: types.ProjectionTypeAll},
+			},
 		},
 		BillingMode: types.BillingModePayPerRequest,
 	})
@@ -96,11 +105,22 @@ func createTables(ctx context.Context, client *dynamodb.Client) error {
 		TableName: aws.String("Connections"),
 		AttributeDefinitions: []types.AttributeDefinition{
 			{AttributeName: aws.String("player_id"), AttributeType: types.ScalarAttributeTypeS},
+			{AttributeName: aws.String("connection_id"), AttributeType: types.ScalarAttributeTypeS},
 		},
 		KeySchema: []types.KeySchemaElement{
 			// プライマリキーを player_id に設定することで、一意のプレイヤーに対して最新の接続IDを保存できる
 			{AttributeName: aws.String("player_id"), KeyType: types.KeyTypeHash},
 		},
+		// 切断時にコネクションIDからプレイヤーIDを逆引きするためのGSI
+		GlobalSecondaryIndexes: []types.GlobalSecondaryIndex{
+			{
+				IndexName: aws.String("ConnectionIdIndex"),
+				KeySchema: []types.KeySchemaElement{
+					{AttributeName: aws.String("connection_id"), KeyType: types.KeyTypeHash},
+				},
+				Projection: &types.Projection{ProjectionType: types.ProjectionTypeAll},
+			},
+		},
 		BillingMode: types.BillingModePayPerRequest,
 	})
 
@@ -143,10 +163,32 @@ func createTables(ctx context.Context, client *dynamodb.Client) error {
 		TableName: aws.String("Games"),
 		AttributeDefinitions: []types.AttributeDefinition{
 			{AttributeName: aws.String("game_id"), AttributeType: types.ScalarAttributeTypeS},
+			{AttributeName: aws.String("player1_id"), AttributeType: types.ScalarAttributeTypeS},
+			{AttributeName: aws.String("player2_id"), AttributeType: types.ScalarAttributeTypeS},
+			{AttributeName: aws.String("turn_started_at"), AttributeType: types.ScalarAttributeTypeS},
 		},
 		KeySchema: []types.KeySchemaElement{
 			{AttributeName: aws.String("game_id"), KeyType: types.KeyTypeHash},
 		},
+		// 再接続時にプレイヤーIDから参加中の最新のゲームを検索するためのGSI
+		GlobalSecondaryIndexes: []types.GlobalSecondaryIndex{
+			{
+				IndexName: aws.String("Player1IdIndex"),
+				KeySchema: []types.KeySchemaElement{
+					{AttributeName: aws.String("player1_id"), KeyType: types.KeyTypeHash},
+					{AttributeName: aws.String("turn_started_at"), KeyType: types.KeyTypeRange},
+				},
+				Projection: &types.Projection{ProjectionType: types.ProjectionTypeAll},
+			},
+			{
+				IndexName: aws.String("Player2IdIndex"),
+				KeySchema: []types.KeySchemaElement{
+					{AttributeName: aws.String("player2_id"), KeyType: types.KeyTypeHash},
+					{AttributeName: aws.String("turn_started_at"), KeyType: types.KeyTypeRange},
+				},
+				Projection: &types.Projection{ProjectionType: types.ProjectionTypeAll},
+			},
+		},
 		BillingMode: types.BillingModePayPerRequest,
 	})
 
@@ -173,6 +215,83 @@ func createTables(ctx context.Context, client *dynamodb.Client) error {
 	} else {
 		log.Println("✅ Turns table created")
 	}
+
+	// GameEventsテーブル
+	_, errGameEvents := client.CreateTable(ctx, &dynamodb.CreateTableInput{
+		TableName: aws.String("GameEvents"),
+		AttributeDefinitions: []types.AttributeDefinition{
+			{AttributeName: aws.String("game_id"), AttributeType: types.ScalarAttributeTypeS},
+			{AttributeName: aws.String("sequence"), AttributeType: types.ScalarAttributeTypeN},
+		},
+		KeySchema: []types.KeySchemaElement{
+			{AttributeName: aws.String("game_id"), KeyType: types.KeyTypeHash},
+			{AttributeName: aws.String("sequence"), KeyType: types.KeyTypeRange},
+		},
+		BillingMode: types.BillingModePayPerRequest,
+	})
+
+	if errGameEvents != nil {
+		log.Printf("⚠️  GameEvents table: %v", errGameEvents)
+	} else {
+		log.Println("✅ GameEvents table created")
+	}
+
+	// Replaysテーブル
+	_, errReplays := client.CreateTable(ctx, &dynamodb.CreateTableInput{
+		TableName: aws.String("Replays"),
+		AttributeDefinitions: []types.AttributeDefinition{
+			{AttributeName: aws.String("game_id"), AttributeType: types.ScalarAttributeTypeS},
+		},
+		KeySchema: []types.KeySchemaElement{
+			{AttributeName: aws.String("game_id"), KeyType: types.KeyTypeHash},
+		},
+		BillingMode: types.BillingModePayPerRequest,
+	})
+
+	if errReplays != nil {
+		log.Printf("⚠️  Replays table: %v", errReplays)
+	} else {
+		log.Println("✅ Replays table created")
+	}
+
+	// Outboxテーブル
+	_, errOutbox := client.CreateTable(ctx, &dynamodb.CreateTableInput{
+		TableName: aws.String("Outbox"),
+		AttributeDefinitions: []types.AttributeDefinition{
+			{AttributeName: aws.String("player_id"), AttributeType: types.ScalarAttributeTypeS},
+			// sequence = 0 のアイテムはプレイヤーごとの通し番号の採番に使用する
+			{AttributeName: aws.String("sequence"), AttributeType: types.ScalarAttributeTypeN},
+		},
+		KeySchema: []types.KeySchemaElement{
+			{AttributeName: aws.String("player_id"), KeyType: types.KeyTypeHash},
+			{AttributeName: aws.String("sequence"), KeyType: types.KeyTypeRange},
+		},
+		BillingMode: types.BillingModePayPerRequest,
+	})
+
+	if errOutbox != nil {
+		log.Printf("⚠️  Outbox table: %v", errOutbox)
+	} else {
+		log.Println("✅ Outbox table created")
+	}
+
+	// Playersテーブル
+	_, errPlayers := client.CreateTable(ctx, &dynamodb.CreateTableInput{
+		TableName: aws.String("Players"),
+		AttributeDefinitions: []types.AttributeDefinition{
+			{AttributeName: aws.String("player_id"), AttributeType: types.ScalarAttributeTypeS},
+		},
+		KeySchema: []types.KeySchemaElement{
+			{AttributeName: aws.String("player_id"), KeyType: types.KeyTypeHash},
+		},
+		BillingMode: types.BillingModePayPerRequest,
+	})
+
+	if errPlayers != nil {
+		log.Printf("⚠️  Players table: %v", errPlayers)
+	} else {
+		log.Println("✅ Players table created")
+	}
 	return nil
 }
 